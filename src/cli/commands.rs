@@ -41,6 +41,12 @@ pub enum Commands {
     Recent(RecentArgs),
     /// Show dependency tree for a task
     Deps(DepsArgs),
+    /// Order open tasks so every task comes after its dependencies
+    Plan(PlanArgs),
+    /// Export the dependency graph as Graphviz DOT, Mermaid, or JSON
+    Graph(GraphArgs),
+    /// Show an epic's members across tracks and how far they have got
+    Epic(EpicArgs),
     /// Validate project integrity; `--fix` repairs what can be repaired safely
     Check(CheckArgs),
     /// Show project identity (version, name, frame dir, actor, track count)
@@ -61,6 +67,8 @@ pub enum Commands {
     Tag(TagArgs),
     /// Add or remove dependencies
     Dep(DepArgs),
+    /// Add or remove a relation: relates, duplicates, supersedes, epic
+    Relate(RelateArgs),
     /// Set task note
     Note(NoteArgs),
     /// Add, remove or set file references
@@ -69,6 +77,8 @@ pub enum Commands {
     Spec(PathFieldArgs),
     /// Change task title
     Title(TitleArgs),
+    /// Set or clear a custom field declared in [fields]
+    Set(SetArgs),
     /// Move a task (reorder or cross-track)
     Mv(MvArgs),
     /// Triage an inbox item to a track
@@ -81,6 +91,8 @@ pub enum Commands {
     Import(ImportArgs),
     /// Permanently delete tasks
     Delete(DeleteArgs),
+    /// Read or change a project.toml setting
+    Config(ConfigCmd),
     /// Manage project registry
     Projects(ProjectsCmd),
     /// Manage this working copy's actor token
@@ -187,9 +199,14 @@ pub struct ListArgs {
     /// Filter by tag
     #[arg(long)]
     pub tag: Option<String>,
+    /// Filter by custom field (repeatable; a list field matches on any item)
+    #[arg(long, value_name = "KEY=VALUE")]
+    pub field: Vec<String>,
     /// Include all tracks (shelved, archived)
     #[arg(long)]
     pub all: bool,
+    #[command(flatten)]
+    pub scope: ProjectScope,
 }
 
 #[derive(Args)]
@@ -215,6 +232,8 @@ pub struct ReadyArgs {
     /// Filter by tag
     #[arg(long)]
     pub tag: Option<String>,
+    #[command(flatten)]
+    pub scope: ProjectScope,
 }
 
 #[derive(Args)]
@@ -227,6 +246,8 @@ pub struct SearchArgs {
     /// Skip archived tasks (searched by default)
     #[arg(long)]
     pub no_archive: bool,
+    #[command(flatten)]
+    pub scope: ProjectScope,
 }
 
 #[derive(Args)]
@@ -249,6 +270,8 @@ pub struct StatsArgs {
     /// Include shelved tracks
     #[arg(long)]
     pub all: bool,
+    #[command(flatten)]
+    pub scope: ProjectScope,
 }
 
 #[derive(Args)]
@@ -256,12 +279,60 @@ pub struct RecentArgs {
     /// Maximum number of recent items to show
     #[arg(long, default_value = "20")]
     pub limit: usize,
+    #[command(flatten)]
+    pub scope: ProjectScope,
+}
+
+/// Which projects a read command covers. With neither flag, the one it is run
+/// in; with either, projects from the registry (`fr projects`), each result
+/// prefixed with its project's name.
+#[derive(Args)]
+pub struct ProjectScope {
+    /// Run across every registered project
+    #[arg(long, conflicts_with = "projects")]
+    pub all_projects: bool,
+    /// Run across these registered projects (comma-separated names)
+    #[arg(long, value_delimiter = ',', value_name = "NAMES")]
+    pub projects: Vec<String>,
 }
 
 #[derive(Args)]
 pub struct DepsArgs {
     /// Task ID to show dependency tree for
     pub id: String,
+    /// Show only the longest chain of unresolved dependencies leading to the task
+    #[arg(long, conflicts_with = "reverse")]
+    pub critical_path: bool,
+    /// Weigh the critical path by this number field (an estimate) instead of
+    /// counting tasks; a task without it counts as 1
+    #[arg(long, value_name = "FIELD", requires = "critical_path")]
+    pub weight: Option<String>,
+    /// Show what the task blocks (its dependents) instead of what it waits on
+    #[arg(long)]
+    pub reverse: bool,
+}
+
+#[derive(Args)]
+pub struct EpicArgs {
+    /// Epic task ID (default: every task an `epic:` line names)
+    pub id: Option<String>,
+}
+
+#[derive(Args)]
+pub struct GraphArgs {
+    /// Graph one track's dependencies (default: all active tracks)
+    #[arg(long)]
+    pub track: Option<String>,
+    /// Output format (default: dot; `--json` implies json)
+    #[arg(long, value_parser = ["dot", "mermaid", "json"])]
+    pub format: Option<String>,
+}
+
+#[derive(Args)]
+pub struct PlanArgs {
+    /// Plan one track only (default: all active tracks)
+    #[arg(long)]
+    pub track: Option<String>,
 }
 
 // ---------------------------------------------------------------------------
@@ -270,13 +341,16 @@ pub struct DepsArgs {
 
 #[derive(Args)]
 pub struct AddArgs {
-    /// Track to add the task to
+    /// Track to add the task to (may be left out when --template names one)
     pub track: String,
     /// Task title
-    pub title: String,
+    pub title: Option<String>,
     /// Insert after this task ID
     #[arg(long)]
     pub after: Option<String>,
+    /// Fill in tags, note, refs and subtasks from `[templates.NAME]` in project.toml
+    #[arg(long, value_name = "NAME")]
+    pub template: Option<String>,
     /// Note that this task was found while working on another task
     #[arg(long)]
     pub found_from: Option<String>,
@@ -362,6 +436,21 @@ pub struct DepArgs {
     pub dry_run: bool,
 }
 
+#[derive(Args)]
+pub struct RelateArgs {
+    /// Task ID
+    pub id: String,
+    /// Action: "add" or "rm"
+    pub action: String,
+    /// Relation: relates, duplicates, supersedes or epic
+    pub kind: String,
+    /// The task ID it names
+    pub target_id: String,
+    /// Preview without writing: report what would change, and change nothing
+    #[arg(long)]
+    pub dry_run: bool,
+}
+
 #[derive(Args)]
 pub struct NoteArgs {
     /// Task ID
@@ -420,6 +509,23 @@ pub struct TitleArgs {
     pub dry_run: bool,
 }
 
+#[derive(Args)]
+pub struct SetArgs {
+    /// Task ID
+    pub id: String,
+    /// Field name, as declared under [fields] in project.toml
+    pub key: String,
+    /// New value. Omit with --clear.
+    #[arg(required_unless_present = "clear")]
+    pub value: Option<String>,
+    /// Remove the field from the task
+    #[arg(long, conflicts_with = "value")]
+    pub clear: bool,
+    /// Preview without writing: report what would change, and change nothing
+    #[arg(long)]
+    pub dry_run: bool,
+}
+
 #[derive(Args)]
 pub struct MvArgs {
     /// Task ID
@@ -441,6 +547,9 @@ pub struct MvArgs {
     /// Reparent under the given task ID
     #[arg(long)]
     pub parent: Option<String>,
+    /// Move into another registered project (with --track naming its track)
+    #[arg(long, conflicts_with_all = ["promote", "parent", "position"])]
+    pub project: Option<String>,
     /// Preview without writing: report what would change, and change nothing
     #[arg(long)]
     pub dry_run: bool,
@@ -619,6 +728,54 @@ pub struct ImportArgs {
     pub dry_run: bool,
 }
 
+// ---------------------------------------------------------------------------
+// Config
+// ---------------------------------------------------------------------------
+
+#[derive(Args)]
+pub struct ConfigCmd {
+    #[command(subcommand)]
+    pub action: ConfigAction,
+}
+
+#[derive(Subcommand)]
+pub enum ConfigAction {
+    /// Print the value in effect for a key (e.g. clean.done_threshold)
+    Get(ConfigKeyArgs),
+    /// Set a key, refusing a value project.toml would not load with
+    Set(ConfigSetArgs),
+    /// Remove a key, so its default applies
+    Unset(ConfigUnsetArgs),
+    /// Print every setting in effect, defaults included
+    List,
+}
+
+#[derive(Args)]
+pub struct ConfigKeyArgs {
+    /// Dotted key, e.g. ui.tag_colors.bug
+    pub key: String,
+}
+
+#[derive(Args)]
+pub struct ConfigSetArgs {
+    /// Dotted key, e.g. ui.tag_colors.bug
+    pub key: String,
+    /// Value: a TOML value (40, true, ["md"]), or else taken as a string
+    pub value: String,
+    /// Preview without writing: report what would change, and change nothing
+    #[arg(long)]
+    pub dry_run: bool,
+}
+
+#[derive(Args)]
+pub struct ConfigUnsetArgs {
+    /// Dotted key, e.g. ui.tag_colors.bug
+    pub key: String,
+    /// Preview without writing: report what would change, and change nothing
+    #[arg(long)]
+    pub dry_run: bool,
+}
+
 // ---------------------------------------------------------------------------
 // Project registry
 // ---------------------------------------------------------------------------
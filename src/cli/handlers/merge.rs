@@ -37,7 +37,9 @@
 //! to a straight answer.
 
 use crate::cli::commands::MergeArgs;
+use crate::model::config::ProjectConfig;
 use crate::ops::merge_files::{self, FileKind, MergeReport};
+use crate::parse::Schema;
 
 /// Merged cleanly.
 const EXIT_MERGED: i32 = 0;
@@ -83,6 +85,7 @@ pub fn cmd_merge(args: MergeArgs) -> i32 {
         std::path::Path::new(ours),
         std::path::Path::new(theirs),
         &stamp,
+        &declared_schema(),
     ) {
         Ok(report) => report,
         Err(e) => {
@@ -110,6 +113,24 @@ pub fn cmd_merge(args: MergeArgs) -> i32 {
     EXIT_CONFLICT
 }
 
+/// What the project being merged declares, so its custom fields, sections and
+/// states merge as what they are.
+///
+/// Git runs the driver at the top of the work tree, which is where the project
+/// is found from. `project.toml` alone, without the user config: declarations
+/// are the project's. No project, or a config that does not parse, reads the
+/// files as a project that declares nothing — the merge still runs, as it did
+/// before there was anything to declare.
+fn declared_schema() -> Schema {
+    std::env::current_dir()
+        .ok()
+        .and_then(|cwd| crate::io::project_io::discover_project(&cwd).ok())
+        .and_then(|root| std::fs::read_to_string(root.join("frame/project.toml")).ok())
+        .and_then(|text| toml::from_str::<ProjectConfig>(&text).ok())
+        .map(|config| Schema::of(&config))
+        .unwrap_or_default()
+}
+
 /// Clear the `conflict:` marker on tasks whose conflict has been dealt with.
 ///
 /// A normal write command, unlike the driver above: it takes the lock, loads the
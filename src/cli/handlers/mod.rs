@@ -22,14 +22,16 @@ use crate::io::dryrun;
 use crate::io::lock::FileLock;
 use crate::io::project_io::{self, ProjectError};
 use crate::io::registry;
+use crate::model::config::{FieldKind, FieldSchema, TaskTemplate, WorkflowConfig};
 use crate::model::inbox::Inbox;
 use crate::model::project::Project;
-use crate::model::task::{Metadata, Task, TaskState};
+use crate::model::task::{Metadata, RelationKind, Task, TaskState};
 use crate::model::track::{Track, TrackNode};
 use crate::ops::ids::Mint;
 use crate::ops::task_ops::PathField;
 use crate::ops::{
-    actor_merge, check, clean, deps, fix, import, inbox_ops, refs, search, task_ops, track_ops,
+    actor_merge, check, clean, config_edit, deps, fix, import, inbox_ops, refs, relations, resolve,
+    search, task_ops, track_ops,
 };
 
 // ---------------------------------------------------------------------------
@@ -63,7 +65,7 @@ fn dispatch_command(
             eprintln!("TUI not yet implemented. Use a subcommand (try `fr --help`).");
             Ok(())
         }
-        Some(cmd) => match cmd {
+        Some(cmd) => match resolve_task_args(cmd)? {
             // Init is handled in main.rs before project discovery
             Commands::Init(args) => cmd_init(args, json),
 
@@ -83,6 +85,9 @@ fn dispatch_command(
             // Repo configuration, not project content
             Commands::Git(args) => cmd_git(args, json),
 
+            // Project configuration (project.toml)
+            Commands::Config(args) => cmd_config(args, json),
+
             // Project registry (doesn't require a project context)
             Commands::Projects(args) => cmd_projects(args, json),
 
@@ -106,6 +111,9 @@ fn dispatch_command(
             Commands::Stats(args) => cmd_stats(args, json),
             Commands::Recent(args) => cmd_recent(args, json),
             Commands::Deps(args) => cmd_deps(args, json),
+            Commands::Plan(args) => cmd_plan(args, json),
+            Commands::Epic(args) => cmd_epic(args, json),
+            Commands::Graph(args) => cmd_graph(args, json),
             Commands::Check(args) => cmd_check(args, json),
             Commands::Info => cmd_info(json),
 
@@ -118,10 +126,12 @@ fn dispatch_command(
             Commands::Done(args) => cmd_done(args, json),
             Commands::Tag(args) => cmd_tag(args, json),
             Commands::Dep(args) => cmd_dep(args, json),
+            Commands::Relate(args) => cmd_relate(args, json),
             Commands::Note(args) => cmd_note(args, json),
             Commands::Ref(args) => cmd_ref(args, json),
             Commands::Spec(args) => cmd_spec(args, json),
             Commands::Title(args) => cmd_title(args, json),
+            Commands::Set(args) => cmd_set(args, json),
             Commands::Mv(args) => cmd_mv(args, json),
             Commands::Triage(args) => cmd_triage(args, json),
 
@@ -143,10 +153,152 @@ fn dispatch_command(
 // Helpers
 // ---------------------------------------------------------------------------
 
+/// Rewrite every task ID argument `cmd` carries to the canonical ID it names —
+/// `eff14`, `14` or a title fragment for `EFF-014` — before any handler sees
+/// it, so each handler keeps comparing IDs exactly. See [`resolve`].
+///
+/// An argument that names a task in the command's track (`add`'s track,
+/// `mv --track`) looks a bare number up there first. A project that will not
+/// load is left for the handler to report.
+fn resolve_task_args(mut cmd: Commands) -> Result<Commands, Box<dyn std::error::Error>> {
+    let unscoped = |arg| (arg, None);
+    // `fr add "title" --template NAME`: the one positional is the title, and
+    // --after names a task in the template's track, known once the project is.
+    let mut template_after: Option<(&mut String, &str)> = None;
+    let args: Vec<(&mut String, Option<&str>)> = match &mut cmd {
+        Commands::Add(a) => match (&a.title, &a.template) {
+            (None, Some(template)) => {
+                template_after = a.after.as_mut().map(|after| (after, template.as_str()));
+                a.found_from.as_mut().map(unscoped).into_iter().collect()
+            }
+            _ => a
+                .after
+                .iter_mut()
+                .map(|after| (after, Some(a.track.as_str())))
+                .chain(a.found_from.as_mut().map(unscoped))
+                .collect(),
+        },
+        Commands::Show(a) => vec![unscoped(&mut a.id)],
+        Commands::Deps(a) => vec![unscoped(&mut a.id)],
+        Commands::Epic(a) => a.id.iter_mut().map(unscoped).collect(),
+        Commands::Sub(a) => vec![unscoped(&mut a.id)],
+        Commands::State(a) => vec![unscoped(&mut a.id)],
+        Commands::Start(a) => vec![unscoped(&mut a.id)],
+        Commands::Done(a) => vec![unscoped(&mut a.id)],
+        Commands::Tag(a) => vec![unscoped(&mut a.id)],
+        Commands::Dep(a) => vec![unscoped(&mut a.id), unscoped(&mut a.dep_id)],
+        Commands::Relate(a) => vec![unscoped(&mut a.id), unscoped(&mut a.target_id)],
+        Commands::Note(a) => vec![unscoped(&mut a.id)],
+        Commands::Ref(a) | Commands::Spec(a) => vec![unscoped(&mut a.id)],
+        Commands::Title(a) => vec![unscoped(&mut a.id)],
+        Commands::Set(a) => vec![unscoped(&mut a.id)],
+        // The task being moved is wherever it is now; --after names a task in
+        // the destination track. With --project it names a task in the other
+        // project, which this one cannot resolve.
+        Commands::Mv(a) => std::iter::once(unscoped(&mut a.id))
+            .chain(
+                a.after
+                    .as_mut()
+                    .filter(|_| a.project.is_none())
+                    .map(|after| (after, a.track.as_deref())),
+            )
+            .chain(a.parent.as_mut().map(unscoped))
+            .collect(),
+        Commands::Delete(a) => a.ids.iter_mut().map(unscoped).collect(),
+        Commands::Triage(a) => a
+            .after
+            .iter_mut()
+            .map(|after| (after, Some(a.track.as_str())))
+            .collect(),
+        Commands::Import(a) => a
+            .after
+            .iter_mut()
+            .map(|after| (after, Some(a.track.as_str())))
+            .collect(),
+        Commands::Merge(a) => a.resolve.iter_mut().map(unscoped).collect(),
+        // Recovery `--for` is resolved by its handler, which looks for the
+        // input as given first.
+        _ => Vec::new(),
+    };
+    if args.is_empty() && template_after.is_none() {
+        return Ok(cmd);
+    }
+    let Ok(project) = load_project_cwd() else {
+        return Ok(cmd);
+    };
+    for (arg, track) in args {
+        *arg = resolve::resolve_task_id(&project, arg, track)?;
+    }
+    if let Some((after, template)) = template_after {
+        let track = project
+            .config
+            .templates
+            .get(template)
+            .and_then(|t| t.track.as_deref());
+        *after = resolve::resolve_task_id(&project, after, track)?;
+    }
+    Ok(cmd)
+}
+
 fn load_project_cwd() -> Result<Project, ProjectError> {
     load_project_at(&discover_project_root()?)
 }
 
+/// A project a read command covers, with the registry name its results are
+/// prefixed with.
+type ScopedProject = (Option<String>, Project);
+
+/// The projects a read command covers, each paired with the name its results
+/// are prefixed with — `None` for the project the command was run in, whose
+/// output is exactly what it was before `--all-projects` existed.
+///
+/// A registered project that will not load is skipped with a warning on
+/// stderr, so one stale entry does not cost the whole view. A name the
+/// registry does not hold is an error: it was asked for by name.
+fn load_scope(scope: &ProjectScope) -> Result<Vec<ScopedProject>, Box<dyn std::error::Error>> {
+    if !scope.all_projects && scope.projects.is_empty() {
+        return Ok(vec![(None, load_project_cwd()?)]);
+    }
+    let reg = registry::read_registry();
+    let entries: Vec<&registry::ProjectEntry> = if scope.all_projects {
+        // A worktree is the same project on another branch; listing it too
+        // would show every task twice.
+        reg.projects
+            .iter()
+            .filter(|e| e.worktree_of.is_none())
+            .collect()
+    } else {
+        scope
+            .projects
+            .iter()
+            .map(|name| {
+                registry::find_by_name(&reg, name)
+                    .ok_or_else(|| format!("no project named '{}' in the registry", name))
+            })
+            .collect::<Result<_, _>>()?
+    };
+    let mut out = Vec::new();
+    for entry in entries {
+        match project_io::load_project(Path::new(&entry.path)) {
+            Ok(project) => out.push((Some(entry.name.clone()), project)),
+            Err(e) => eprintln!(
+                "warning: project '{}' skipped ({}: {})",
+                entry.name, entry.path, e
+            ),
+        }
+    }
+    Ok(out)
+}
+
+/// A track id as a multi-project listing prints it: `lib:main`, the form a
+/// cross-project dep uses, or plain `main` for the current project.
+fn scoped_track(project: Option<&str>, track_id: &str) -> String {
+    match project {
+        Some(name) => format!("{}:{}", name, track_id),
+        None => track_id.to_string(),
+    }
+}
+
 /// Load and register a project whose root is already known.
 ///
 /// Split out so [`lock_and_load`] can discover the root, take the lock, and
@@ -285,10 +437,16 @@ fn save_track(project: &Project, track_id: &str) -> Result<(), ProjectError> {
 }
 
 /// Check if a task has unresolved (non-done) deps
-fn has_unresolved_deps(task: &Task, project: &Project) -> bool {
+fn has_unresolved_deps(task: &Task, project: &Project, external: &deps::ExternalDeps) -> bool {
     for m in &task.metadata {
         if let Metadata::Dep(deps) = m {
             for dep_id in deps {
+                if deps::external_ref(dep_id).is_some() {
+                    if external.is_open(dep_id) {
+                        return true;
+                    }
+                    continue;
+                }
                 // Find the dep task and check if it's done
                 for (_, track) in &project.tracks {
                     if let Some(dep_task) = task_ops::find_task_in_track(track, dep_id)
@@ -303,6 +461,17 @@ fn has_unresolved_deps(task: &Task, project: &Project) -> bool {
     false
 }
 
+/// Say which projects named by a cross-project dep could not be loaded. To
+/// stderr, so it reaches a human either way and never a `--json` parser.
+fn warn_unreachable(external: &deps::ExternalDeps) {
+    for (name, reason) in &external.unreachable {
+        eprintln!(
+            "warning: project '{}' is unreachable ({}); its deps are not counted",
+            name, reason
+        );
+    }
+}
+
 /// Find which track a task ID belongs to
 fn find_task_track<'a>(project: &'a Project, task_id: &str) -> Option<&'a str> {
     for (track_id, track) in &project.tracks {
@@ -359,43 +528,79 @@ fn resolved_date(task: &Task) -> String {
 // ---------------------------------------------------------------------------
 
 fn cmd_list(args: ListArgs, json: bool) -> Result<(), Box<dyn std::error::Error>> {
-    let project = load_project_cwd()?;
-    let state_filter = args
-        .state
-        .as_deref()
-        .map(parse_task_state)
-        .transpose()
-        .map_err(Box::<dyn std::error::Error>::from)?;
+    let scope = load_scope(&args.scope)?;
+    // Each project names its own custom states, so `--state` is read per
+    // project. It is an error only when no project in scope knows the name;
+    // a project that does not is simply listed without a match.
+    let state_in = |project: &Project| {
+        args.state
+            .as_deref()
+            .map(|s| parse_task_state(s, &project.config.workflow))
+            .transpose()
+    };
+    if let Some(Err(e)) = scope
+        .iter()
+        .map(|(_, p)| state_in(p))
+        .reduce(|a, b| a.or(b))
+    {
+        return Err(e.into());
+    }
     let tag_filter = args.tag.as_deref();
+    let field_args = args
+        .field
+        .iter()
+        .map(|f| {
+            f.split_once('=')
+                .ok_or_else(|| format!("--field expects KEY=VALUE, got '{}'", f))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    // Each project types its own fields.
+    let field_filters = |project| field_filters(&field_args, project);
 
     // Both surfaces walk the same tracks, in the same order, with the same
     // tasks selected from each. Only the rendering below differs.
-    let listed: Vec<(&String, &Track)> = project
-        .tracks
+    let listed: Vec<(Option<&str>, &Project, &String, &Track)> = scope
         .iter()
-        .filter(|(track_id, _)| track_is_listed(&project, track_id, &args))
-        .map(|(track_id, track)| (track_id, track))
+        .flat_map(|(name, project)| {
+            project
+                .tracks
+                .iter()
+                .filter(|(track_id, _)| track_is_listed(project, track_id, &args))
+                .filter(|_| state_in(project).is_ok())
+                .map(move |(track_id, track)| (name.as_deref(), project, track_id, track))
+        })
         .collect();
 
     if json {
         let results: Vec<TaskListJson> = listed
             .iter()
-            .map(|(track_id, track)| TaskListJson {
+            .map(|(name, project, track_id, track)| TaskListJson {
+                project: name.map(str::to_string),
                 track: (*track_id).clone(),
-                tasks: select_tasks(track, state_filter, tag_filter)
-                    .all()
-                    .map(task_to_json)
-                    .collect(),
+                tasks: select_tasks(
+                    track,
+                    state_in(project).ok().flatten(),
+                    tag_filter,
+                    &field_filters(project),
+                )
+                .all()
+                .map(|task| task_to_json(task, &project.config.workflow))
+                .collect(),
             })
             .collect();
         println!("{}", serde_json::to_string_pretty(&results)?);
     } else {
-        for (i, (track_id, track)) in listed.iter().enumerate() {
+        for (i, (name, project, track_id, track)) in listed.iter().enumerate() {
             if i > 0 {
                 println!();
             }
-            let tasks = select_tasks(track, state_filter, tag_filter);
-            for line in format_track_listing(track_id, track, &tasks) {
+            let tasks = select_tasks(
+                track,
+                state_in(project).ok().flatten(),
+                tag_filter,
+                &field_filters(project),
+            );
+            for line in format_track_listing(&scoped_track(*name, track_id), track, &tasks) {
                 println!("{}", line);
             }
         }
@@ -403,6 +608,17 @@ fn cmd_list(args: ListArgs, json: bool) -> Result<(), Box<dyn std::error::Error>
     Ok(())
 }
 
+/// `--field` filters for one project, typed by its `[fields]`.
+fn field_filters<'a>(args: &'a [(&'a str, &'a str)], project: &'a Project) -> Vec<FieldFilter<'a>> {
+    args.iter()
+        .map(|&(key, wanted)| FieldFilter {
+            key,
+            wanted,
+            schema: project.config.fields.get(key),
+        })
+        .collect()
+}
+
 /// Whether `fr list` shows this track: the one named by the positional
 /// argument, or — with no argument and without `--all` — every active track.
 fn track_is_listed(project: &Project, track_id: &str, args: &ListArgs) -> bool {
@@ -431,6 +647,7 @@ fn track_is_listed(project: &Project, track_id: &str, args: &ListArgs) -> bool {
 /// not.
 fn cmd_show(args: ShowArgs, json: bool) -> Result<(), Box<dyn std::error::Error>> {
     let project = load_project_cwd()?;
+    let backlinks = relations::backlinks(&project, &args.id);
 
     for (_, track) in &project.tracks {
         if let Some(task) = task_ops::find_task_in_track(track, &args.id) {
@@ -438,7 +655,15 @@ fn cmd_show(args: ShowArgs, json: bool) -> Result<(), Box<dyn std::error::Error>
                 .iter()
                 .filter_map(|aid| task_ops::find_task_in_track(track, aid))
                 .collect();
-            return print_task_detail(&args, json, task, &ancestors, None);
+            return print_task_detail(
+                &args,
+                json,
+                &project.config.workflow,
+                task,
+                &ancestors,
+                None,
+                &backlinks,
+            );
         }
     }
 
@@ -447,14 +672,25 @@ fn cmd_show(args: ShowArgs, json: bool) -> Result<(), Box<dyn std::error::Error>
     // `fr show` is the only surface that can read it — the TUI's own archive
     // hits are read-only stubs. Not finding it here was the bug.
     if !args.no_archive {
-        for list in project_io::archived_task_lists(&project.frame_dir) {
+        for list in project_io::archived_task_lists(
+            &project.frame_dir,
+            &crate::parse::Schema::of(&project.config),
+        ) {
             if let Some(task) = find_task_by_id(&list.tasks, &args.id) {
                 let origin = ArchivedIn::new(&list.track_id, &list.file);
                 let ancestors: Vec<&Task> = collect_ancestor_ids(&args.id)
                     .iter()
                     .filter_map(|aid| find_task_by_id(&list.tasks, aid))
                     .collect();
-                return print_task_detail(&args, json, task, &ancestors, Some(&origin));
+                return print_task_detail(
+                    &args,
+                    json,
+                    &project.config.workflow,
+                    task,
+                    &ancestors,
+                    Some(&origin),
+                    &backlinks,
+                );
             }
         }
     }
@@ -467,26 +703,38 @@ fn cmd_show(args: ShowArgs, json: bool) -> Result<(), Box<dyn std::error::Error>
 /// prints — plus the `archived:` line, which is the only difference between them.
 ///
 /// `ancestors` are resolved by the caller, against the same container the task
-/// came out of.
+/// came out of. `backlinks` come from the live tracks either way: a task still
+/// in play can name one already archived.
 fn print_task_detail(
     args: &ShowArgs,
     json: bool,
+    workflow: &WorkflowConfig,
     task: &Task,
     ancestors: &[&Task],
     archived: Option<&ArchivedIn>,
+    backlinks: &[relations::Backlink],
 ) -> Result<(), Box<dyn std::error::Error>> {
     if json {
-        let mut tj = task_to_json(task);
+        let mut tj = task_to_json(task, workflow);
         tj.archived = archived.cloned();
+        for link in backlinks {
+            tj.related_from
+                .entry(link.kind.key().to_string())
+                .or_default()
+                .push(link.source.clone());
+        }
         // JSON always includes ancestors
-        tj.ancestors = ancestors.iter().map(|a| task_to_json(a)).collect();
+        tj.ancestors = ancestors
+            .iter()
+            .map(|a| task_to_json(a, workflow))
+            .collect();
         println!("{}", serde_json::to_string_pretty(&tj)?);
     } else if args.context {
-        for line in format_task_detail_with_context(ancestors, task, archived) {
+        for line in format_task_detail_with_context(ancestors, task, archived, backlinks) {
             println!("{}", line);
         }
     } else {
-        for line in format_task_detail(task, archived) {
+        for line in format_task_detail(task, archived, backlinks) {
             println!("{}", line);
         }
     }
@@ -499,8 +747,9 @@ fn print_task_detail(
 /// otherwise; it answers the question the bare message provoked, which is "but I
 /// can see it in the file". The archives are read only on the error path, so a
 /// command that succeeds never pays for this.
-fn task_not_found(frame_dir: &Path, id: &str) -> String {
-    for list in project_io::archived_task_lists(frame_dir) {
+fn task_not_found(project: &Project, id: &str) -> String {
+    let schema = crate::parse::Schema::of(&project.config);
+    for list in project_io::archived_task_lists(&project.frame_dir, &schema) {
         if find_task_by_id(&list.tasks, id).is_some() {
             return format!(
                 "task not found: {id} (archived in {}, frame/{}; read it with `fr show {id}`)",
@@ -525,7 +774,47 @@ fn collect_ancestor_ids(task_id: &str) -> Vec<String> {
 }
 
 fn cmd_ready(args: ReadyArgs, json: bool) -> Result<(), Box<dyn std::error::Error>> {
-    let project = load_project_cwd()?;
+    let scope = load_scope(&args.scope)?;
+    let ready_tasks: Vec<(Option<&str>, &Project, String, &Task)> = scope
+        .iter()
+        .flat_map(|(name, project)| {
+            ready_tasks_in(project, &args)
+                .into_iter()
+                .map(move |(track_id, task)| (name.as_deref(), project, track_id, task))
+        })
+        .collect();
+
+    if json {
+        // The focus track is one project's setting; across several there is no
+        // single answer to give.
+        let single = match scope.as_slice() {
+            [(None, project)] if args.cc => Some(project),
+            _ => None,
+        };
+        let output = ReadyJson {
+            focus_track: single.and_then(|p| p.config.agent.cc_focus.clone()),
+            cc_only: single.map(|p| p.config.agent.cc_only),
+            tasks: ready_tasks
+                .iter()
+                .map(|(name, project, tid, task)| TaskWithTrackJson {
+                    project: name.map(str::to_string),
+                    track: tid.clone(),
+                    task: task_to_json(task, &project.config.workflow),
+                })
+                .collect(),
+        };
+        println!("{}", serde_json::to_string_pretty(&output)?);
+    } else {
+        for (name, _, track_id, task) in &ready_tasks {
+            let line = format_task_line(task);
+            println!("[{}] {}", scoped_track(*name, track_id), line);
+        }
+    }
+    Ok(())
+}
+
+/// The tasks `fr ready` lists from one project, in display order.
+fn ready_tasks_in<'a>(project: &'a Project, args: &ReadyArgs) -> Vec<(String, &'a Task)> {
     let mut ready_tasks: Vec<(String, &Task)> = Vec::new();
 
     let target_tracks: Vec<&str> = if args.cc {
@@ -553,55 +842,28 @@ fn cmd_ready(args: ReadyArgs, json: bool) -> Result<(), Box<dyn std::error::Erro
             .collect()
     };
 
+    let external = deps::ExternalDeps::load(project);
+    warn_unreachable(&external);
     for track_id in &target_tracks {
-        if let Some(track) = find_track(&project, track_id) {
+        if let Some(track) = find_track(project, track_id) {
             let backlog = track.backlog();
             for task in backlog {
-                collect_ready_tasks(task, track_id, &project, &args, &mut ready_tasks);
+                collect_ready_tasks(task, track_id, project, &external, args, &mut ready_tasks);
             }
         }
     }
-
-    if json {
-        let focus_track = if args.cc {
-            project.config.agent.cc_focus.clone()
-        } else {
-            None
-        };
-        let cc_only = if args.cc {
-            Some(project.config.agent.cc_only)
-        } else {
-            None
-        };
-        let output = ReadyJson {
-            focus_track,
-            cc_only,
-            tasks: ready_tasks
-                .iter()
-                .map(|(tid, task)| TaskWithTrackJson {
-                    track: tid.clone(),
-                    task: task_to_json(task),
-                })
-                .collect(),
-        };
-        println!("{}", serde_json::to_string_pretty(&output)?);
-    } else {
-        for (track_id, task) in &ready_tasks {
-            let line = format_task_line(task);
-            println!("[{}] {}", track_id, line);
-        }
-    }
-    Ok(())
+    ready_tasks
 }
 
 fn collect_ready_tasks<'a>(
     task: &'a Task,
     track_id: &str,
     project: &'a Project,
+    external: &deps::ExternalDeps,
     args: &ReadyArgs,
     result: &mut Vec<(String, &'a Task)>,
 ) {
-    if task.state == TaskState::Todo && !has_unresolved_deps(task, project) {
+    if task.state == TaskState::Todo && !has_unresolved_deps(task, project, external) {
         let mut include = true;
         if args.cc && !task.tags.iter().any(|t| t == "cc") {
             include = false;
@@ -617,7 +879,7 @@ fn collect_ready_tasks<'a>(
     }
     // Also check subtasks
     for sub in &task.subtasks {
-        collect_ready_tasks(sub, track_id, project, args, result);
+        collect_ready_tasks(sub, track_id, project, external, args, result);
     }
 }
 
@@ -647,8 +909,9 @@ fn cmd_blocked(json: bool) -> Result<(), Box<dyn std::error::Error>> {
         let output: Vec<TaskWithTrackJson> = blocked_tasks
             .iter()
             .map(|(tid, task)| TaskWithTrackJson {
+                project: None,
                 track: tid.clone(),
-                task: task_to_json(task),
+                task: task_to_json(task, &project.config.workflow),
             })
             .collect();
         println!("{}", serde_json::to_string_pretty(&output)?);
@@ -728,11 +991,16 @@ fn group_task_hits<'a>(
     out
 }
 
-fn hits_to_json(hits: &[SearchTaskHit]) -> Vec<SearchHitJson> {
+fn hits_to_json(
+    hits: &[SearchTaskHit],
+    project: Option<&str>,
+    workflow: &WorkflowConfig,
+) -> Vec<SearchHitJson> {
     hits.iter()
         .map(|hit| SearchHitJson {
+            project: project.map(str::to_string),
             track: hit.track_id.clone(),
-            task: hit.task.map(task_to_json),
+            task: hit.task.map(|task| task_to_json(task, workflow)),
             matched_fields: hit.fields.iter().map(|f| f.to_string()).collect(),
         })
         .collect()
@@ -753,13 +1021,53 @@ fn format_search_hits(hits: &[SearchTaskHit], prefix: &str) -> Vec<String> {
 }
 
 fn cmd_search(args: SearchArgs, json: bool) -> Result<(), Box<dyn std::error::Error>> {
-    let project = load_project_cwd()?;
+    let scope = load_scope(&args.scope)?;
     let re = Regex::new(&args.pattern)?;
 
+    let mut output = SearchJson {
+        pattern: args.pattern.clone(),
+        tasks: Vec::new(),
+        archived: Vec::new(),
+        inbox: Vec::new(),
+    };
+    let mut lines = Vec::new();
+    for (name, project) in &scope {
+        search_project(
+            name.as_deref(),
+            project,
+            &re,
+            &args,
+            &mut output,
+            &mut lines,
+        )?;
+    }
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&output)?);
+    } else {
+        for line in lines {
+            println!("{}", line);
+        }
+    }
+    Ok(())
+}
+
+/// One project's share of `fr search`, added to both surfaces. Across several
+/// projects the human lines stay grouped by project — live, archived, inbox,
+/// then the next — and the JSON arrays hold every project's hits in the same
+/// order.
+fn search_project(
+    name: Option<&str>,
+    project: &Project,
+    re: &Regex,
+    args: &SearchArgs,
+    output: &mut SearchJson,
+    lines: &mut Vec<String>,
+) -> Result<(), Box<dyn std::error::Error>> {
     let live = group_task_hits(
-        &search::search_tasks(&project, &re, args.track.as_deref()),
+        &search::search_tasks(project, re, args.track.as_deref()),
         |track_id, task_id| {
-            find_track(&project, track_id)
+            find_track(project, track_id)
                 .and_then(|track| task_ops::find_task_in_track(track, task_id))
         },
     );
@@ -773,7 +1081,7 @@ fn cmd_search(args: SearchArgs, json: bool) -> Result<(), Box<dyn std::error::Er
         project_io::load_archives(&project.frame_dir)?
     };
     let archived = group_task_hits(
-        &search::search_archive_tasks(&archives, &re, args.track.as_deref()),
+        &search::search_archive_tasks(&archives, re, args.track.as_deref()),
         |track_id, task_id| {
             archives
                 .iter()
@@ -788,7 +1096,7 @@ fn cmd_search(args: SearchArgs, json: bool) -> Result<(), Box<dyn std::error::Er
         && let Some(ref inbox) = project.inbox
     {
         let mut seen: std::collections::HashMap<usize, usize> = std::collections::HashMap::new();
-        for hit in &search::search_inbox(inbox, &re) {
+        for hit in &search::search_inbox(inbox, re) {
             let name = hit.field.name();
             match seen.get(&hit.item_index) {
                 Some(&i) => {
@@ -810,46 +1118,49 @@ fn cmd_search(args: SearchArgs, json: bool) -> Result<(), Box<dyn std::error::Er
         }
     }
 
-    if json {
-        let output = SearchJson {
-            pattern: args.pattern.clone(),
-            tasks: hits_to_json(&live),
-            archived: hits_to_json(&archived),
-            inbox: inbox_hits
-                .iter()
-                .map(|hit| InboxSearchHitJson {
-                    index: hit.index + 1,
-                    title: hit.item.title.clone(),
-                    tags: hit.item.tags.clone(),
-                    body: hit.item.body.clone(),
-                    matched_fields: hit.fields.iter().map(|f| f.to_string()).collect(),
-                })
-                .collect(),
+    let workflow = &project.config.workflow;
+    output.tasks.extend(hits_to_json(&live, name, workflow));
+    output
+        .archived
+        .extend(hits_to_json(&archived, name, workflow));
+    output
+        .inbox
+        .extend(inbox_hits.iter().map(|hit| InboxSearchHitJson {
+            project: name.map(str::to_string),
+            index: hit.index + 1,
+            title: hit.item.title.clone(),
+            tags: hit.item.tags.clone(),
+            body: hit.item.body.clone(),
+            matched_fields: hit.fields.iter().map(|f| f.to_string()).collect(),
+        }));
+
+    let prefix = scoped_track(name, "");
+    lines.extend(format_search_hits(&live, &prefix));
+    lines.extend(format_search_hits(
+        &archived,
+        &format!("{}archive:", prefix),
+    ));
+    for hit in &inbox_hits {
+        let tags = if hit.item.tags.is_empty() {
+            String::new()
+        } else {
+            format!(
+                " {}",
+                hit.item
+                    .tags
+                    .iter()
+                    .map(|t| format!("#{}", t))
+                    .collect::<Vec<_>>()
+                    .join(" ")
+            )
         };
-        println!("{}", serde_json::to_string_pretty(&output)?);
-    } else {
-        for line in format_search_hits(&live, "") {
-            println!("{}", line);
-        }
-        for line in format_search_hits(&archived, "archive:") {
-            println!("{}", line);
-        }
-        for hit in &inbox_hits {
-            let tags = if hit.item.tags.is_empty() {
-                String::new()
-            } else {
-                format!(
-                    " {}",
-                    hit.item
-                        .tags
-                        .iter()
-                        .map(|t| format!("#{}", t))
-                        .collect::<Vec<_>>()
-                        .join(" ")
-                )
-            };
-            println!("[inbox:{}] {}{}", hit.index + 1, hit.item.title, tags);
-        }
+        lines.push(format!(
+            "[{}inbox:{}] {}{}",
+            prefix,
+            hit.index + 1,
+            hit.item.title,
+            tags
+        ));
     }
 
     Ok(())
@@ -1094,6 +1405,14 @@ fn cmd_info(json: bool) -> Result<(), Box<dyn std::error::Error>> {
         .count();
     let archived = project.config.tracks.len() - active - shelved;
 
+    // The per-user config layered over this project, and which of its keys
+    // are in effect here. It already loaded, or the project would not have.
+    let user_config_path = crate::io::user_config::path();
+    let user_keys = crate::io::user_config::read()?.map(|user| {
+        let text = std::fs::read_to_string(frame_dir.join("project.toml")).unwrap_or_default();
+        crate::io::user_config::provided(&user, &text)
+    });
+
     if json {
         #[derive(serde::Serialize)]
         struct FrontierJson {
@@ -1107,6 +1426,14 @@ fn cmd_info(json: bool) -> Result<(), Box<dyn std::error::Error>> {
             recorded: std::collections::BTreeMap<String, u32>,
         }
         #[derive(serde::Serialize)]
+        struct UserConfigJson {
+            path: String,
+            /// Keys the file sets that are in effect here.
+            applied: Vec<String>,
+            /// Shared keys it sets that this project's own value overrides.
+            overridden: Vec<String>,
+        }
+        #[derive(serde::Serialize)]
         struct InfoJson {
             /// Bare crate version, so consumers can parse it as-is. The build's
             /// commit is a separate field.
@@ -1130,6 +1457,8 @@ fn cmd_info(json: bool) -> Result<(), Box<dyn std::error::Error>> {
             shelved_tracks: usize,
             archived_tracks: usize,
             id_frontier: FrontierJson,
+            /// `null` when there is no user config.
+            user_config: Option<UserConfigJson>,
         }
         let info = InfoJson {
             version: crate::version::VERSION.to_string(),
@@ -1157,6 +1486,19 @@ fn cmd_info(json: bool) -> Result<(), Box<dyn std::error::Error>> {
                     .map_or_else(|| "null".to_string(), |t| t.as_str().to_string()),
                 recorded: frontier,
             },
+            user_config: user_keys.as_ref().map(|keys| UserConfigJson {
+                path: user_config_path.display().to_string(),
+                applied: keys
+                    .iter()
+                    .filter(|(_, applied)| *applied)
+                    .map(|(k, _)| k.clone())
+                    .collect(),
+                overridden: keys
+                    .iter()
+                    .filter(|(_, applied)| !*applied)
+                    .map(|(k, _)| k.clone())
+                    .collect(),
+            }),
         };
         println!("{}", serde_json::to_string_pretty(&info)?);
         return Ok(());
@@ -1204,42 +1546,82 @@ fn cmd_info(json: bool) -> Result<(), Box<dyn std::error::Error>> {
         summary,
         frontier_health.path.display()
     );
+
+    let user_summary = match &user_keys {
+        None => "none".to_string(),
+        Some(keys) => {
+            let list = |applied: bool| {
+                keys.iter()
+                    .filter(|(_, a)| *a == applied)
+                    .map(|(k, _)| k.as_str())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            };
+            let (applied, overridden) = (list(true), list(false));
+            match (applied.is_empty(), overridden.is_empty()) {
+                (true, true) => "empty".to_string(),
+                (false, true) => format!("sets {applied}"),
+                (true, false) => format!("project.toml overrides {overridden}"),
+                (false, false) => {
+                    format!("sets {applied}; project.toml overrides {overridden}")
+                }
+            }
+        }
+    };
+    println!(
+        "{:<10} {}  ({})",
+        "user",
+        user_summary,
+        user_config_path.display()
+    );
     Ok(())
 }
 
 fn cmd_stats(args: StatsArgs, json: bool) -> Result<(), Box<dyn std::error::Error>> {
-    let project = load_project_cwd()?;
+    let scope = load_scope(&args.scope)?;
     let mut active_entries = Vec::new();
     let mut shelved_entries = Vec::new();
     let mut totals = track_ops::TrackStats::default();
 
-    for tc in &project.config.tracks {
-        let is_active = tc.state == "active";
-        if !is_active && !args.all {
-            continue;
-        }
-        let stats = find_track(&project, &tc.id)
-            .map(track_ops::task_counts)
-            .unwrap_or_default();
-        let prefix = project
-            .config
-            .ids
-            .prefixes
-            .get(&tc.id)
-            .cloned()
-            .unwrap_or_default();
+    for (project_name, project) in &scope {
+        for tc in &project.config.tracks {
+            let is_active = tc.state == "active";
+            if !is_active && !args.all {
+                continue;
+            }
+            let stats = find_track(project, &tc.id)
+                .map(track_ops::task_counts)
+                .unwrap_or_default();
+            let prefix = project
+                .config
+                .ids
+                .prefixes
+                .get(&tc.id)
+                .cloned()
+                .unwrap_or_default();
 
-        totals.active += stats.active;
-        totals.blocked += stats.blocked;
-        totals.todo += stats.todo;
-        totals.parked += stats.parked;
-        totals.done += stats.done;
+            totals.active += stats.active;
+            totals.blocked += stats.blocked;
+            totals.todo += stats.todo;
+            totals.parked += stats.parked;
+            totals.done += stats.done;
+            totals.custom += stats.custom;
 
-        let entry = (tc.id.clone(), tc.name.clone(), prefix, stats);
-        if is_active {
-            active_entries.push(entry);
-        } else {
-            shelved_entries.push(entry);
+            // The human table prints the project in the name column,
+            // `lib:Main Track`; the JSON carries it as a field of its own.
+            let entry = (
+                project_name.clone(),
+                tc.id.clone(),
+                tc.name.clone(),
+                scoped_track(project_name.as_deref(), &tc.name),
+                prefix,
+                stats,
+            );
+            if is_active {
+                active_entries.push(entry);
+            } else {
+                shelved_entries.push(entry);
+            }
         }
     }
 
@@ -1251,7 +1633,8 @@ fn cmd_stats(args: StatsArgs, json: bool) -> Result<(), Box<dyn std::error::Erro
         let output = StatsJson {
             tracks: all_entries
                 .iter()
-                .map(|(id, name, _, stats)| TrackStatsEntryJson {
+                .map(|(project, id, name, _, _, stats)| TrackStatsEntryJson {
+                    project: project.clone(),
                     id: id.clone(),
                     name: name.clone(),
                     stats: stats_to_json(stats),
@@ -1268,13 +1651,13 @@ fn cmd_stats(args: StatsArgs, json: bool) -> Result<(), Box<dyn std::error::Erro
             .collect();
         let name_w = all_entries
             .iter()
-            .map(|(_, name, _, _)| name.len())
+            .map(|(_, _, _, label, _, _)| label.len())
             .max()
             .unwrap_or(0)
             .max(5); // "Total"
         let pfx_w = all_entries
             .iter()
-            .map(|(_, _, pfx, _)| pfx.len())
+            .map(|(_, _, _, _, pfx, _)| pfx.len())
             .max()
             .unwrap_or(0)
             .max(3); // "pfx"
@@ -1311,8 +1694,8 @@ fn cmd_stats(args: StatsArgs, json: bool) -> Result<(), Box<dyn std::error::Erro
 
         if !active_entries.is_empty() {
             print_header("Active");
-            for (_, name, pfx, stats) in &active_entries {
-                print_row(name, pfx, stats);
+            for (_, _, _, label, pfx, stats) in &active_entries {
+                print_row(label, pfx, stats);
             }
         }
 
@@ -1321,8 +1704,8 @@ fn cmd_stats(args: StatsArgs, json: bool) -> Result<(), Box<dyn std::error::Erro
                 println!();
             }
             print_header("Shelved");
-            for (_, name, pfx, stats) in &shelved_entries {
-                print_row(name, pfx, stats);
+            for (_, _, _, label, pfx, stats) in &shelved_entries {
+                print_row(label, pfx, stats);
             }
         }
 
@@ -1344,22 +1727,33 @@ fn cmd_stats(args: StatsArgs, json: bool) -> Result<(), Box<dyn std::error::Erro
 }
 
 fn cmd_recent(args: RecentArgs, json: bool) -> Result<(), Box<dyn std::error::Error>> {
-    let project = load_project_cwd()?;
-    let recent = collect_recent_tasks(&project);
+    let scope = load_scope(&args.scope)?;
+    let mut recent: Vec<(Option<&str>, &Project, String, &Task)> = scope
+        .iter()
+        .flat_map(|(name, project)| {
+            collect_recent_tasks(project)
+                .into_iter()
+                .map(move |(track_id, task)| (name.as_deref(), project, track_id, task))
+        })
+        .collect();
+    // Each project's list is already newest first; a stable sort interleaves
+    // them by date without reordering a project's own same-day tasks.
+    recent.sort_by_key(|(_, _, _, task)| std::cmp::Reverse(resolved_date(task)));
     let limited: Vec<_> = recent.into_iter().take(args.limit).collect();
 
     if json {
         let items: Vec<TaskWithTrackJson> = limited
             .iter()
-            .map(|(tid, task)| TaskWithTrackJson {
+            .map(|(name, project, tid, task)| TaskWithTrackJson {
+                project: name.map(str::to_string),
                 track: tid.clone(),
-                task: task_to_json(task),
+                task: task_to_json(task, &project.config.workflow),
             })
             .collect();
         println!("{}", serde_json::to_string_pretty(&items)?);
     } else {
         let mut current_date = String::new();
-        for (track_id, task) in &limited {
+        for (name, _, track_id, task) in &limited {
             let date = resolved_date(task);
             if date != current_date {
                 if !current_date.is_empty() {
@@ -1374,7 +1768,7 @@ fn cmd_recent(args: RecentArgs, json: bool) -> Result<(), Box<dyn std::error::Er
                 task.state.checkbox_char(),
                 id_str,
                 task.title,
-                track_id
+                scoped_track(*name, track_id)
             );
         }
     }
@@ -1384,27 +1778,187 @@ fn cmd_recent(args: RecentArgs, json: bool) -> Result<(), Box<dyn std::error::Er
 fn cmd_deps(args: DepsArgs, json: bool) -> Result<(), Box<dyn std::error::Error>> {
     let project = load_project_cwd()?;
 
-    let tree = deps::dep_tree(&project, &args.id);
+    if args.critical_path {
+        if let Some(ref key) = args.weight
+            && find_field(&project, key)?.kind != FieldKind::Number
+        {
+            return Err(format!("--weight needs a number field; '{}' is not one", key).into());
+        }
+        let weight = args.weight.as_deref();
+        let Some(chain) = deps::critical_path(&project, &args.id, weight) else {
+            return Err(task_not_found(&project, &args.id).into());
+        };
+        if json {
+            println!(
+                "{}",
+                serde_json::to_string_pretty(&critical_path_to_json(
+                    &args.id,
+                    &chain,
+                    weight,
+                    &project.config.workflow,
+                ))?
+            );
+        } else {
+            for line in format_critical_path(&chain, weight) {
+                println!("{}", line);
+            }
+        }
+        return Ok(());
+    }
+
+    let tree = if args.reverse {
+        deps::dependents_tree(&project, &args.id)
+    } else {
+        let external = deps::ExternalDeps::load(&project);
+        warn_unreachable(&external);
+        deps::dep_tree_across(&project, &args.id, &external)
+    };
     if tree.status == deps::DepStatus::Missing {
         // `fr deps` reads live tracks only — whether an archived dep counts as
         // satisfied is its own question — but the miss is the same one `fr show`
         // used to report, so it says where the task went.
-        return Err(task_not_found(&project.frame_dir, &args.id).into());
+        return Err(task_not_found(&project, &args.id).into());
+    }
+
+    if json {
+        println!(
+            "{}",
+            serde_json::to_string_pretty(&dep_tree_to_json(&tree, &project.config.workflow))?
+        );
+    } else {
+        let lines = if args.reverse {
+            format_dependents_tree(&tree)
+        } else {
+            format_dep_tree(&tree)
+        };
+        for line in lines {
+            println!("{}", line);
+        }
+    }
+    Ok(())
+}
+
+/// The tracks `fr plan` and `fr graph` read: the one `--track` names, or every
+/// active track.
+fn graph_scope<'a>(
+    project: &'a Project,
+    track: Option<&'a str>,
+) -> Result<Vec<&'a str>, Box<dyn std::error::Error>> {
+    match track {
+        Some(track_id) => {
+            if find_track(project, track_id).is_none() {
+                return Err(format!("track not found: {}", track_id).into());
+            }
+            Ok(vec![track_id])
+        }
+        None => Ok(project
+            .config
+            .tracks
+            .iter()
+            .filter(|tc| tc.state == "active")
+            .map(|tc| tc.id.as_str())
+            .collect()),
     }
+}
+
+/// `fr plan`: the open tasks of the active tracks, or of `--track`, in an
+/// order that respects every `dep:`.
+fn cmd_plan(args: PlanArgs, json: bool) -> Result<(), Box<dyn std::error::Error>> {
+    let project = load_project_cwd()?;
 
+    let track_ids = graph_scope(&project, args.track.as_deref())?;
+
+    let plan = deps::plan(&project, &track_ids);
     if json {
         println!(
             "{}",
-            serde_json::to_string_pretty(&dep_tree_to_json(&tree))?
+            serde_json::to_string_pretty(&plan_to_json(&plan, &project.config.workflow))?
         );
     } else {
-        for line in format_dep_tree(&tree) {
+        for line in format_plan(&plan) {
             println!("{}", line);
         }
     }
     Ok(())
 }
 
+/// `fr epic [ID]`: an epic's members, from whichever tracks hold them, and how
+/// far they have got. Without an id, every epic in the project.
+fn cmd_epic(args: EpicArgs, json: bool) -> Result<(), Box<dyn std::error::Error>> {
+    let project = load_project_cwd()?;
+
+    let epics = match &args.id {
+        Some(id) => {
+            let Some(epic) = relations::epic(&project, id) else {
+                return Err(task_not_found(&project, id).into());
+            };
+            vec![epic]
+        }
+        None => relations::epics(&project),
+    };
+    if json {
+        let items: Vec<EpicJson> = epics
+            .iter()
+            .map(|epic| epic_to_json(epic, &project.config.workflow))
+            .collect();
+        let out = match args.id {
+            Some(_) => serde_json::to_string_pretty(&items[0])?,
+            None => serde_json::to_string_pretty(&items)?,
+        };
+        println!("{}", out);
+    } else if epics.is_empty() {
+        println!("(no epics — a task becomes one when another names it with `epic:`)");
+    } else {
+        for (i, epic) in epics.iter().enumerate() {
+            if i > 0 {
+                println!();
+            }
+            for line in format_epic(epic) {
+                println!("{}", line);
+            }
+        }
+    }
+    Ok(())
+}
+
+/// `fr graph`: the dependency graph of the active tracks, or of `--track`, for
+/// pasting into a document.
+fn cmd_graph(args: GraphArgs, json: bool) -> Result<(), Box<dyn std::error::Error>> {
+    let project = load_project_cwd()?;
+
+    let track_ids = graph_scope(&project, args.track.as_deref())?;
+
+    let graph = deps::dep_graph(&project, &track_ids);
+    let track_names: HashMap<&str, &str> = project
+        .config
+        .tracks
+        .iter()
+        .map(|tc| (tc.id.as_str(), tc.name.as_str()))
+        .collect();
+    let format = if json {
+        "json"
+    } else {
+        args.format.as_deref().unwrap_or("dot")
+    };
+    match format {
+        "json" => println!(
+            "{}",
+            serde_json::to_string_pretty(&dep_graph_to_json(&graph, &project.config.workflow))?
+        ),
+        "mermaid" => {
+            for line in format_graph_mermaid(&graph, &track_names, &project.config.workflow) {
+                println!("{}", line);
+            }
+        }
+        _ => {
+            for line in format_graph_dot(&graph, &track_names) {
+                println!("{}", line);
+            }
+        }
+    }
+    Ok(())
+}
+
 fn cmd_check(args: CheckArgs, json: bool) -> Result<(), Box<dyn std::error::Error>> {
     // `fr check` with no flags stays read-only, on exactly the code it always
     // ran. The repair path is a separate function so that promise is visible
@@ -1577,6 +2131,54 @@ fn cmd_check(args: CheckArgs, json: bool) -> Result<(), Box<dyn std::error::Erro
                             track_id, task_id
                         );
                     }
+                    check::CheckWarning::BlockedWithoutCause { track_id, task_id } => {
+                        println!(
+                            "  [{}] {} is blocked, but none of its deps is open and its note gives no reason — unblock it, or say what it waits on",
+                            track_id, task_id
+                        );
+                    }
+                    check::CheckWarning::ExternalProjectUnreachable { project, reason } => {
+                        println!(
+                            "  project '{}' is named by a dep but could not be loaded ({}) — its deps are not checked",
+                            project, reason
+                        );
+                    }
+                    check::CheckWarning::InvalidField {
+                        track_id,
+                        task_id,
+                        key,
+                        value,
+                        reason,
+                    } => {
+                        println!(
+                            "  [{}] {} {}: {} — {} ([fields.{}] in project.toml)",
+                            track_id, task_id, key, value, reason, key
+                        );
+                    }
+                    check::CheckWarning::UndeclaredState {
+                        track_id,
+                        task_id,
+                        checkbox,
+                    } => {
+                        println!(
+                            "  [{}] {} is in state [{}], which [workflow.states] in project.toml does not declare",
+                            track_id, task_id, checkbox
+                        );
+                    }
+                    check::CheckWarning::DanglingRelation {
+                        track_id,
+                        task_id,
+                        relation,
+                        target,
+                    } => {
+                        println!(
+                            "  [{}] {} has dangling {}: {}",
+                            track_id,
+                            task_id,
+                            relation.key(),
+                            target
+                        );
+                    }
                     check::CheckWarning::ChildIdNotUnderParent {
                         track_id,
                         task_id,
@@ -2173,6 +2775,7 @@ fn resolve_mint_namespace(
 fn report_task_write(
     json: bool,
     command: &'static str,
+    project: &Project,
     changed: bool,
     track: Option<&str>,
     tasks: Vec<&Task>,
@@ -2181,6 +2784,7 @@ fn report_task_write(
     report_task_write_with(
         json,
         command,
+        project,
         changed,
         track,
         tasks,
@@ -2197,6 +2801,7 @@ fn report_task_write(
 struct WriteNotice {
     displaced_bytes: Option<usize>,
     warnings: Vec<String>,
+    auto_state: Vec<deps::AutoStateChange>,
 }
 
 /// [`report_task_write`], plus what the write destroyed on its way through.
@@ -2206,9 +2811,11 @@ struct WriteNotice {
 /// unaffected either way. Under `--json` they travel in the object instead —
 /// a warning a program cannot see is one that only reaches the surface that
 /// was already going to notice.
+#[allow(clippy::too_many_arguments)]
 fn report_task_write_with(
     json: bool,
     command: &'static str,
+    project: &Project,
     changed: bool,
     track: Option<&str>,
     tasks: Vec<&Task>,
@@ -2224,13 +2831,24 @@ fn report_task_write_with(
                 dry_run: dryrun::is_active(),
                 would_write: would_write_paths(),
                 track: track.map(str::to_string),
-                tasks: tasks.into_iter().map(task_to_json).collect(),
+                tasks: tasks
+                    .into_iter()
+                    .map(|task| task_to_json(task, &project.config.workflow))
+                    .collect(),
                 displaced_bytes: notice.displaced_bytes,
                 warnings: notice.warnings,
+                auto_state: notice
+                    .auto_state
+                    .iter()
+                    .map(|change| auto_state_to_json(change, &project.config.workflow))
+                    .collect(),
             })?
         );
     } else {
         human();
+        for change in &notice.auto_state {
+            println!("{}", format_auto_state(change));
+        }
         for warning in &notice.warnings {
             eprintln!("warning: {}", warning);
         }
@@ -2423,10 +3041,13 @@ fn report_task_change_with(
 ) -> Result<(), Box<dyn std::error::Error>> {
     let after =
         find_track(project, track_id).and_then(|t| task_ops::find_task_in_track(t, task_id));
-    let changed = before.as_ref() != after;
+    // An auto_block change to some other task is a change this write made.
+    let changed =
+        before.as_ref() != after || notice.auto_state.iter().any(|c| c.task_id != task_id);
     report_task_write_with(
         json,
         command,
+        project,
         changed,
         Some(track_id),
         after.into_iter().collect(),
@@ -2452,10 +3073,30 @@ fn cmd_add(args: AddArgs, json: bool) -> Result<(), Box<dyn std::error::Error>>
     dryrun::arm(args.dry_run);
     let (mut project, _lock) = lock_and_load()?;
 
-    reject_add_to_shelved(&project, &args.track)?;
+    let template = match args.template {
+        Some(ref name) => Some(find_template(&project, name)?.clone()),
+        None => None,
+    };
+    // `fr add "title" --template bug` with the track taken from the template:
+    // the one positional given is the title.
+    let (track_id, title) = match (args.title, &template) {
+        (Some(title), _) => (args.track, title),
+        // A positional naming a track is one left without its title
+        (
+            None,
+            Some(TaskTemplate {
+                track: Some(track), ..
+            }),
+        ) if !project.config.tracks.iter().any(|tc| tc.id == args.track) => {
+            (track.clone(), args.track)
+        }
+        (None, _) => return Err("missing task title (usage: fr add TRACK TITLE)".into()),
+    };
 
-    let prefix = track_prefix(&project, &args.track)
-        .ok_or_else(|| format!("no ID prefix configured for track '{}'", args.track))?
+    reject_add_to_shelved(&project, &track_id)?;
+
+    let prefix = track_prefix(&project, &track_id)
+        .ok_or_else(|| format!("no ID prefix configured for track '{}'", track_id))?
         .to_string();
     let token = resolve_mint_namespace(&project.frame_dir)?;
 
@@ -2467,31 +3108,70 @@ fn cmd_add(args: AddArgs, json: bool) -> Result<(), Box<dyn std::error::Error>>
 
     let frame_dir = project.frame_dir.clone();
     let limit = note_limit(&project);
-    let track = find_track_mut(&mut project, &args.track)
-        .ok_or_else(|| format!("track not found: {}", args.track))?;
+    let track = find_track_mut(&mut project, &track_id)
+        .ok_or_else(|| format!("track not found: {}", track_id))?;
 
-    let mint = Mint::new(&frame_dir, &args.track, &prefix, token.as_ref());
-    let id = task_ops::add_task(track, args.title.clone(), position, mint)?;
-
-    // If --found-from, add a note
-    if let Some(ref from_id) = args.found_from {
-        task_ops::set_note(
-            track,
-            &id,
-            format!("Found while working on {}", from_id),
-            limit,
-        )
-        .map_err(explain_note_limit)?;
+    let mint = Mint::new(&frame_dir, &track_id, &prefix, token.as_ref());
+    let id = task_ops::add_task(track, title, position, mint)?;
+
+    // If --found-from, add a note — above the template's, which it would
+    // otherwise replace.
+    let mut found_from = args
+        .found_from
+        .map(|from_id| format!("Found while working on {}", from_id));
+    if let Some(mut template) = template {
+        if let Some(line) = found_from.take() {
+            template.note = Some(match template.note {
+                Some(note) => format!("{}\n\n{}", line, note),
+                None => line,
+            });
+        }
+        task_ops::apply_template(track, &id, &template, token.as_ref(), limit)
+            .map_err(explain_note_limit)?;
+    }
+    if let Some(note) = found_from {
+        task_ops::set_note(track, &id, note, limit).map_err(explain_note_limit)?;
     }
 
-    save_track(&project, &args.track)?;
+    save_track(&project, &track_id)?;
 
-    let created = find_track(&project, &args.track)
+    let created = find_track(&project, &track_id)
         .and_then(|t| task_ops::find_task_in_track(t, &id))
         .into_iter()
         .collect();
-    report_task_write(json, "add", true, Some(&args.track), created, || {
-        println!("{}", id)
+    report_task_write(
+        json,
+        "add",
+        &project,
+        true,
+        Some(&track_id),
+        created,
+        || println!("{}", id),
+    )
+}
+
+/// `[templates.NAME]`, or an error naming the templates there are.
+fn find_template<'a>(
+    project: &'a Project,
+    name: &str,
+) -> Result<&'a TaskTemplate, Box<dyn std::error::Error>> {
+    project.config.templates.get(name).ok_or_else(|| {
+        let known: Vec<&str> = project
+            .config
+            .templates
+            .keys()
+            .map(|k| k.as_str())
+            .collect();
+        if known.is_empty() {
+            format!("no template named '{}' (project.toml defines none)", name).into()
+        } else {
+            format!(
+                "no template named '{}' (project.toml defines: {})",
+                name,
+                known.join(", ")
+            )
+            .into()
+        }
     })
 }
 
@@ -2523,9 +3203,15 @@ fn cmd_push(args: PushArgs, json: bool) -> Result<(), Box<dyn std::error::Error>
         .and_then(|t| task_ops::find_task_in_track(t, &id))
         .into_iter()
         .collect();
-    report_task_write(json, "push", true, Some(&args.track), created, || {
-        println!("{}", id)
-    })
+    report_task_write(
+        json,
+        "push",
+        &project,
+        true,
+        Some(&args.track),
+        created,
+        || println!("{}", id),
+    )
 }
 
 fn cmd_sub(args: SubArgs, json: bool) -> Result<(), Box<dyn std::error::Error>> {
@@ -2534,7 +3220,7 @@ fn cmd_sub(args: SubArgs, json: bool) -> Result<(), Box<dyn std::error::Error>>
 
     // Find which track the parent task is in
     let track_id = find_task_track(&project, &args.id)
-        .ok_or_else(|| task_not_found(&project.frame_dir, &args.id))?
+        .ok_or_else(|| task_not_found(&project, &args.id))?
         .to_string();
     reject_add_to_shelved(&project, &track_id)?;
     let token = resolve_mint_namespace(&project.frame_dir)?;
@@ -2550,9 +3236,15 @@ fn cmd_sub(args: SubArgs, json: bool) -> Result<(), Box<dyn std::error::Error>>
         .and_then(|t| task_ops::find_task_in_track(t, &sub_id))
         .into_iter()
         .collect();
-    report_task_write(json, "sub", true, Some(&track_id), created, || {
-        println!("{}", sub_id)
-    })
+    report_task_write(
+        json,
+        "sub",
+        &project,
+        true,
+        Some(&track_id),
+        created,
+        || println!("{}", sub_id),
+    )
 }
 
 fn cmd_inbox_add(args: InboxCmd, json: bool) -> Result<(), Box<dyn std::error::Error>> {
@@ -2615,10 +3307,11 @@ fn cmd_state(args: StateArgs, json: bool) -> Result<(), Box<dyn std::error::Erro
     dryrun::arm(args.dry_run);
     let (mut project, _lock) = lock_and_load()?;
 
-    let new_state = parse_task_state(&args.state).map_err(Box::<dyn std::error::Error>::from)?;
+    let new_state = parse_task_state(&args.state, &project.config.workflow)
+        .map_err(Box::<dyn std::error::Error>::from)?;
 
     let track_id = find_task_track(&project, &args.id)
-        .ok_or_else(|| task_not_found(&project.frame_dir, &args.id))?
+        .ok_or_else(|| task_not_found(&project, &args.id))?
         .to_string();
 
     // Taken before the write so the report can say whether anything changed.
@@ -2647,15 +3340,48 @@ fn cmd_state(args: StateArgs, json: bool) -> Result<(), Box<dyn std::error::Erro
     // total: the enumerated form here had no case for Done → Parked, so a
     // `[~]` task stayed sitting in `## Done`.
     {
+        let workflow = project.config.workflow.clone();
         let track = find_track_mut(&mut project, &track_id)
             .ok_or_else(|| format!("track not found: {}", track_id))?;
-        task_ops::reconcile_task_section(track, &args.id, new_state);
+        task_ops::reconcile_task_section(track, &args.id, new_state, &workflow);
     }
 
-    save_track(&project, &track_id)?;
-    report_task_change(json, "state", &project, &track_id, &args.id, before, || {
-        println!("{} → {}", args.id, args.state)
-    })
+    let auto_state = apply_auto_block(&mut project, &track_id)?;
+    save_track(&project, &track_id)?;
+    report_task_change_with(
+        json,
+        "state",
+        &project,
+        &track_id,
+        &args.id,
+        before,
+        WriteNotice {
+            auto_state,
+            ..WriteNotice::default()
+        },
+        || println!("{} → {}", args.id, args.state),
+    )
+}
+
+/// The `[deps] auto_block` pass, run after a write that can change what blocks
+/// what. Saves every track it touched except `written`, which the caller is
+/// about to save anyway, and returns the changes for the report.
+fn apply_auto_block(
+    project: &mut Project,
+    written: &str,
+) -> Result<Vec<deps::AutoStateChange>, Box<dyn std::error::Error>> {
+    if !project.config.deps.auto_block {
+        return Ok(Vec::new());
+    }
+    let changes = deps::auto_block(project);
+    let mut saved = vec![written.to_string()];
+    for change in &changes {
+        if !saved.contains(&change.track_id) {
+            save_track(project, &change.track_id)?;
+            saved.push(change.track_id.clone());
+        }
+    }
+    Ok(changes)
 }
 
 fn cmd_tag(args: TagArgs, json: bool) -> Result<(), Box<dyn std::error::Error>> {
@@ -2663,7 +3389,7 @@ fn cmd_tag(args: TagArgs, json: bool) -> Result<(), Box<dyn std::error::Error>>
     let (mut project, _lock) = lock_and_load()?;
 
     let track_id = find_task_track(&project, &args.id)
-        .ok_or_else(|| task_not_found(&project.frame_dir, &args.id))?
+        .ok_or_else(|| task_not_found(&project, &args.id))?
         .to_string();
 
     // Taken before the write so the report can say whether anything changed.
@@ -2689,7 +3415,7 @@ fn cmd_dep(args: DepArgs, json: bool) -> Result<(), Box<dyn std::error::Error>>
     let (mut project, _lock) = lock_and_load()?;
 
     let track_id = find_task_track(&project, &args.id)
-        .ok_or_else(|| task_not_found(&project.frame_dir, &args.id))?
+        .ok_or_else(|| task_not_found(&project, &args.id))?
         .to_string();
 
     // Taken before the write so the report can say whether anything changed.
@@ -2701,6 +3427,20 @@ fn cmd_dep(args: DepArgs, json: bool) -> Result<(), Box<dyn std::error::Error>>
             let track = find_track_mut(&mut project, &track_id)
                 .ok_or_else(|| format!("track not found: {}", track_id))?;
             task_ops::add_dep(track, &args.id, &args.dep_id, &all_tracks_snapshot)?;
+            // `add_dep` leaves a cross-project target to us. Checked after the
+            // edit, which is only in memory until the save below. A project
+            // that cannot be loaded is left for `fr deps` and `fr check` to
+            // report.
+            if deps::external_ref(&args.dep_id).is_some() {
+                let external = deps::ExternalDeps::load(&project);
+                if external.status(&args.dep_id) == Some(&deps::ExternalStatus::Missing) {
+                    return Err(task_ops::TaskError::NotFound(format!(
+                        "dep target {}",
+                        args.dep_id
+                    ))
+                    .into());
+                }
+            }
         }
         "rm" => {
             let track = find_track_mut(&mut project, &track_id)
@@ -2710,10 +3450,87 @@ fn cmd_dep(args: DepArgs, json: bool) -> Result<(), Box<dyn std::error::Error>>
         other => return Err(format!("unknown action '{}' (expected: add, rm)", other).into()),
     }
 
+    let auto_state = apply_auto_block(&mut project, &track_id)?;
     save_track(&project, &track_id)?;
-    report_task_change(json, "dep", &project, &track_id, &args.id, before, || {
-        println!("{} dep {} {}", args.id, args.action, args.dep_id)
-    })
+    report_task_change_with(
+        json,
+        "dep",
+        &project,
+        &track_id,
+        &args.id,
+        before,
+        WriteNotice {
+            auto_state,
+            ..WriteNotice::default()
+        },
+        || println!("{} dep {} {}", args.id, args.action, args.dep_id),
+    )
+}
+
+fn cmd_relate(args: RelateArgs, json: bool) -> Result<(), Box<dyn std::error::Error>> {
+    dryrun::arm(args.dry_run);
+    let kind = RelationKind::from_key(&args.kind).ok_or_else(|| {
+        format!(
+            "unknown relation '{}' (expected: relates, duplicates, supersedes, epic)",
+            args.kind
+        )
+    })?;
+    let (mut project, _lock) = lock_and_load()?;
+
+    let track_id = find_task_track(&project, &args.id)
+        .ok_or_else(|| task_not_found(&project, &args.id))?
+        .to_string();
+
+    // Taken before the write so the report can say whether anything changed.
+    let before = snapshot(&project, &track_id, &args.id);
+
+    match args.action.as_str() {
+        "add" => {
+            // A live task or an archived one: what a task supersedes is often
+            // done and cleaned away already.
+            let archived = || {
+                project_io::archived_task_lists(
+                    &project.frame_dir,
+                    &crate::parse::Schema::of(&project.config),
+                )
+                .iter()
+                .any(|list| find_task_by_id(&list.tasks, &args.target_id).is_some())
+            };
+            if find_task_track(&project, &args.target_id).is_none() && !archived() {
+                return Err(task_ops::TaskError::NotFound(format!(
+                    "{} target {}",
+                    kind.key(),
+                    args.target_id
+                ))
+                .into());
+            }
+            let track = find_track_mut(&mut project, &track_id)
+                .ok_or_else(|| format!("track not found: {}", track_id))?;
+            task_ops::add_relation(track, &args.id, kind, &args.target_id)?;
+        }
+        "rm" => {
+            let track = find_track_mut(&mut project, &track_id)
+                .ok_or_else(|| format!("track not found: {}", track_id))?;
+            task_ops::remove_relation(track, &args.id, kind, &args.target_id)?;
+        }
+        other => return Err(format!("unknown action '{}' (expected: add, rm)", other).into()),
+    }
+
+    save_track(&project, &track_id)?;
+    report_task_change(
+        json,
+        "relate",
+        &project,
+        &track_id,
+        &args.id,
+        before,
+        || {
+            println!(
+                "{} {} {} {}",
+                args.id, args.action, args.kind, args.target_id
+            )
+        },
+    )
 }
 
 fn cmd_note(args: NoteArgs, json: bool) -> Result<(), Box<dyn std::error::Error>> {
@@ -2727,7 +3544,7 @@ fn cmd_note(args: NoteArgs, json: bool) -> Result<(), Box<dyn std::error::Error>
     let (mut project, _lock) = lock_and_load()?;
 
     let track_id = find_task_track(&project, &args.id)
-        .ok_or_else(|| task_not_found(&project.frame_dir, &args.id))?
+        .ok_or_else(|| task_not_found(&project, &args.id))?
         .to_string();
 
     // Taken before the write so the report can say whether anything changed.
@@ -2766,6 +3583,7 @@ fn cmd_note(args: NoteArgs, json: bool) -> Result<(), Box<dyn std::error::Error>
         WriteNotice {
             displaced_bytes: Some(was),
             warnings,
+            ..WriteNotice::default()
         }
     } else {
         WriteNotice::default()
@@ -3083,7 +3901,7 @@ fn cmd_path_field(
     }
 
     let track_id = find_task_track(&project, &args.id)
-        .ok_or_else(|| task_not_found(&project.frame_dir, &args.id))?
+        .ok_or_else(|| task_not_found(&project, &args.id))?
         .to_string();
 
     // Taken before the write so the report can say whether anything changed.
@@ -3131,7 +3949,7 @@ fn cmd_title(args: TitleArgs, json: bool) -> Result<(), Box<dyn std::error::Erro
     let (mut project, _lock) = lock_and_load()?;
 
     let track_id = find_task_track(&project, &args.id)
-        .ok_or_else(|| task_not_found(&project.frame_dir, &args.id))?
+        .ok_or_else(|| task_not_found(&project, &args.id))?
         .to_string();
 
     // Taken before the write so the report can say whether anything changed.
@@ -3148,6 +3966,239 @@ fn cmd_title(args: TitleArgs, json: bool) -> Result<(), Box<dyn std::error::Erro
     })
 }
 
+fn cmd_set(args: SetArgs, json: bool) -> Result<(), Box<dyn std::error::Error>> {
+    dryrun::arm(args.dry_run);
+    let (mut project, _lock) = lock_and_load()?;
+
+    let field = find_field(&project, &args.key)?;
+    if let Some(ref value) = args.value {
+        if let Some(reason) = field.reject(value) {
+            return Err(format!("invalid {} '{}': {}", args.key, value, reason).into());
+        }
+        // As `fr dep add` does: an id that names nothing is refused here rather
+        // than left for `fr check` to find.
+        if field.kind == FieldKind::IdList {
+            for id in field.items(value) {
+                if deps::external_ref(id).is_none() && find_task_track(&project, id).is_none() {
+                    return Err(task_ops::TaskError::NotFound(id.to_string()).into());
+                }
+            }
+        }
+    }
+
+    let track_id = find_task_track(&project, &args.id)
+        .ok_or_else(|| task_not_found(&project, &args.id))?
+        .to_string();
+
+    // Taken before the write so the report can say whether anything changed.
+    let before = snapshot(&project, &track_id, &args.id);
+
+    let track = find_track_mut(&mut project, &track_id)
+        .ok_or_else(|| format!("track not found: {}", track_id))?;
+
+    let value = args.value.as_deref().map(str::trim);
+    task_ops::set_field(track, &args.id, &args.key, value)?;
+
+    save_track(&project, &track_id)?;
+    report_task_change(
+        json,
+        "set",
+        &project,
+        &track_id,
+        &args.id,
+        before,
+        || match value {
+            Some(value) => println!("{} {}: {}", args.id, args.key, value),
+            None => println!("{} {} cleared", args.id, args.key),
+        },
+    )
+}
+
+/// `[fields.KEY]`, or an error naming the fields there are.
+fn find_field<'a>(
+    project: &'a Project,
+    key: &str,
+) -> Result<&'a FieldSchema, Box<dyn std::error::Error>> {
+    project.config.fields.get(key).ok_or_else(|| {
+        let known: Vec<&str> = project.config.fields.keys().map(|k| k.as_str()).collect();
+        if known.is_empty() {
+            format!("no field named '{}' (project.toml declares none)", key).into()
+        } else {
+            format!(
+                "no field named '{}' (project.toml declares: {})",
+                key,
+                known.join(", ")
+            )
+            .into()
+        }
+    })
+}
+
+/// `fr mv ID --project NAME [--track T]`: move a top-level task, with its
+/// subtasks, into another registered project.
+///
+/// The task is re-minted in the destination and leaves a done `moved-to:` stub
+/// behind, so the old id still says where it went. Deps travel with it:
+/// references back into the source become `source:ID`, and what in the source
+/// depended on the task now names it `dest:NEW`.
+///
+/// Both projects are locked, the destination second. The destination is
+/// written first, for the reason `--track` writes its target first; the
+/// in-flight marker lives in the source, whose write is the one left to finish.
+fn mv_to_project(
+    args: &MvArgs,
+    target_name: &str,
+    project: &mut Project,
+    source_track_id: &str,
+    json: bool,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let reg = registry::read_registry();
+    let entry = registry::find_by_name(&reg, target_name)
+        .ok_or_else(|| format!("no registered project named '{target_name}' (see fr projects)"))?;
+    let target_root = PathBuf::from(&entry.path);
+    let same_root = |a: &Path, b: &Path| match (a.canonicalize(), b.canonicalize()) {
+        (Ok(a), Ok(b)) => a == b,
+        _ => a == b,
+    };
+    if same_root(&target_root, &project.root) {
+        return Err(
+            format!("'{target_name}' is this project — use --track to move within it").into(),
+        );
+    }
+    // The name the destination will know this project by, in `source:ID`.
+    let source_name = reg
+        .projects
+        .iter()
+        .find(|e| same_root(Path::new(&e.path), &project.root))
+        .map(|e| e.name.clone())
+        .unwrap_or_else(|| project.config.project.name.clone());
+
+    let location = find_track(project, source_track_id)
+        .and_then(|t| task_ops::find_task_location_any_section(t, &args.id))
+        .ok_or_else(|| format!("task not found: {}", args.id))?;
+    if location.parent_id.is_some() {
+        return Err(format!(
+            "{} is a subtask — promote it first (fr mv {} --promote), then move it",
+            args.id, args.id
+        )
+        .into());
+    }
+
+    let _target_lock = FileLock::acquire_default(&target_root.join("frame"))?;
+    let mut target = load_project_at(&target_root)?;
+    recover_under_lock(&mut target)?;
+
+    let target_track_id = match args.track {
+        Some(ref t) => t.clone(),
+        None => target
+            .config
+            .agent
+            .cc_focus
+            .clone()
+            .or_else(|| {
+                target
+                    .config
+                    .tracks
+                    .iter()
+                    .find(|tc| tc.state == "active")
+                    .map(|tc| tc.id.clone())
+            })
+            .ok_or_else(|| format!("'{target_name}' has no active track to move into"))?,
+    };
+    reject_add_to_shelved(&target, &target_track_id)?;
+    let target_prefix = track_prefix(&target, &target_track_id)
+        .ok_or_else(|| format!("no ID prefix configured for track '{}'", target_track_id))?
+        .to_string();
+    // Minted in the mover's namespace within the destination, as any add is.
+    let token = resolve_mint_namespace(&target.frame_dir)?;
+
+    let position = if args.top {
+        task_ops::InsertPosition::Top
+    } else if let Some(ref after_id) = args.after {
+        task_ops::InsertPosition::After(after_id.clone())
+    } else {
+        task_ops::InsertPosition::Bottom
+    };
+
+    let target_frame_dir = target.frame_dir.clone();
+    let source_track = project
+        .tracks
+        .iter_mut()
+        .find(|(id, _)| id == source_track_id)
+        .map(|(_, t)| t)
+        .ok_or_else(|| format!("track not found: {}", source_track_id))?;
+    let target_track = target
+        .tracks
+        .iter_mut()
+        .find(|(id, _)| *id == target_track_id)
+        .map(|(_, t)| t)
+        .ok_or_else(|| format!("track not found in '{target_name}': {}", target_track_id))?;
+    let moved = task_ops::move_task_to_project(
+        source_track,
+        target_track,
+        &args.id,
+        position,
+        Mint::new(
+            &target_frame_dir,
+            &target_track_id,
+            &target_prefix,
+            token.as_ref(),
+        ),
+        &source_name,
+        target_name,
+    )?;
+    let new_id = moved.new_root_id;
+    let qualified: Vec<(String, String)> = moved
+        .id_mappings
+        .iter()
+        .map(|(old, new)| (old.clone(), format!("{target_name}:{new}")))
+        .collect();
+    task_ops::apply_id_map_to_deps(&mut project.tracks, &qualified);
+
+    let marker = crate::io::inflight::InFlight::begin(
+        &project.frame_dir,
+        crate::io::inflight::Operation::CrossProjectMove {
+            moves: moved
+                .id_mappings
+                .iter()
+                .map(|(old, new)| crate::io::inflight::MovedTask {
+                    old_id: old.clone(),
+                    new_id: new.clone(),
+                })
+                .collect(),
+            source_track: source_track_id.to_string(),
+            target_project: target_name.to_string(),
+            target_root: target_root.to_string_lossy().into_owned(),
+            target_track: target_track_id.clone(),
+        },
+        &format!("fr mv {} --project {}", args.id, target_name),
+    )?;
+    save_track(&target, &target_track_id)?;
+    // A failure from here on leaves the marker in place: the destination
+    // holds the task, and the source's next command replaces its copy.
+    save_track(project, source_track_id)?;
+    for (other_id, other_track) in &project.tracks {
+        if other_id != source_track_id && task_ops::track_has_dirty_task(other_track) {
+            save_track(project, other_id)?;
+        }
+    }
+    marker.commit();
+
+    let moved_task = find_track(&target, &target_track_id)
+        .and_then(|t| task_ops::find_task_in_track(t, &new_id))
+        .into_iter()
+        .collect();
+    report_task_write(
+        json,
+        "mv",
+        &target,
+        true,
+        Some(&target_track_id),
+        moved_task,
+        || println!("{} → {target_name}:{new_id} ({target_track_id})", args.id),
+    )
+}
+
 fn cmd_mv(args: MvArgs, json: bool) -> Result<(), Box<dyn std::error::Error>> {
     dryrun::arm(args.dry_run);
     let (mut project, _lock) = lock_and_load()?;
@@ -3158,9 +4209,13 @@ fn cmd_mv(args: MvArgs, json: bool) -> Result<(), Box<dyn std::error::Error>> {
     // reparent flags against each other) are declared on `MvArgs` and rejected
     // by clap before this runs.
     let source_track_id = find_task_track(&project, &args.id)
-        .ok_or_else(|| task_not_found(&frame_dir, &args.id))?
+        .ok_or_else(|| task_not_found(&project, &args.id))?
         .to_string();
 
+    if let Some(ref target_name) = args.project {
+        return mv_to_project(&args, target_name, &mut project, &source_track_id, json);
+    }
+
     // Handle --promote
     if args.promote {
         let prefix = track_prefix(&project, &source_track_id)
@@ -3227,9 +4282,15 @@ fn cmd_mv(args: MvArgs, json: bool) -> Result<(), Box<dyn std::error::Error>> {
             .and_then(|t| task_ops::find_task_in_track(t, &result.new_root_id))
             .into_iter()
             .collect();
-        return report_task_write(json, "mv", true, Some(&source_track_id), moved, || {
-            println!("{} → {} (promoted)", args.id, result.new_root_id)
-        });
+        return report_task_write(
+            json,
+            "mv",
+            &project,
+            true,
+            Some(&source_track_id),
+            moved,
+            || println!("{} → {} (promoted)", args.id, result.new_root_id),
+        );
     }
 
     // Handle --parent
@@ -3293,9 +4354,15 @@ fn cmd_mv(args: MvArgs, json: bool) -> Result<(), Box<dyn std::error::Error>> {
             .and_then(|t| task_ops::find_task_in_track(t, &result.new_root_id))
             .into_iter()
             .collect();
-        return report_task_write(json, "mv", true, Some(&source_track_id), moved, || {
-            println!("{} → {} (under {})", args.id, result.new_root_id, parent_id)
-        });
+        return report_task_write(
+            json,
+            "mv",
+            &project,
+            true,
+            Some(&source_track_id),
+            moved,
+            || println!("{} → {} (under {})", args.id, result.new_root_id, parent_id),
+        );
     }
 
     // `--track` naming the track the task is already in is not a cross-track
@@ -3319,6 +4386,7 @@ fn cmd_mv(args: MvArgs, json: bool) -> Result<(), Box<dyn std::error::Error>> {
                 return report_task_write(
                     json,
                     "mv",
+                    &project,
                     false,
                     Some(&source_track_id),
                     already,
@@ -3495,9 +4563,15 @@ fn cmd_mv(args: MvArgs, json: bool) -> Result<(), Box<dyn std::error::Error>> {
             .and_then(|t| task_ops::find_task_in_track(t, &new_id))
             .into_iter()
             .collect();
-        report_task_write(json, "mv", true, Some(target_track_id), moved, || {
-            println!("{} → {} ({})", args.id, new_id, target_track_id)
-        })
+        report_task_write(
+            json,
+            "mv",
+            &project,
+            true,
+            Some(target_track_id),
+            moved,
+            || println!("{} → {} ({})", args.id, new_id, target_track_id),
+        )
     } else {
         // Same-track reorder
         let position = if args.top {
@@ -3550,9 +4624,15 @@ fn cmd_mv(args: MvArgs, json: bool) -> Result<(), Box<dyn std::error::Error>> {
             .and_then(|t| task_ops::find_task_in_track(t, &args.id))
             .into_iter()
             .collect();
-        report_task_write(json, "mv", true, Some(&source_track_id), moved, || {
-            println!("{} moved", args.id)
-        })
+        report_task_write(
+            json,
+            "mv",
+            &project,
+            true,
+            Some(&source_track_id),
+            moved,
+            || println!("{} moved", args.id),
+        )
     }
 }
 
@@ -3623,9 +4703,15 @@ fn cmd_triage(args: TriageArgs, json: bool) -> Result<(), Box<dyn std::error::Er
         .and_then(|t| task_ops::find_task_in_track(t, &task_id))
         .into_iter()
         .collect();
-    report_task_write(json, "triage", true, Some(&args.track), created, || {
-        println!("{}", task_id)
-    })
+    report_task_write(
+        json,
+        "triage",
+        &project,
+        true,
+        Some(&args.track),
+        created,
+        || println!("{}", task_id),
+    )
 }
 
 // ---------------------------------------------------------------------------
@@ -4359,6 +5445,120 @@ fn report_clean(
     }
 }
 
+// ---------------------------------------------------------------------------
+// Config handlers
+// ---------------------------------------------------------------------------
+
+fn cmd_config(args: ConfigCmd, json: bool) -> Result<(), Box<dyn std::error::Error>> {
+    match args.action {
+        ConfigAction::Get(a) => cmd_config_get(a, json),
+        ConfigAction::Set(a) => cmd_config_set(a, json),
+        ConfigAction::Unset(a) => cmd_config_unset(a, json),
+        ConfigAction::List => cmd_config_list(json),
+    }
+}
+
+/// A config value as a person reads it: a string bare, as `git config` prints
+/// one, and anything else as TOML.
+fn config_value_text(value: &toml::Value) -> String {
+    match value {
+        toml::Value::String(s) => s.clone(),
+        other => other.to_string(),
+    }
+}
+
+fn cmd_config_get(args: ConfigKeyArgs, json: bool) -> Result<(), Box<dyn std::error::Error>> {
+    let project = load_project_cwd()?;
+    let value = config_edit::get(&project.config, &args.key)?;
+    if json {
+        println!(
+            "{}",
+            serde_json::to_string_pretty(&serde_json::json!({
+                "key": args.key,
+                "value": value,
+            }))?
+        );
+    } else {
+        println!("{}", config_value_text(&value));
+    }
+    Ok(())
+}
+
+fn cmd_config_list(json: bool) -> Result<(), Box<dyn std::error::Error>> {
+    let project = load_project_cwd()?;
+    if json {
+        println!("{}", serde_json::to_string_pretty(&project.config)?);
+        return Ok(());
+    }
+    for (key, value) in config_edit::list(&project.config) {
+        println!("{key} = {value}");
+    }
+    Ok(())
+}
+
+fn cmd_config_set(args: ConfigSetArgs, json: bool) -> Result<(), Box<dyn std::error::Error>> {
+    dryrun::arm(args.dry_run);
+    let (project, _lock) = lock_and_load()?;
+    let (_, mut doc) = config_io::read_config(&project.frame_dir)?;
+    let before = doc.to_string();
+    let config = config_edit::set(&mut doc, &args.key, &args.value)?;
+    let changed = doc.to_string() != before;
+    if changed {
+        config_io::write_config(&project.frame_dir, &doc)?;
+    }
+    report_config_write(json, "config set", changed, &args.key, &config, || {
+        println!("{} = {}", args.key, args.value)
+    })
+}
+
+fn cmd_config_unset(args: ConfigUnsetArgs, json: bool) -> Result<(), Box<dyn std::error::Error>> {
+    dryrun::arm(args.dry_run);
+    let (project, _lock) = lock_and_load()?;
+    let (_, mut doc) = config_io::read_config(&project.frame_dir)?;
+    let (removed, config) = config_edit::unset(&mut doc, &args.key)?;
+    if removed {
+        config_io::write_config(&project.frame_dir, &doc)?;
+    }
+    report_config_write(json, "config unset", removed, &args.key, &config, || {
+        let now = match config_edit::get(&config, &args.key) {
+            Ok(value) => format!(" (default: {})", config_value_text(&value)),
+            Err(_) => String::new(),
+        };
+        if removed {
+            println!("{} unset{now}", args.key);
+        } else {
+            println!("{} was not set{now}", args.key);
+        }
+    })
+}
+
+/// The outcome of a config write: the key and the value now in effect, which
+/// after an unset is the default, or `null` for a key with none.
+fn report_config_write(
+    json: bool,
+    command: &str,
+    changed: bool,
+    key: &str,
+    config: &crate::model::config::ProjectConfig,
+    human: impl FnOnce(),
+) -> Result<(), Box<dyn std::error::Error>> {
+    if json {
+        let value = config_edit::get(config, key).ok();
+        println!(
+            "{}",
+            serde_json::to_string_pretty(&with_dry_run(serde_json::json!({
+                "command": command,
+                "changed": changed,
+                "key": key,
+                "value": value,
+            })))?
+        );
+    } else {
+        human();
+    }
+    Ok(())
+}
+
 // ---------------------------------------------------------------------------
 // Project registry handlers
 // ---------------------------------------------------------------------------
@@ -4632,16 +5832,24 @@ fn cmd_import(args: ImportArgs, json: bool) -> Result<(), Box<dyn std::error::Er
                 .collect()
         })
         .unwrap_or_default();
-    report_task_write(json, "import", true, Some(&args.track), imported, || {
-        println!(
-            "imported {} tasks ({} including subtasks)",
-            result.assigned_ids.len(),
-            result.total_count
-        );
-        for id in &result.assigned_ids {
-            println!("  {}", id);
-        }
-    })
+    report_task_write(
+        json,
+        "import",
+        &project,
+        true,
+        Some(&args.track),
+        imported,
+        || {
+            println!(
+                "imported {} tasks ({} including subtasks)",
+                result.assigned_ids.len(),
+                result.total_count
+            );
+            for id in &result.assigned_ids {
+                println!("  {}", id);
+            }
+        },
+    )
 }
 
 fn cmd_delete(args: DeleteArgs, json: bool) -> Result<(), Box<dyn std::error::Error>> {
@@ -4654,7 +5862,7 @@ fn cmd_delete(args: DeleteArgs, json: bool) -> Result<(), Box<dyn std::error::Er
     let mut to_delete: Vec<(String, String)> = Vec::new(); // (track_id, task_id)
     for task_id in &args.ids {
         let track_id = find_task_track(&project, task_id)
-            .ok_or_else(|| task_not_found(&project.frame_dir, task_id))?
+            .ok_or_else(|| task_not_found(&project, task_id))?
             .to_string();
         to_delete.push((track_id, task_id.clone()));
     }
@@ -4719,11 +5927,19 @@ fn cmd_delete(args: DeleteArgs, json: bool) -> Result<(), Box<dyn std::error::Er
         save_track(&project, track_id)?;
     }
 
-    report_task_write(json, "delete", true, None, doomed.iter().collect(), || {
-        for (_, task_id) in &to_delete {
-            println!("deleted {}", task_id);
-        }
-    })
+    report_task_write(
+        json,
+        "delete",
+        &project,
+        true,
+        None,
+        doomed.iter().collect(),
+        || {
+            for (_, task_id) in &to_delete {
+                println!("deleted {}", task_id);
+            }
+        },
+    )
 }
 
 // ---------------------------------------------------------------------------
@@ -4909,7 +6125,8 @@ fn cmd_actor_merge(args: ActorMergeArgs, json: bool) -> Result<(), Box<dyn std::
     let mut track_archives: Vec<(PathBuf, Track)> = Vec::new();
     for path in whole_track_archive_files(&frame_dir) {
         let content = std::fs::read_to_string(&path)?;
-        let track = crate::parse::parse_track(&content);
+        let schema = crate::parse::Schema::of(&project.config);
+        let track = crate::parse::parse_track_with(&content, &schema);
         actor_merge::collect_ids_in_track(&track, &mut all_ids);
         track_archives.push((path, track));
     }
@@ -5503,18 +6720,35 @@ fn cmd_recovery(args: RecoveryCmd, global_json: bool) -> Result<(), Box<dyn std:
                 None
             };
 
-            let listing = recovery::read_recovery_filtered(
-                &project.frame_dir,
-                limit,
-                since,
-                args.for_id.as_deref(),
-                args.here,
-            );
+            let read = |for_id: Option<&str>| {
+                recovery::read_recovery_filtered(
+                    &project.frame_dir,
+                    limit,
+                    since,
+                    for_id,
+                    args.here,
+                )
+            };
+            // The log keeps timestamps and the IDs of deleted tasks, which a
+            // short form of some live task's ID could shadow (`M-0` is loosely
+            // `M-000`), so `--for` is looked for as given and only read as a
+            // short ID when nothing mentions it.
+            let mut for_id = args.for_id.clone();
+            let mut listing = read(for_id.as_deref());
+            if let Some(input) = &args.for_id
+                && listing.entries.is_empty()
+            {
+                let resolved = resolve::resolve_task_id(&project, input, None)?;
+                if &resolved != input {
+                    listing = read(Some(&resolved));
+                    for_id = Some(resolved);
+                }
+            }
 
             if listing.entries.is_empty() {
                 if json {
                     println!("[]");
-                } else if let Some(id) = &args.for_id {
+                } else if let Some(id) = &for_id {
                     println!("No recovery log entries for {id}.");
                 } else {
                     println!("No recovery log entries.");
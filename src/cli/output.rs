@@ -1,8 +1,13 @@
+use std::collections::{HashMap, HashSet};
+
+use indexmap::IndexMap;
 use serde::Serialize;
 
-use crate::model::task::{Metadata, Task, TaskState, ordered_metadata};
-use crate::model::track::Track;
-use crate::ops::deps::{DepNode, DepStatus};
+use crate::model::config::{FieldSchema, WorkflowConfig};
+use crate::model::task::{Metadata, RelationKind, Task, TaskState, ordered_metadata};
+use crate::model::track::{SectionKind, Track};
+use crate::ops::deps::{AutoStateChange, DepGraph, DepNode, DepStatus, Plan};
+use crate::ops::relations::{self, Backlink, Epic};
 use crate::ops::track_ops::TrackStats;
 
 // ---------------------------------------------------------------------------
@@ -23,7 +28,8 @@ use crate::ops::track_ops::TrackStats;
 pub struct TaskJson {
     pub id: Option<String>,
     pub title: String,
-    pub state: TaskState,
+    /// The state's name: built-in, or as `[workflow.states]` declares it.
+    pub state: String,
     pub tags: Vec<String>,
     /// Where an archived task was read from. Present only when the task came out
     /// of an archive rather than a live track — absent, not null, so a consumer
@@ -45,12 +51,25 @@ pub struct TaskJson {
     pub resolved: Option<String>,
     #[serde(skip_serializing_if = "Vec::is_empty")]
     pub deps: Vec<String>,
+    /// Typed relations by key, as the task declares them:
+    /// `{"duplicates": ["EFF-003"]}`.
+    #[serde(skip_serializing_if = "IndexMap::is_empty")]
+    pub relations: IndexMap<String, Vec<String>>,
+    /// The other end: relations other tasks declare naming this one, by the
+    /// key *they* carry — `{"duplicates": ["EFF-007"]}` on EFF-003 says EFF-007
+    /// duplicates it. `fr show` fills it in; everywhere else it is absent.
+    #[serde(skip_serializing_if = "IndexMap::is_empty")]
+    pub related_from: IndexMap<String, Vec<String>>,
     /// Spec paths. An array since 0.1.8 — a task may carry several, the same way
     /// `refs` always could. It was a bare string before that.
     #[serde(skip_serializing_if = "Vec::is_empty")]
     pub spec: Vec<String>,
     #[serde(skip_serializing_if = "Vec::is_empty")]
     pub refs: Vec<String>,
+    /// Custom `key: value` fields, in the order the task carries them. Values
+    /// are the text as written — `[fields]` types them for `fr check`, not here.
+    #[serde(skip_serializing_if = "IndexMap::is_empty")]
+    pub fields: IndexMap<String, String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub note: Option<String>,
     #[serde(skip_serializing_if = "Vec::is_empty")]
@@ -156,6 +175,21 @@ pub struct TaskWriteJson {
     /// the same strings to stderr.
     #[serde(skip_serializing_if = "Vec::is_empty")]
     pub warnings: Vec<String>,
+    /// States `[deps] auto_block` changed as a consequence of this write — on
+    /// this task or on others that depend on it.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub auto_state: Vec<AutoStateJson>,
+}
+
+/// A state change nobody asked for by name. See [`TaskWriteJson::auto_state`].
+#[derive(Serialize)]
+pub struct AutoStateJson {
+    pub track: String,
+    pub id: String,
+    pub from: String,
+    pub to: String,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub waiting_on: Vec<String>,
 }
 
 /// What a track-writing command did. [`TaskWriteJson`]'s rules, one level up.
@@ -171,6 +205,9 @@ pub struct TrackWriteJson {
 
 #[derive(Serialize)]
 pub struct TaskListJson {
+    /// The registered project, under `--all-projects` / `--projects`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub project: Option<String>,
     pub track: String,
     pub tasks: Vec<TaskJson>,
 }
@@ -186,6 +223,9 @@ pub struct ReadyJson {
 
 #[derive(Serialize)]
 pub struct TaskWithTrackJson {
+    /// The registered project, under `--all-projects` / `--projects`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub project: Option<String>,
     pub track: String,
     #[serde(flatten)]
     pub task: TaskJson,
@@ -208,6 +248,13 @@ pub struct TrackStatsJson {
     pub todo: usize,
     pub parked: usize,
     pub done: usize,
+    /// Tasks in a `[workflow.states]` state; absent when there are none.
+    #[serde(skip_serializing_if = "is_zero")]
+    pub custom: usize,
+}
+
+fn is_zero(n: &usize) -> bool {
+    *n == 0
 }
 
 #[derive(Serialize)]
@@ -227,6 +274,9 @@ pub struct StatsJson {
 
 #[derive(Serialize)]
 pub struct TrackStatsEntryJson {
+    /// The registered project, under `--all-projects` / `--projects`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub project: Option<String>,
     pub id: String,
     pub name: String,
     pub stats: TrackStatsJson,
@@ -239,26 +289,131 @@ pub struct TrackStatsEntryJson {
 /// surfaces directly. A consumer that wants edges can flatten this; a parity
 /// test cannot un-flatten a graph.
 ///
-/// Everything but `id` and `status` is absent on a non-`resolved` node. A
-/// `cycle` or `repeat` node points at a record that appears elsewhere in the
-/// same document, and repeating the record would invite a consumer to count one
-/// task twice; a `missing` node has no record at all.
+/// Everything but `id`, `status` and `project` is absent on a non-`resolved`
+/// node. A `cycle` or `repeat` node points at a record that appears elsewhere
+/// in the same document, and repeating the record would invite a consumer to
+/// count one task twice; a `missing` node has no record at all, and an
+/// `unreachable` one's project could not be loaded to look.
 #[derive(Serialize)]
 pub struct DepNodeJson {
     pub id: String,
     pub status: DepStatus,
+    /// The registered project a cross-project dep points into, whatever its
+    /// status.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub project: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub track: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub title: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub state: Option<TaskState>,
+    pub state: Option<String>,
     #[serde(skip_serializing_if = "Vec::is_empty")]
     pub tags: Vec<String>,
     #[serde(skip_serializing_if = "Vec::is_empty")]
     pub deps: Vec<DepNodeJson>,
 }
 
+/// `fr graph --json`. Nodes then edges, the order DOT and Mermaid print them.
+#[derive(Serialize)]
+pub struct DepGraphJson {
+    pub nodes: Vec<GraphNodeJson>,
+    pub edges: Vec<GraphEdgeJson>,
+}
+
+/// A `missing` node — a dep target no task holds — carries `id` alone.
+#[derive(Serialize)]
+pub struct GraphNodeJson {
+    pub id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub track: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub state: Option<String>,
+}
+
+/// `from` has to be done before `to` can be.
+#[derive(Serialize)]
+pub struct GraphEdgeJson {
+    pub from: String,
+    pub to: String,
+}
+
+/// `fr plan --json`.
+///
+/// Flat entries rather than [`TaskWithTrackJson`]: subtasks are planned as
+/// steps of their own, so nesting them under their parent as well would name
+/// one task twice. `unordered` is always present, empty when nothing sits on a
+/// cycle, so the shape does not change with the project.
+#[derive(Serialize)]
+pub struct PlanJson {
+    pub tasks: Vec<PlanStepJson>,
+    pub unordered: Vec<String>,
+}
+
+#[derive(Serialize)]
+pub struct PlanStepJson {
+    pub step: usize,
+    pub track: String,
+    pub id: Option<String>,
+    pub title: String,
+    pub state: String,
+    pub tags: Vec<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub after: Vec<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub waiting_on: Vec<String>,
+}
+
+/// `fr deps ID --critical-path --json`. `tasks` runs first-to-do first and ends
+/// at the goal, the order the human surface prints.
+#[derive(Serialize)]
+pub struct CriticalPathJson {
+    pub goal: String,
+    pub length: usize,
+    /// Present under `--weight`: the field and the chain's total of it.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub weight: Option<PathWeightJson>,
+    pub tasks: Vec<CriticalPathTaskJson>,
+}
+
+#[derive(Serialize)]
+pub struct PathWeightJson {
+    pub field: String,
+    pub total: f64,
+}
+
+#[derive(Serialize)]
+pub struct CriticalPathTaskJson {
+    pub track: String,
+    pub id: Option<String>,
+    pub title: String,
+    pub state: String,
+}
+
+/// `fr epic --json`: the epic, its rollup, then its members in track and file
+/// order — the order the human surface prints.
+#[derive(Serialize)]
+pub struct EpicJson {
+    pub track: String,
+    pub id: Option<String>,
+    pub title: String,
+    pub state: String,
+    pub total: usize,
+    pub done: usize,
+    pub blocked: usize,
+    pub tasks: Vec<EpicMemberJson>,
+}
+
+#[derive(Serialize)]
+pub struct EpicMemberJson {
+    pub track: String,
+    pub id: Option<String>,
+    pub title: String,
+    pub state: String,
+}
+
 /// `fr search --json`.
 ///
 /// Three named arrays rather than one flat array of tagged hits: each is
@@ -286,6 +441,9 @@ pub struct SearchJson {
 /// mean reporting whichever field the scan happened to reach first.
 #[derive(Serialize)]
 pub struct SearchHitJson {
+    /// The registered project, under `--all-projects` / `--projects`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub project: Option<String>,
     pub track: String,
     /// Absent when the hit does not resolve to a task. Reachable for a task
     /// with no id: hits carry `""` for those, and nothing can be looked up by
@@ -297,6 +455,9 @@ pub struct SearchHitJson {
 
 #[derive(Serialize)]
 pub struct InboxSearchHitJson {
+    /// The registered project, under `--all-projects` / `--projects`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub project: Option<String>,
     pub index: usize,
     pub title: String,
     pub tags: Vec<String>,
@@ -309,54 +470,75 @@ pub struct InboxSearchHitJson {
 // Conversions
 // ---------------------------------------------------------------------------
 
-pub fn task_to_json(task: &Task) -> TaskJson {
+pub fn task_to_json(task: &Task, workflow: &WorkflowConfig) -> TaskJson {
     let mut deps = Vec::new();
+    let mut relations: IndexMap<String, Vec<String>> = IndexMap::new();
     let mut refs = Vec::new();
     let mut spec = Vec::new();
     let mut note = None;
     let mut added = None;
     let mut resolved = None;
     let mut conflict = None;
+    let mut fields = IndexMap::new();
 
     for m in &task.metadata {
         match m {
             Metadata::Dep(d) => deps.extend(d.iter().cloned()),
+            Metadata::Relation(kind, ids) => relations
+                .entry(kind.key().to_string())
+                .or_default()
+                .extend(ids.iter().cloned()),
             Metadata::Ref(r) => refs.extend(r.iter().cloned()),
             Metadata::Spec(s) => spec.extend(s.iter().cloned()),
             Metadata::Note(n) => note = Some(n.clone()),
             Metadata::Added(a) => added = Some(a.clone()),
             Metadata::Resolved(r) => resolved = Some(r.clone()),
             Metadata::Conflict(c) => conflict = Some(c.clone()),
+            Metadata::Custom { key, value } => {
+                fields.insert(key.clone(), value.clone());
+            }
         }
     }
 
     TaskJson {
         id: task.id.as_ref().map(|i| i.to_string()),
         title: task.title.clone(),
-        state: task.state,
+        state: workflow.state_name(task.state),
         tags: task.tags.clone(),
         deps,
+        relations,
+        related_from: IndexMap::new(),
         spec,
         refs,
+        fields,
         note,
         added,
         resolved,
         conflict,
         archived: None,
-        subtasks: task.subtasks.iter().map(task_to_json).collect(),
+        subtasks: task
+            .subtasks
+            .iter()
+            .map(|sub| task_to_json(sub, workflow))
+            .collect(),
         ancestors: Vec::new(),
     }
 }
 
-pub fn dep_tree_to_json(node: &DepNode) -> DepNodeJson {
+pub fn dep_tree_to_json(node: &DepNode, workflow: &WorkflowConfig) -> DepNodeJson {
     DepNodeJson {
         id: node.id.clone(),
         status: node.status,
+        project: node.project.clone(),
         track: node.track_id.clone(),
         title: node.title.clone(),
-        state: node.state,
+        state: node.state.map(|state| workflow.state_name(state)),
         tags: node.tags.clone(),
-        deps: node.deps.iter().map(dep_tree_to_json).collect(),
+        deps: node
+            .deps
+            .iter()
+            .map(|dep| dep_tree_to_json(dep, workflow))
+            .collect(),
     }
 }
 
@@ -367,6 +549,7 @@ pub fn stats_to_json(stats: &TrackStats) -> TrackStatsJson {
         todo: stats.todo,
         parked: stats.parked,
         done: stats.done,
+        custom: stats.custom,
     }
 }
 
@@ -414,7 +597,11 @@ pub fn format_task_tree(task: &Task, indent: usize) -> Vec<String> {
 }
 
 /// Format detailed task view
-pub fn format_task_detail(task: &Task, archived: Option<&ArchivedIn>) -> Vec<String> {
+pub fn format_task_detail(
+    task: &Task,
+    archived: Option<&ArchivedIn>,
+    backlinks: &[Backlink],
+) -> Vec<String> {
     let mut lines = Vec::new();
 
     // Header
@@ -439,7 +626,7 @@ pub fn format_task_detail(task: &Task, archived: Option<&ArchivedIn>) -> Vec<Str
     }
 
     lines.extend(format_archived_line(archived, ""));
-    lines.extend(format_metadata_lines(task, ""));
+    lines.extend(format_metadata_lines(task, "", backlinks));
 
     // Subtasks
     if !task.subtasks.is_empty() {
@@ -474,17 +661,18 @@ pub fn format_task_detail_with_context(
     ancestors: &[&Task],
     task: &Task,
     archived: Option<&ArchivedIn>,
+    backlinks: &[Backlink],
 ) -> Vec<String> {
     let mut lines = Vec::new();
 
     for ancestor in ancestors {
         lines.push(format_context_separator("Parent", ancestor));
-        lines.extend(format_context_fields(ancestor, None));
+        lines.extend(format_context_fields(ancestor, None, &[]));
         lines.push(String::new());
     }
 
     lines.push(format_context_separator("Task", task));
-    lines.extend(format_context_fields(task, archived));
+    lines.extend(format_context_fields(task, archived, backlinks));
 
     // Subtasks
     if !task.subtasks.is_empty() {
@@ -501,17 +689,14 @@ pub fn format_task_detail_with_context(
 }
 
 /// Format the fields of a task for context display (indented, no header)
-fn format_context_fields(task: &Task, archived: Option<&ArchivedIn>) -> Vec<String> {
+fn format_context_fields(
+    task: &Task,
+    archived: Option<&ArchivedIn>,
+    backlinks: &[Backlink],
+) -> Vec<String> {
     let mut lines = Vec::new();
 
-    let state_str = match task.state {
-        TaskState::Todo => "todo",
-        TaskState::Active => "active",
-        TaskState::Blocked => "blocked",
-        TaskState::Done => "done",
-        TaskState::Parked => "parked",
-    };
-    lines.push(format!("  state: {}", state_str));
+    lines.push(format!("  state: {}", state_name(task.state)));
 
     if !task.tags.is_empty() {
         lines.push(format!(
@@ -525,7 +710,7 @@ fn format_context_fields(task: &Task, archived: Option<&ArchivedIn>) -> Vec<Stri
     }
 
     lines.extend(format_archived_line(archived, "  "));
-    lines.extend(format_metadata_lines(task, "  "));
+    lines.extend(format_metadata_lines(task, "  ", backlinks));
 
     lines
 }
@@ -557,14 +742,29 @@ fn format_archived_line(archived: Option<&ArchivedIn>, indent: &str) -> Vec<Stri
 ///
 /// Order comes from [`ordered_metadata`], not from the file: a field appended
 /// after a note otherwise renders past the end of it.
-fn format_metadata_lines(task: &Task, indent: &str) -> Vec<String> {
+///
+/// `backlinks` — relations other tasks declare naming this one — print where
+/// the task's own relations rank, after them: they are not in this task's
+/// file, but they answer the same question.
+fn format_metadata_lines(task: &Task, indent: &str, backlinks: &[Backlink]) -> Vec<String> {
+    let relation_rank = Metadata::Relation(RelationKind::Relates, Vec::new()).rank();
+    let mut backlink_lines: Vec<String> = relations::group_backlinks(backlinks)
+        .into_iter()
+        .map(|(kind, sources)| format!("{indent}{}: {}", kind.inverse_label(), sources.join(", ")))
+        .collect();
     let mut lines = Vec::new();
     for m in ordered_metadata(task) {
+        if m.rank() > relation_rank {
+            lines.append(&mut backlink_lines);
+        }
         match m {
             Metadata::Conflict(c) => lines.push(format!("{indent}conflict: {c}")),
             Metadata::Added(d) => lines.push(format!("{indent}added: {d}")),
             Metadata::Resolved(d) => lines.push(format!("{indent}resolved: {d}")),
             Metadata::Dep(deps) => lines.push(format!("{indent}dep: {}", deps.join(", "))),
+            Metadata::Relation(kind, ids) => {
+                lines.push(format!("{indent}{}: {}", kind.key(), ids.join(", ")))
+            }
             Metadata::Spec(specs) => {
                 for s in specs {
                     lines.push(format!("{indent}spec: {s}"));
@@ -575,6 +775,7 @@ fn format_metadata_lines(task: &Task, indent: &str) -> Vec<String> {
                     lines.push(format!("{indent}ref: {r}"));
                 }
             }
+            Metadata::Custom { key, value } => lines.push(format!("{indent}{key}: {value}")),
             Metadata::Note(n) => {
                 lines.push(format!("{indent}note:"));
                 for line in n.lines() {
@@ -583,6 +784,7 @@ fn format_metadata_lines(task: &Task, indent: &str) -> Vec<String> {
             }
         }
     }
+    lines.append(&mut backlink_lines);
     lines
 }
 
@@ -603,6 +805,8 @@ pub fn format_track_header(track_id: &str, track: &Track) -> String {
 pub struct FilteredTasks<'a> {
     pub backlog: Vec<&'a Task>,
     pub parked: Vec<&'a Task>,
+    /// `[workflow] sections`, in file order, each with its selected tasks.
+    pub custom: Vec<(SectionKind, Vec<&'a Task>)>,
     pub done: Vec<&'a Task>,
 }
 
@@ -612,6 +816,7 @@ impl<'a> FilteredTasks<'a> {
         self.backlog
             .iter()
             .chain(&self.parked)
+            .chain(self.custom.iter().flat_map(|(_, tasks)| tasks))
             .chain(&self.done)
             .copied()
     }
@@ -622,6 +827,7 @@ pub fn select_tasks<'a>(
     track: &'a Track,
     state_filter: Option<TaskState>,
     tag_filter: Option<&str>,
+    field_filters: &[FieldFilter],
 ) -> FilteredTasks<'a> {
     let matches = |task: &&Task| -> bool {
         if let Some(sf) = state_filter
@@ -634,12 +840,23 @@ pub fn select_tasks<'a>(
         {
             return false;
         }
-        true
+        field_filters.iter().all(|f| f.accepts(task))
     };
 
     FilteredTasks {
         backlog: track.backlog().iter().filter(matches).collect(),
         parked: track.parked().iter().filter(matches).collect(),
+        custom: track
+            .section_kinds()
+            .into_iter()
+            .filter(|kind| matches!(kind, SectionKind::Custom(_)))
+            .map(|kind| {
+                (
+                    kind,
+                    track.section_tasks(kind).iter().filter(matches).collect(),
+                )
+            })
+            .collect(),
         // Done tasks are only surfaced when explicitly filtered for; otherwise
         // the completed pile would drown out the live backlog.
         done: if state_filter == Some(TaskState::Done) {
@@ -650,6 +867,23 @@ pub fn select_tasks<'a>(
     }
 }
 
+/// `fr list --field KEY=VALUE`, with the schema that says how to compare.
+pub struct FieldFilter<'a> {
+    pub key: &'a str,
+    pub wanted: &'a str,
+    /// `None` for a key `[fields]` does not declare: compared as text.
+    pub schema: Option<&'a FieldSchema>,
+}
+
+impl FieldFilter<'_> {
+    pub fn accepts(&self, task: &Task) -> bool {
+        task.field(self.key).is_some_and(|value| match self.schema {
+            Some(schema) => schema.matches(value, self.wanted),
+            None => value.trim() == self.wanted,
+        })
+    }
+}
+
 /// Format a track's task listing
 pub fn format_track_listing(track_id: &str, track: &Track, tasks: &FilteredTasks) -> Vec<String> {
     let mut lines = Vec::new();
@@ -677,6 +911,19 @@ pub fn format_track_listing(track_id: &str, track: &Track, tasks: &FilteredTasks
         any_shown = true;
     }
 
+    for (kind, section) in tasks.custom.iter().filter(|(_, t)| !t.is_empty()) {
+        if any_shown {
+            lines.push(String::new());
+        }
+        lines.push(format!("-- {kind} --"));
+        for task in section {
+            for line in format_task_tree(task, 0) {
+                lines.push(line);
+            }
+        }
+        any_shown = true;
+    }
+
     if !tasks.done.is_empty() {
         if any_shown {
             lines.push(String::new());
@@ -697,6 +944,16 @@ pub fn format_track_listing(track_id: &str, track: &Track, tasks: &FilteredTasks
 /// The root line carries tags and the descendants do not; that asymmetry is
 /// how `fr deps` has always printed and is preserved deliberately.
 pub fn format_dep_tree(root: &DepNode) -> Vec<String> {
+    format_tree(root, "(no dependencies)")
+}
+
+/// `fr deps --reverse`: the same tree drawn the other way up — each child is a
+/// task that depends on its parent.
+pub fn format_dependents_tree(root: &DepNode) -> Vec<String> {
+    format_tree(root, "(no dependents)")
+}
+
+fn format_tree(root: &DepNode, empty: &str) -> Vec<String> {
     let mut lines = Vec::new();
 
     let state = root.state.unwrap_or(TaskState::Todo);
@@ -721,7 +978,7 @@ pub fn format_dep_tree(root: &DepNode) -> Vec<String> {
     ));
 
     if root.deps.is_empty() {
-        lines.push("  (no dependencies)".to_string());
+        lines.push(format!("  {}", empty));
     } else {
         for dep in &root.deps {
             format_dep_node(dep, 1, &mut lines);
@@ -751,22 +1008,455 @@ fn format_dep_node(node: &DepNode, indent: usize, lines: &mut Vec<String>) {
         DepStatus::Cycle => lines.push(format!("{}└─ {} (circular)", prefix, node.id)),
         DepStatus::Repeat => lines.push(format!("{}└─ {} (already shown)", prefix, node.id)),
         DepStatus::Missing => lines.push(format!("{}└─ {} (not found)", prefix, node.id)),
+        DepStatus::Unreachable => {
+            lines.push(format!("{}└─ {} (project unreachable)", prefix, node.id))
+        }
     }
 }
 
-/// Parse a state string into TaskState
-pub fn parse_task_state(s: &str) -> Result<TaskState, String> {
-    match s {
-        "todo" => Ok(TaskState::Todo),
-        "active" => Ok(TaskState::Active),
-        "blocked" => Ok(TaskState::Blocked),
-        "done" => Ok(TaskState::Done),
-        "parked" => Ok(TaskState::Parked),
-        _ => Err(format!(
-            "unknown state '{}' (expected: todo, active, blocked, done, parked)",
-            s
-        )),
+pub fn dep_graph_to_json(graph: &DepGraph, workflow: &WorkflowConfig) -> DepGraphJson {
+    DepGraphJson {
+        nodes: graph
+            .nodes
+            .iter()
+            .map(|n| GraphNodeJson {
+                id: n.id.clone(),
+                track: n.track_id.clone(),
+                title: n.title.clone(),
+                state: n.state.map(|state| workflow.state_name(state)),
+            })
+            .collect(),
+        edges: graph
+            .edges
+            .iter()
+            .map(|(from, to)| GraphEdgeJson {
+                from: from.clone(),
+                to: to.clone(),
+            })
+            .collect(),
+    }
+}
+
+/// Fill colour per state, shared by both diagram formats so a diagram reads
+/// the same whichever tool draws it.
+fn state_fill(state: Option<TaskState>) -> &'static str {
+    match state {
+        Some(TaskState::Todo) => "#ffffff",
+        Some(TaskState::Active) => "#fff3b0",
+        Some(TaskState::Blocked) => "#f8c4c4",
+        Some(TaskState::Done) => "#c8e6c9",
+        Some(TaskState::Parked) => "#e0e0e0",
+        Some(TaskState::Custom(_)) => "#d6e4f0",
+        None => "#ffffff",
+    }
+}
+
+/// Group a graph's nodes into per-track runs, in node order, with the dangling
+/// targets (no track) as a final run of their own.
+fn graph_clusters(graph: &DepGraph) -> Vec<(Option<&str>, Vec<&crate::ops::deps::GraphNode>)> {
+    let mut clusters: Vec<(Option<&str>, Vec<&crate::ops::deps::GraphNode>)> = Vec::new();
+    for node in &graph.nodes {
+        let track = node.track_id.as_deref();
+        match clusters.last_mut() {
+            Some((t, nodes)) if *t == track => nodes.push(node),
+            _ => clusters.push((track, vec![node])),
+        }
+    }
+    clusters
+}
+
+fn dot_escape(s: &str) -> String {
+    s.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+/// Graphviz DOT: one cluster per track, nodes filled by state, dangling
+/// targets dashed. Node lines first and edge lines after, so every line that
+/// names a task names it first.
+pub fn format_graph_dot(
+    graph: &DepGraph,
+    track_names: &std::collections::HashMap<&str, &str>,
+) -> Vec<String> {
+    let mut lines = vec![
+        "digraph deps {".to_string(),
+        "  rankdir=LR;".to_string(),
+        "  node [shape=box, style=\"rounded,filled\", fontname=\"Helvetica\"];".to_string(),
+    ];
+    for (track, nodes) in graph_clusters(graph) {
+        let indent = match track {
+            Some(track) => {
+                lines.push(format!("  subgraph \"cluster_{}\" {{", dot_escape(track)));
+                let name = track_names.get(track).copied().unwrap_or(track);
+                lines.push(format!("    label=\"{}\";", dot_escape(name)));
+                "    "
+            }
+            None => "  ",
+        };
+        for node in nodes {
+            match &node.title {
+                Some(title) => lines.push(format!(
+                    "{}\"{}\" [label=\"{}\\n{}\", fillcolor=\"{}\"];",
+                    indent,
+                    dot_escape(&node.id),
+                    dot_escape(&node.id),
+                    dot_escape(title),
+                    state_fill(node.state)
+                )),
+                None => lines.push(format!(
+                    "{}\"{}\" [label=\"{}\\n(not found)\", style=dashed];",
+                    indent,
+                    dot_escape(&node.id),
+                    dot_escape(&node.id)
+                )),
+            }
+        }
+        if track.is_some() {
+            lines.push("  }".to_string());
+        }
+    }
+    for (from, to) in &graph.edges {
+        lines.push(format!(
+            "  \"{}\" -> \"{}\";",
+            dot_escape(from),
+            dot_escape(to)
+        ));
+    }
+    lines.push("}".to_string());
+    lines
+}
+
+/// `s` with anything but a letter or digit swapped for `_`. Mermaid reads `-`
+/// and `.` as edge syntax, so ids cannot be used bare.
+fn mermaid_name(s: &str) -> String {
+    s.chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect()
+}
+
+/// The Mermaid ids of a graph's tracks and tasks, from [`mermaid_name`]. Two
+/// ids that read the same — `EFF-1` beside `EFF_1`, `lib:X-1` beside `lib_X-1`
+/// — would be one node, so the later one takes a `_2`, `_3`... suffix. The
+/// label still shows the real id.
+fn mermaid_ids(graph: &DepGraph) -> (HashMap<&str, String>, HashMap<&str, String>) {
+    let mut taken = HashSet::new();
+    let mut unique = |base: String| {
+        let mut id = base.clone();
+        let mut n = 1;
+        while !taken.insert(id.clone()) {
+            n += 1;
+            id = format!("{}_{}", base, n);
+        }
+        id
+    };
+    let mut tracks = HashMap::new();
+    for track in graph.nodes.iter().filter_map(|n| n.track_id.as_deref()) {
+        if !tracks.contains_key(track) {
+            tracks.insert(track, unique(format!("track_{}", mermaid_name(track))));
+        }
+    }
+    let nodes = graph
+        .nodes
+        .iter()
+        .map(|n| (n.id.as_str(), unique(mermaid_name(&n.id))))
+        .collect();
+    (tracks, nodes)
+}
+
+fn mermaid_escape(s: &str) -> String {
+    s.replace('"', "#quot;")
+}
+
+/// Mermaid flowchart: one subgraph per track, a `classDef` per state, the
+/// `[workflow.states]` included.
+pub fn format_graph_mermaid(
+    graph: &DepGraph,
+    track_names: &std::collections::HashMap<&str, &str>,
+    workflow: &WorkflowConfig,
+) -> Vec<String> {
+    let (track_ids, node_ids) = mermaid_ids(graph);
+    let mut lines = vec!["flowchart LR".to_string()];
+    for (track, nodes) in graph_clusters(graph) {
+        let indent = match track {
+            Some(track) => {
+                let name = track_names.get(track).copied().unwrap_or(track);
+                lines.push(format!(
+                    "  subgraph {}[\"{}\"]",
+                    track_ids[track],
+                    mermaid_escape(name)
+                ));
+                "    "
+            }
+            None => "  ",
+        };
+        for node in nodes {
+            let label = match &node.title {
+                Some(title) => format!("{} {}", node.id, title),
+                None => format!("{} (not found)", node.id),
+            };
+            lines.push(format!(
+                "{}{}[\"{}\"]",
+                indent,
+                node_ids[node.id.as_str()],
+                mermaid_escape(&label)
+            ));
+        }
+        if track.is_some() {
+            lines.push("  end".to_string());
+        }
+    }
+    // Every edge end is a node of the graph, dangling targets included
+    for (from, to) in &graph.edges {
+        lines.push(format!(
+            "  {} --> {}",
+            node_ids[from.as_str()],
+            node_ids[to.as_str()]
+        ));
+    }
+
+    let mut classes: Vec<(String, Option<TaskState>)> = [
+        ("todo", TaskState::Todo),
+        ("active", TaskState::Active),
+        ("blocked", TaskState::Blocked),
+        ("done", TaskState::Done),
+        ("parked", TaskState::Parked),
+    ]
+    .into_iter()
+    .map(|(class, state)| (class.to_string(), Some(state)))
+    .collect();
+    classes.extend(
+        workflow
+            .states
+            .iter()
+            .map(|(name, config)| (mermaid_name(name), Some(TaskState::Custom(config.checkbox)))),
+    );
+    classes.push(("missing".to_string(), None));
+    for (class, state) in classes {
+        let members: Vec<&str> = graph
+            .nodes
+            .iter()
+            .filter(|n| n.state == state)
+            .map(|n| node_ids[n.id.as_str()].as_str())
+            .collect();
+        if members.is_empty() {
+            continue;
+        }
+        let dash = if state.is_none() {
+            ",stroke-dasharray:4"
+        } else {
+            ""
+        };
+        lines.push(format!(
+            "  classDef {} fill:{},stroke:#333{}",
+            class,
+            state_fill(state),
+            dash
+        ));
+        lines.push(format!("  class {} {}", members.join(","), class));
     }
+    lines
+}
+
+pub fn plan_to_json(plan: &Plan, workflow: &WorkflowConfig) -> PlanJson {
+    PlanJson {
+        tasks: plan
+            .steps
+            .iter()
+            .map(|s| PlanStepJson {
+                step: s.step,
+                track: s.track_id.clone(),
+                id: s.task.id.as_ref().map(|i| i.to_string()),
+                title: s.task.title.clone(),
+                state: workflow.state_name(s.task.state),
+                tags: s.task.tags.clone(),
+                after: s.after.clone(),
+                waiting_on: s.waiting_on.clone(),
+            })
+            .collect(),
+        unordered: plan.unordered.clone(),
+    }
+}
+
+/// One line per task, numbered by step, then a line naming what could not be
+/// ordered. Deps go after the task on the same line, so the task is always the
+/// first id a line names.
+pub fn format_plan(plan: &Plan) -> Vec<String> {
+    let mut lines = Vec::new();
+    let width = plan.steps.last().map_or(1, |s| s.step.to_string().len());
+    for s in &plan.steps {
+        let mut line = format!(
+            "{:>width$}. [{}] {}",
+            s.step,
+            s.track_id,
+            format_task_line(s.task),
+            width = width
+        );
+        if !s.after.is_empty() {
+            line.push_str(&format!(" (after: {})", s.after.join(", ")));
+        }
+        if !s.waiting_on.is_empty() {
+            line.push_str(&format!(" (waiting on: {})", s.waiting_on.join(", ")));
+        }
+        lines.push(line);
+    }
+    if plan.steps.is_empty() && plan.unordered.is_empty() {
+        lines.push("(no open tasks)".to_string());
+    }
+    if !plan.unordered.is_empty() {
+        lines.push(format!(
+            "unordered: {} (on or behind a dependency cycle; see `fr deps`)",
+            plan.unordered.join(", ")
+        ));
+    }
+    lines
+}
+
+pub fn critical_path_to_json(
+    goal: &str,
+    chain: &[(&str, &Task)],
+    weight: Option<&str>,
+    workflow: &WorkflowConfig,
+) -> CriticalPathJson {
+    CriticalPathJson {
+        goal: goal.to_string(),
+        length: chain.len(),
+        weight: weight.map(|field| PathWeightJson {
+            field: field.to_string(),
+            total: path_weight(chain, weight),
+        }),
+        tasks: chain
+            .iter()
+            .map(|(track, task)| CriticalPathTaskJson {
+                track: track.to_string(),
+                id: task.id.as_ref().map(|i| i.to_string()),
+                title: task.title.clone(),
+                state: workflow.state_name(task.state),
+            })
+            .collect(),
+    }
+}
+
+/// The chain first-to-do first, ending at the goal. The header names no id, so
+/// each task is named exactly once.
+pub fn format_critical_path(chain: &[(&str, &Task)], weight: Option<&str>) -> Vec<String> {
+    let total = match weight {
+        Some(field) => format!(", {} {}", field, path_weight(chain, weight)),
+        None => String::new(),
+    };
+    let mut lines = vec![format!(
+        "critical path, {} {}{}, first to do first",
+        chain.len(),
+        if chain.len() == 1 { "task" } else { "tasks" },
+        total
+    )];
+    for (track, task) in chain {
+        lines.push(format!("  [{}] {}", track, format_task_line(task)));
+    }
+    lines
+}
+
+pub fn epic_to_json(epic: &Epic, workflow: &WorkflowConfig) -> EpicJson {
+    let progress = epic.progress();
+    EpicJson {
+        track: epic.track_id.to_string(),
+        id: epic.task.id.as_ref().map(|i| i.to_string()),
+        title: epic.task.title.clone(),
+        state: workflow.state_name(epic.task.state),
+        total: progress.total,
+        done: progress.done,
+        blocked: progress.blocked,
+        tasks: epic
+            .members
+            .iter()
+            .map(|(track, task)| EpicMemberJson {
+                track: track.to_string(),
+                id: task.id.as_ref().map(|i| i.to_string()),
+                title: task.title.clone(),
+                state: workflow.state_name(task.state),
+            })
+            .collect(),
+    }
+}
+
+/// The epic with its rollup, then one line per member.
+pub fn format_epic(epic: &Epic) -> Vec<String> {
+    let progress = epic.progress();
+    let mut rollup = format!("{}/{} done", progress.done, progress.total);
+    if progress.blocked > 0 {
+        rollup.push_str(&format!(", {} blocked", progress.blocked));
+    }
+    let mut lines = vec![format!(
+        "[{}] {} — {}",
+        epic.track_id,
+        format_task_line(epic.task),
+        rollup
+    )];
+    for (track, task) in &epic.members {
+        lines.push(format!("  [{}] {}", track, format_task_line(task)));
+    }
+    if epic.members.is_empty() {
+        lines.push("  (no members — a task joins with `epic: ID`)".to_string());
+    }
+    lines
+}
+
+/// The chain's total weight, the goal included.
+fn path_weight(chain: &[(&str, &Task)], weight: Option<&str>) -> f64 {
+    chain
+        .iter()
+        .map(|(_, task)| crate::ops::deps::task_weight(task, weight))
+        .sum()
+}
+
+/// A state as `fr state` spells it — the inverse of [`parse_task_state`].
+///
+/// Config-free, so a custom state comes out as its checkbox character; where
+/// the config is at hand, [`crate::model::WorkflowConfig::state_name`] has its
+/// declared name.
+pub fn state_name(state: TaskState) -> String {
+    state
+        .builtin_name()
+        .map_or_else(|| state.checkbox_char().to_string(), str::to_string)
+}
+
+/// One `[deps] auto_block` change, as the line printed under the write it
+/// followed from.
+pub fn format_auto_state(change: &AutoStateChange) -> String {
+    if change.waiting_on.is_empty() {
+        format!(
+            "{} → {} (auto: its deps are done)",
+            change.task_id,
+            state_name(change.to)
+        )
+    } else {
+        format!(
+            "{} → {} (auto: waiting on {})",
+            change.task_id,
+            state_name(change.to),
+            change.waiting_on.join(", ")
+        )
+    }
+}
+
+pub fn auto_state_to_json(change: &AutoStateChange, workflow: &WorkflowConfig) -> AutoStateJson {
+    AutoStateJson {
+        track: change.track_id.clone(),
+        id: change.task_id.clone(),
+        from: workflow.state_name(change.from),
+        to: workflow.state_name(change.to),
+        waiting_on: change.waiting_on.clone(),
+    }
+}
+
+/// Parse a state string into TaskState: a built-in name, or one declared under
+/// `[workflow.states]`.
+pub fn parse_task_state(s: &str, workflow: &WorkflowConfig) -> Result<TaskState, String> {
+    workflow.state_named(s).ok_or_else(|| {
+        let expected: Vec<String> = workflow
+            .all_states()
+            .into_iter()
+            .map(|state| workflow.state_name(state))
+            .collect();
+        format!("unknown state '{}' (expected: {})", s, expected.join(", "))
+    })
 }
 
 #[cfg(test)]
@@ -795,7 +1485,7 @@ mod tests {
             Metadata::Resolved("2025-05-14".into()),
             Metadata::Added("2025-05-01".into()),
         ]);
-        let lines = format_task_detail(&task, None);
+        let lines = format_task_detail(&task, None, &[]);
         let keys: Vec<&str> = lines
             .iter()
             .filter_map(|l| l.split_once(':').map(|(k, _)| k))
@@ -813,11 +1503,11 @@ mod tests {
             Metadata::Added("2025-05-01".into()),
             Metadata::Conflict("both-edited 2026-08-03T04:08:38Z".into()),
         ]);
-        let plain: Vec<String> = format_task_detail(&task, None)
+        let plain: Vec<String> = format_task_detail(&task, None, &[])
             .iter()
             .filter_map(|l| l.split_once(':').map(|(k, _)| k.trim().to_string()))
             .collect();
-        let context: Vec<String> = format_context_fields(&task, None)
+        let context: Vec<String> = format_context_fields(&task, None, &[])
             .iter()
             .filter_map(|l| l.split_once(':').map(|(k, _)| k.trim().to_string()))
             .collect();
@@ -839,7 +1529,7 @@ mod tests {
         ]);
         let origin = ArchivedIn::new("bac", "archive/bac.md");
 
-        let plain = format_task_detail(&task, Some(&origin));
+        let plain = format_task_detail(&task, Some(&origin), &[]);
         let fields: Vec<&str> = plain
             .iter()
             .filter_map(|l| l.split_once(':').map(|(k, _)| k.trim()))
@@ -853,7 +1543,7 @@ mod tests {
         );
 
         // The context form indents the same line, under the shown task only.
-        let context = format_task_detail_with_context(&[&task], &task, Some(&origin));
+        let context = format_task_detail_with_context(&[&task], &task, Some(&origin), &[]);
         assert_eq!(
             context
                 .iter()
@@ -875,7 +1565,7 @@ mod tests {
             Metadata::Added("2025-05-01".into()),
             Metadata::Note("second".into()),
         ]);
-        let lines = format_task_detail(&task, None);
+        let lines = format_task_detail(&task, None, &[]);
         let bodies: Vec<&String> = lines.iter().filter(|l| l.starts_with("  ")).collect();
         assert_eq!(bodies, ["  first", "  second"], "{lines:?}");
     }
@@ -64,12 +64,23 @@ pub fn write_config_from_struct(
 /// Every overwrite of an existing key here goes through this. Creating a key
 /// that was not there gets the default decor, which is correct: there is
 /// nothing to preserve.
-fn set_keeping_decor(table: &mut toml_edit::Table, key: &str, value: &str) {
+pub fn set_keeping_decor(
+    table: &mut dyn toml_edit::TableLike,
+    key: &str,
+    value: impl Into<toml_edit::Value>,
+) {
     let decor = table
         .get(key)
         .and_then(|item| item.as_value())
         .map(|v| v.decor().clone());
-    table[key] = toml_edit::value(value);
+    // Assigned in place when the key exists: `insert` would replace the key
+    // too, and a comment on the line *above* a setting is the key's decor.
+    match table.get_mut(key) {
+        Some(item) => *item = toml_edit::value(value),
+        None => {
+            table.insert(key, toml_edit::value(value));
+        }
+    }
     if let Some(decor) = decor
         && let Some(new) = table.get_mut(key).and_then(|item| item.as_value_mut())
     {
@@ -356,7 +367,7 @@ pub fn update_track_id(doc: &mut toml_edit::DocumentMut, old_id: &str, new_id: &
         for table in tracks.iter_mut() {
             if table.get("id").and_then(|v| v.as_str()) == Some(old_id) {
                 set_keeping_decor(table, "id", new_id);
-                set_keeping_decor(table, "file", &format!("tracks/{}.md", new_id));
+                set_keeping_decor(table, "file", format!("tracks/{}.md", new_id));
                 break;
             }
         }
@@ -82,6 +82,22 @@ pub enum Operation {
         source_track: String,
         target_track: String,
     },
+    /// A task moved into another project (`fr mv --project`), re-minted there.
+    ///
+    /// The marker lives in the *source* project: the destination is written
+    /// first, so the source's write is the one an interruption can leave
+    /// undone, and the source's next command is what finishes it.
+    CrossProjectMove {
+        /// The moved task first, then its descendants.
+        moves: Vec<MovedTask>,
+        source_track: String,
+        /// The destination's registry name, which the `moved-to:` line uses.
+        target_project: String,
+        /// The destination's root, so finishing the move does not depend on
+        /// the registry still naming it.
+        target_root: String,
+        target_track: String,
+    },
     /// A track marked archived in config, its file moved to `archive/_tracks/`.
     TrackArchive { track_id: String, file: String },
     /// The inverse: a track marked active in config, its file moved back out of
@@ -129,6 +145,7 @@ impl Operation {
     pub fn name(&self) -> &'static str {
         match self {
             Operation::CrossTrackMove { .. } => "mv --track",
+            Operation::CrossProjectMove { .. } => "mv --project",
             Operation::TrackArchive { .. } => "track archive",
             Operation::TrackUnarchive { .. } => "track activate",
             Operation::TrackRename { .. } => "track rename --id",
@@ -283,6 +300,16 @@ mod tests {
                 track_id: "a".into(),
                 file: "tracks/a.md".into(),
             },
+            Operation::CrossProjectMove {
+                moves: vec![MovedTask {
+                    old_id: "A-001".into(),
+                    new_id: "L-004".into(),
+                }],
+                source_track: "a".into(),
+                target_project: "lib".into(),
+                target_root: "/src/lib".into(),
+                target_track: "core".into(),
+            },
             Operation::ActorMerge {
                 sources: vec!["x".into(), "z".into()],
                 target: "y".into(),
@@ -10,4 +10,5 @@ pub mod project_io;
 pub mod recovery;
 pub mod registry;
 pub mod state;
+pub mod user_config;
 pub mod watcher;
@@ -1,17 +1,16 @@
 use std::fs;
 use std::path::{Path, PathBuf};
 
-use crate::model::config::ProjectConfig;
 use crate::model::inbox::Inbox;
 use crate::model::project::Project;
 use crate::model::task::Task;
 use crate::model::track::Track;
-use crate::parse::{parse_inbox, parse_track};
+use crate::parse::{Schema, parse_inbox, parse_track_with};
 
 /// Files inside `frame/` that belong to a **single working copy** and must never
-/// be committed: per-worktree UI state, the advisory lock, the append-only
-/// recovery log, this working copy's actor token, and the ID frontier store with
-/// its lock (which only land here for a project outside git — inside git the
+/// be committed: per-worktree UI state and undo history, the advisory lock, the
+/// append-only recovery log, this working copy's actor token, and the ID
+/// frontier store with its lock (which only land here for a project outside git — inside git the
 /// frontier lives under `.git/`, see [`crate::io::ids`]). Committing any of them
 /// leaks machine-local state into shared history (and the append-only log
 /// conflicts on every merge).
@@ -19,8 +18,9 @@ use crate::parse::{parse_inbox, parse_track};
 /// `fr check` verifies these against git from this one list. `.gitignore`
 /// coverage is a single pattern rather than an entry each — see
 /// [`gitignore_pattern_for`].
-pub const LOCAL_ONLY_FRAME_FILES: [&str; 9] = [
-    ".state.json",
+pub const LOCAL_ONLY_FRAME_FILES: [&str; 10] = [
+    crate::io::state::STATE_FILE,
+    crate::io::state::UNDO_FILE,
     ".lock",
     ".recovery.log",
     crate::io::recovery::RECOVERY_LOCK,
@@ -118,6 +118,8 @@ pub enum ProjectError {
     },
     #[error("could not parse project.toml: {0}")]
     ConfigParseError(#[from] toml::de::Error),
+    #[error("could not use {}: {reason}", path.display())]
+    UserConfigError { path: PathBuf, reason: String },
     #[error("could not serialize project.toml: {0}")]
     ConfigSerializeError(#[from] toml::ser::Error),
     #[error("io error: {0}")]
@@ -177,8 +179,14 @@ pub fn discover_project(start: &Path) -> Result<PathBuf, ProjectError> {
     }
 }
 
-/// Load a complete Frame project from the given root directory.
+/// Load a complete Frame project from the given root directory, with the
+/// user config layered over its `project.toml`.
 pub fn load_project(root: &Path) -> Result<Project, ProjectError> {
+    load_project_with(root, crate::io::user_config::read()?.as_ref())
+}
+
+/// [`load_project`] with the user config already in hand — `None` for none.
+pub fn load_project_with(root: &Path, user: Option<&toml::Table>) -> Result<Project, ProjectError> {
     let frame_dir = root.join("frame");
     if !frame_dir.is_dir() {
         return Err(ProjectError::NotAProject);
@@ -190,9 +198,10 @@ pub fn load_project(root: &Path) -> Result<Project, ProjectError> {
         path: config_path.clone(),
         source: e,
     })?;
-    let config: ProjectConfig = toml::from_str(&config_text)?;
+    let config = crate::io::user_config::parse_layered_with(&config_text, user)?;
 
     // Load tracks
+    let schema = Schema::of(&config);
     let mut tracks = Vec::new();
     for track_config in &config.tracks {
         let track_path = frame_dir.join(&track_config.file);
@@ -202,7 +211,7 @@ pub fn load_project(root: &Path) -> Result<Project, ProjectError> {
                     path: track_path.clone(),
                     source: e,
                 })?;
-            let track = parse_track(&track_text);
+            let track = parse_track_with(&track_text, &schema);
             tracks.push((track_config.id.clone(), track));
         }
     }
@@ -326,7 +335,7 @@ pub struct ArchivedTasks {
 /// track id. Unreadable files contribute nothing — this is a fallback path for
 /// readers, and a project whose archive directory is damaged should still answer
 /// what it can rather than fail the command that asked.
-pub fn archived_task_lists(frame_dir: &Path) -> Vec<ArchivedTasks> {
+pub fn archived_task_lists(frame_dir: &Path, schema: &Schema) -> Vec<ArchivedTasks> {
     let mut out = Vec::new();
 
     if let Ok(archives) = load_archives(frame_dir) {
@@ -352,7 +361,7 @@ pub fn archived_task_lists(frame_dir: &Path) -> Vec<ArchivedTasks> {
             let Some(track_id) = path.file_stem().and_then(|s| s.to_str()) else {
                 continue;
             };
-            let track = parse_track(&content);
+            let track = parse_track_with(&content, schema);
             let mut tasks = Vec::new();
             for node in &track.nodes {
                 if let crate::model::track::TrackNode::Section { tasks: section, .. } = node {
@@ -552,7 +561,7 @@ file = "tracks/main.md"
         let tmp = TempDir::new().unwrap();
         create_test_project(tmp.path());
 
-        let project = load_project(tmp.path()).unwrap();
+        let project = load_project_with(tmp.path(), None).unwrap();
         assert_eq!(project.config.project.name, "test");
         assert_eq!(project.tracks.len(), 1);
         assert_eq!(project.tracks[0].0, "main");
@@ -35,12 +35,17 @@ pub struct ProjectRegistry {
     pub projects: Vec<ProjectEntry>,
 }
 
+/// Frame's per-user directory, `$XDG_CONFIG_HOME/frame` or `~/.config/frame`.
+pub fn config_dir() -> PathBuf {
+    std::env::var("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .unwrap_or_else(|_| dirs_home().join(".config"))
+        .join("frame")
+}
+
 /// Get the registry file path, respecting XDG_CONFIG_HOME
 pub fn registry_path() -> PathBuf {
-    let config_dir = std::env::var("XDG_CONFIG_HOME")
-        .map(PathBuf::from)
-        .unwrap_or_else(|_| dirs_home().join(".config"));
-    config_dir.join("frame").join("projects.toml")
+    config_dir().join("projects.toml")
 }
 
 /// Get the user's home directory
@@ -251,6 +256,20 @@ pub fn remove_project_from(
     }
 }
 
+/// The entry a project name refers to. A project's own entry is preferred to
+/// one of its worktrees, which carry the same name and may be on another
+/// branch. The name is matched exactly if any entry has it, and otherwise
+/// without regard to case, so `lib` finds a project registered as `Lib`.
+pub fn find_by_name<'a>(reg: &'a ProjectRegistry, name: &str) -> Option<&'a ProjectEntry> {
+    let find = |same: fn(&str, &str) -> bool| {
+        let named = || reg.projects.iter().filter(move |e| same(&e.name, name));
+        named()
+            .find(|e| e.worktree_of.is_none())
+            .or_else(|| named().next())
+    };
+    find(|a, b| a == b).or_else(|| find(str::eq_ignore_ascii_case))
+}
+
 /// True if a registered entry's project directory still exists on disk.
 /// Mirrors the "(not found)" criterion used by `projects list` and the picker:
 /// the path must contain a `frame/` directory.
@@ -685,6 +704,21 @@ mod tests {
         assert!(reg.projects.is_empty());
     }
 
+    #[test]
+    fn find_by_name_falls_back_to_any_case() {
+        let reg = ProjectRegistry {
+            projects: vec![
+                entry("Lib", "/p/lib", None),
+                entry("app", "/p/app", None),
+                entry("APP", "/p/APP", None),
+            ],
+        };
+        assert_eq!(find_by_name(&reg, "lib").unwrap().path, "/p/lib");
+        assert_eq!(find_by_name(&reg, "APP").unwrap().path, "/p/APP");
+        assert_eq!(find_by_name(&reg, "App").unwrap().path, "/p/app");
+        assert!(find_by_name(&reg, "li").is_none());
+    }
+
     #[test]
     fn test_remove_not_found() {
         let (_tmp, path) = temp_registry();
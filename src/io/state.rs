@@ -1,9 +1,15 @@
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::fs;
 use std::path::Path;
 
 use serde::{Deserialize, Serialize};
 
+/// The TUI state file, inside the frame directory.
+pub const STATE_FILE: &str = ".state.json";
+/// The TUI's undo history file, inside the frame directory (see
+/// [`crate::tui::undo_history`]).
+pub const UNDO_FILE: &str = ".undo.json";
+
 /// Persisted TUI state (written to .state.json)
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct UiState {
@@ -27,9 +33,19 @@ pub struct UiState {
     /// Board mode ("cc" or "all")
     #[serde(default)]
     pub board_mode: Option<String>,
-    /// Board focus column index (0=Ready, 1=InProgress, 2=Done)
+    /// Board focus column index, left to right: Ready, In Progress, any
+    /// custom workflow states, Done — or the `[[board.columns]]` entries
     #[serde(default)]
     pub board_focus_column: Option<usize>,
+    /// Whether the Track view is split with a detail pane
+    #[serde(default)]
+    pub split_pane: bool,
+    /// The split's list pane share, in percent (None = default)
+    #[serde(default)]
+    pub split_ratio: Option<u16>,
+    /// Named marks: letter to task ID
+    #[serde(default)]
+    pub marks: BTreeMap<char, String>,
 }
 
 /// Per-track UI state
@@ -48,14 +64,14 @@ pub struct TrackUiState {
 
 /// Read .state.json from the frame directory
 pub fn read_ui_state(frame_dir: &Path) -> Option<UiState> {
-    let path = frame_dir.join(".state.json");
+    let path = frame_dir.join(STATE_FILE);
     let content = fs::read_to_string(&path).ok()?;
     serde_json::from_str(&content).ok()
 }
 
 /// Write .state.json to the frame directory
 pub fn write_ui_state(frame_dir: &Path, state: &UiState) -> Result<(), std::io::Error> {
-    let path = frame_dir.join(".state.json");
+    let path = frame_dir.join(STATE_FILE);
     let content = serde_json::to_string_pretty(state)?;
     crate::io::recovery::atomic_write(&path, content.as_bytes())
 }
@@ -73,6 +89,7 @@ mod tests {
             active_track: "effects".into(),
             note_wrap_override: Some(false),
             search_history: vec!["foo".into(), "bar".into()],
+            marks: [('a', "T-001".into())].into(),
             ..Default::default()
         };
 
@@ -91,6 +108,7 @@ mod tests {
         assert_eq!(loaded.active_track, "effects");
         assert_eq!(loaded.note_wrap_override, Some(false));
         assert_eq!(loaded.search_history, vec!["foo", "bar"]);
+        assert_eq!(loaded.marks.get(&'a').map(String::as_str), Some("T-001"));
         let ts = loaded.tracks.get("effects").unwrap();
         assert_eq!(ts.cursor, 5);
         assert_eq!(ts.scroll_offset, 10);
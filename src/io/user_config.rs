@@ -0,0 +1,347 @@
+//! `~/.config/frame/config.toml`: settings that belong to a person rather than
+//! to a project.
+//!
+//! `project.toml` is committed, so a colour scheme or a terminal workaround
+//! set there is imposed on everyone who clones the project, and changing it is
+//! churn in everyone's history. The user config holds the same `[ui]` and
+//! `[keys]` shapes, plus defaults for `[clean]`, `[deps]` and `[limits]`, and
+//! is layered over every project at load time.
+//!
+//! **Precedence depends on whose decision a setting is.** The purely visual
+//! `[ui]` keys in [`USER_WINS`] are the person's, as is every key binding:
+//! their value wins over the project's. Everything else is shared, so the user's value is only a
+//! default — it applies where `project.toml` is silent, and a project that
+//! sets the key keeps its value. Tables merge key by key under the same rule,
+//! so a user's `[ui.tag_colors]` fills in tags the project has not coloured.
+//!
+//! The layering is done on the TOML before [`ProjectConfig`] is built, not on
+//! the struct: the struct has a value for every key, and "the project is
+//! silent about this" is only visible in the text.
+//!
+//! **Layering is for reading.** Whatever writes `project.toml` works from the
+//! project's own config — the file, or memory with the user's layer taken back
+//! out by [`unlayer`] — since a person's colours or thresholds written into the
+//! file would be imposed on everyone who clones it.
+
+use std::fs;
+use std::path::PathBuf;
+
+use crate::io::project_io::ProjectError;
+use crate::io::registry;
+use crate::model::config::ProjectConfig;
+
+/// Sections the user config may hold. The rest of `project.toml` — tracks,
+/// prefixes, templates, fields, workflow — describes the project itself.
+pub const SECTIONS: &[&str] = &["ui", "keys", "clean", "deps", "limits"];
+
+/// `[ui]` keys where the user's value wins over the project's: they change
+/// how frame looks and behaves in one person's terminal, and nothing else.
+pub const USER_WINS: &[&str] = &[
+    "colors",
+    "kitty_keyboard",
+    "mouse",
+    "note_wrap",
+    "show_key_hints",
+    "theme",
+];
+
+/// Sections where the user's value wins throughout: which key does what is a
+/// matter of one person's hands.
+pub const USER_SECTIONS: &[&str] = &["keys"];
+
+pub fn path() -> PathBuf {
+    registry::config_dir().join("config.toml")
+}
+
+/// The user config, or `None` when there is none.
+///
+/// It is checked on its own, so that a bad value is reported against this
+/// file instead of surfacing as a `project.toml` error nobody can find.
+pub fn read() -> Result<Option<toml::Table>, ProjectError> {
+    let path = path();
+    let text = match fs::read_to_string(&path) {
+        Ok(text) => text,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+        Err(source) => return Err(ProjectError::ReadError { path, source }),
+    };
+    let invalid = |reason: String| ProjectError::UserConfigError {
+        path: path.clone(),
+        reason,
+    };
+    let table: toml::Table = toml::from_str(&text).map_err(|e| invalid(e.message().into()))?;
+    if let Some(key) = table.keys().find(|k| !SECTIONS.contains(&k.as_str())) {
+        return Err(invalid(format!(
+            "[{key}] belongs in project.toml — this file holds [{}]",
+            SECTIONS.join("], [")
+        )));
+    }
+    let mut probe = table.clone();
+    let mut project = toml::Table::new();
+    project.insert("name".into(), "user".into());
+    probe.insert("project".into(), project.into());
+    toml::Value::Table(probe)
+        .try_into::<ProjectConfig>()
+        .map_err(|e| invalid(e.message().into()))?;
+    Ok(Some(table))
+}
+
+/// `project` with `user` layered over it, by the precedence in the module docs.
+pub fn layer(project: toml::Table, user: &toml::Table) -> toml::Table {
+    let mut merged = user.clone();
+    merge(&mut merged, project);
+    if let Some(toml::Value::Table(user_ui)) = user.get("ui")
+        && let Some(toml::Value::Table(ui)) = merged.get_mut("ui")
+    {
+        for key in USER_WINS {
+            if let Some(value) = user_ui.get(*key) {
+                let mut over = toml::Table::new();
+                over.insert((*key).into(), value.clone());
+                merge(ui, over);
+            }
+        }
+    }
+    for section in USER_SECTIONS {
+        if let Some(toml::Value::Table(user_section)) = user.get(*section)
+            && let Some(toml::Value::Table(ours)) = merged.get_mut(*section)
+        {
+            merge(ours, user_section.clone());
+        }
+    }
+    merged
+}
+
+/// `over` written onto `base`, tables merged key by key.
+fn merge(base: &mut toml::Table, over: toml::Table) {
+    for (key, value) in over {
+        match (base.get_mut(&key), value) {
+            (Some(toml::Value::Table(inner)), toml::Value::Table(over)) => merge(inner, over),
+            (_, value) => {
+                base.insert(key, value);
+            }
+        }
+    }
+}
+
+/// The config a session runs with: `project.toml`'s text, with the user config
+/// layered over it.
+///
+/// The project text is parsed on its own first, so that a mistake in it is
+/// reported with the line and column a plain parse gives.
+pub fn parse_layered(text: &str) -> Result<ProjectConfig, ProjectError> {
+    parse_layered_with(text, read()?.as_ref())
+}
+
+/// [`parse_layered`] with the user config already in hand — `None` for none.
+pub fn parse_layered_with(
+    text: &str,
+    user: Option<&toml::Table>,
+) -> Result<ProjectConfig, ProjectError> {
+    let config: ProjectConfig = toml::from_str(text)?;
+    let Some(user) = user else {
+        return Ok(config);
+    };
+    let project: toml::Table = toml::from_str(text)?;
+    Ok(toml::Value::Table(layer(project, user)).try_into()?)
+}
+
+/// A layered `config` with `user`'s layer taken back out: what `project.toml`
+/// should hold, given it held `project_text` when the config was layered.
+///
+/// Every key `user` sets that still has the user's value goes back to the
+/// project's own value, or away — to its default — where the project was
+/// silent. A key changed since it was layered keeps the change: that value is
+/// no longer the user's.
+pub fn unlayer(config: &ProjectConfig, project_text: &str, user: &toml::Table) -> ProjectConfig {
+    fn walk(ours: &mut toml::Table, user: &toml::Table, project: Option<&toml::Table>) {
+        for (key, value) in user {
+            let theirs = project.and_then(|p| p.get(key));
+            let Some(current) = ours.get_mut(key) else {
+                continue;
+            };
+            if let (toml::Value::Table(user), toml::Value::Table(inner)) = (value, &mut *current) {
+                walk(inner, user, theirs.and_then(|v| v.as_table()));
+                continue;
+            }
+            if current != value {
+                continue;
+            }
+            match theirs {
+                Some(theirs) => *current = theirs.clone(),
+                None => {
+                    ours.remove(key);
+                }
+            }
+        }
+    }
+    let Ok(toml::Value::Table(mut ours)) = toml::Value::try_from(config) else {
+        return config.clone();
+    };
+    let project: toml::Table = toml::from_str(project_text).unwrap_or_default();
+    walk(&mut ours, user, Some(&project));
+    toml::Value::Table(ours)
+        .try_into()
+        .unwrap_or_else(|_| config.clone())
+}
+
+/// The dotted keys the user config supplies, each with whether it is in effect
+/// here — `false` where the project's own value wins. For `fr info`.
+pub fn provided(user: &toml::Table, project_text: &str) -> Vec<(String, bool)> {
+    fn under(dotted: &str, key: &str) -> bool {
+        dotted
+            .strip_prefix(key)
+            .is_some_and(|rest| rest.is_empty() || rest.starts_with('.'))
+    }
+    fn user_wins(dotted: &str) -> bool {
+        USER_SECTIONS.iter().any(|section| under(dotted, section))
+            || USER_WINS.iter().any(|key| {
+                dotted
+                    .strip_prefix("ui.")
+                    .is_some_and(|rest| under(rest, key))
+            })
+    }
+    fn walk(
+        prefix: &str,
+        user: &toml::Table,
+        project: Option<&toml::Table>,
+        out: &mut Vec<(String, bool)>,
+    ) {
+        for (key, value) in user {
+            let dotted = if prefix.is_empty() {
+                key.clone()
+            } else {
+                format!("{prefix}.{key}")
+            };
+            let theirs = project.and_then(|p| p.get(key));
+            match value {
+                toml::Value::Table(inner) => {
+                    walk(&dotted, inner, theirs.and_then(|v| v.as_table()), out)
+                }
+                _ => {
+                    let in_effect = user_wins(&dotted) || theirs.is_none();
+                    out.push((dotted, in_effect));
+                }
+            }
+        }
+    }
+    let project: toml::Table = toml::from_str(project_text).unwrap_or_default();
+    let mut out = Vec::new();
+    walk("", user, Some(&project), &mut out);
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::config::KeyBinding;
+
+    #[test]
+    fn visual_keys_are_the_users_and_shared_ones_the_projects() {
+        let project: toml::Table = toml::from_str(
+            r##"
+[project]
+name = "p"
+
+[clean]
+done_threshold = 50
+
+[ui]
+note_wrap = true
+board_done_days = 3
+
+[ui.colors]
+background = "#000000"
+
+[ui.tag_colors]
+bug = "#FF0000"
+
+[keys]
+mark_done = "d"
+undo = "u"
+"##,
+        )
+        .unwrap();
+        let user: toml::Table = toml::from_str(
+            r##"
+[clean]
+done_threshold = 20
+done_retain = 5
+
+[ui]
+note_wrap = false
+board_done_days = 14
+
+[ui.colors]
+background = "#FFFFFF"
+
+[ui.tag_colors]
+bug = "#00FF00"
+docs = "#0000FF"
+
+[keys]
+mark_done = "X"
+"##,
+        )
+        .unwrap();
+        let config: ProjectConfig = toml::Value::Table(layer(project, &user))
+            .try_into()
+            .unwrap();
+
+        // Visual: the user's
+        assert!(!config.ui.note_wrap);
+        assert_eq!(config.ui.colors["background"], "#FFFFFF");
+        assert_eq!(config.keys["mark_done"], KeyBinding::Key("X".into()));
+        assert_eq!(config.keys["undo"], KeyBinding::Key("u".into()));
+        // Shared: the project's where it says, the user's where it is silent
+        assert_eq!(config.clean.done_threshold, 50);
+        assert_eq!(config.clean.done_retain, 5);
+        assert_eq!(config.ui.board_done_days, 3);
+        assert_eq!(config.ui.tag_colors["bug"], "#FF0000");
+        assert_eq!(config.ui.tag_colors["docs"], "#0000FF");
+    }
+
+    #[test]
+    fn unlayering_gives_back_the_projects_own_config() {
+        let project = r##"
+[project]
+name = "p"
+
+[clean]
+done_threshold = 50
+
+[ui]
+note_wrap = true
+
+[ui.tag_colors]
+bug = "#FF0000"
+"##;
+        let user: toml::Table = toml::from_str(
+            r##"
+[clean]
+done_threshold = 20
+done_retain = 5
+
+[ui]
+note_wrap = false
+
+[ui.tag_colors]
+docs = "#0000FF"
+ops = "#00FF00"
+"##,
+        )
+        .unwrap();
+        let mut config = parse_layered_with(project, Some(&user)).unwrap();
+        // What the session changed since stays changed
+        config.ui.tag_colors["docs"] = "#123456".into();
+
+        let own = unlayer(&config, project, &user);
+        assert!(own.ui.note_wrap);
+        assert_eq!(own.clean.done_threshold, 50);
+        assert_eq!(
+            own.clean.done_retain,
+            crate::model::config::CleanConfig::default().done_retain
+        );
+        assert_eq!(own.ui.tag_colors["bug"], "#FF0000");
+        assert_eq!(own.ui.tag_colors["docs"], "#123456");
+        assert!(!own.ui.tag_colors.contains_key("ops"));
+    }
+}
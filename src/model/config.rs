@@ -1,6 +1,9 @@
 use indexmap::IndexMap;
 use serde::{Deserialize, Serialize};
 
+use super::task::{TaskState, is_field_key};
+use super::track::{SectionKind, SectionName};
+
 /// Configuration from project.toml
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ProjectConfig {
@@ -19,6 +22,24 @@ pub struct ProjectConfig {
     pub recovery: RecoveryConfig,
     #[serde(default)]
     pub limits: LimitsConfig,
+    #[serde(default)]
+    pub deps: DepsConfig,
+    /// `[templates.<name>]`, in file order — the order the TUI offers them in.
+    #[serde(default)]
+    pub templates: IndexMap<String, TaskTemplate>,
+    /// `[fields.<key>]`: the schema for custom `key: value` metadata, and
+    /// the only keys the parser reads as fields.
+    #[serde(default, deserialize_with = "field_keys")]
+    pub fields: IndexMap<String, FieldSchema>,
+    /// `[workflow]`: states and sections beyond the built-in ones.
+    #[serde(default)]
+    pub workflow: WorkflowConfig,
+    /// `[board]`: the Board view's columns and swimlanes.
+    #[serde(default)]
+    pub board: BoardConfig,
+    /// `[keys]`: the TUI's actions rebound, by the command palette's action id.
+    #[serde(default)]
+    pub keys: IndexMap<String, KeyBinding>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -40,6 +61,444 @@ pub struct AgentConfig {
     pub cc_only: bool,
 }
 
+/// How `dep:` lines bear on task state.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct DepsConfig {
+    /// Keep `[-]` in step with `dep:`: a todo task with an open dependency is
+    /// stored as blocked, and a blocked one goes back to todo when its last
+    /// dependency is done. Off by default, because it makes state a function of
+    /// other tasks, and a project that sets `[-]` by hand for its own reasons
+    /// would see those choices overwritten.
+    ///
+    /// A blocked task with no `dep:` at all is never unblocked — whatever
+    /// blocks it is something frame cannot see.
+    #[serde(default)]
+    pub auto_block: bool,
+}
+
+/// The fixed shape of a kind of task — a bug report, a spike — applied by
+/// `fr add --template NAME` and the TUI's "Add from template".
+///
+/// Everything is optional. The task itself is still created by
+/// `task_ops::add_task`, so its id and `added:` date are minted as for any
+/// other; the template only fills in what comes after.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TaskTemplate {
+    /// The track `fr add --template` adds to when none is named.
+    #[serde(default)]
+    pub track: Option<String>,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    /// Note skeleton, markdown, written as the new task's note.
+    #[serde(default)]
+    pub note: Option<String>,
+    /// Subtask titles, added in order.
+    #[serde(default)]
+    pub subtasks: Vec<String>,
+    #[serde(default)]
+    pub refs: Vec<String>,
+}
+
+/// What a custom field holds. The parser keeps a declared field's value as
+/// text; this is what `fr check`, `fr set` and `fr list --field` read it as.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FieldSchema {
+    #[serde(rename = "type")]
+    pub kind: FieldKind,
+    /// The allowed values of an `enum` field. Ignored for every other type.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub values: Vec<String>,
+}
+
+/// `[fields]`, refusing a key the parser could never read as a field.
+fn field_keys<'de, D: serde::Deserializer<'de>>(
+    deserializer: D,
+) -> Result<IndexMap<String, FieldSchema>, D::Error> {
+    let fields = IndexMap::<String, FieldSchema>::deserialize(deserializer)?;
+    if let Some(key) = fields.keys().find(|key| !is_field_key(key)) {
+        return Err(serde::de::Error::custom(format!(
+            "[fields.{key}] is not a field key: lowercase letters, digits, '-' and '_', \
+             and not one frame already reads"
+        )));
+    }
+    Ok(fields)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum FieldKind {
+    String,
+    /// `YYYY-MM-DD`, the form `added:` and `resolved:` use.
+    Date,
+    Number,
+    Enum,
+    /// Task ids, comma-separated like `dep:`.
+    IdList,
+    /// Paths from the project root, comma-separated like `ref:`.
+    PathList,
+}
+
+impl FieldSchema {
+    /// Why `value` is not a valid value of this field, or `None` when it is.
+    ///
+    /// The form only: whether the ids of an `id-list` name real tasks and the
+    /// paths of a `path-list` exist is a question about the project, which
+    /// `fr check` answers.
+    pub fn reject(&self, value: &str) -> Option<String> {
+        let value = value.trim();
+        match self.kind {
+            FieldKind::String => None,
+            FieldKind::Date => chrono::NaiveDate::parse_from_str(value, "%Y-%m-%d")
+                .is_err()
+                .then(|| "not a YYYY-MM-DD date".to_string()),
+            FieldKind::Number => value
+                .parse::<f64>()
+                .map_or(true, |n| !n.is_finite())
+                .then(|| "not a number".to_string()),
+            FieldKind::Enum => (!self.values.iter().any(|v| v == value))
+                .then(|| format!("not one of: {}", self.values.join(", "))),
+            FieldKind::IdList | FieldKind::PathList => self
+                .items(value)
+                .is_empty()
+                .then(|| "empty list".to_string()),
+        }
+    }
+
+    /// The items of a list field; the whole value, alone, for any other type.
+    pub fn items<'a>(&self, value: &'a str) -> Vec<&'a str> {
+        match self.kind {
+            FieldKind::IdList | FieldKind::PathList => value
+                .split(',')
+                .map(str::trim)
+                .filter(|s| !s.is_empty())
+                .collect(),
+            _ => vec![value.trim()],
+        }
+    }
+
+    /// Whether a stored `value` matches what `fr list --field key=wanted`
+    /// asked for: a list field matches on any one item, a number by value,
+    /// everything else by its text.
+    pub fn matches(&self, value: &str, wanted: &str) -> bool {
+        match self.kind {
+            FieldKind::Number => match (value.trim().parse::<f64>(), wanted.parse::<f64>()) {
+                (Ok(a), Ok(b)) => a == b,
+                _ => value.trim() == wanted,
+            },
+            _ => self.items(value).contains(&wanted),
+        }
+    }
+}
+
+/// `[workflow]`: the states and sections a project adds to the built-in five
+/// and three.
+///
+/// Validated as it is read, so every caller can take a declared state's char
+/// and section on trust: a char that clashes with a built-in or another state,
+/// or a section that names nothing, is a load error rather than a task that
+/// silently lands somewhere nobody meant.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "RawWorkflow", into = "RawWorkflow")]
+pub struct WorkflowConfig {
+    /// Extra `##` sections, in the order they are written: after Parked,
+    /// before Done.
+    pub sections: Vec<SectionName>,
+    /// Extra states, in the order the TUI cycles through them.
+    pub states: IndexMap<String, StateConfig>,
+}
+
+/// One `[workflow.states.<name>]`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StateConfig {
+    /// The checkbox character, `?` for `- [?]`.
+    #[serde(rename = "char")]
+    pub checkbox: char,
+    /// The section a top-level task in this state lives in.
+    #[serde(default = "default_state_section")]
+    pub section: SectionKind,
+}
+
+fn default_state_section() -> SectionKind {
+    SectionKind::Backlog
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+struct RawWorkflow {
+    #[serde(default)]
+    sections: Vec<String>,
+    #[serde(default)]
+    states: IndexMap<String, StateConfig>,
+}
+
+impl TryFrom<RawWorkflow> for WorkflowConfig {
+    type Error = String;
+
+    fn try_from(raw: RawWorkflow) -> Result<Self, Self::Error> {
+        let mut sections = Vec::new();
+        for name in &raw.sections {
+            match SectionKind::from_heading(name) {
+                Some(SectionKind::Custom(section)) if !sections.contains(&section) => {
+                    sections.push(section)
+                }
+                Some(SectionKind::Custom(_)) => {
+                    return Err(format!("[workflow] section '{name}' is listed twice"));
+                }
+                Some(_) => {
+                    return Err(format!(
+                        "[workflow] section '{name}' is built in and needs no declaring"
+                    ));
+                }
+                None => {
+                    return Err(format!(
+                        "[workflow] section '{}' is not a heading of 1 to {} bytes",
+                        name.trim(),
+                        SectionName::MAX
+                    ));
+                }
+            }
+        }
+        let mut states = raw.states;
+        let mut seen: Vec<char> = Vec::new();
+        for (name, state) in &mut states {
+            if TaskState::from_checkbox_char(state.checkbox).is_some()
+                || name.parse::<BuiltinStateName>().is_ok()
+            {
+                return Err(format!(
+                    "[workflow.states.{name}] clashes with a built-in state"
+                ));
+            }
+            if TaskState::from_any_checkbox_char(state.checkbox).is_none() {
+                return Err(format!(
+                    "[workflow.states.{name}] char '{}' is not punctuation",
+                    state.checkbox
+                ));
+            }
+            if seen.contains(&state.checkbox) {
+                return Err(format!(
+                    "[workflow.states.{name}] char '{}' is used by another state",
+                    state.checkbox
+                ));
+            }
+            seen.push(state.checkbox);
+            if let SectionKind::Custom(section) = state.section {
+                // Spelled as `sections` spells it, which is the heading a new
+                // section is written with.
+                let declared = sections.iter().find(|&&s| s == section).ok_or_else(|| {
+                    format!(
+                        "[workflow.states.{name}] section '{}' is not in [workflow] sections",
+                        section.as_str()
+                    )
+                })?;
+                state.section = SectionKind::Custom(*declared);
+            }
+        }
+        Ok(WorkflowConfig { sections, states })
+    }
+}
+
+impl From<WorkflowConfig> for RawWorkflow {
+    fn from(workflow: WorkflowConfig) -> Self {
+        RawWorkflow {
+            sections: workflow
+                .sections
+                .iter()
+                .map(|s| s.as_str().to_string())
+                .collect(),
+            states: workflow.states,
+        }
+    }
+}
+
+/// A name `fr state` knows without any config.
+struct BuiltinStateName(TaskState);
+
+impl std::str::FromStr for BuiltinStateName {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, ()> {
+        [
+            TaskState::Todo,
+            TaskState::Active,
+            TaskState::Blocked,
+            TaskState::Done,
+            TaskState::Parked,
+        ]
+        .into_iter()
+        .find(|state| state.builtin_name() == Some(s))
+        .map(BuiltinStateName)
+        .ok_or(())
+    }
+}
+
+impl WorkflowConfig {
+    /// The state `fr state` means by `name`: a built-in, or a declared one.
+    pub fn state_named(&self, name: &str) -> Option<TaskState> {
+        if let Ok(BuiltinStateName(state)) = name.parse() {
+            return Some(state);
+        }
+        self.states
+            .get(name)
+            .map(|state| TaskState::Custom(state.checkbox))
+    }
+
+    /// What a state is called: its built-in name, its declared one, or — for
+    /// a checkbox nobody declared — the character itself.
+    pub fn state_name(&self, state: TaskState) -> String {
+        if let Some(name) = state.builtin_name() {
+            return name.to_string();
+        }
+        self.states
+            .iter()
+            .find(|(_, s)| TaskState::Custom(s.checkbox) == state)
+            .map(|(name, _)| name.clone())
+            .unwrap_or_else(|| state.checkbox_char().to_string())
+    }
+
+    /// Every state, built-ins first and then the declared ones in file order —
+    /// the order the TUI state cycle and the Board view's columns follow.
+    pub fn all_states(&self) -> Vec<TaskState> {
+        let mut states = vec![
+            TaskState::Todo,
+            TaskState::Active,
+            TaskState::Blocked,
+            TaskState::Done,
+            TaskState::Parked,
+        ];
+        states.extend(self.states.values().map(|s| TaskState::Custom(s.checkbox)));
+        states
+    }
+
+    /// The section a top-level task in `state` belongs in. A custom state
+    /// nobody declared is open, and goes where open tasks go.
+    pub fn section_of(&self, state: TaskState) -> SectionKind {
+        match state {
+            TaskState::Parked => SectionKind::Parked,
+            TaskState::Done => SectionKind::Done,
+            TaskState::Custom(c) => self
+                .states
+                .values()
+                .find(|s| s.checkbox == c)
+                .map_or(SectionKind::Backlog, |s| s.section),
+            _ => SectionKind::Backlog,
+        }
+    }
+}
+
+/// `[board]`: the Board view's own layout.
+///
+/// With no `[[board.columns]]` the board has its built-in columns: Ready, In
+/// Progress, one per `[workflow.states]` state, and Done.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct BoardConfig {
+    /// `[[board.columns]]`, left to right.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub columns: Vec<BoardColumnConfig>,
+    /// How the cards in each column are grouped.
+    #[serde(default)]
+    pub swimlanes: Swimlanes,
+}
+
+/// One `[[board.columns]]`: the tasks in a state, the tasks carrying a tag, or
+/// both at once. Moving a card onto the column gives it the state and the tag.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BoardColumnConfig {
+    pub name: String,
+    /// A state name, as `fr state` takes it. `todo` means ready — in the
+    /// Backlog with every dep done — and `done` means done within
+    /// `ui.board_done_days`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub state: Option<String>,
+    /// A tag, with or without its `#`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tag: Option<String>,
+}
+
+impl BoardColumnConfig {
+    /// The column's tag, without its `#`.
+    pub fn tag(&self) -> Option<&str> {
+        self.tag
+            .as_deref()
+            .map(|t| t.strip_prefix('#').unwrap_or(t))
+    }
+}
+
+impl BoardConfig {
+    /// Whether every column names something `workflow` knows. A column is
+    /// matched against the states as they are *now*, so this is the TUI's to
+    /// check when a session starts, as it checks `[keys]`.
+    pub fn check(&self, workflow: &WorkflowConfig) -> Result<(), String> {
+        for column in &self.columns {
+            if column.name.trim().is_empty() {
+                return Err("a column has no name".into());
+            }
+            if column.state.is_none() && column.tag().is_none_or(str::is_empty) {
+                return Err(format!(
+                    "column '{}' names no state and no tag",
+                    column.name
+                ));
+            }
+            if let Some(state) = &column.state
+                && workflow.state_named(state).is_none()
+            {
+                return Err(format!(
+                    "column '{}': '{state}' is not a state",
+                    column.name
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// `[board] swimlanes`: `"track"` (the default), `"none"`, or a list of tags —
+/// one lane per tag in that order, then one for the cards carrying none of them.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "RawSwimlanes", into = "RawSwimlanes")]
+pub enum Swimlanes {
+    #[default]
+    Track,
+    Off,
+    Tags(Vec<String>),
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+enum RawSwimlanes {
+    Name(String),
+    Tags(Vec<String>),
+}
+
+impl TryFrom<RawSwimlanes> for Swimlanes {
+    type Error = String;
+
+    fn try_from(raw: RawSwimlanes) -> Result<Self, Self::Error> {
+        match raw {
+            RawSwimlanes::Name(name) => match name.as_str() {
+                "track" => Ok(Swimlanes::Track),
+                "none" => Ok(Swimlanes::Off),
+                _ => Err(format!(
+                    "[board] swimlanes '{name}' is not \"track\", \"none\" or a list of tags"
+                )),
+            },
+            RawSwimlanes::Tags(tags) => Ok(Swimlanes::Tags(
+                tags.into_iter()
+                    .map(|t| t.strip_prefix('#').map(str::to_string).unwrap_or(t))
+                    .collect(),
+            )),
+        }
+    }
+}
+
+impl From<Swimlanes> for RawSwimlanes {
+    fn from(lanes: Swimlanes) -> Self {
+        match lanes {
+            Swimlanes::Track => RawSwimlanes::Name("track".into()),
+            Swimlanes::Off => RawSwimlanes::Name("none".into()),
+            Swimlanes::Tags(tags) => RawSwimlanes::Tags(tags),
+        }
+    }
+}
+
 fn empty_string_as_none<'de, D: serde::Deserializer<'de>>(
     deserializer: D,
 ) -> Result<Option<String>, D::Error> {
@@ -466,11 +925,43 @@ impl<'de> Deserialize<'de> for ByteSize {
     }
 }
 
+/// One entry of `[keys]`.
+///
+/// Only the shape is checked here. Whether an action or a key exists is the
+/// TUI's to say, and it says so when a session starts.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum KeyBinding {
+    /// `mark_done = "d"`
+    Key(String),
+    /// `undo = ["z", "u"]`; empty leaves the action on no key at all.
+    Keys(Vec<String>),
+    /// `[keys.detail]`: bindings that hold in one view only.
+    View(IndexMap<String, KeyBinding>),
+}
+
+/// `ui.theme`: the palette the TUI starts from, before `[ui.colors]`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum ThemeName {
+    /// Light or dark, by what the terminal reports; dark when it says nothing.
+    #[default]
+    Auto,
+    Dark,
+    Light,
+    Solarized,
+    HighContrast,
+    /// No colour at all: state is carried by glyphs, bold and underline.
+    Mono,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct UiConfig {
     #[serde(default)]
     pub show_key_hints: bool,
     #[serde(default)]
+    pub theme: ThemeName,
+    #[serde(default)]
     pub colors: IndexMap<String, String>,
     #[serde(default)]
     pub tag_colors: IndexMap<String, String>,
@@ -489,6 +980,11 @@ pub struct UiConfig {
     /// Disable if your terminal has issues with enhanced key reporting.
     #[serde(default)]
     pub kitty_keyboard: Option<bool>,
+    /// Mouse support: clicks, the wheel and board drag-and-drop (default: false).
+    /// Off unless asked for, since taking the mouse takes the terminal's own
+    /// text selection with it.
+    #[serde(default)]
+    pub mouse: bool,
     /// Whether note editing uses soft word wrap (default: true).
     #[serde(default = "default_true")]
     pub note_wrap: bool,
@@ -546,6 +1042,7 @@ mod tests {
         // When deserialized from empty object, note_wrap should be true via serde
         let u: UiConfig = serde_json::from_str("{}").unwrap();
         assert!(u.note_wrap);
+        assert!(!u.mouse);
     }
 
     // -- [recovery] ---------------------------------------------------------
@@ -635,9 +1132,75 @@ mod tests {
         assert_eq!(parsed.prune_age_days, 30);
     }
 
+    // -- [board] ------------------------------------------------------------
+
+    #[test]
+    fn board_columns_and_swimlanes_parse() {
+        let config: ProjectConfig = toml::from_str(
+            r##"
+[project]
+name = "x"
+
+[board]
+swimlanes = ["#frontend", "backend"]
+
+[[board.columns]]
+name = "Blocked"
+state = "blocked"
+
+[[board.columns]]
+name = "Review"
+tag = "#review"
+"##,
+        )
+        .unwrap();
+        let board = &config.board;
+        assert_eq!(
+            board.swimlanes,
+            Swimlanes::Tags(vec!["frontend".into(), "backend".into()])
+        );
+        assert_eq!(board.columns[0].state.as_deref(), Some("blocked"));
+        assert_eq!(board.columns[1].tag(), Some("review"));
+        assert_eq!(board.check(&config.workflow), Ok(()));
+
+        let lanes: BoardConfig = toml::from_str("swimlanes = \"none\"").unwrap();
+        assert_eq!(lanes.swimlanes, Swimlanes::Off);
+        assert!(toml::from_str::<BoardConfig>("swimlanes = \"tags\"").is_err());
+        assert_eq!(BoardConfig::default().swimlanes, Swimlanes::Track);
+    }
+
+    #[test]
+    fn a_board_column_must_name_a_known_state_or_a_tag() {
+        let workflow = WorkflowConfig::default();
+        let board = |text: &str| toml::from_str::<BoardConfig>(text).unwrap();
+
+        let err = board("[[columns]]\nname = \"Review\"\nstate = \"review\"\n")
+            .check(&workflow)
+            .unwrap_err();
+        assert!(err.contains("'review' is not a state"), "{err}");
+        let err = board("[[columns]]\nname = \"Empty\"\n")
+            .check(&workflow)
+            .unwrap_err();
+        assert!(err.contains("no state and no tag"), "{err}");
+    }
+
     #[test]
     fn a_project_without_a_recovery_section_still_parses() {
         let config: ProjectConfig = toml::from_str("[project]\nname = \"x\"\n").unwrap();
         assert_eq!(config.recovery.max_size.bytes(), 5 * K * K);
     }
+
+    #[test]
+    fn a_field_key_frame_could_not_read_is_refused() {
+        let parse = |key: &str| {
+            toml::from_str::<ProjectConfig>(&format!(
+                "[project]\nname = \"x\"\n[fields.{key}]\ntype = \"string\"\n"
+            ))
+        };
+        assert!(parse("area").is_ok());
+        for key in ["Area", "dep", "note", "epic", "moved-to", "\"my area\""] {
+            let err = parse(key).unwrap_err().to_string();
+            assert!(err.contains("is not a field key"), "{key}: {err}");
+        }
+    }
 }
@@ -3,14 +3,23 @@ use serde::{Deserialize, Serialize};
 use crate::model::task_id::TaskId;
 
 /// Task checkbox state
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
-#[serde(rename_all = "lowercase")]
+///
+/// The five built-in states, plus [`TaskState::Custom`] for a checkbox character
+/// a project declares itself under `[workflow.states]` — `[?]` for in-review,
+/// say. The parser reads a custom state only when [`crate::parse::Schema`]
+/// declares it; any other character is a todo, as it always was.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum TaskState {
     Todo,
     Active,
     Blocked,
     Done,
     Parked,
+    /// A project-defined state, held as its checkbox character.
+    ///
+    /// Open, like todo: it does not satisfy a dep and is not done. Which
+    /// section it lives in is the config's to say.
+    Custom(char),
 }
 
 impl TaskState {
@@ -22,10 +31,11 @@ impl TaskState {
             TaskState::Blocked => '-',
             TaskState::Done => 'x',
             TaskState::Parked => '~',
+            TaskState::Custom(c) => c,
         }
     }
 
-    /// Parse a checkbox character into a state
+    /// Parse a checkbox character into a built-in state
     pub fn from_checkbox_char(c: char) -> Option<TaskState> {
         match c {
             ' ' => Some(TaskState::Todo),
@@ -36,6 +46,123 @@ impl TaskState {
             _ => None,
         }
     }
+
+    /// Parse a checkbox character into a state a project could declare, custom
+    /// ones included.
+    ///
+    /// A custom state is any ASCII punctuation that is not a built-in and not
+    /// a bracket. Letters stay out on purpose: `[X]` is how other markdown
+    /// tools spell done, and reading it as a state nobody declared would turn
+    /// a familiar typo into a warning about a workflow the project does not have.
+    ///
+    /// What `[workflow.states]` may declare, and what `fr check` looks for in a
+    /// checkbox the config does not. Not what the parser reads: see
+    /// [`crate::parse::Schema::state`].
+    pub fn from_any_checkbox_char(c: char) -> Option<TaskState> {
+        TaskState::from_checkbox_char(c).or_else(|| {
+            (c.is_ascii_punctuation() && c != '[' && c != ']').then_some(TaskState::Custom(c))
+        })
+    }
+
+    /// The built-in state's name, as `fr state` and `--json` spell it.
+    ///
+    /// `None` for a custom state, whose name lives in the config.
+    pub fn builtin_name(self) -> Option<&'static str> {
+        match self {
+            TaskState::Todo => Some("todo"),
+            TaskState::Active => Some("active"),
+            TaskState::Blocked => Some("blocked"),
+            TaskState::Done => Some("done"),
+            TaskState::Parked => Some("parked"),
+            TaskState::Custom(_) => None,
+        }
+    }
+}
+
+/// A built-in state serializes as its name; a custom one as its checkbox
+/// character, the only spelling of it that does not need the config. `--json`
+/// has the config at hand and writes the declared name instead — see
+/// [`crate::model::WorkflowConfig::state_name`].
+impl Serialize for TaskState {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self.builtin_name() {
+            Some(name) => serializer.serialize_str(name),
+            None => serializer.serialize_str(&self.checkbox_char().to_string()),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for TaskState {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        let mut chars = s.chars();
+        match (s.as_str(), chars.next(), chars.next()) {
+            ("todo", ..) => Ok(TaskState::Todo),
+            ("active", ..) => Ok(TaskState::Active),
+            ("blocked", ..) => Ok(TaskState::Blocked),
+            ("done", ..) => Ok(TaskState::Done),
+            ("parked", ..) => Ok(TaskState::Parked),
+            (_, Some(c), None) if TaskState::from_any_checkbox_char(c).is_some() => {
+                Ok(TaskState::Custom(c))
+            }
+            _ => Err(serde::de::Error::custom(format!(
+                "unknown task state '{s}'"
+            ))),
+        }
+    }
+}
+
+/// How a [`Metadata::Relation`] links a task to the ones it names.
+///
+/// Unlike `dep:`, a relation orders nothing: `fr ready` and the Board ignore
+/// it. It records what a tag alone cannot — `#duplicate` says a task is a
+/// duplicate, `duplicates: EFF-003` says of what.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RelationKind {
+    /// `relates: EFF-003` — worth reading together.
+    Relates,
+    /// `duplicates: EFF-003` — the same work, tracked twice.
+    Duplicates,
+    /// `supersedes: EFF-003` — this task replaces that one.
+    Supersedes,
+    /// `epic: EFF-001` — this task is part of that larger one, which may be in
+    /// another track.
+    Epic,
+}
+
+impl RelationKind {
+    pub const ALL: [RelationKind; 4] = [
+        RelationKind::Relates,
+        RelationKind::Duplicates,
+        RelationKind::Supersedes,
+        RelationKind::Epic,
+    ];
+
+    /// The metadata key, as written in the track file.
+    pub fn key(self) -> &'static str {
+        match self {
+            RelationKind::Relates => "relates",
+            RelationKind::Duplicates => "duplicates",
+            RelationKind::Supersedes => "supersedes",
+            RelationKind::Epic => "epic",
+        }
+    }
+
+    pub fn from_key(key: &str) -> Option<RelationKind> {
+        RelationKind::ALL.into_iter().find(|k| k.key() == key)
+    }
+
+    /// How the *named* task sees the link: `EFF-007` carrying
+    /// `duplicates: EFF-003` shows on EFF-003 as `duplicated by: EFF-007`.
+    pub fn inverse_label(self) -> &'static str {
+        match self {
+            RelationKind::Relates => "related by",
+            RelationKind::Duplicates => "duplicated by",
+            RelationKind::Supersedes => "superseded by",
+            RelationKind::Epic => "members",
+        }
+    }
 }
 
 /// A single metadata entry on a task
@@ -44,6 +171,10 @@ impl TaskState {
 pub enum Metadata {
     /// `dep: EFF-003, INFRA-007`
     Dep(Vec<String>),
+    /// `relates: EFF-003`, `duplicates:`, `supersedes:` or `epic:` — task IDs,
+    /// comma-separated like [`Metadata::Dep`], and rewritten alongside it when
+    /// IDs change.
+    Relation(RelationKind, Vec<String>),
     /// `ref: path/to/file, other/file`
     ///
     /// Comma-separated, and **only** comma-separated: a ref may contain spaces.
@@ -71,19 +202,54 @@ pub enum Metadata {
     /// and drop theirs, with nothing but scrolled-away stderr to say so. `fr
     /// check` reports it as an error; `fr merge --resolve <ID>` clears it.
     Conflict(String),
+    /// `area: parser` — a `key: value` line whose key `[fields]` declares, kept
+    /// as written.
+    ///
+    /// Only a declared key: the parser is handed the declarations (see
+    /// [`crate::parse::Schema`]), and an undeclared `word: text` line stays the
+    /// text it always was. The `[fields.<key>]` schema is also what types the
+    /// value — `fr check` validates it, and `fr list --field` compares by it.
+    Custom { key: String, value: String },
+}
+
+/// The field the stub [`crate::ops::task_ops::tombstone_moved_task`] leaves
+/// carries: where the task went, as the qualified `project:ID` a cross-project
+/// dep would name it by.
+///
+/// A [`Metadata::Custom`] field, but frame's own: it is read whether or not
+/// `[fields]` declares it (see [`crate::parse::Schema::declares_field`]), so a
+/// stub still says where its task went after a reload.
+pub const MOVED_TO_KEY: &str = "moved-to";
+
+/// Whether `key` can name a [`Metadata::Custom`] field: a lowercase ASCII
+/// letter, then letters, digits, `-` or `_`, and not a key frame already
+/// reads (relation keys such as `epic` included). `[fields]` may declare
+/// nothing else — a declared `dep` would never be read as the field it
+/// claims to be.
+pub fn is_field_key(key: &str) -> bool {
+    let mut chars = key.chars();
+    chars.next().is_some_and(|c| c.is_ascii_lowercase())
+        && chars.all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-' || c == '_')
+        && !matches!(
+            key,
+            "dep" | "ref" | "spec" | "note" | "added" | "resolved" | "conflict" | MOVED_TO_KEY
+        )
+        && RelationKind::from_key(key).is_none()
 }
 
 impl Metadata {
     /// Returns the key name for this metadata variant
-    pub fn key(&self) -> &'static str {
+    pub fn key(&self) -> &str {
         match self {
             Metadata::Dep(_) => "dep",
+            Metadata::Relation(kind, _) => kind.key(),
             Metadata::Ref(_) => "ref",
             Metadata::Spec(_) => "spec",
             Metadata::Note(_) => "note",
             Metadata::Added(_) => "added",
             Metadata::Resolved(_) => "resolved",
             Metadata::Conflict(_) => "conflict",
+            Metadata::Custom { key, .. } => key,
         }
     }
 
@@ -98,6 +264,9 @@ impl Metadata {
     /// `added → note → ref` are what a working project accumulates. 54% of the
     /// tasks in the project this was found on were in some such order.
     ///
+    /// A custom field is a short scalar like the rest, so it sits after them
+    /// and before the note; several keep the order they were written in.
+    ///
     /// `conflict:` leads because it is the most urgent thing a task can say: the
     /// merge that left it wrote no conflict markers, so this line is the only
     /// mark in the file that ours was kept and theirs went to the recovery log.
@@ -114,9 +283,27 @@ impl Metadata {
             Metadata::Added(_) => 1,
             Metadata::Resolved(_) => 2,
             Metadata::Dep(_) => 3,
-            Metadata::Spec(_) => 4,
-            Metadata::Ref(_) => 5,
-            Metadata::Note(_) => 6,
+            Metadata::Relation(..) => 4,
+            Metadata::Spec(_) => 5,
+            Metadata::Ref(_) => 6,
+            Metadata::Custom { .. } => 7,
+            Metadata::Note(_) => 8,
+        }
+    }
+
+    /// The task IDs this entry holds: a `dep:` or a relation's. What every
+    /// pass that renumbers, re-prefixes or re-tokens IDs rewrites.
+    pub fn task_ids(&self) -> Option<&Vec<String>> {
+        match self {
+            Metadata::Dep(ids) | Metadata::Relation(_, ids) => Some(ids),
+            _ => None,
+        }
+    }
+
+    pub fn task_ids_mut(&mut self) -> Option<&mut Vec<String>> {
+        match self {
+            Metadata::Dep(ids) | Metadata::Relation(_, ids) => Some(ids),
+            _ => None,
         }
     }
 }
@@ -129,9 +316,9 @@ impl Metadata {
 /// from every surface at once.
 ///
 /// **Stable, so duplicate keys keep their relative order.** That is reachable,
-/// not theoretical — an *unknown* metadata key parses to a `Note` carrying its
-/// own `key: value` text, so one task can hold several notes, and reordering
-/// them against each other would scramble text a user wrote.
+/// not theoretical — nothing stops a hand edit writing two `note:` lines, and
+/// every [`Metadata::Custom`] field shares one rank, so reordering them against
+/// each other would scramble text a user wrote.
 ///
 /// Display surfaces call this unconditionally. The **serializer** does not: a
 /// task whose stranded lines would be absorbed by a note moved last keeps its
@@ -264,6 +451,23 @@ impl Task {
     pub fn mark_dirty(&mut self) {
         self.dirty = true;
     }
+
+    /// The value of custom field `key`, as written. The first, if a hand edit
+    /// left two.
+    pub fn field(&self, key: &str) -> Option<&str> {
+        self.metadata.iter().find_map(|m| match m {
+            Metadata::Custom { key: k, value } if k == key => Some(value.as_str()),
+            _ => None,
+        })
+    }
+
+    /// The task's `added:` date, as written.
+    pub fn added_date(&self) -> Option<String> {
+        self.metadata.iter().find_map(|m| match m {
+            Metadata::Added(date) => Some(date.clone()),
+            _ => None,
+        })
+    }
 }
 
 /// Compares the *semantic* fields only. `source_text`, `dirty`, `leading_lines`
@@ -314,6 +518,26 @@ mod tests {
         assert_eq!(TaskState::from_checkbox_char('a'), None);
     }
 
+    #[test]
+    fn punctuation_checkboxes_are_custom_states() {
+        assert_eq!(
+            TaskState::from_any_checkbox_char('?'),
+            Some(TaskState::Custom('?'))
+        );
+        assert_eq!(
+            TaskState::from_any_checkbox_char('x'),
+            Some(TaskState::Done)
+        );
+        assert_eq!(TaskState::from_any_checkbox_char('X'), None);
+        assert_eq!(TaskState::from_any_checkbox_char(']'), None);
+        let json = serde_json::to_string(&TaskState::Custom('?')).unwrap();
+        assert_eq!(json, "\"?\"");
+        assert_eq!(
+            serde_json::from_str::<TaskState>(&json).unwrap(),
+            TaskState::Custom('?')
+        );
+    }
+
     #[test]
     fn metadata_key_all_variants() {
         assert_eq!(Metadata::Dep(vec![]).key(), "dep");
@@ -357,21 +581,24 @@ mod tests {
             Metadata::Conflict("both-edited".into()),
             Metadata::Spec(vec!["s.md".into()]),
             Metadata::Dep(vec!["T-1".into()]),
+            Metadata::Custom {
+                key: "area".into(),
+                value: "parser".into(),
+            },
         ];
 
         let keys: Vec<&str> = ordered_metadata(&task).iter().map(|m| m.key()).collect();
         assert_eq!(
             keys,
             [
-                "conflict", "added", "resolved", "dep", "spec", "ref", "note"
+                "conflict", "added", "resolved", "dep", "spec", "ref", "area", "note"
             ]
         );
         assert_eq!(ordered_metadata(&task).len(), task.metadata.len());
     }
 
-    /// Two entries sharing a key keep their relative order — an unknown metadata
-    /// key parses to a `Note`, so a task can hold several, and they are text
-    /// somebody wrote in an order they chose.
+    /// Two entries sharing a rank keep their relative order — a task can hold
+    /// several notes, and they are text somebody wrote in an order they chose.
     #[test]
     fn ordering_is_stable_within_a_key() {
         let mut task = Task::new(TaskState::Todo, None, "t".into());
@@ -29,12 +29,31 @@ pub enum TrackNode {
 }
 
 /// The kind of task section in a track file
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
-#[serde(rename_all = "lowercase")]
+///
+/// The three built-in sections, plus [`SectionKind::Custom`] for a `##` heading
+/// a project declares under `[workflow] sections` — `## Icebox`, `## Next`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum SectionKind {
     Backlog,
     Parked,
     Done,
+    /// A project-defined section, by its heading text.
+    Custom(SectionName),
+}
+
+impl SectionKind {
+    /// The kind a `## heading` names: a built-in one case-insensitively, any
+    /// other heading short enough to hold as a custom one. Whether the project
+    /// declares it is the caller's to ask — the parser reads only the custom
+    /// sections [`crate::parse::Schema`] names.
+    pub fn from_heading(heading: &str) -> Option<SectionKind> {
+        match heading.to_lowercase().as_str() {
+            "backlog" => Some(SectionKind::Backlog),
+            "parked" => Some(SectionKind::Parked),
+            "done" => Some(SectionKind::Done),
+            _ => SectionName::new(heading).map(SectionKind::Custom),
+        }
+    }
 }
 
 impl std::fmt::Display for SectionKind {
@@ -43,10 +62,86 @@ impl std::fmt::Display for SectionKind {
             SectionKind::Backlog => write!(f, "Backlog"),
             SectionKind::Parked => write!(f, "Parked"),
             SectionKind::Done => write!(f, "Done"),
+            SectionKind::Custom(name) => write!(f, "{}", name.as_str()),
+        }
+    }
+}
+
+/// Built-ins serialize lowercase, as they always have; a custom section as its
+/// heading, lowercased to match.
+impl Serialize for SectionKind {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.to_string().to_lowercase())
+    }
+}
+
+impl<'de> Deserialize<'de> for SectionKind {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        SectionKind::from_heading(&s)
+            .ok_or_else(|| serde::de::Error::custom(format!("not a section name: '{s}'")))
+    }
+}
+
+/// A custom section's heading text, held inline so [`SectionKind`] stays `Copy`.
+///
+/// `SectionKind` is passed by value at a few hundred call sites; a `String` in
+/// one variant would have put a `.clone()` on every one of them for the sake of
+/// a heading nobody writes at length. Anything over [`SectionName::MAX`] bytes
+/// is not a section name — the parser leaves it as an unknown heading, and the
+/// config refuses to declare it. Compared without regard to ASCII case, as the
+/// built-in headings are.
+#[derive(Clone, Copy)]
+pub struct SectionName {
+    len: u8,
+    bytes: [u8; SectionName::MAX],
+}
+
+impl SectionName {
+    /// The longest heading a custom section may have, in bytes.
+    pub const MAX: usize = 40;
+
+    pub fn new(name: &str) -> Option<SectionName> {
+        let name = name.trim();
+        if name.is_empty() || name.len() > Self::MAX {
+            return None;
+        }
+        let mut bytes = [0; Self::MAX];
+        bytes[..name.len()].copy_from_slice(name.as_bytes());
+        Some(SectionName {
+            len: name.len() as u8,
+            bytes,
+        })
+    }
+
+    pub fn as_str(&self) -> &str {
+        // Built from a `&str` cut at its own length, so always valid.
+        std::str::from_utf8(&self.bytes[..self.len as usize]).unwrap_or_default()
+    }
+}
+
+impl PartialEq for SectionName {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_str().eq_ignore_ascii_case(other.as_str())
+    }
+}
+
+impl Eq for SectionName {}
+
+impl std::hash::Hash for SectionName {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        for b in self.as_str().bytes() {
+            state.write_u8(b.to_ascii_lowercase());
         }
     }
 }
 
+impl std::fmt::Debug for SectionName {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self.as_str())
+    }
+}
+
 /// A parsed track file
 #[derive(Debug, Clone)]
 pub struct Track {
@@ -101,6 +196,22 @@ impl Track {
         &[]
     }
 
+    /// The kinds of section this track has, in file order, each once.
+    ///
+    /// What to walk when every section matters: a custom one such as
+    /// `## Icebox` is only in this list, never in the built-in three.
+    pub fn section_kinds(&self) -> Vec<SectionKind> {
+        let mut kinds = Vec::new();
+        for node in &self.nodes {
+            if let TrackNode::Section { kind, .. } = node
+                && !kinds.contains(kind)
+            {
+                kinds.push(*kind);
+            }
+        }
+        kinds
+    }
+
     /// Section kinds this track has more than one of.
     ///
     /// Cheap — a walk of the node list — so callers on the write path can ask
@@ -140,7 +251,8 @@ impl Track {
     /// task lines are only parsed inside a section. So a stray heading does not
     /// merely sit there being ignored; the tasks behind it stop being tasks.
     /// That is why this is reported even when it stranded nothing: the next
-    /// task written under it would vanish.
+    /// task written under it would vanish. A custom heading `[workflow]
+    /// sections` does not declare is one of these, like any other.
     pub fn unknown_headings(&self) -> Vec<UnknownHeading> {
         let mut out = Vec::new();
         for node in &self.nodes {
@@ -268,29 +380,28 @@ impl Track {
     }
 
     /// Ensure a section exists, creating it if missing.
-    /// New sections are inserted in canonical order: Backlog → Parked → Done.
+    /// New sections are inserted in canonical order: Backlog → Parked → custom
+    /// sections → Done.
     pub fn ensure_section(&mut self, kind: SectionKind) {
         if self.section_tasks_mut(kind).is_some() {
             return;
         }
-        let header = match kind {
-            SectionKind::Backlog => "## Backlog",
-            SectionKind::Parked => "## Parked",
-            SectionKind::Done => "## Done",
-        };
         let new_node = TrackNode::Section {
             kind,
-            header_lines: vec![header.to_string()],
+            header_lines: vec![format!("## {kind}")],
             tasks: Vec::new(),
             trailing_lines: vec![String::new()],
         };
 
         // Find the right position: insert before the first section that should come after.
+        // Custom sections go after Parked and before Done, each new one after
+        // those already there.
         let order = |k: SectionKind| -> u8 {
             match k {
                 SectionKind::Backlog => 0,
                 SectionKind::Parked => 1,
-                SectionKind::Done => 2,
+                SectionKind::Custom(_) => 2,
+                SectionKind::Done => 3,
             }
         };
         let target_order = order(kind);
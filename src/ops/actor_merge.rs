@@ -251,7 +251,7 @@ pub fn apply_map_to_tasks(
 
         for m in &mut t.metadata {
             match m {
-                Metadata::Dep(deps) => {
+                Metadata::Dep(deps) | Metadata::Relation(_, deps) => {
                     for d in deps.iter_mut() {
                         if let Some(new) = map.get(d.as_str()) {
                             *d = new.as_str().to_string();
@@ -269,6 +269,18 @@ pub fn apply_map_to_tasks(
                         }
                     }
                 }
+                // A custom field is free text to the parser; an `id-list`
+                // field holds IDs, so it gets the same scan as a ref.
+                Metadata::Custom { value, .. } => {
+                    let (new_text, found) = scan_prose(value, map, rewrite_notes);
+                    if !found.is_empty() {
+                        hits.extend(found);
+                        if rewrite_notes && &new_text != value {
+                            *value = new_text;
+                            dirty = true;
+                        }
+                    }
+                }
                 Metadata::Ref(refs) | Metadata::Spec(refs) => {
                     for r in refs.iter_mut() {
                         let (new_text, found) = scan_prose(r, map, rewrite_notes);
@@ -4,8 +4,9 @@ use std::path::Path;
 use chrono;
 use serde::Serialize;
 
+use crate::model::config::WorkflowConfig;
 use crate::model::project::Project;
-use crate::model::task::{Metadata, Task, TaskState};
+use crate::model::task::{Metadata, RelationKind, Task, TaskState};
 use crate::model::track::{Track, TrackNode};
 use crate::ops::refs as refs_ops;
 
@@ -212,6 +213,71 @@ pub enum CheckWarning {
     /// Task has the #lost tag (created by recovery system)
     #[serde(rename = "lost_task")]
     LostTask { track_id: String, task_id: String },
+    /// A task marked `[-]` that nothing visible blocks: none of its `dep:`
+    /// targets is still open, and it carries no note to say what it is waiting
+    /// for. Blocked set by hand and never cleared is how `[-]` drifts from
+    /// `dep:` — the dep was finished, or removed, and the marker stayed.
+    ///
+    /// The note counts as the reason because it is where a reason would be
+    /// written: a task blocked on a vendor or a decision has nothing to put in
+    /// `dep:`, and one sentence in its note is enough to take it off this list.
+    /// No `--fix` — only the author knows whether the block still holds.
+    #[serde(rename = "blocked_without_cause")]
+    BlockedWithoutCause { track_id: String, task_id: String },
+    /// A cross-project dep names a project that could not be loaded — not in
+    /// the registry on this machine, or registered at a path with no project.
+    ///
+    /// A **warning**, once per project: the deps themselves may be fine, and a
+    /// teammate who never cloned the other repository should not see every one
+    /// of them reported as dangling. No `--fix`; registering the project
+    /// (`fr projects add`) is what clears it.
+    #[serde(rename = "external_project_unreachable")]
+    ExternalProjectUnreachable {
+        /// The name as the dep writes it, `lib` in `lib:LIB-012`.
+        project: String,
+        reason: String,
+    },
+    /// A custom field declared in `[fields]` holds a value its schema rejects:
+    /// a date that is not a date, an enum value not in the list, an `id-list`
+    /// naming a task that does not exist, a `path-list` path that is not there.
+    ///
+    /// A **warning**: the task is fine to read and write, the field is just
+    /// wrong. Keys with no schema are not checked — they are free text. No
+    /// `--fix`; the right value is the author's to say.
+    #[serde(rename = "invalid_field")]
+    InvalidField {
+        track_id: String,
+        task_id: String,
+        key: String,
+        value: String,
+        reason: String,
+    },
+    /// A task's checkbox holds a custom state — `[?]`, `[!]` — that
+    /// `[workflow.states]` does not declare.
+    ///
+    /// A **warning**: the task parses, as a todo — which is what the next write
+    /// of it will say, `[ ]` in place of the character. No `--fix`; declaring
+    /// the state or choosing a built-in one is the author's call.
+    #[serde(rename = "undeclared_state")]
+    UndeclaredState {
+        track_id: String,
+        task_id: String,
+        checkbox: char,
+    },
+    /// A relation — `relates:`, `duplicates:`, `supersedes:`, `epic:` — names a
+    /// task ID that exists nowhere, live or archived.
+    ///
+    /// A **warning** where a dangling `dep:` is an error: a dep gates `fr
+    /// ready`, and one naming nothing holds its task back for ever, while a
+    /// relation only records a link. No `--fix`; removing it forgets which task
+    /// was meant, which is the one thing the line was there to say.
+    #[serde(rename = "dangling_relation")]
+    DanglingRelation {
+        track_id: String,
+        task_id: String,
+        relation: RelationKind,
+        target: String,
+    },
     /// A subtask's ID does not extend its parent's — e.g. `BAC-207` nested under
     /// `BAC-153`. The ID no longer says where the task lives, and the parent's
     /// child-number scan cannot see it, so a later subtask can be handed a number
@@ -672,7 +738,8 @@ fn check_headings(project: &Project, result: &mut CheckResult) {
                 .file_name()
                 .map(|n| n.to_string_lossy().to_string())
                 .unwrap_or_default();
-            let track = crate::parse::parse_track(&text);
+            let schema = crate::parse::Schema::of(&project.config);
+            let track = crate::parse::parse_track_with(&text, &schema);
             for dup in track.duplicate_sections() {
                 result.errors.push(CheckError::DuplicateSection {
                     track_id: name.clone(),
@@ -799,6 +866,216 @@ fn check_note_duplication(project: &Project, result: &mut CheckResult) {
     }
 }
 
+/// Deps of the form `lib:LIB-012`: a dangling error when the other project
+/// loads and has no such task, one warning per project that does not load.
+fn check_external_deps(
+    project: &Project,
+    external: &crate::ops::deps::ExternalDeps,
+    result: &mut CheckResult,
+) {
+    use crate::ops::deps::ExternalStatus;
+    for (track_id, track) in &project.tracks {
+        for node in &track.nodes {
+            if let TrackNode::Section { tasks, .. } = node {
+                let mut all = Vec::new();
+                collect_tasks_flat(tasks, &mut all);
+                for task in all {
+                    let Some(ref task_id) = task.id else {
+                        continue;
+                    };
+                    for dep_id in crate::ops::deps::task_deps(task) {
+                        if external.status(&dep_id) == Some(&ExternalStatus::Missing) {
+                            result.errors.push(CheckError::DanglingDep {
+                                track_id: track_id.to_string(),
+                                task_id: task_id.to_string(),
+                                dep_id,
+                            });
+                        }
+                    }
+                }
+            }
+        }
+    }
+    for (project, reason) in &external.unreachable {
+        result
+            .warnings
+            .push(CheckWarning::ExternalProjectUnreachable {
+                project: project.clone(),
+                reason: reason.clone(),
+            });
+    }
+}
+
+/// See [`CheckWarning::InvalidField`].
+fn check_fields(project: &Project, all_ids: &HashSet<String>, result: &mut CheckResult) {
+    use crate::model::config::FieldKind;
+    let schema = &project.config.fields;
+    if schema.is_empty() {
+        return;
+    }
+    for (track_id, track) in &project.tracks {
+        for node in &track.nodes {
+            let TrackNode::Section { tasks, .. } = node else {
+                continue;
+            };
+            let mut all = Vec::new();
+            collect_tasks_flat(tasks, &mut all);
+            for task in all {
+                for meta in &task.metadata {
+                    let Metadata::Custom { key, value } = meta else {
+                        continue;
+                    };
+                    let Some(field) = schema.get(key) else {
+                        continue;
+                    };
+                    let reason = field.reject(value).or_else(|| match field.kind {
+                        FieldKind::IdList => field
+                            .items(value)
+                            .into_iter()
+                            .find(|id| {
+                                crate::ops::deps::external_ref(id).is_none()
+                                    && !all_ids.contains(*id)
+                            })
+                            .map(|id| format!("no task {}", id)),
+                        FieldKind::PathList => field
+                            .items(value)
+                            .into_iter()
+                            .find(|p| !refs_ops::exists(&project.root, p))
+                            .map(|p| format!("no such file {}", p)),
+                        _ => None,
+                    });
+                    if let Some(reason) = reason {
+                        result.warnings.push(CheckWarning::InvalidField {
+                            track_id: track_id.to_string(),
+                            task_id: task.id.as_deref().unwrap_or("").to_string(),
+                            key: key.clone(),
+                            value: value.clone(),
+                            reason,
+                        });
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// See [`CheckWarning::DanglingRelation`]. An archived task is a fine target —
+/// a task a newer one supersedes is usually done and cleaned away — so the
+/// archives are read, and only when some relation needs them.
+fn check_relations(project: &Project, all_ids: &HashSet<String>, result: &mut CheckResult) {
+    let mut archived: Option<HashSet<String>> = None;
+    for (track_id, track) in &project.tracks {
+        for node in &track.nodes {
+            let TrackNode::Section { tasks, .. } = node else {
+                continue;
+            };
+            let mut all = Vec::new();
+            collect_tasks_flat(tasks, &mut all);
+            for task in all {
+                for (kind, target) in crate::ops::relations::relations(task) {
+                    // A qualified `other:ID` names a task this project cannot
+                    // see; a move to another project writes them.
+                    if all_ids.contains(target) || crate::ops::deps::external_ref(target).is_some()
+                    {
+                        continue;
+                    }
+                    let archived = archived.get_or_insert_with(|| {
+                        let mut ids = HashSet::new();
+                        for list in crate::io::project_io::archived_task_lists(
+                            &project.frame_dir,
+                            &crate::parse::Schema::of(&project.config),
+                        ) {
+                            collect_ids_from_tasks(&list.tasks, &mut ids);
+                        }
+                        ids
+                    });
+                    if !archived.contains(target) {
+                        result.warnings.push(CheckWarning::DanglingRelation {
+                            track_id: track_id.to_string(),
+                            task_id: task.id.as_deref().unwrap_or("").to_string(),
+                            relation: kind,
+                            target: target.to_string(),
+                        });
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn collect_tasks_flat<'a>(tasks: &'a [Task], out: &mut Vec<&'a Task>) {
+    for task in tasks {
+        out.push(task);
+        collect_tasks_flat(&task.subtasks, out);
+    }
+}
+
+/// See [`CheckWarning::BlockedWithoutCause`]. A dep on a task that does not
+/// exist counts as done here, as it does for `fr ready`; the dangling dep is
+/// reported on its own. So does one in a project that cannot be loaded.
+fn check_blocked_causes(
+    project: &Project,
+    external: &crate::ops::deps::ExternalDeps,
+    result: &mut CheckResult,
+) {
+    let mut open = HashSet::new();
+    for (_, track) in &project.tracks {
+        for node in &track.nodes {
+            if let TrackNode::Section { tasks, .. } = node {
+                collect_open_ids(tasks, &mut open);
+            }
+        }
+    }
+
+    for (track_id, track) in &project.tracks {
+        for node in &track.nodes {
+            if let TrackNode::Section { tasks, .. } = node {
+                check_tasks_blocked_cause(tasks, track_id, &open, external, result);
+            }
+        }
+    }
+}
+
+fn collect_open_ids(tasks: &[Task], open: &mut HashSet<String>) {
+    for task in tasks {
+        if let Some(ref id) = task.id
+            && task.state != TaskState::Done
+        {
+            open.insert(id.to_string());
+        }
+        collect_open_ids(&task.subtasks, open);
+    }
+}
+
+fn check_tasks_blocked_cause(
+    tasks: &[Task],
+    track_id: &str,
+    open: &HashSet<String>,
+    external: &crate::ops::deps::ExternalDeps,
+    result: &mut CheckResult,
+) {
+    for task in tasks {
+        if task.state == TaskState::Blocked
+            && let Some(ref id) = task.id
+        {
+            let waiting = crate::ops::deps::task_deps(task)
+                .iter()
+                .any(|d| open.contains(d) || external.is_open(d));
+            let noted = task
+                .metadata
+                .iter()
+                .any(|m| matches!(m, Metadata::Note(n) if !n.trim().is_empty()));
+            if !waiting && !noted {
+                result.warnings.push(CheckWarning::BlockedWithoutCause {
+                    track_id: track_id.to_string(),
+                    task_id: id.to_string(),
+                });
+            }
+        }
+        check_tasks_blocked_cause(&task.subtasks, track_id, open, external, result);
+    }
+}
+
 fn check_task_note_duplication(
     task: &Task,
     track_id: &str,
@@ -848,7 +1125,14 @@ pub fn check_project(project: &Project) -> CheckResult {
     }
 
     for (track_id, track) in &project.tracks {
-        check_track(track, track_id, &all_ids, &project.root, &mut result);
+        check_track(
+            track,
+            track_id,
+            &all_ids,
+            &project.root,
+            &project.config.workflow,
+            &mut result,
+        );
     }
 
     // Actor-registry drift: this clone's `.actor` token should have an active
@@ -901,6 +1185,16 @@ pub fn check_project(project: &Project) -> CheckResult {
     // refuses, found where it has already happened.
     check_note_duplication(project, &mut result);
 
+    // Deps on tasks in other registered projects. Reads the registry only
+    // when some dep names another project.
+    let external = crate::ops::deps::ExternalDeps::load(project);
+    check_external_deps(project, &external, &mut result);
+    check_fields(project, &all_ids, &mut result);
+    check_relations(project, &all_ids, &mut result);
+
+    // Blocked markers that no open dep and no note account for.
+    check_blocked_causes(project, &external, &mut result);
+
     // Does the recovery log actually hold the other side of each conflict?
     resolve_conflict_evidence(&project.frame_dir, &mut result);
 
@@ -932,12 +1226,22 @@ fn check_track(
     track_id: &str,
     all_ids: &HashSet<String>,
     project_root: &Path,
+    workflow: &WorkflowConfig,
     result: &mut CheckResult,
 ) {
     for node in &track.nodes {
         if let TrackNode::Section { kind, tasks, .. } = node {
             for task in tasks {
-                check_task(task, None, track_id, *kind, all_ids, project_root, result);
+                check_task(
+                    task,
+                    None,
+                    track_id,
+                    *kind,
+                    all_ids,
+                    project_root,
+                    workflow,
+                    result,
+                );
             }
         }
     }
@@ -1020,6 +1324,7 @@ fn check_task(
     section: crate::model::track::SectionKind,
     all_ids: &HashSet<String>,
     project_root: &Path,
+    workflow: &WorkflowConfig,
     result: &mut CheckResult,
 ) {
     let task_id = task.id.as_deref().unwrap_or("");
@@ -1074,19 +1379,39 @@ fn check_task(
         }
     }
 
+    // Warning: a custom checkbox the project never declared. The parser read
+    // it as a todo, so it is only in the line as written — and gone from the
+    // file the next time the task is.
+    if let Some(checkbox) = task
+        .source_text
+        .as_ref()
+        .and_then(|lines| lines.first())
+        .and_then(|line| line.trim_start().strip_prefix("- ["))
+        .and_then(|rest| rest.chars().next())
+        && checkbox != task.state.checkbox_char()
+        && TaskState::from_any_checkbox_char(checkbox).is_some()
+    {
+        result.warnings.push(CheckWarning::UndeclaredState {
+            track_id: track_id.to_string(),
+            task_id: task_id.to_string(),
+            checkbox,
+        });
+    }
+
     // Warning: a top-level task in a section its state does not call for.
     // `parent.is_none()` is the top-level test — a subtask has no section of its
     // own and simply inherits the one passed down this recursion.
-    if parent.is_none() && task.id.is_some() {
-        let expected = crate::ops::task_ops::canonical_section(task.state);
-        if expected != section {
-            result.warnings.push(CheckWarning::TaskInWrongSection {
-                track_id: track_id.to_string(),
-                task_id: task_id.to_string(),
-                expected,
-                actual: section,
-            });
-        }
+    if parent.is_none()
+        && task.id.is_some()
+        && !crate::ops::task_ops::section_accepts(section, task.state, workflow)
+    {
+        let expected = crate::ops::task_ops::canonical_section(task.state, workflow);
+        result.warnings.push(CheckWarning::TaskInWrongSection {
+            track_id: track_id.to_string(),
+            task_id: task_id.to_string(),
+            expected,
+            actual: section,
+        });
     }
 
     // Warning: lines frame could not attribute to any task, held ahead of this
@@ -1139,6 +1464,10 @@ fn check_task(
         match meta {
             Metadata::Dep(deps) => {
                 for dep_id in deps {
+                    // Another project's task: `check_external_deps` asks it.
+                    if crate::ops::deps::external_ref(dep_id).is_some() {
+                        continue;
+                    }
                     if !all_ids.contains(dep_id) {
                         result.errors.push(CheckError::DanglingDep {
                             track_id: track_id.to_string(),
@@ -1193,6 +1522,7 @@ fn check_task(
             section,
             all_ids,
             project_root,
+            workflow,
             result,
         );
     }
@@ -1583,7 +1913,10 @@ fn check_archived_id_collisions(project: &Project, result: &mut CheckResult) {
     }
 
     let mut archived: HashMap<String, Vec<String>> = HashMap::new();
-    for list in crate::io::project_io::archived_task_lists(&project.frame_dir) {
+    for list in crate::io::project_io::archived_task_lists(
+        &project.frame_dir,
+        &crate::parse::Schema::of(&project.config),
+    ) {
         collect_id_locations(&list.tasks, &list.file, &mut archived);
     }
 
@@ -1947,6 +2280,12 @@ mod tests {
             ui: UiConfig::default(),
             recovery: Default::default(),
             limits: Default::default(),
+            deps: Default::default(),
+            templates: Default::default(),
+            fields: Default::default(),
+            workflow: Default::default(),
+            board: Default::default(),
+            keys: Default::default(),
         }
     }
 
@@ -3435,6 +3774,50 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_warn_blocked_without_cause() {
+        let tmp = TempDir::new().unwrap();
+        let project = make_project_at(
+            tmp.path(),
+            "\
+# Main
+
+## Backlog
+
+- [-] `M-001` Dep finished, marker stayed
+  - added: 2025-05-01
+  - dep: M-005
+- [-] `M-002` Still waiting
+  - added: 2025-05-01
+  - dep: M-004
+- [-] `M-003` Waiting on the vendor
+  - added: 2025-05-01
+  - note: Quote requested 2025-05-02.
+- [ ] `M-004` Open
+  - added: 2025-05-01
+- [-] `M-006` No reason at all
+  - added: 2025-05-01
+
+## Done
+
+- [x] `M-005` Finished
+  - added: 2025-05-01
+  - resolved: 2025-05-02
+",
+        );
+
+        let result = check_project(&project);
+        let flagged: Vec<&str> = result
+            .warnings
+            .iter()
+            .filter_map(|w| match w {
+                CheckWarning::BlockedWithoutCause { task_id, .. } => Some(task_id.as_str()),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(flagged, vec!["M-001", "M-006"]);
+    }
+
     #[test]
     fn test_lost_task_no_id_no_warning() {
         let tmp = TempDir::new().unwrap();
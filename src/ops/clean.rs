@@ -21,10 +21,10 @@ pub struct Normalized {
     /// The task's ID, or its title when it has none.
     pub task: String,
     /// Field keys in the order the file holds them.
-    pub was: Vec<&'static str>,
+    pub was: Vec<String>,
     /// Field keys in canonical order — what the task was rewritten to, or for a
     /// task in [`NormalizeResult::skipped`] what it would have become.
-    pub now: Vec<&'static str>,
+    pub now: Vec<String>,
 }
 
 /// Result of a normalize pass.
@@ -116,10 +116,10 @@ fn normalize_tasks(
                     .as_ref()
                     .map(|i| i.to_string())
                     .unwrap_or_else(|| task.title.clone()),
-                was: task.metadata.iter().map(|m| m.key()).collect(),
+                was: task.metadata.iter().map(|m| m.key().to_string()).collect(),
                 now: crate::model::task::ordered_metadata(task)
                     .iter()
-                    .map(|m| m.key())
+                    .map(|m| m.key().to_string())
                     .collect(),
             };
             // Ask the writer, rather than re-deriving its rule here: a task it
@@ -344,7 +344,7 @@ pub fn ensure_ids_and_dates(project: &mut Project, scope: IdScope) -> Vec<String
     // Reconcile misplaced tasks (e.g., parked task in Backlog section) — no
     // minting, so it runs regardless of claim state.
     for (track_id, track) in &mut project.tracks {
-        if reconcile_sections_for_track(track, track_id, &mut result) {
+        if reconcile_sections_for_track(track, track_id, &project.config.workflow, &mut result) {
             modified.insert(track_id.clone());
         }
     }
@@ -356,7 +356,8 @@ pub fn ensure_ids_and_dates(project: &mut Project, scope: IdScope) -> Vec<String
 // Section reconciliation — move misplaced top-level tasks to correct section
 // ---------------------------------------------------------------------------
 
-use crate::ops::task_ops::canonical_section;
+use crate::model::config::WorkflowConfig;
+use crate::ops::task_ops::{canonical_section, section_accepts};
 
 /// Move top-level tasks that are in the wrong section to the correct one.
 /// For example, a `[~]` parked task sitting in `## Backlog` gets moved to `## Parked`.
@@ -364,6 +365,7 @@ use crate::ops::task_ops::canonical_section;
 fn reconcile_sections_for_track(
     track: &mut Track,
     track_id: &str,
+    workflow: &WorkflowConfig,
     result: &mut CleanResult,
 ) -> bool {
     // Collect (task_id, current_section, target_section) for misplaced tasks.
@@ -373,11 +375,14 @@ fn reconcile_sections_for_track(
     for node in &track.nodes {
         if let TrackNode::Section { kind, tasks, .. } = node {
             for task in tasks {
-                let target = canonical_section(task.state);
-                if target != *kind
+                if !section_accepts(*kind, task.state, workflow)
                     && let Some(ref id) = task.id
                 {
-                    moves.push((id.to_string(), *kind, target));
+                    moves.push((
+                        id.to_string(),
+                        *kind,
+                        canonical_section(task.state, workflow),
+                    ));
                 }
             }
         }
@@ -407,7 +412,7 @@ pub fn reconcile_sections(project: &mut Project) -> Vec<String> {
     let mut modified = Vec::new();
 
     for (track_id, track) in &mut project.tracks {
-        if reconcile_sections_for_track(track, track_id, &mut result) {
+        if reconcile_sections_for_track(track, track_id, &project.config.workflow, &mut result) {
             modified.push(track_id.clone());
         }
     }
@@ -486,7 +491,7 @@ pub fn clean_project_with(project: &mut Project, scope: IdScope, mode: CleanMode
 
     // 3b. Reconcile misplaced tasks (e.g., parked task in Backlog section)
     for (track_id, track) in &mut project.tracks {
-        reconcile_sections_for_track(track, track_id, &mut result);
+        reconcile_sections_for_track(track, track_id, &project.config.workflow, &mut result);
     }
 
     // Collect all task IDs across all tracks for dep validation (after duplicate resolution)
@@ -1016,6 +1021,9 @@ fn validate_deps_in_tasks(
         for meta in &task.metadata {
             if let Metadata::Dep(deps) = meta {
                 for dep_id in deps {
+                    if crate::ops::deps::external_ref(dep_id).is_some() {
+                        continue;
+                    }
                     if !all_ids.contains(dep_id) {
                         result.dangling_deps.push(DanglingDep {
                             track_id: track_id.to_string(),
@@ -1445,6 +1453,12 @@ mod tests {
             ui: UiConfig::default(),
             recovery: Default::default(),
             limits: Default::default(),
+            deps: Default::default(),
+            templates: Default::default(),
+            fields: Default::default(),
+            workflow: Default::default(),
+            board: Default::default(),
+            keys: Default::default(),
         }
     }
 
@@ -0,0 +1,358 @@
+//! `fr config`: read and edit `project.toml` one key at a time.
+//!
+//! Keys are TOML dotted keys — `clean.done_threshold`, `ui.tag_colors.bug`,
+//! `ui.tag_colors."needs.review"` — and edits go through the `toml_edit`
+//! document, so every comment and blank line in the file survives them, as it
+//! does for the `fr track` commands.
+//!
+//! **Every edit is validated before it is written**, by deserializing the edited
+//! document into [`ProjectConfig`] exactly as the next load will. A value the
+//! loader would reject is refused with the loader's own reason, rather than
+//! written and discovered by whichever command runs next.
+//!
+//! Reads go through the *parsed* config instead, so `get` and `list` report the
+//! value in effect — a default included — rather than only what the file says.
+
+use crate::io::config_io;
+use crate::model::config::ProjectConfig;
+
+#[derive(Debug, thiserror::Error)]
+pub enum ConfigEditError {
+    #[error("not a config key: '{0}'")]
+    BadKey(String),
+    #[error("unknown config key: {0}")]
+    UnknownKey(String),
+    #[error("{0} is not set")]
+    NotSet(String),
+    #[error("{0} is a table — name one of its keys (fr config list shows them)")]
+    Table(String),
+    #[error("tracks are edited with fr track, not fr config")]
+    Tracks,
+    #[error("invalid value for {key}: {reason}")]
+    Invalid { key: String, reason: String },
+}
+
+/// A dotted key split into its segments, quoted segments unquoted.
+fn parse_key(key: &str) -> Result<Vec<String>, ConfigEditError> {
+    let segments = toml_edit::Key::parse(key).map_err(|_| ConfigEditError::BadKey(key.into()))?;
+    let segments: Vec<String> = segments.iter().map(|k| k.get().to_string()).collect();
+    if segments.first().map(String::as_str) == Some("tracks") {
+        return Err(ConfigEditError::Tracks);
+    }
+    Ok(segments)
+}
+
+/// The config as a TOML tree, defaults filled in.
+fn effective(config: &ProjectConfig) -> toml::Table {
+    toml::Table::try_from(config).unwrap_or_default()
+}
+
+fn lookup<'a>(table: &'a toml::Table, path: &[String]) -> Option<&'a toml::Value> {
+    let (last, parents) = path.split_last()?;
+    let mut table = table;
+    for segment in parents {
+        table = table.get(segment)?.as_table()?;
+    }
+    table.get(last)
+}
+
+/// The value in effect for `key`.
+pub fn get(config: &ProjectConfig, key: &str) -> Result<toml::Value, ConfigEditError> {
+    let path = parse_key(key)?;
+    lookup(&effective(config), &path)
+        .cloned()
+        .ok_or_else(|| ConfigEditError::NotSet(key.into()))
+}
+
+/// Every value in effect, as `(dotted key, value)` sorted by key. Tables are
+/// walked into; anything else — an array included — is one entry.
+pub fn list(config: &ProjectConfig) -> Vec<(String, toml::Value)> {
+    fn walk(prefix: &str, table: &toml::Table, out: &mut Vec<(String, toml::Value)>) {
+        for (key, value) in table {
+            let quoted = toml_edit::Key::new(key.as_str()).display_repr().to_string();
+            let dotted = if prefix.is_empty() {
+                quoted
+            } else {
+                format!("{prefix}.{quoted}")
+            };
+            match value {
+                toml::Value::Table(sub) if !sub.is_empty() => walk(&dotted, sub, out),
+                _ => out.push((dotted, value.clone())),
+            }
+        }
+    }
+    let mut out = Vec::new();
+    walk("", &effective(config), &mut out);
+    out
+}
+
+/// Set `key` to `raw`, returning the config as it will load.
+///
+/// `raw` is read as a TOML value when it is one — `100`, `true`, `["md"]` —
+/// and as a string otherwise, so `#cc6666` and `parser work` need no quoting.
+/// A value that reads as TOML but only fits the key as a string (`name 2024`)
+/// is stored as the string.
+pub fn set(
+    doc: &mut toml_edit::DocumentMut,
+    key: &str,
+    raw: &str,
+) -> Result<ProjectConfig, ConfigEditError> {
+    let path = parse_key(key)?;
+    let mut candidates = Vec::new();
+    if let Ok(mut typed) = raw.parse::<toml_edit::Value>() {
+        typed.decor_mut().clear();
+        candidates.push(typed);
+    }
+    if !matches!(candidates.first(), Some(toml_edit::Value::String(_))) {
+        candidates.push(raw.into());
+    }
+
+    let mut first_error = None;
+    for value in candidates {
+        let mut edited = doc.clone();
+        insert(&mut edited, &path, key, value)?;
+        match validate(&edited, &path, key) {
+            Ok(config) => {
+                *doc = edited;
+                return Ok(config);
+            }
+            Err(e) => {
+                first_error.get_or_insert(e);
+            }
+        }
+    }
+    Err(first_error.expect("there is always at least one candidate"))
+}
+
+/// Remove `key` from the file, returning whether it was there and the config
+/// as it will load — the default, for a key that has one.
+pub fn unset(
+    doc: &mut toml_edit::DocumentMut,
+    key: &str,
+) -> Result<(bool, ProjectConfig), ConfigEditError> {
+    let path = parse_key(key)?;
+    let mut edited = doc.clone();
+    let removed = remove(&mut edited, &path, key)?;
+    let config = parse(&edited, key)?;
+    if !removed && !known(&config, doc, &path) {
+        return Err(ConfigEditError::UnknownKey(key.into()));
+    }
+    *doc = edited;
+    Ok((removed, config))
+}
+
+fn insert(
+    doc: &mut toml_edit::DocumentMut,
+    path: &[String],
+    key: &str,
+    value: toml_edit::Value,
+) -> Result<(), ConfigEditError> {
+    let (last, parents) = path.split_last().expect("a parsed key has a segment");
+    let mut table: &mut dyn toml_edit::TableLike = doc.as_table_mut();
+    for segment in parents {
+        if !table.contains_key(segment) {
+            let mut new = toml_edit::Table::new();
+            // Only the innermost table gets a `[header]` of its own, which is
+            // how a person would have written `[ui.tag_colors]`.
+            new.set_implicit(true);
+            table.insert(segment, toml_edit::Item::Table(new));
+        }
+        let item = table.get_mut(segment).expect("inserted above");
+        if item.is_array_of_tables() {
+            return Err(ConfigEditError::Tracks);
+        }
+        table = item
+            .as_table_like_mut()
+            .ok_or_else(|| ConfigEditError::Invalid {
+                key: key.into(),
+                reason: format!("'{segment}' holds a value, not a table"),
+            })?;
+    }
+    if table.get(last).is_some_and(|item| item.is_table_like()) {
+        return Err(ConfigEditError::Table(key.into()));
+    }
+    config_io::set_keeping_decor(table, last, value);
+    Ok(())
+}
+
+fn remove(
+    doc: &mut toml_edit::DocumentMut,
+    path: &[String],
+    key: &str,
+) -> Result<bool, ConfigEditError> {
+    let (last, parents) = path.split_last().expect("a parsed key has a segment");
+    let mut table: &mut dyn toml_edit::TableLike = doc.as_table_mut();
+    for segment in parents {
+        match table
+            .get_mut(segment)
+            .and_then(|item| item.as_table_like_mut())
+        {
+            Some(sub) => table = sub,
+            None => return Ok(false),
+        }
+    }
+    if table.get(last).is_some_and(|item| item.is_table_like()) {
+        return Err(ConfigEditError::Table(key.into()));
+    }
+    Ok(table.remove(last).is_some())
+}
+
+fn parse(doc: &toml_edit::DocumentMut, key: &str) -> Result<ProjectConfig, ConfigEditError> {
+    toml::from_str(&doc.to_string()).map_err(|e| ConfigEditError::Invalid {
+        key: key.into(),
+        reason: e.message().to_string(),
+    })
+}
+
+/// The edited document as the loader will read it, or why it will not.
+fn validate(
+    doc: &toml_edit::DocumentMut,
+    path: &[String],
+    key: &str,
+) -> Result<ProjectConfig, ConfigEditError> {
+    let config = parse(doc, key)?;
+    if !known(&config, doc, path) {
+        return Err(ConfigEditError::UnknownKey(key.into()));
+    }
+    check_color(&config, path, key)?;
+    Ok(config)
+}
+
+/// A `[ui.colors]` or `[ui.tag_colors]` value has to be one the theme reads.
+///
+/// The loader only asks for a string, and the theme skips one it cannot parse,
+/// so `notacolor` would be written and then ignored without a word.
+fn check_color(config: &ProjectConfig, path: &[String], key: &str) -> Result<(), ConfigEditError> {
+    let value = match path {
+        [ui, table, name] if ui == "ui" && table == "colors" => config.ui.colors.get(name),
+        [ui, table, name] if ui == "ui" && table == "tag_colors" => config.ui.tag_colors.get(name),
+        _ => None,
+    };
+    match value {
+        Some(value) if crate::tui::theme::parse_hex_color_pub(value).is_none() => {
+            Err(ConfigEditError::Invalid {
+                key: key.into(),
+                reason: format!("'{value}' is not a color; expected #RRGGBB"),
+            })
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Whether `path` is a key the config reads, rather than one it ignores.
+///
+/// The loader ignores a key it does not model, so a typo like
+/// `clean.done_treshold` would deserialize cleanly and do nothing. A key the
+/// config reads comes back out when it is serialized again — with the value
+/// set, or failing that with it removed, which is how an optional key whose
+/// value means "none" (`done_bytes_threshold = "off"`) still shows its default.
+fn known(config: &ProjectConfig, doc: &toml_edit::DocumentMut, path: &[String]) -> bool {
+    if lookup(&effective(config), path).is_some() {
+        return true;
+    }
+    let mut without = doc.clone();
+    if remove(&mut without, path, "").is_err() {
+        return false;
+    }
+    toml::from_str::<ProjectConfig>(&without.to_string())
+        .is_ok_and(|defaults| lookup(&effective(&defaults), path).is_some())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CONFIG: &str = r#"[project]
+name = "test"
+
+[clean]
+# Archive once a track holds this many done tasks.
+done_threshold = 100  # the default
+
+[[tracks]]
+id = "main"
+name = "Main"
+state = "active"
+file = "tracks/main.md"
+"#;
+
+    fn doc() -> toml_edit::DocumentMut {
+        CONFIG.parse().unwrap()
+    }
+
+    #[test]
+    fn set_keeps_comments_and_types_the_value() {
+        let mut doc = doc();
+        let config = set(&mut doc, "clean.done_threshold", "40").unwrap();
+        assert_eq!(config.clean.done_threshold, 40);
+        let text = doc.to_string();
+        assert!(text.contains("# Archive once a track holds this many done tasks."));
+        assert!(
+            text.contains("done_threshold = 40  # the default"),
+            "{text}"
+        );
+
+        // A string where the value does not parse as TOML, into a new table
+        let config = set(&mut doc, "ui.tag_colors.bug", "#cc6666").unwrap();
+        assert_eq!(config.ui.tag_colors["bug"], "#cc6666");
+        assert!(
+            doc.to_string()
+                .contains("[ui.tag_colors]\nbug = \"#cc6666\"")
+        );
+        // TOML-shaped, but the key wants a string
+        let config = set(&mut doc, "project.name", "2024").unwrap();
+        assert_eq!(config.project.name, "2024");
+    }
+
+    #[test]
+    fn set_refuses_what_the_loader_would() {
+        let mut doc = doc();
+        let err = set(&mut doc, "clean.done_threshold", "-5").unwrap_err();
+        assert!(
+            err.to_string()
+                .starts_with("invalid value for clean.done_threshold:"),
+            "{err}"
+        );
+        let err = set(&mut doc, "clean.done_treshold", "5").unwrap_err();
+        assert!(matches!(err, ConfigEditError::UnknownKey(_)), "{err}");
+        assert!(matches!(
+            set(&mut doc, "tracks.main.state", "shelved"),
+            Err(ConfigEditError::Tracks)
+        ));
+        assert!(matches!(
+            set(&mut doc, "clean", "1"),
+            Err(ConfigEditError::Table(_))
+        ));
+        // "off" reads back as none, and is still a key the config knows
+        let config = set(&mut doc, "clean.done_bytes_threshold", "off").unwrap();
+        assert!(config.clean.done_bytes_threshold.is_none());
+    }
+
+    #[test]
+    fn set_refuses_a_color_the_theme_cannot_read() {
+        let mut doc = doc();
+        let err = set(&mut doc, "ui.tag_colors.bug", "notacolor").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "invalid value for ui.tag_colors.bug: 'notacolor' is not a color; expected #RRGGBB"
+        );
+        let err = set(&mut doc, "ui.colors.background", "zzz").unwrap_err();
+        assert!(matches!(err, ConfigEditError::Invalid { .. }), "{err}");
+        assert!(!doc.to_string().contains("[ui"), "{doc}");
+        set(&mut doc, "ui.colors.background", "#0C001B").unwrap();
+    }
+
+    #[test]
+    fn unset_falls_back_to_the_default() {
+        let mut doc = doc();
+        let (removed, config) = unset(&mut doc, "clean.done_threshold").unwrap();
+        assert!(removed);
+        assert_eq!(
+            get(&config, "clean.done_threshold").unwrap(),
+            toml::Value::Integer(100)
+        );
+        let (removed, _) = unset(&mut doc, "clean.done_threshold").unwrap();
+        assert!(!removed);
+        assert!(unset(&mut doc, "project.name").is_err());
+    }
+}
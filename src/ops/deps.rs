@@ -1,14 +1,23 @@
-//! Dependency-tree traversal.
+//! Dependency-tree traversal, and the project-wide analyses built on the same
+//! edges: a topological [`plan`] of open work, a goal's [`critical_path`], and
+//! the `[deps] auto_block` pass that keeps `[-]` in step with `dep:`.
+//!
+//! A dep may also name a task in another registered project, `lib:LIB-012`.
+//! [`ExternalDeps`] resolves those by loading the other project read-only.
 //!
 //! One traversal, rendered two ways: `cli::output::format_dep_tree` for humans
 //! and `cli::output::dep_tree_to_json` for `--json`. Building the tree once and
 //! rendering it twice is deliberate — a listing implemented separately per
 //! surface is what `b664a3e` was, and `tests/parity.rs` exists because of it.
+//! The plan and the critical path follow the same rule.
 
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
 
-use crate::model::Project;
+use crate::io::registry::ProjectRegistry;
 use crate::model::task::{Metadata, Task, TaskState};
+use crate::model::{Project, TrackNode};
+use crate::ops::task_ops;
 
 /// Why a node in the tree looks the way it does.
 ///
@@ -28,6 +37,9 @@ pub enum DepStatus {
     Repeat,
     /// No task anywhere in the project holds this id.
     Missing,
+    /// A cross-project dep whose project could not be loaded, so whether the
+    /// task exists is unknown.
+    Unreachable,
 }
 
 /// A node in a dependency tree.
@@ -44,6 +56,11 @@ pub struct DepNode {
     pub title: Option<String>,
     pub state: Option<TaskState>,
     pub tags: Vec<String>,
+    /// The registered project holding the task, for a cross-project dep.
+    /// `None` for a task in this project.
+    pub project: Option<String>,
+    /// Children: the task's deps, or — in a [`dependents_tree`] — the tasks
+    /// that depend on it.
     pub deps: Vec<DepNode>,
 }
 
@@ -52,6 +69,7 @@ impl DepNode {
         DepNode {
             id: id.to_string(),
             status,
+            project: None,
             track_id: None,
             title: None,
             state: None,
@@ -83,56 +101,721 @@ fn find_task<'a>(project: &'a Project, id: &str) -> Option<(&'a str, &'a Task)>
 /// The root is a [`DepStatus::Missing`] node when no task holds that id; the
 /// caller decides whether that is an error.
 pub fn dep_tree(project: &Project, root_id: &str) -> DepNode {
+    dep_tree_across(project, root_id, &ExternalDeps::default())
+}
+
+/// [`dep_tree`], with cross-project deps looked up in `external`. Such a node
+/// is a leaf: the other project's own deps are its business.
+pub fn dep_tree_across(project: &Project, root_id: &str, external: &ExternalDeps) -> DepNode {
     let mut path = Vec::new();
     let mut expanded = HashSet::new();
-    build(project, root_id, &mut path, &mut expanded)
+    let walk = Walk {
+        project,
+        edges: &task_deps,
+        external,
+    };
+    walk.build(root_id, &mut path, &mut expanded)
 }
 
-/// Two sets, checked in this order, and the order matters.
+/// Build the *dependents* tree rooted at `root_id`: what it blocks, then what
+/// those block, and so on.
 ///
-/// `path` is the ancestor chain of the branch being walked, pushed on the way
-/// down and **popped on the way back up**, so it holds exactly the ids that
-/// reaching this one again would make a cycle. The previous implementation used
-/// a single set that was never popped, which made every re-encounter a "cycle".
-///
-/// `expanded` is every id expanded anywhere so far and is never cleared. It
-/// serves two purposes at once: it names the `Repeat` case, and — now that
-/// `path` alone no longer stops re-entry — it is what keeps a wide diamond from
-/// expanding combinatorially. Each id is expanded at most once per tree.
-fn build(
-    project: &Project,
-    id: &str,
-    path: &mut Vec<String>,
-    expanded: &mut HashSet<String>,
-) -> DepNode {
-    if path.iter().any(|p| p == id) {
-        return DepNode::terminal(id, DepStatus::Cycle);
-    }
-    let Some((track_id, task)) = find_task(project, id) else {
-        // Not recorded as expanded: there is nothing to expand, so a second
-        // reference to the same dangling id should report Missing again rather
-        // than pointing at a record that does not exist.
-        return DepNode::terminal(id, DepStatus::Missing);
+/// The same walk as [`dep_tree`] over the inverted edges, so the four statuses
+/// mean what they mean there. `Missing` can only occur at the root — every
+/// other node was reached by finding a task that names its parent.
+pub fn dependents_tree(project: &Project, root_id: &str) -> DepNode {
+    let index = dependents_index(project);
+    let dependents = |task: &Task| {
+        task.id
+            .as_deref()
+            .and_then(|id| index.get(id))
+            .cloned()
+            .unwrap_or_default()
     };
-    if !expanded.insert(id.to_string()) {
-        return DepNode::terminal(id, DepStatus::Repeat);
+    let mut path = Vec::new();
+    let mut expanded = HashSet::new();
+    let walk = Walk {
+        project,
+        edges: &dependents,
+        external: &ExternalDeps::default(),
+    };
+    walk.build(root_id, &mut path, &mut expanded)
+}
+
+/// Every dep target mapped to the tasks that name it, across all loaded tracks
+/// and every depth, in document order.
+///
+/// Keyed by the target as written, so a dangling target still gets an entry —
+/// the TUI's dep popup shows "blocking" for a task even when the index is the
+/// only place its id appears.
+pub fn dependents_index(project: &Project) -> HashMap<String, Vec<String>> {
+    fn walk(tasks: &[Task], index: &mut HashMap<String, Vec<String>>) {
+        for task in tasks {
+            if let Some(task_id) = &task.id {
+                for dep_id in task_deps(task) {
+                    index.entry(dep_id).or_default().push(task_id.to_string());
+                }
+            }
+            walk(&task.subtasks, index);
+        }
+    }
+    let mut index = HashMap::new();
+    for (_, track) in &project.tracks {
+        for node in &track.nodes {
+            if let crate::model::TrackNode::Section { tasks, .. } = node {
+                walk(tasks, &mut index);
+            }
+        }
+    }
+    index
+}
+
+/// What one tree walk reads: the project, which edges to follow, and where to
+/// look up a cross-project dep.
+///
+/// `edges` is what makes one walk serve both directions: a task's own deps for
+/// [`dep_tree`], the tasks naming it for [`dependents_tree`].
+struct Walk<'a> {
+    project: &'a Project,
+    edges: &'a dyn Fn(&Task) -> Vec<String>,
+    external: &'a ExternalDeps,
+}
+
+impl Walk<'_> {
+    /// Two sets, checked in this order, and the order matters.
+    ///
+    /// `path` is the ancestor chain of the branch being walked, pushed on the
+    /// way down and **popped on the way back up**, so it holds exactly the ids
+    /// that reaching this one again would make a cycle. The previous
+    /// implementation used a single set that was never popped, which made
+    /// every re-encounter a "cycle".
+    ///
+    /// `expanded` is every id expanded anywhere so far and is never cleared. It
+    /// serves two purposes at once: it names the `Repeat` case, and — now that
+    /// `path` alone no longer stops re-entry — it is what keeps a wide diamond
+    /// from expanding combinatorially. Each id is expanded at most once per
+    /// tree.
+    fn build(&self, id: &str, path: &mut Vec<String>, expanded: &mut HashSet<String>) -> DepNode {
+        if path.iter().any(|p| p == id) {
+            return DepNode::terminal(id, DepStatus::Cycle);
+        }
+        if let Some((project_name, _)) = external_ref(id) {
+            return self.external_node(id, project_name);
+        }
+        let Some((track_id, task)) = find_task(self.project, id) else {
+            // Not recorded as expanded: there is nothing to expand, so a second
+            // reference to the same dangling id should report Missing again
+            // rather than pointing at a record that does not exist.
+            return DepNode::terminal(id, DepStatus::Missing);
+        };
+        if !expanded.insert(id.to_string()) {
+            return DepNode::terminal(id, DepStatus::Repeat);
+        }
+
+        path.push(id.to_string());
+        let deps = (self.edges)(task)
+            .iter()
+            .map(|dep_id| self.build(dep_id, path, expanded))
+            .collect();
+        path.pop();
+
+        DepNode {
+            id: id.to_string(),
+            status: DepStatus::Resolved,
+            project: None,
+            track_id: Some(track_id.to_string()),
+            title: Some(task.title.clone()),
+            state: Some(task.state),
+            tags: task.tags.clone(),
+            deps,
+        }
+    }
+
+    fn external_node(&self, id: &str, project_name: &str) -> DepNode {
+        let mut node = match self.external.status(id) {
+            Some(ExternalStatus::Found {
+                track_id,
+                title,
+                state,
+                tags,
+            }) => DepNode {
+                id: id.to_string(),
+                status: DepStatus::Resolved,
+                project: None,
+                track_id: Some(track_id.clone()),
+                title: Some(title.clone()),
+                state: Some(*state),
+                tags: tags.clone(),
+                deps: Vec::new(),
+            },
+            Some(ExternalStatus::Missing) => DepNode::terminal(id, DepStatus::Missing),
+            Some(ExternalStatus::Unreachable) | None => {
+                DepNode::terminal(id, DepStatus::Unreachable)
+            }
+        };
+        node.project = Some(project_name.to_string());
+        node
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Project-wide analysis
+// ---------------------------------------------------------------------------
+
+/// A task in a [`DepGraph`]. `track_id`, `title` and `state` are `None` for a
+/// dangling target: an id some task depends on that no task holds.
+#[derive(Debug, Clone)]
+pub struct GraphNode {
+    pub id: String,
+    pub track_id: Option<String>,
+    pub title: Option<String>,
+    pub state: Option<TaskState>,
+}
+
+/// The dependency graph, flat: for export, where a tree would repeat every
+/// shared node.
+#[derive(Debug, Clone, Default)]
+pub struct DepGraph {
+    /// Grouped by track in tab order, document order within one, with
+    /// dangling targets last.
+    pub nodes: Vec<GraphNode>,
+    /// `(dependency, dependent)` — an edge points the way work flows, from
+    /// the task that has to finish to the task it unblocks.
+    pub edges: Vec<(String, String)>,
+}
+
+/// Every dependency edge with an end in `track_ids`, and the tasks at both
+/// ends.
+///
+/// A task with no edges is left out: the graph is for reading dependencies,
+/// and a node with nothing attached says nothing about them. An edge leaving
+/// the selected tracks brings its far end along, in that end's own track, so
+/// `--track` narrows the picture without cutting lines off mid-air.
+pub fn dep_graph(project: &Project, track_ids: &[&str]) -> DepGraph {
+    let mut edges: Vec<(String, String)> = Vec::new();
+    let in_scope = |track_id: &str| track_ids.contains(&track_id);
+
+    // Dependents first, in scope order: each edge is found on the task that
+    // declares it, and a dependent outside scope is found through the index.
+    let mut scoped: Vec<(String, &Task)> = Vec::new();
+    for (track_id, track) in &project.tracks {
+        if in_scope(track_id) {
+            for node in &track.nodes {
+                if let crate::model::TrackNode::Section { tasks, .. } = node {
+                    collect_all(tasks, track_id, &mut scoped);
+                }
+            }
+        }
+    }
+    let index = dependents_index(project);
+    for (_, task) in &scoped {
+        let Some(id) = task.id.as_deref() else {
+            continue;
+        };
+        for dep_id in task_deps(task) {
+            push_unique(&mut edges, (dep_id, id.to_string()));
+        }
+        for dependent in index.get(id).into_iter().flatten() {
+            push_unique(&mut edges, (id.to_string(), dependent.clone()));
+        }
+    }
+
+    let mut on_edge: HashSet<&str> = HashSet::new();
+    for (from, to) in &edges {
+        on_edge.insert(from);
+        on_edge.insert(to);
+    }
+
+    let mut graph = DepGraph::default();
+    let mut placed: HashSet<String> = HashSet::new();
+    for (track_id, track) in &project.tracks {
+        let mut tasks = Vec::new();
+        for node in &track.nodes {
+            if let crate::model::TrackNode::Section { tasks: list, .. } = node {
+                collect_all(list, track_id, &mut tasks);
+            }
+        }
+        for (_, task) in tasks {
+            let Some(id) = task.id.as_deref() else {
+                continue;
+            };
+            if on_edge.contains(id) && placed.insert(id.to_string()) {
+                graph.nodes.push(GraphNode {
+                    id: id.to_string(),
+                    track_id: Some(track_id.clone()),
+                    title: Some(task.title.clone()),
+                    state: Some(task.state),
+                });
+            }
+        }
     }
+    for (from, to) in &edges {
+        for id in [from, to] {
+            if placed.insert(id.clone()) {
+                graph.nodes.push(GraphNode {
+                    id: id.clone(),
+                    track_id: None,
+                    title: None,
+                    state: None,
+                });
+            }
+        }
+    }
+    graph.edges = edges;
+    graph
+}
+
+fn collect_all<'a>(tasks: &'a [Task], track_id: &str, out: &mut Vec<(String, &'a Task)>) {
+    for task in tasks {
+        out.push((track_id.to_string(), task));
+        collect_all(&task.subtasks, track_id, out);
+    }
+}
 
-    path.push(id.to_string());
-    let deps = task_deps(task)
+fn push_unique(edges: &mut Vec<(String, String)>, edge: (String, String)) {
+    if !edges.contains(&edge) {
+        edges.push(edge);
+    }
+}
+
+/// Whether a task still needs doing, for planning purposes.
+///
+/// Parked is left out: a parked task has been set aside on purpose, and putting
+/// it in a plan would schedule work somebody decided not to do yet. A dep *on* a
+/// parked task is still unresolved, though — see [`is_unresolved`].
+fn is_plannable(task: &Task) -> bool {
+    matches!(
+        task.state,
+        TaskState::Todo | TaskState::Active | TaskState::Blocked
+    )
+}
+
+/// Whether a dependency on this task still holds its dependent back. The same
+/// test `fr ready` applies: anything short of done.
+fn is_unresolved(task: &Task) -> bool {
+    task.state != TaskState::Done
+}
+
+/// One task's place in a [`Plan`].
+#[derive(Debug, Clone)]
+pub struct PlanStep<'a> {
+    /// 1-based. Every task in step `n` depends only on tasks in steps before
+    /// it, so a step's tasks can be worked in any order — or in parallel.
+    pub step: usize,
+    pub track_id: String,
+    pub task: &'a Task,
+    /// Unresolved deps that are themselves in the plan, in declaration order.
+    pub after: Vec<String>,
+    /// Unresolved deps the plan does not contain: a task on a track outside
+    /// the plan's scope, or a parked one. Nothing in the plan will clear these.
+    pub waiting_on: Vec<String>,
+}
+
+/// A topological order of a project's open work.
+#[derive(Debug, Clone, Default)]
+pub struct Plan<'a> {
+    pub steps: Vec<PlanStep<'a>>,
+    /// Open tasks that cannot be ordered: members of a dependency cycle, and
+    /// anything downstream of one. In document order.
+    pub unordered: Vec<String>,
+}
+
+/// Order the open tasks of `track_ids` so every task comes after its deps.
+///
+/// Steps are *levels*, not a single arbitrary sequence: a task's step is one
+/// past the latest step among its in-plan deps. That is what makes the output
+/// useful for deciding what unblocks what — the first step is everything that
+/// can start today, and a task's step says how many rounds of work stand
+/// between it and starting. Within a step, tasks keep their document order
+/// (track order, then position), so the plan reads like the backlog it came
+/// from.
+///
+/// Done and missing deps impose nothing, which matches `fr ready`. Subtasks are
+/// planned alongside top-level tasks, since a `dep:` may name either.
+pub fn plan<'a>(project: &'a Project, track_ids: &[&str]) -> Plan<'a> {
+    let mut nodes: Vec<(String, &'a Task)> = Vec::new();
+    for track_id in track_ids {
+        if let Some((_, track)) = project.tracks.iter().find(|(id, _)| id == track_id) {
+            collect_plannable(track.backlog(), track_id, &mut nodes);
+        }
+    }
+
+    let index: HashMap<&str, usize> = nodes
         .iter()
-        .map(|dep_id| build(project, dep_id, path, expanded))
+        .enumerate()
+        .filter_map(|(i, (_, t))| t.id.as_deref().map(|id| (id, i)))
         .collect();
-    path.pop();
 
-    DepNode {
-        id: id.to_string(),
-        status: DepStatus::Resolved,
-        track_id: Some(track_id.to_string()),
-        title: Some(task.title.clone()),
-        state: Some(task.state),
-        tags: task.tags.clone(),
-        deps,
+    let mut after: Vec<Vec<String>> = Vec::with_capacity(nodes.len());
+    let mut waiting_on: Vec<Vec<String>> = Vec::with_capacity(nodes.len());
+    for (_, task) in &nodes {
+        let mut inside = Vec::new();
+        let mut outside = Vec::new();
+        for dep_id in task_deps(task) {
+            if index.contains_key(dep_id.as_str()) {
+                if !inside.contains(&dep_id) {
+                    inside.push(dep_id);
+                }
+            } else if find_task(project, &dep_id).is_some_and(|(_, t)| is_unresolved(t))
+                && !outside.contains(&dep_id)
+            {
+                outside.push(dep_id);
+            }
+        }
+        after.push(inside);
+        waiting_on.push(outside);
+    }
+
+    // Kahn's algorithm, one level at a time. A node is placed once every in-plan
+    // dep has been; what is never placed sits on or behind a cycle.
+    let mut step: Vec<Option<usize>> = vec![None; nodes.len()];
+    let mut level = 0;
+    loop {
+        level += 1;
+        let ready: Vec<usize> = (0..nodes.len())
+            .filter(|&i| step[i].is_none())
+            .filter(|&i| {
+                after[i]
+                    .iter()
+                    .all(|d| step[index[d.as_str()]].is_some_and(|s| s < level))
+            })
+            .collect();
+        if ready.is_empty() {
+            break;
+        }
+        for i in ready {
+            step[i] = Some(level);
+        }
+    }
+
+    let mut plan = Plan::default();
+    let mut placed: Vec<usize> = (0..nodes.len()).filter(|&i| step[i].is_some()).collect();
+    placed.sort_by_key(|&i| (step[i], i));
+    for i in placed {
+        let (track_id, task) = &nodes[i];
+        plan.steps.push(PlanStep {
+            step: step[i].unwrap_or_default(),
+            track_id: track_id.clone(),
+            task,
+            after: std::mem::take(&mut after[i]),
+            waiting_on: std::mem::take(&mut waiting_on[i]),
+        });
+    }
+    plan.unordered = (0..nodes.len())
+        .filter(|&i| step[i].is_none())
+        .filter_map(|i| nodes[i].1.id.as_ref().map(|id| id.to_string()))
+        .collect();
+    plan
+}
+
+fn collect_plannable<'a>(tasks: &'a [Task], track_id: &str, out: &mut Vec<(String, &'a Task)>) {
+    for task in tasks {
+        if is_plannable(task) && task.id.is_some() {
+            out.push((track_id.to_string(), task));
+        }
+        collect_plannable(&task.subtasks, track_id, out);
+    }
+}
+
+/// The longest chain of unresolved deps ending at `goal_id`, first task first
+/// and the goal last. `None` when no task holds that id.
+///
+/// Length is counted in tasks, or with `weight` naming a number field, in the
+/// sum of that field — an estimate — where a task without one counts as 1. A
+/// done dep ends a chain — it is not work left — and so does a missing one,
+/// which has nothing behind it to follow. A goal that is already done has a
+/// path of itself alone.
+///
+/// Chains are compared on weight, then on length, so an open dep is followed
+/// even when its estimate is 0 or less. Ties go to the dep declared first, so
+/// the answer is stable from one run to the next. An edge that would close a cycle is not followed: the chain is a
+/// path, and `fr deps` is where the cycle itself gets reported.
+pub fn critical_path<'a>(
+    project: &'a Project,
+    goal_id: &str,
+    weight: Option<&str>,
+) -> Option<Vec<(&'a str, &'a Task)>> {
+    let (track_id, goal) = find_task(project, goal_id)?;
+    if !is_unresolved(goal) {
+        return Some(vec![(track_id, goal)]);
+    }
+
+    let mut memo: HashMap<String, Chain<'a>> = HashMap::new();
+    let mut path = vec![goal_id.to_string()];
+    let (_, mut chain) = longest_below(project, goal, weight, &mut path, &mut memo);
+    chain.push((track_id, goal));
+    Some(chain)
+}
+
+/// A task's weight on the critical path: its `weight` field, or 1.
+pub fn task_weight(task: &Task, weight: Option<&str>) -> f64 {
+    weight
+        .and_then(|key| task.field(key))
+        .and_then(|v| v.trim().parse::<f64>().ok())
+        .filter(|w| w.is_finite())
+        .unwrap_or(1.0)
+}
+
+/// A chain and its total weight.
+type Chain<'a> = (f64, Vec<(&'a str, &'a Task)>);
+
+/// The longest unresolved chain *under* `task`, not including it.
+fn longest_below<'a>(
+    project: &'a Project,
+    task: &'a Task,
+    weight: Option<&str>,
+    path: &mut Vec<String>,
+    memo: &mut HashMap<String, Chain<'a>>,
+) -> Chain<'a> {
+    let mut best: Option<Chain<'a>> = None;
+    for dep_id in task_deps(task) {
+        if path.contains(&dep_id) {
+            continue;
+        }
+        let Some((dep_track, dep)) = find_task(project, &dep_id) else {
+            continue;
+        };
+        if !is_unresolved(dep) {
+            continue;
+        }
+        let chain = match memo.get(&dep_id) {
+            Some(chain) => chain.clone(),
+            None => {
+                path.push(dep_id.clone());
+                let (total, mut chain) = longest_below(project, dep, weight, path, memo);
+                path.pop();
+                chain.push((dep_track, dep));
+                let chain = (total + task_weight(dep, weight), chain);
+                memo.insert(dep_id.clone(), chain.clone());
+                chain
+            }
+        };
+        let heavier = |b: &Chain<'a>| (chain.0, chain.1.len()) > (b.0, b.1.len());
+        if best.as_ref().is_none_or(heavier) {
+            best = Some(chain);
+        }
+    }
+    best.unwrap_or_default()
+}
+
+// ---------------------------------------------------------------------------
+// Cross-project deps
+// ---------------------------------------------------------------------------
+
+/// Split a cross-project dep, `lib:LIB-012`, into the registered project's
+/// name and the task id. `None` for a plain id: task ids never hold a colon.
+pub fn external_ref(dep: &str) -> Option<(&str, &str)> {
+    let (project, id) = dep.split_once(':')?;
+    (!project.is_empty() && !id.is_empty()).then_some((project, id))
+}
+
+/// What a cross-project dep points at.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ExternalStatus {
+    Found {
+        track_id: String,
+        title: String,
+        state: TaskState,
+        tags: Vec<String>,
+    },
+    /// The project loaded and holds no such task: a dangling dep like any
+    /// other.
+    Missing,
+    /// The project could not be loaded. Says nothing about the task.
+    Unreachable,
+}
+
+/// Every cross-project dep in a project, resolved against the other projects
+/// in the registry.
+///
+/// Each project named is loaded once, read-only, and only if some dep names
+/// it. **An unreachable project is not a dangling dep.** A teammate who has not
+/// cloned the library, or a registry entry that moved, says nothing about
+/// whether `LIB-012` exists — so the project is reported in
+/// [`ExternalDeps::unreachable`], and its deps hold nothing back, the same
+/// reading `fr ready` gives a local dep it cannot find.
+#[derive(Debug, Default)]
+pub struct ExternalDeps {
+    statuses: HashMap<String, ExternalStatus>,
+    /// Each project a dep names that could not be loaded, with the reason, in
+    /// order of first mention.
+    pub unreachable: Vec<(String, String)>,
+}
+
+impl ExternalDeps {
+    /// Resolve against the global registry. Reads nothing at all when the
+    /// project has no cross-project deps.
+    pub fn load(project: &Project) -> Self {
+        if external_deps_in(project).is_empty() {
+            return Self::default();
+        }
+        Self::load_with(project, &crate::io::registry::read_registry())
+    }
+
+    pub fn load_with(project: &Project, registry: &ProjectRegistry) -> Self {
+        let mut out = Self::default();
+        let mut loaded: HashMap<String, Option<Project>> = HashMap::new();
+        for dep in external_deps_in(project) {
+            let Some((name, id)) = external_ref(&dep) else {
+                continue;
+            };
+            let other = loaded.entry(name.to_string()).or_insert_with(|| {
+                match load_registered(registry, name) {
+                    Ok(other) => Some(other),
+                    Err(reason) => {
+                        out.unreachable.push((name.to_string(), reason));
+                        None
+                    }
+                }
+            });
+            let status = match other {
+                None => ExternalStatus::Unreachable,
+                Some(other) => match find_task(other, id) {
+                    Some((track_id, task)) => ExternalStatus::Found {
+                        track_id: track_id.to_string(),
+                        title: task.title.clone(),
+                        state: task.state,
+                        tags: task.tags.clone(),
+                    },
+                    None => ExternalStatus::Missing,
+                },
+            };
+            out.statuses.insert(dep, status);
+        }
+        out
+    }
+
+    pub fn status(&self, dep: &str) -> Option<&ExternalStatus> {
+        self.statuses.get(dep)
+    }
+
+    /// Whether a cross-project dep still holds its dependent back: found, and
+    /// not done.
+    pub fn is_open(&self, dep: &str) -> bool {
+        matches!(
+            self.status(dep),
+            Some(ExternalStatus::Found { state, .. }) if *state != TaskState::Done
+        )
+    }
+}
+
+/// Every cross-project dep named anywhere in the project, once each, in
+/// document order.
+fn external_deps_in(project: &Project) -> Vec<String> {
+    let mut all = Vec::new();
+    for (track_id, track) in &project.tracks {
+        for node in &track.nodes {
+            if let TrackNode::Section { tasks, .. } = node {
+                collect_all(tasks, track_id, &mut all);
+            }
+        }
+    }
+    let mut out: Vec<String> = Vec::new();
+    for (_, task) in all {
+        for dep in task_deps(task) {
+            if external_ref(&dep).is_some() && !out.contains(&dep) {
+                out.push(dep);
+            }
+        }
+    }
+    out
+}
+
+/// Load the project registered as `name`.
+fn load_registered(registry: &ProjectRegistry, name: &str) -> Result<Project, String> {
+    let entry = crate::io::registry::find_by_name(registry, name)
+        .ok_or_else(|| format!("no project named '{name}' in the registry"))?;
+    // Only its tasks are read, so this reader's user config has no part in it
+    crate::io::project_io::load_project_with(Path::new(&entry.path), None)
+        .map_err(|e| format!("{}: {}", entry.path, e))
+}
+
+/// A state change [`auto_block`] made without being asked to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AutoStateChange {
+    pub track_id: String,
+    pub task_id: String,
+    pub from: TaskState,
+    pub to: TaskState,
+    /// The open deps that blocked the task. Empty when it was unblocked.
+    pub waiting_on: Vec<String>,
+}
+
+/// Bring every task's blocked state in line with its `dep:` lines, returning
+/// each change made. The `[deps] auto_block` setting is the caller's to check.
+///
+/// Todo with an open dependency becomes blocked; blocked with dependencies, all
+/// of them done, becomes todo. Nothing else moves. An active task keeps its
+/// state — somebody has started it, and taking it back is theirs to decide —
+/// and a blocked task with no `dep:` keeps its, since what blocks it is not
+/// written down anywhere frame can read. A dep on a task that does not exist
+/// counts as done, the same reading `fr ready` gives it.
+///
+/// A task with a cross-project dep is not touched either way.
+///
+/// One pass is enough: blocking or unblocking a task changes no other task's
+/// inputs, because both states are equally "not done" to a dependent.
+pub fn auto_block(project: &mut Project) -> Vec<AutoStateChange> {
+    let mut states: HashMap<String, TaskState> = HashMap::new();
+    for (track_id, track) in &project.tracks {
+        let mut all = Vec::new();
+        for node in &track.nodes {
+            if let TrackNode::Section { tasks, .. } = node {
+                collect_all(tasks, track_id, &mut all);
+            }
+        }
+        for (_, task) in all {
+            if let Some(id) = &task.id {
+                states.entry(id.to_string()).or_insert(task.state);
+            }
+        }
+    }
+
+    let mut changes = Vec::new();
+    for (track_id, track) in &mut project.tracks {
+        for node in &mut track.nodes {
+            if let TrackNode::Section { tasks, .. } = node {
+                auto_block_tasks(tasks, track_id, &states, &mut changes);
+            }
+        }
+    }
+    changes
+}
+
+fn auto_block_tasks(
+    tasks: &mut [Task],
+    track_id: &str,
+    states: &HashMap<String, TaskState>,
+    changes: &mut Vec<AutoStateChange>,
+) {
+    for task in tasks {
+        auto_block_tasks(&mut task.subtasks, track_id, states, changes);
+        let deps = task_deps(task);
+        // A cross-project dep would mean loading the other project on every
+        // state change, so a task that has one is left as it is.
+        if deps.is_empty() || deps.iter().any(|d| external_ref(d).is_some()) {
+            continue;
+        }
+        let waiting_on: Vec<String> = deps
+            .into_iter()
+            .filter(|d| states.get(d).is_some_and(|s| *s != TaskState::Done))
+            .collect();
+        let to = match task.state {
+            TaskState::Todo if !waiting_on.is_empty() => TaskState::Blocked,
+            TaskState::Blocked if waiting_on.is_empty() => TaskState::Todo,
+            _ => continue,
+        };
+        let Some(task_id) = task.id.as_ref().map(|id| id.to_string()) else {
+            continue;
+        };
+        changes.push(AutoStateChange {
+            track_id: track_id.to_string(),
+            task_id,
+            from: task.state,
+            to,
+            waiting_on,
+        });
+        task_ops::set_state(task, to);
     }
 }
 
@@ -158,6 +841,12 @@ mod tests {
                 ui: Default::default(),
                 recovery: Default::default(),
                 limits: Default::default(),
+                deps: Default::default(),
+                templates: Default::default(),
+                fields: Default::default(),
+                workflow: Default::default(),
+                board: Default::default(),
+                keys: Default::default(),
             },
             tracks: vec![("main".to_string(), parse_track(track_md))],
             inbox: None,
@@ -241,6 +930,181 @@ mod tests {
         assert_eq!(dep_tree(&fixture(), "M-999").status, DepStatus::Missing);
     }
 
+    #[test]
+    fn a_dependents_tree_walks_the_edges_backwards() {
+        let tree = dependents_tree(&fixture(), "M-004");
+        assert_eq!(tree.status, DepStatus::Resolved);
+        let names: Vec<&str> = tree.deps.iter().map(|n| n.id.as_str()).collect();
+        assert_eq!(names, vec!["M-002", "M-003"]);
+        // Both reach M-001; the second says so rather than expanding it again.
+        assert_eq!(kid(kid(&tree, 0), 0).id, "M-001");
+        assert_eq!(kid(kid(&tree, 0), 0).status, DepStatus::Resolved);
+        assert_eq!(kid(kid(&tree, 1), 0).status, DepStatus::Repeat);
+    }
+
+    #[test]
+    fn a_dependents_tree_stops_a_cycle() {
+        let tree = dependents_tree(&fixture(), "M-005");
+        assert_eq!(kid(&tree, 0).id, "M-006");
+        assert_eq!(kid(kid(&tree, 0), 0).status, DepStatus::Cycle);
+    }
+
+    #[test]
+    fn the_graph_holds_every_edge_once_and_only_tasks_on_one() {
+        let graph = dep_graph(&fixture(), &["main"]);
+        assert!(graph.edges.contains(&("M-004".into(), "M-002".into())));
+        assert!(graph.edges.contains(&("M-999".into(), "M-007".into())));
+        assert_eq!(
+            graph.edges.len(),
+            7,
+            "M-001→2, M-001→3, 2→4, 3→4, 5↔6, 7→999: {:?}",
+            graph.edges
+        );
+        let dangling = graph.nodes.last().unwrap();
+        assert_eq!(dangling.id, "M-999");
+        assert!(dangling.track_id.is_none());
+    }
+
+    fn ids(steps: &[PlanStep]) -> Vec<(usize, String)> {
+        steps
+            .iter()
+            .map(|s| (s.step, s.task.id.as_ref().unwrap().to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn a_plan_puts_every_task_after_its_deps() {
+        let p = project(
+            "# Main\n\n## Backlog\n\n\
+             - [ ] `M-001` Needs both\n  - dep: M-002, M-003\n\
+             - [ ] `M-002` Needs three\n  - dep: M-003\n\
+             - [ ] `M-003` Leaf\n\
+             - [ ] `M-004` Independent\n\
+             - [ ] `M-005` Waits on done\n  - dep: M-009\n\n\
+             ## Done\n\n- [x] `M-009` Finished\n",
+        );
+        let plan = plan(&p, &["main"]);
+        assert_eq!(
+            ids(&plan.steps),
+            vec![
+                (1, "M-003".into()),
+                (1, "M-004".into()),
+                (1, "M-005".into()),
+                (2, "M-002".into()),
+                (3, "M-001".into()),
+            ]
+        );
+        assert!(plan.unordered.is_empty());
+        assert_eq!(plan.steps[4].after, vec!["M-002", "M-003"]);
+    }
+
+    #[test]
+    fn a_cycle_and_what_waits_on_it_are_unordered() {
+        let p = project(
+            "# Main\n\n## Backlog\n\n\
+             - [ ] `M-001` Cycle a\n  - dep: M-002\n\
+             - [ ] `M-002` Cycle b\n  - dep: M-001\n\
+             - [ ] `M-003` Downstream\n  - dep: M-002\n\
+             - [ ] `M-004` Free\n\n## Done\n",
+        );
+        let plan = plan(&p, &["main"]);
+        assert_eq!(ids(&plan.steps), vec![(1, "M-004".into())]);
+        assert_eq!(plan.unordered, vec!["M-001", "M-002", "M-003"]);
+    }
+
+    #[test]
+    fn a_parked_dep_is_waited_on_but_not_planned() {
+        let p = project(
+            "# Main\n\n## Backlog\n\n\
+             - [ ] `M-001` Needs parked\n  - dep: M-002\n\n\
+             ## Parked\n\n- [~] `M-002` Someday\n\n## Done\n",
+        );
+        let plan = plan(&p, &["main"]);
+        assert_eq!(ids(&plan.steps), vec![(1, "M-001".into())]);
+        assert_eq!(plan.steps[0].waiting_on, vec!["M-002"]);
+    }
+
+    #[test]
+    fn the_critical_path_is_the_longest_unresolved_chain() {
+        let p = project(
+            "# Main\n\n## Backlog\n\n\
+             - [ ] `M-001` Goal\n  - dep: M-002, M-003\n\
+             - [ ] `M-002` Short\n\
+             - [ ] `M-003` Long\n  - dep: M-004\n\
+             - [ ] `M-004` Longer\n  - dep: M-005\n\n\
+             ## Done\n\n- [x] `M-005` Finished\n",
+        );
+        let chain: Vec<&str> = critical_path(&p, "M-001", None)
+            .unwrap()
+            .iter()
+            .map(|(_, t)| t.id.as_deref().unwrap())
+            .collect();
+        assert_eq!(chain, vec!["M-004", "M-003", "M-001"]);
+    }
+
+    /// Weighted by an estimate, one big task outweighs two small ones; a task
+    /// with no estimate counts as 1.
+    #[test]
+    fn a_weighted_critical_path_follows_the_estimate() {
+        let mut p = project("");
+        let schema = crate::parse::Schema {
+            fields: vec!["estimate".into()],
+            ..Default::default()
+        };
+        p.tracks[0].1 = crate::parse::parse_track_with(
+            "# Main\n\n## Backlog\n\n\
+             - [ ] `M-001` Goal\n  - dep: M-002, M-003\n\
+             - [ ] `M-002` Big\n  - estimate: 5\n\
+             - [ ] `M-003` Small\n  - dep: M-004\n  - estimate: 1\n\
+             - [ ] `M-004` Unestimated\n\n## Done\n",
+            &schema,
+        );
+        let ids = |weight| -> Vec<String> {
+            critical_path(&p, "M-001", weight)
+                .unwrap()
+                .iter()
+                .map(|(_, t)| t.id.as_ref().unwrap().to_string())
+                .collect()
+        };
+        assert_eq!(ids(None), vec!["M-004", "M-003", "M-001"]);
+        assert_eq!(ids(Some("estimate")), vec!["M-002", "M-001"]);
+    }
+
+    /// An estimate of 0 or less still leaves the dep on the path.
+    #[test]
+    fn a_zero_or_negative_estimate_is_still_followed() {
+        let mut p = project("");
+        let schema = crate::parse::Schema {
+            fields: vec!["estimate".into()],
+            ..Default::default()
+        };
+        p.tracks[0].1 = crate::parse::parse_track_with(
+            "# Main\n\n## Backlog\n\n\
+             - [ ] `M-001` Goal\n  - dep: M-002\n\
+             - [ ] `M-002` Free\n  - dep: M-003\n  - estimate: 0\n\
+             - [ ] `M-003` Refund\n  - estimate: -2\n\n## Done\n",
+            &schema,
+        );
+        let ids: Vec<&str> = critical_path(&p, "M-001", Some("estimate"))
+            .unwrap()
+            .iter()
+            .map(|(_, t)| t.id.as_deref().unwrap())
+            .collect();
+        assert_eq!(ids, vec!["M-003", "M-002", "M-001"]);
+    }
+
+    #[test]
+    fn the_critical_path_does_not_loop_on_a_cycle() {
+        let p = fixture();
+        let chain = critical_path(&p, "M-005", None).unwrap();
+        let chain: Vec<&str> = chain
+            .iter()
+            .map(|(_, t)| t.id.as_deref().unwrap())
+            .collect();
+        assert_eq!(chain, vec!["M-006", "M-005"]);
+        assert!(critical_path(&p, "M-999", None).is_none());
+    }
+
     #[test]
     fn a_self_dependency_is_a_cycle() {
         let p = project("# Main\n\n## Backlog\n\n- [ ] `M-001` Self\n  - dep: M-001\n\n## Done\n");
@@ -248,4 +1112,85 @@ mod tests {
         assert_eq!(tree.status, DepStatus::Resolved);
         assert_eq!(kid(&tree, 0).status, DepStatus::Cycle);
     }
+
+    fn state_of(p: &Project, id: &str) -> TaskState {
+        find_task(p, id).unwrap().1.state
+    }
+
+    #[test]
+    fn auto_block_blocks_on_an_open_dep_and_unblocks_when_it_is_done() {
+        let mut p = project(
+            "# Main\n\n## Backlog\n\n\
+             - [ ] `M-001` Groundwork\n\
+             - [ ] `M-002` Walls\n  - dep: M-001\n\
+             - [-] `M-003` Roof\n  - dep: M-004\n\
+             - [-] `M-005` Waiting on a vendor\n\
+             - [>] `M-006` Started anyway\n  - dep: M-001\n\
+             - [ ] `M-007` Dangling\n  - dep: M-999\n\n\
+             ## Done\n\n- [x] `M-004` Done already\n",
+        );
+        let changes = auto_block(&mut p);
+        let moved: Vec<(&str, TaskState)> =
+            changes.iter().map(|c| (c.task_id.as_str(), c.to)).collect();
+        assert_eq!(
+            moved,
+            vec![("M-002", TaskState::Blocked), ("M-003", TaskState::Todo)]
+        );
+        assert_eq!(changes[0].waiting_on, vec!["M-001"]);
+        assert_eq!(state_of(&p, "M-005"), TaskState::Blocked);
+        assert_eq!(state_of(&p, "M-006"), TaskState::Active);
+        assert_eq!(state_of(&p, "M-007"), TaskState::Todo);
+
+        // Settled: a second pass has nothing to do.
+        assert!(auto_block(&mut p).is_empty());
+    }
+
+    #[test]
+    fn a_qualified_dep_splits_on_the_first_colon() {
+        assert_eq!(external_ref("lib:LIB-012"), Some(("lib", "LIB-012")));
+        assert_eq!(external_ref("LIB-012"), None);
+        assert_eq!(external_ref(":LIB-012"), None);
+        assert_eq!(external_ref("lib:"), None);
+    }
+
+    #[test]
+    fn an_unloadable_project_is_unreachable_not_missing() {
+        let p = project(
+            "# Main\n\n## Backlog\n\n\
+             - [ ] `M-001` Uses the library\n  - dep: lib:LIB-1, gone:G-1\n\
+             - [ ] `M-002` Also\n  - dep: lib:LIB-2\n",
+        );
+        let registry = ProjectRegistry {
+            projects: vec![crate::io::registry::ProjectEntry {
+                name: "lib".into(),
+                path: "/nonexistent/deps-test/lib".into(),
+                last_accessed_tui: None,
+                last_accessed_cli: None,
+                worktree_of: None,
+            }],
+        };
+        let external = ExternalDeps::load_with(&p, &registry);
+        let names: Vec<&str> = external
+            .unreachable
+            .iter()
+            .map(|(n, _)| n.as_str())
+            .collect();
+        // Once per project, however many deps name it.
+        assert_eq!(names, vec!["lib", "gone"]);
+        assert!(
+            external.unreachable[1]
+                .1
+                .contains("no project named 'gone'")
+        );
+        assert_eq!(
+            external.status("lib:LIB-2"),
+            Some(&ExternalStatus::Unreachable)
+        );
+        assert!(!external.is_open("lib:LIB-1"));
+
+        let tree = dep_tree_across(&p, "M-001", &external);
+        let statuses: Vec<DepStatus> = tree.deps.iter().map(|c| c.status).collect();
+        assert_eq!(statuses, vec![DepStatus::Unreachable; 2]);
+        assert_eq!(tree.deps[0].project.as_deref(), Some("lib"));
+    }
 }
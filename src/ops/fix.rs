@@ -41,6 +41,13 @@
 //! - `ActorTokenUnregistered` — already self-heals on the next mint.
 //! - `LostTask` — the recovery system flagged content *for human review*.
 //!   Clearing the tag automatically defeats the purpose.
+//! - `BlockedWithoutCause` — unblocking assumes the block is over, and a
+//!   block that was never written down may be exactly the one still holding.
+//! - `ExternalProjectUnreachable` — the fix is registering the other project
+//!   on this machine, which only its user can point at.
+//! - `InvalidField` — the right value for a field is the author's to say.
+//! - `UndeclaredState` — declaring the state or picking a built-in one is a
+//!   workflow decision.
 //! - `LocalFileCommitted` where git already **tracks** the file — needs
 //!   `git rm --cached`; mutating the git index is outside frame's remit. The
 //!   not-yet-ignored half *is* repaired here.
@@ -48,6 +55,7 @@
 //!   warning names a file still worth inspecting (`doc/architecture.md`).
 //! - `DanglingDep` — removing the dep discards intent; the blocker may be about
 //!   to be created.
+//! - `DanglingRelation` — the same, and the link is all the line records.
 //! - `BrokenRef` / `BrokenSpec` — a path can be legitimately absent on the
 //!   current branch. Deleting refs after a branch switch would be badly wrong.
 //! - `DuplicatedNoteText` — deleting the second copy assumes the copies are
@@ -157,13 +165,8 @@ pub enum Repair {
 }
 
 /// A section's name as it appears in the file, for messages.
-pub fn section_name(kind: crate::model::track::SectionKind) -> &'static str {
-    use crate::model::track::SectionKind;
-    match kind {
-        SectionKind::Backlog => "## Backlog",
-        SectionKind::Parked => "## Parked",
-        SectionKind::Done => "## Done",
-    }
+pub fn section_name(kind: crate::model::track::SectionKind) -> String {
+    format!("## {kind}")
 }
 
 impl Repair {
@@ -1002,6 +1005,32 @@ mod tests {
                 track_id: "t".into(),
                 task_id: "T-2".into(),
             },
+            CheckWarning::BlockedWithoutCause {
+                track_id: "t".into(),
+                task_id: "T-3".into(),
+            },
+            CheckWarning::ExternalProjectUnreachable {
+                project: "lib".into(),
+                reason: "no project named 'lib' in the registry".into(),
+            },
+            CheckWarning::InvalidField {
+                track_id: "t".into(),
+                task_id: "T-4".into(),
+                key: "risk".into(),
+                value: "huge".into(),
+                reason: "not one of: low, high".into(),
+            },
+            CheckWarning::UndeclaredState {
+                track_id: "t".into(),
+                task_id: "T-5".into(),
+                checkbox: '?',
+            },
+            CheckWarning::DanglingRelation {
+                track_id: "t".into(),
+                task_id: "T-6".into(),
+                relation: crate::model::task::RelationKind::Duplicates,
+                target: "T-99".into(),
+            },
             CheckWarning::IdFrontierUnreadable {
                 path: "/x".into(),
                 detail: "bad".into(),
@@ -1173,6 +1202,12 @@ mod tests {
                 ui: UiConfig::default(),
                 recovery: Default::default(),
                 limits: Default::default(),
+                deps: Default::default(),
+                templates: Default::default(),
+                fields: Default::default(),
+                workflow: Default::default(),
+                board: Default::default(),
+                keys: Default::default(),
             },
             tracks: tracks
                 .into_iter()
@@ -53,7 +53,7 @@
 use std::path::Path;
 
 use crate::model::task_id::Token;
-use crate::model::track::Track;
+use crate::model::track::{SectionName, Track};
 use crate::ops::task_ops::{find_max_id_in_tasks, find_max_id_in_track};
 
 /// The project files a mint consults beyond the in-memory track.
@@ -174,11 +174,23 @@ fn archived_tasks_max(path: &Path, prefix_dash: &str, token: Option<&Token>, max
 
 /// Scan `frame/archive/_tracks/<track>.md` — a whole archived track file, so it
 /// parses as a track — raising `max`.
+///
+/// Every `##` heading in it is read as a section. A task under a custom section
+/// is a task whether or not this mint knows the config that declares it, and a
+/// census that read it as text would hand its number straight back out.
 fn archived_track_max(path: &Path, prefix_dash: &str, token: Option<&Token>, max: &mut usize) {
     let Ok(content) = std::fs::read_to_string(path) else {
         return;
     };
-    let track = crate::parse::parse_track(&content);
+    let schema = crate::parse::Schema {
+        sections: content
+            .lines()
+            .filter_map(|line| line.strip_prefix("## "))
+            .filter_map(SectionName::new)
+            .collect(),
+        ..Default::default()
+    };
+    let track = crate::parse::parse_track_with(&content, &schema);
     find_max_id_in_track(&track, prefix_dash, token, max);
 }
 
@@ -231,12 +243,13 @@ mod tests {
         let frame = frame_dir(&tmp);
         fs::write(
             frame.join("archive").join("_tracks").join("demo.md"),
-            "# Demo\n\n## Backlog\n\n- [ ] `DEM-012` task\n\n## Done\n",
+            "# Demo\n\n## Backlog\n\n- [ ] `DEM-012` task\n\n\
+             ## Icebox\n\n- [ ] `DEM-020` under a custom section\n\n## Done\n",
         )
         .unwrap();
 
         let empty = track_with(&[]);
-        assert_eq!(Mint::new(&frame, "demo", "DEM", None).next(&empty), 13);
+        assert_eq!(Mint::new(&frame, "demo", "DEM", None).next(&empty), 21);
     }
 
     #[test]
@@ -53,7 +53,8 @@ use crate::model::task::{Metadata, Task};
 use crate::model::track::{Track, TrackNode};
 use crate::ops::reconcile::{self, Conflict};
 use crate::parse::{
-    parse_archive, parse_inbox, parse_track, serialize_archive, serialize_inbox, serialize_track,
+    Schema, parse_archive, parse_inbox, parse_track_with, serialize_archive, serialize_inbox,
+    serialize_track,
 };
 
 /// Which frame file shape a path holds. The three merge by different rules —
@@ -204,15 +205,22 @@ pub fn kind_for_path(path: &str) -> Option<FileKind> {
 ///
 /// `stamp` dates the `conflict:` markers left on undecided tasks, and ties each
 /// one to its recovery-log entry. Taken as a parameter rather than read from the
-/// clock so the merge stays a pure function of its inputs.
+/// clock so the merge stays a pure function of its inputs. `schema` is what
+/// the project declares, so a custom field merges as the field it is rather
+/// than as a stray line between two tasks, and a custom section's tasks as
+/// tasks rather than as text.
 pub fn merge_track_text(
     base: &str,
     ours: &str,
     theirs: &str,
     stamp: &str,
+    schema: &Schema,
 ) -> (String, MergeReport) {
-    let mut result =
-        reconcile::reconcile_track(&parse_track(base), &parse_track(ours), &parse_track(theirs));
+    let mut result = reconcile::reconcile_track(
+        &parse_track_with(base, schema),
+        &parse_track_with(ours, schema),
+        &parse_track_with(theirs, schema),
+    );
     mark_conflicts(&mut result.track, &result.conflicts, stamp);
     (
         serialize_track(&result.track),
@@ -363,9 +371,10 @@ pub fn merge_text(
     ours: &str,
     theirs: &str,
     stamp: &str,
+    schema: &Schema,
 ) -> (String, MergeReport) {
     match kind {
-        FileKind::Track => merge_track_text(base, ours, theirs, stamp),
+        FileKind::Track => merge_track_text(base, ours, theirs, stamp, schema),
         FileKind::Archive => merge_archive_text(base, ours, theirs, stamp),
         FileKind::Inbox => merge_inbox_text(base, ours, theirs),
     }
@@ -421,6 +430,7 @@ pub fn merge_files(
     ours: &Path,
     theirs: &Path,
     stamp: &str,
+    schema: &Schema,
 ) -> Result<MergeReport, MergeFileError> {
     let base_text = match std::fs::read_to_string(base) {
         Ok(text) => text,
@@ -441,7 +451,7 @@ pub fn merge_files(
         source: e,
     })?;
 
-    let (merged, report) = merge_text(kind, &base_text, &ours_text, &theirs_text, stamp);
+    let (merged, report) = merge_text(kind, &base_text, &ours_text, &theirs_text, stamp, schema);
 
     crate::io::recovery::atomic_write(ours, merged.as_bytes()).map_err(|e| {
         MergeFileError::Write {
@@ -673,7 +683,7 @@ mod tests {
             &[],
         );
 
-        let (merged, report) = merge_track_text(&base, &ours, &theirs, STAMP);
+        let (merged, report) = merge_track_text(&base, &ours, &theirs, STAMP, &Schema::default());
 
         assert!(report.is_clean(), "conflicts: {:?}", report.conflicts);
         assert_eq!(merged.matches("BAC-179").count(), 1, "merged:\n{merged}");
@@ -691,7 +701,7 @@ mod tests {
         let ours = track(&["- [ ] `BAC-1` One", "- [ ] `BAC-2` Two"], &[]);
         let theirs = track(&[], &["- [x] `BAC-1` One"]);
 
-        let (merged, _) = merge_track_text(&base, &ours, &theirs, STAMP);
+        let (merged, _) = merge_track_text(&base, &ours, &theirs, STAMP, &Schema::default());
 
         assert!(merged.contains("## Backlog"));
         assert!(merged.contains("## Parked"));
@@ -705,7 +715,7 @@ mod tests {
         let ours = track(&["- [ ] `BAC-1` Ours"], &[]);
         let theirs = track(&["- [ ] `BAC-2` Theirs"], &[]);
 
-        let (merged, report) = merge_track_text("", &ours, &theirs, STAMP);
+        let (merged, report) = merge_track_text("", &ours, &theirs, STAMP, &Schema::default());
 
         assert!(report.is_clean(), "conflicts: {:?}", report.conflicts);
         assert!(merged.contains("BAC-1"));
@@ -720,7 +730,7 @@ mod tests {
         let ours = track(&["- [ ] `BAC-1` Our title"], &[]);
         let theirs = track(&["- [ ] `BAC-1` Their title"], &[]);
 
-        let (merged, report) = merge_track_text(&base, &ours, &theirs, STAMP);
+        let (merged, report) = merge_track_text(&base, &ours, &theirs, STAMP, &Schema::default());
 
         assert!(!report.is_clean());
         assert_eq!(report.conflicts.len(), 1);
@@ -744,11 +754,11 @@ mod tests {
         let ours = track(&["- [ ] `BAC-1` Our title"], &[]);
         let theirs = track(&["- [ ] `BAC-1` Their title"], &[]);
 
-        let (merged, report) = merge_track_text(&base, &ours, &theirs, STAMP);
+        let (merged, report) = merge_track_text(&base, &ours, &theirs, STAMP, &Schema::default());
         assert!(!report.is_clean());
 
         assert!(!merged.contains("<<<<<<<"));
-        let reparsed = serialize_track(&parse_track(&merged));
+        let reparsed = serialize_track(&crate::parse::parse_track(&merged));
         assert_eq!(reparsed, merged, "merged output is not stable");
     }
 
@@ -797,8 +807,15 @@ mod tests {
         )
         .unwrap();
 
-        let report =
-            merge_files(FileKind::Track, &base_path, &ours_path, &theirs_path, STAMP).unwrap();
+        let report = merge_files(
+            FileKind::Track,
+            &base_path,
+            &ours_path,
+            &theirs_path,
+            STAMP,
+            &Schema::default(),
+        )
+        .unwrap();
 
         assert!(report.is_clean());
         assert_eq!(report.took_theirs, 1);
@@ -828,6 +845,7 @@ mod tests {
             &ours_path,
             &theirs_path,
             STAMP,
+            &Schema::default(),
         )
         .unwrap();
 
@@ -848,6 +866,7 @@ mod tests {
             &ours_path,
             &dir.path().join("theirs.md"),
             STAMP,
+            &Schema::default(),
         );
 
         assert!(matches!(err, Err(MergeFileError::Read { .. })));
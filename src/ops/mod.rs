@@ -1,6 +1,7 @@
 pub mod actor_merge;
 pub mod check;
 pub mod clean;
+pub mod config_edit;
 pub mod deps;
 pub mod fix;
 pub mod git_setup;
@@ -11,6 +12,9 @@ pub mod merge_files;
 pub mod reconcile;
 pub mod recover;
 pub mod refs;
+pub mod relations;
+pub mod resolve;
 pub mod search;
+pub mod stats;
 pub mod task_ops;
 pub mod track_ops;
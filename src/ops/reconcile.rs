@@ -1320,7 +1320,11 @@ fn rebuild(ours: &Track, theirs: &Track, resolved: Vec<(SectionKind, Task)>) ->
     }
 
     // Any section holding resolved tasks must exist before it can be filled.
-    for kind in [SectionKind::Backlog, SectionKind::Parked, SectionKind::Done] {
+    // Custom sections are created in the order theirs has them.
+    let mut kinds = vec![SectionKind::Backlog, SectionKind::Parked, SectionKind::Done];
+    kinds.extend(theirs.section_kinds());
+    kinds.extend(by_section.keys().copied());
+    for kind in kinds {
         if by_section.contains_key(&kind) && track.section_tasks_mut(kind).is_none() {
             // Prefer their header lines when we never had this section.
             track.ensure_section(kind);
@@ -24,6 +24,7 @@
 //! | Interrupted | Inspected | Remaining |
 //! |---|---|---|
 //! | `mv --track` | does the target hold the new ID? | yes → drop the old ID from the source; no → nothing landed |
+//! | `mv --project` | does the other project hold the new ID? | yes → replace the source copy with its `moved-to:` stub |
 //! | `track archive` | is the file still in `tracks/`? | yes → move it to `archive/_tracks/` |
 //! | `track activate` (un-archive) | is the file still in `archive/_tracks/`? | yes → move it back to `tracks/` |
 //! | `track rename --id` | does the config still name the old id? | yes → finish the renames and the config entry |
@@ -49,7 +50,9 @@
 use crate::io::inflight::{self, Marker, MovedTask, Operation};
 use crate::io::project_io;
 use crate::model::project::Project;
+use crate::model::task::MOVED_TO_KEY;
 use crate::model::track::TrackNode;
+use crate::ops::task_ops;
 
 /// What recovery did.
 #[derive(Debug)]
@@ -105,6 +108,19 @@ fn apply(project: &mut Project, marker: &Marker) -> Outcome {
             source_track,
             target_track,
         } => recover_cross_track_move(project, operation, moves, source_track, target_track),
+        Operation::CrossProjectMove {
+            moves,
+            source_track,
+            target_project,
+            target_root,
+            target_track,
+        } => recover_cross_project_move(
+            project,
+            operation,
+            moves,
+            source_track,
+            (target_project, target_root, target_track),
+        ),
         Operation::TrackArchive { track_id, file } => {
             recover_track_archive(project, operation, track_id, file)
         }
@@ -221,6 +237,86 @@ fn remove_task(track: &mut crate::model::track::Track, task_id: &str) -> bool {
     false
 }
 
+// ---------------------------------------------------------------------------
+// Cross-project move
+// ---------------------------------------------------------------------------
+
+/// The destination project is written first, so the remaining step is the
+/// source's: replace the old copy with its `moved-to:` stub, and point what
+/// depended on it at the new qualified id. Verified against the destination
+/// itself, loaded from the root the marker recorded.
+fn recover_cross_project_move(
+    project: &mut Project,
+    operation: String,
+    moves: &[MovedTask],
+    source_track: &str,
+    (target_project, target_root, target_track): (&str, &str, &str),
+) -> Outcome {
+    let Some(root) = moves.first() else {
+        return Outcome::AlreadyComplete { operation };
+    };
+    let target = match project_io::load_project(std::path::Path::new(target_root)) {
+        Ok(target) => target,
+        Err(e) => {
+            return Outcome::Indeterminate {
+                operation,
+                reason: format!("could not load '{target_project}' at {target_root}: {e}"),
+            };
+        }
+    };
+    let target_has_it = target
+        .tracks
+        .iter()
+        .find(|(id, _)| id == target_track)
+        .is_some_and(|(_, t)| task_ops::find_task_in_track(t, &root.new_id).is_some());
+    // Already a stub is a move that finished; no copy at all is one a human
+    // has since tidied. Either way there is nothing left to do.
+    let source_copy = project
+        .tracks
+        .iter()
+        .find(|(id, _)| id == source_track)
+        .and_then(|(_, t)| task_ops::find_task_in_track(t, &root.old_id))
+        .filter(|t| t.field(MOVED_TO_KEY).is_none())
+        .map(|t| (t.title.clone(), t.added_date()));
+    let (true, Some((title, added))) = (target_has_it, source_copy) else {
+        return Outcome::AlreadyComplete { operation };
+    };
+
+    let moved_to = format!("{target_project}:{}", root.new_id);
+    if let Some((_, track)) = project.tracks.iter_mut().find(|(id, _)| id == source_track) {
+        task_ops::tombstone_moved_task(track, &root.old_id, &title, added, &moved_to);
+    }
+    let qualified: Vec<(String, String)> = moves
+        .iter()
+        .map(|m| (m.old_id.clone(), format!("{target_project}:{}", m.new_id)))
+        .collect();
+    task_ops::apply_id_map_to_deps(&mut project.tracks, &qualified);
+
+    let touched: Vec<String> = project
+        .tracks
+        .iter()
+        .filter(|(id, t)| id == source_track || task_ops::track_has_dirty_task(t))
+        .map(|(id, _)| id.clone())
+        .collect();
+    for track_id in &touched {
+        if let Err(e) = save_track(project, track_id) {
+            return Outcome::Indeterminate {
+                operation,
+                reason: format!("could not write '{track_id}': {e}"),
+            };
+        }
+    }
+
+    Outcome::Completed {
+        operation,
+        steps: vec![format!(
+            "replaced {} in '{source_track}' with a pointer to {moved_to} — it is already \
+             in '{target_project}'",
+            root.old_id
+        )],
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Track archive
 // ---------------------------------------------------------------------------
@@ -0,0 +1,260 @@
+//! Typed relations between tasks — `relates:`, `duplicates:`, `supersedes:`
+//! and `epic:` — read from both ends.
+//!
+//! A relation is written once, on the task that makes the claim. The other end
+//! is derived here rather than stored: a second line on the named task would be
+//! a fact kept in two places, and the two would drift the first time a merge or
+//! a hand edit touched only one of them.
+
+use std::collections::HashSet;
+
+use crate::model::Project;
+use crate::model::task::{Metadata, RelationKind, Task, TaskState};
+use crate::model::track::TrackNode;
+
+/// A relation seen from the task it names: `source` carries `kind: <that task>`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Backlink {
+    pub kind: RelationKind,
+    pub source: String,
+}
+
+/// The relations `task` declares, in the order it carries them.
+pub fn relations(task: &Task) -> Vec<(RelationKind, &str)> {
+    task.metadata
+        .iter()
+        .filter_map(|m| match m {
+            Metadata::Relation(kind, ids) => Some(ids.iter().map(move |id| (*kind, id.as_str()))),
+            _ => None,
+        })
+        .flatten()
+        .collect()
+}
+
+/// Every relation in the project's live tracks that names `id`, in track and
+/// file order.
+pub fn backlinks(project: &Project, id: &str) -> Vec<Backlink> {
+    let mut out = Vec::new();
+    for (_, task) in live_tasks(project) {
+        let Some(source) = &task.id else { continue };
+        for (kind, target) in relations(task) {
+            if target == id {
+                out.push(Backlink {
+                    kind,
+                    source: source.to_string(),
+                });
+            }
+        }
+    }
+    out
+}
+
+/// Every task in the project's live tracks, subtasks after their parent, with
+/// the track holding it.
+fn live_tasks(project: &Project) -> Vec<(&str, &Task)> {
+    fn walk<'a>(track_id: &'a str, tasks: &'a [Task], out: &mut Vec<(&'a str, &'a Task)>) {
+        for task in tasks {
+            out.push((track_id, task));
+            walk(track_id, &task.subtasks, out);
+        }
+    }
+    let mut out = Vec::new();
+    for (track_id, track) in &project.tracks {
+        for node in &track.nodes {
+            if let TrackNode::Section { tasks, .. } = node {
+                walk(track_id, tasks, &mut out);
+            }
+        }
+    }
+    out
+}
+
+/// Group `backlinks` by kind, in [`RelationKind::ALL`] order, for the surfaces
+/// that print one line per kind.
+pub fn group_backlinks(backlinks: &[Backlink]) -> Vec<(RelationKind, Vec<&str>)> {
+    RelationKind::ALL
+        .into_iter()
+        .filter_map(|kind| {
+            let sources: Vec<&str> = backlinks
+                .iter()
+                .filter(|b| b.kind == kind)
+                .map(|b| b.source.as_str())
+                .collect();
+            (!sources.is_empty()).then_some((kind, sources))
+        })
+        .collect()
+}
+
+/// An epic: a task other tasks name with `epic:`, wherever they live.
+///
+/// Membership is read off the members, not listed on the epic, so a task joins
+/// or leaves an epic by an edit to its own track file and the epic's file never
+/// changes for it.
+#[derive(Debug)]
+pub struct Epic<'a> {
+    pub track_id: &'a str,
+    pub task: &'a Task,
+    /// Members in track and file order, each with the track holding it.
+    pub members: Vec<(&'a str, &'a Task)>,
+}
+
+/// How far an epic's members have got.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct EpicProgress {
+    pub total: usize,
+    pub done: usize,
+    /// Members marked `[-]`.
+    pub blocked: usize,
+}
+
+impl Epic<'_> {
+    pub fn progress(&self) -> EpicProgress {
+        let mut progress = EpicProgress {
+            total: self.members.len(),
+            ..Default::default()
+        };
+        for (_, task) in &self.members {
+            match task.state {
+                TaskState::Done => progress.done += 1,
+                TaskState::Blocked => progress.blocked += 1,
+                _ => {}
+            }
+        }
+        progress
+    }
+}
+
+/// The epic `id` and its members. `None` when no live task holds `id`; a task
+/// nothing names is an epic with no members.
+pub fn epic<'a>(project: &'a Project, id: &str) -> Option<Epic<'a>> {
+    let tasks = live_tasks(project);
+    let (track_id, task) = tasks
+        .iter()
+        .find(|(_, t)| t.id.as_deref() == Some(id))
+        .copied()?;
+    Some(Epic {
+        track_id,
+        task,
+        members: members_of(&tasks, id),
+    })
+}
+
+/// Every live task at least one `epic:` line names, in track and file order.
+/// An `epic:` naming a task that does not exist is `fr check`'s to report, and
+/// is left out here.
+pub fn epics(project: &Project) -> Vec<Epic<'_>> {
+    let tasks = live_tasks(project);
+    let named: HashSet<&str> = tasks
+        .iter()
+        .flat_map(|(_, t)| relations(t))
+        .filter(|(kind, _)| *kind == RelationKind::Epic)
+        .map(|(_, id)| id)
+        .collect();
+    tasks
+        .iter()
+        .filter_map(|&(track_id, task)| {
+            let id = task.id.as_deref()?;
+            named.contains(id).then(|| Epic {
+                track_id,
+                task,
+                members: members_of(&tasks, id),
+            })
+        })
+        .collect()
+}
+
+fn members_of<'a>(tasks: &[(&'a str, &'a Task)], epic_id: &str) -> Vec<(&'a str, &'a Task)> {
+    tasks
+        .iter()
+        .filter(|(_, t)| {
+            relations(t)
+                .iter()
+                .any(|&(kind, id)| kind == RelationKind::Epic && id == epic_id)
+        })
+        .copied()
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::config::ProjectConfig;
+    use crate::parse::parse_track;
+
+    #[test]
+    fn backlinks_are_found_from_the_named_task() {
+        let track = parse_track(
+            "# T\n\n## Backlog\n\n\
+             - [ ] `T-001` Original\n\
+             - [ ] `T-002` Same thing again\n  - duplicates: T-001\n\
+             - [ ] `T-003` Nearby\n  - relates: T-001, T-002\n\
+             \x20 - [ ] `T-003.1` Part of the big one\n    - epic: T-001\n\n## Done\n",
+        );
+        let config: ProjectConfig = toml::from_str("[project]\nname = \"t\"\n").unwrap();
+        let project = Project {
+            root: "/tmp/t".into(),
+            frame_dir: "/tmp/t/frame".into(),
+            config,
+            tracks: vec![("t".into(), track)],
+            inbox: None,
+        };
+
+        let found = backlinks(&project, "T-001");
+        let grouped = group_backlinks(&found);
+        assert_eq!(
+            grouped,
+            vec![
+                (RelationKind::Relates, vec!["T-003"]),
+                (RelationKind::Duplicates, vec!["T-002"]),
+                (RelationKind::Epic, vec!["T-003.1"]),
+            ]
+        );
+        assert!(backlinks(&project, "T-003").is_empty());
+    }
+
+    #[test]
+    fn an_epic_rolls_up_members_from_every_track() {
+        let infra = parse_track(
+            "# I\n\n## Backlog\n\n\
+             - [ ] `I-001` Ship effects\n\
+             - [-] `I-002` Pipeline\n  - epic: I-001\n\n\
+             ## Done\n\n- [x] `I-003` Schema\n  - epic: I-001\n",
+        );
+        let docs = parse_track(
+            "# D\n\n## Backlog\n\n- [ ] `D-001` Write it up\n  - epic: I-001, D-404\n\n## Done\n",
+        );
+        let config: ProjectConfig = toml::from_str("[project]\nname = \"t\"\n").unwrap();
+        let project = Project {
+            root: "/tmp/t".into(),
+            frame_dir: "/tmp/t/frame".into(),
+            config,
+            tracks: vec![("infra".into(), infra), ("docs".into(), docs)],
+            inbox: None,
+        };
+
+        let found = epic(&project, "I-001").unwrap();
+        let members: Vec<&str> = found
+            .members
+            .iter()
+            .map(|(_, t)| t.id.as_deref().unwrap())
+            .collect();
+        assert_eq!(members, ["I-002", "I-003", "D-001"]);
+        assert_eq!(
+            found.progress(),
+            EpicProgress {
+                total: 3,
+                done: 1,
+                blocked: 1
+            }
+        );
+
+        // The dangling D-404 is not an epic; an unnamed task is not listed
+        let all: Vec<&str> = epics(&project)
+            .iter()
+            .map(|e| e.task.id.as_deref().unwrap())
+            .collect();
+        assert_eq!(all, ["I-001"]);
+        assert!(epic(&project, "D-001").unwrap().members.is_empty());
+        assert!(epic(&project, "D-404").is_none());
+    }
+}
@@ -0,0 +1,361 @@
+//! Turning what someone typed into the task ID they meant.
+//!
+//! Every command that takes a task ID used to need it byte for byte — `EFF-014`,
+//! not `EFF-14`, `eff14` or `14`. The resolver accepts those short forms and a
+//! title fragment, and hands back the canonical ID. It is lookup only: an input
+//! that names nothing comes back unchanged, so the command that receives it
+//! reports the miss exactly as it always has (archived tasks included).
+//!
+//! ID-shaped input — digits, optionally after a prefix and a token — is only
+//! ever matched as an ID, and anything else only as a title. A typo'd ID is
+//! then a clean "not found" rather than whichever task happens to mention it.
+
+use crate::model::Project;
+use crate::model::task::{Task, TaskState};
+use crate::model::track::TrackNode;
+
+/// A task the input could mean, as the ambiguity error lists it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Candidate {
+    pub id: String,
+    pub track_id: String,
+    pub title: String,
+    pub state: TaskState,
+}
+
+/// How many candidates the ambiguity error prints before summarising the rest.
+const MAX_LISTED: usize = 8;
+
+#[derive(Debug, thiserror::Error)]
+pub enum ResolveError {
+    #[error("'{input}' matches {} tasks:\n{}", candidates.len(), list_candidates(candidates))]
+    Ambiguous {
+        input: String,
+        candidates: Vec<Candidate>,
+    },
+}
+
+fn list_candidates(candidates: &[Candidate]) -> String {
+    let mut lines: Vec<String> = candidates
+        .iter()
+        .take(MAX_LISTED)
+        .map(|c| {
+            format!(
+                "  [{}] {} {} ({})",
+                c.state.checkbox_char(),
+                c.id,
+                c.title,
+                c.track_id
+            )
+        })
+        .collect();
+    if candidates.len() > MAX_LISTED {
+        lines.push(format!("  … and {} more", candidates.len() - MAX_LISTED));
+    }
+    lines.join("\n")
+}
+
+/// The canonical ID `input` refers to among the project's live tasks.
+///
+/// In order: an exact ID; an ID written loosely — any case, with or without
+/// the dash, without zero padding (`eff14`, `EFF-14.2`); a bare number (`14`),
+/// looked up in `track` (the track the command names, if any) first, then in
+/// the cc-focus track, then in every track; and a title fragment,
+/// case-insensitively. More than one match is an error listing them,
+/// best first — except that an exact title beats a fragment of a longer one.
+///
+/// A cross-project ID (`lib:LIB-012`) and anything that matches nothing are
+/// returned as given.
+pub fn resolve_task_id(
+    project: &Project,
+    input: &str,
+    track: Option<&str>,
+) -> Result<String, ResolveError> {
+    let tasks = live_tasks(project);
+    if input.contains(':') || tasks.iter().any(|(_, t)| t.id.as_deref() == Some(input)) {
+        return Ok(input.to_string());
+    }
+
+    let matches = match parse_loose(input) {
+        Some(loose) => id_matches(project, &tasks, &loose, track),
+        None => title_matches(&tasks, input),
+    };
+    match matches.as_slice() {
+        [] => Ok(input.to_string()),
+        [only] => Ok(only.id.clone()),
+        _ => Err(ResolveError::Ambiguous {
+            input: input.to_string(),
+            candidates: matches,
+        }),
+    }
+}
+
+/// Every task in the project's live tracks, subtasks after their parent.
+fn live_tasks(project: &Project) -> Vec<(&str, &Task)> {
+    fn walk<'a>(track_id: &'a str, tasks: &'a [Task], out: &mut Vec<(&'a str, &'a Task)>) {
+        for task in tasks {
+            out.push((track_id, task));
+            walk(track_id, &task.subtasks, out);
+        }
+    }
+    let mut out = Vec::new();
+    for (track_id, track) in &project.tracks {
+        for node in &track.nodes {
+            if let TrackNode::Section { tasks, .. } = node {
+                walk(track_id, tasks, &mut out);
+            }
+        }
+    }
+    out
+}
+
+fn candidate(track_id: &str, task: &Task) -> Option<Candidate> {
+    Some(Candidate {
+        id: task.id.as_deref()?.to_string(),
+        track_id: track_id.to_string(),
+        title: task.title.clone(),
+        state: task.state,
+    })
+}
+
+/// An ID as typed: the letters before the number, if any, and its segments as
+/// (token, number) pairs. Case and zero padding are gone.
+#[derive(Debug, PartialEq, Eq)]
+struct LooseId {
+    /// Everything before the first digit of the first segment, lowercased and
+    /// without a trailing dash. Whether that is a prefix, a token or both is
+    /// decided against the project's IDs, not here.
+    head: String,
+    /// The first segment's number, then every later segment whole.
+    first: u32,
+    rest: Vec<(String, u32)>,
+}
+
+/// `eff14`, `EFF-014`, `14`, `eff-a14.2` → [`LooseId`]. `None` for anything
+/// that is not digits with at most letters and a dash in front.
+fn parse_loose(input: &str) -> Option<LooseId> {
+    let s = input.trim().to_ascii_lowercase();
+    let mut parts = s.split('.');
+    let first = parts.next()?;
+    let digits_at = first.find(|c: char| c.is_ascii_digit())?;
+    let (head, number) = first.split_at(digits_at);
+    // The dash is optional wherever it goes: `eff-a14`, `effa14`, `eff-14`
+    let head = head.replacen('-', "", 1);
+    if !head.chars().all(|c| c.is_ascii_alphanumeric())
+        || !number.chars().all(|c| c.is_ascii_digit())
+    {
+        return None;
+    }
+    let rest = parts
+        .map(|seg| {
+            let at = seg.find(|c: char| c.is_ascii_digit())?;
+            let (token, number) = seg.split_at(at);
+            token
+                .chars()
+                .all(|c| c.is_ascii_lowercase())
+                .then_some(())?;
+            Some((token.to_string(), number.parse().ok()?))
+        })
+        .collect::<Option<Vec<_>>>()?;
+    Some(LooseId {
+        head,
+        first: number.parse().ok()?,
+        rest,
+    })
+}
+
+/// Whether `id` is `loose` written properly. The head must be the prefix, the
+/// first segment's token, or the two run together (`effa14` for `EFF-a14`).
+fn loose_matches(loose: &LooseId, id: &crate::model::TaskId, bare: bool) -> bool {
+    let Some((prefix, segments)) = id.segments() else {
+        return false;
+    };
+    let Some((first, later)) = segments.split_first() else {
+        return false;
+    };
+    if first.number != loose.first || later.len() != loose.rest.len() {
+        return false;
+    }
+    let token = first.token.as_ref().map_or("", |t| t.as_str());
+    let head_ok = if bare {
+        loose.head == token
+    } else {
+        let prefix = prefix.to_ascii_lowercase();
+        loose.head == format!("{prefix}{token}")
+    };
+    head_ok
+        && later.iter().zip(&loose.rest).all(|(seg, (t, n))| {
+            seg.number == *n && seg.token.as_ref().map_or("", |t| t.as_str()) == t
+        })
+}
+
+fn id_matches(
+    project: &Project,
+    tasks: &[(&str, &Task)],
+    loose: &LooseId,
+    track: Option<&str>,
+) -> Vec<Candidate> {
+    let matching = |bare: bool| -> Vec<(&str, &Task)> {
+        tasks
+            .iter()
+            .filter(|(_, t)| {
+                t.id.as_ref()
+                    .is_some_and(|id| loose_matches(loose, id, bare))
+            })
+            .copied()
+            .collect()
+    };
+    let with_prefix = matching(false);
+    if !with_prefix.is_empty() {
+        return with_prefix
+            .into_iter()
+            .filter_map(|(tr, t)| candidate(tr, t))
+            .collect();
+    }
+
+    // No prefix: the head, if any, is a token. The track the command names has
+    // the first claim on a bare number, then the cc-focus track, which is the
+    // track a session works in.
+    let bare = matching(true);
+    let focus = project.config.agent.cc_focus.as_deref();
+    let chosen: Vec<&(&str, &Task)> = [track, focus]
+        .into_iter()
+        .flatten()
+        .map(|scope| {
+            bare.iter()
+                .filter(|(tr, _)| *tr == scope)
+                .collect::<Vec<_>>()
+        })
+        .find(|in_scope| !in_scope.is_empty())
+        .unwrap_or_else(|| bare.iter().collect());
+    chosen
+        .into_iter()
+        .filter_map(|(tr, t)| candidate(tr, t))
+        .collect()
+}
+
+/// How well a title matches a fragment; lower is better.
+fn title_rank(title: &str, fragment: &str) -> Option<u8> {
+    let title = title.to_lowercase();
+    let at = title.find(fragment)?;
+    let word_start = at == 0
+        || !title[..at]
+            .chars()
+            .next_back()
+            .is_some_and(char::is_alphanumeric);
+    Some(match (title == fragment, at == 0, word_start) {
+        (true, _, _) => 0,
+        (_, true, _) => 1,
+        (_, _, true) => 2,
+        _ => 3,
+    })
+}
+
+fn title_matches(tasks: &[(&str, &Task)], input: &str) -> Vec<Candidate> {
+    let fragment = input.trim().to_lowercase();
+    if fragment.is_empty() {
+        return Vec::new();
+    }
+    let mut ranked: Vec<(u8, bool, Candidate)> = tasks
+        .iter()
+        .filter_map(|(tr, t)| {
+            let rank = title_rank(&t.title, &fragment)?;
+            Some((rank, t.state == TaskState::Done, candidate(tr, t)?))
+        })
+        .collect();
+    // Stable, so tasks that rank alike stay in track and file order
+    ranked.sort_by_key(|(rank, done, _)| (*rank, *done));
+    let exact = ranked.iter().filter(|(rank, ..)| *rank == 0).count();
+    if exact == 1 {
+        ranked.truncate(1);
+    }
+    ranked.into_iter().map(|(.., c)| c).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::config::ProjectConfig;
+    use crate::parse::parse_track;
+
+    fn project(focus: Option<&str>) -> Project {
+        let effects = parse_track(
+            "# E\n\n## Backlog\n\n\
+             - [ ] `EFF-014` Row unification\n\
+             \x20 - [ ] `EFF-014.2` Parser support\n\
+             - [ ] `EFF-a3` Effect inference\n\
+             - [ ] `EFF-020` Parser\n\n## Done\n",
+        );
+        let docs = parse_track(
+            "# D\n\n## Backlog\n\n- [ ] `DOC-014` Write up row unification\n\n## Done\n",
+        );
+        let mut config: ProjectConfig = toml::from_str("[project]\nname = \"t\"\n").unwrap();
+        config.agent.cc_focus = focus.map(str::to_string);
+        Project {
+            root: "/tmp/t".into(),
+            frame_dir: "/tmp/t/frame".into(),
+            config,
+            tracks: vec![("effects".into(), effects), ("docs".into(), docs)],
+            inbox: None,
+        }
+    }
+
+    fn resolve(p: &Project, input: &str) -> Result<String, Vec<String>> {
+        resolve_in(p, input, None)
+    }
+
+    fn resolve_in(p: &Project, input: &str, track: Option<&str>) -> Result<String, Vec<String>> {
+        resolve_task_id(p, input, track).map_err(
+            |ResolveError::Ambiguous { candidates, .. }| {
+                candidates.into_iter().map(|c| c.id).collect()
+            },
+        )
+    }
+
+    #[test]
+    fn loose_ids_resolve_to_the_written_form() {
+        let p = project(None);
+        for input in ["EFF-014", "EFF-14", "eff14", "eff-014", " Eff14 "] {
+            assert_eq!(resolve(&p, input).unwrap(), "EFF-014", "{input}");
+        }
+        assert_eq!(resolve(&p, "eff14.2").unwrap(), "EFF-014.2");
+        assert_eq!(resolve(&p, "effa3").unwrap(), "EFF-a3");
+        assert_eq!(resolve(&p, "EFF-A3").unwrap(), "EFF-a3");
+        assert_eq!(resolve(&p, "a3").unwrap(), "EFF-a3");
+        // Nothing by that number: handed back for the caller's not-found error
+        assert_eq!(resolve(&p, "EFF-99").unwrap(), "EFF-99");
+        assert_eq!(resolve(&p, "lib:LIB-1").unwrap(), "lib:LIB-1");
+    }
+
+    #[test]
+    fn a_bare_number_prefers_the_focus_track() {
+        assert_eq!(
+            resolve(&project(None), "14").unwrap_err(),
+            ["EFF-014", "DOC-014"]
+        );
+        assert_eq!(resolve(&project(Some("docs")), "14").unwrap(), "DOC-014");
+        assert_eq!(resolve(&project(Some("docs")), "20").unwrap(), "EFF-020");
+    }
+
+    #[test]
+    fn a_bare_number_prefers_the_track_the_command_names() {
+        let p = project(Some("docs"));
+        assert_eq!(resolve_in(&p, "14", Some("effects")).unwrap(), "EFF-014");
+        // Nothing by that number there: the focus track, then everywhere
+        assert_eq!(resolve_in(&p, "14", Some("nope")).unwrap(), "DOC-014");
+        assert_eq!(resolve_in(&p, "20", Some("docs")).unwrap(), "EFF-020");
+    }
+
+    #[test]
+    fn a_title_fragment_resolves_when_it_names_one_task() {
+        let p = project(None);
+        assert_eq!(resolve(&p, "inference").unwrap(), "EFF-a3");
+        // An exact title wins over longer titles containing it
+        assert_eq!(resolve(&p, "parser").unwrap(), "EFF-020");
+        assert_eq!(resolve(&p, "row unification").unwrap(), "EFF-014");
+        // Otherwise ambiguity lists the best matches first
+        assert_eq!(resolve(&p, "unif").unwrap_err(), ["EFF-014", "DOC-014"]);
+        assert_eq!(resolve(&p, "pars").unwrap_err(), ["EFF-014.2", "EFF-020"]);
+        assert_eq!(resolve(&p, "no such thing").unwrap(), "no such thing");
+    }
+}
@@ -17,6 +17,8 @@ pub enum MatchField {
     Dep,
     Ref,
     Spec,
+    /// A custom `key: value` field's value
+    Field,
     /// Inbox body text
     Body,
 }
@@ -33,6 +35,7 @@ impl MatchField {
             MatchField::Dep => "dep",
             MatchField::Ref => "ref",
             MatchField::Spec => "spec",
+            MatchField::Field => "field",
             MatchField::Body => "body",
         }
     }
@@ -199,6 +202,17 @@ fn search_task(re: &Regex, task: &Task, track_id: &str, hits: &mut Vec<SearchHit
                     }
                 }
             }
+            Metadata::Custom { value, .. } => {
+                let spans = find_matches(re, value);
+                if !spans.is_empty() {
+                    hits.push(SearchHit {
+                        track_id: track_id.to_string(),
+                        task_id: task_id.to_string(),
+                        field: MatchField::Field,
+                        spans,
+                    });
+                }
+            }
             _ => {} // Added, Resolved not searched
         }
     }
@@ -376,6 +390,12 @@ mod tests {
             ui: UiConfig::default(),
             recovery: Default::default(),
             limits: Default::default(),
+            deps: Default::default(),
+            templates: Default::default(),
+            fields: Default::default(),
+            workflow: Default::default(),
+            board: Default::default(),
+            keys: Default::default(),
         }
     }
 
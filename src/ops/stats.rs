@@ -0,0 +1,236 @@
+//! Project activity over time, for the TUI's Stats view: when tasks were
+//! added and resolved, how far along each track is, and which tags the open
+//! work carries.
+//!
+//! Dates come from the `added:` and `resolved:` lines, so a task counts on
+//! the day it was written as done, not the day its file last changed. The
+//! archives count too — `fr clean` moving done tasks out of a track does not
+//! unmake the work — and so do subtasks, each on its own date.
+
+use std::collections::{BTreeMap, HashMap};
+
+use chrono::{Datelike, Duration, NaiveDate};
+
+use crate::io::project_io::ArchivedTasks;
+use crate::model::Project;
+use crate::model::task::{Metadata, Task, TaskState};
+use crate::model::track::TrackNode;
+
+/// How many tags [`Activity::top_tags`] keeps.
+const TOP_TAGS: usize = 8;
+
+/// What the project did, day by day.
+#[derive(Debug, Clone, Default)]
+pub struct Activity {
+    /// Tasks resolved on each day.
+    pub resolved: BTreeMap<NaiveDate, usize>,
+    /// Tasks added on each day.
+    pub added: BTreeMap<NaiveDate, usize>,
+    /// Each live track, in the order the project lists them.
+    pub tracks: Vec<TrackProgress>,
+    /// The tags open tasks carry most, most first, ties by name.
+    pub top_tags: Vec<(String, usize)>,
+}
+
+/// A track's top-level tasks, and how many of them are done.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TrackProgress {
+    pub track_id: String,
+    pub done: usize,
+    pub total: usize,
+}
+
+impl TrackProgress {
+    /// The done share, 0.0 for an empty track.
+    pub fn ratio(&self) -> f64 {
+        if self.total == 0 {
+            0.0
+        } else {
+            self.done as f64 / self.total as f64
+        }
+    }
+}
+
+/// Gather the project's activity from its live tracks and `archived`.
+pub fn activity(project: &Project, archived: &[ArchivedTasks]) -> Activity {
+    let mut out = Activity::default();
+    let mut tags: HashMap<&str, usize> = HashMap::new();
+
+    for (track_id, track) in &project.tracks {
+        let mut progress = TrackProgress {
+            track_id: track_id.clone(),
+            done: 0,
+            total: 0,
+        };
+        for node in &track.nodes {
+            if let TrackNode::Section { tasks, .. } = node {
+                for task in tasks {
+                    progress.total += 1;
+                    if task.state == TaskState::Done {
+                        progress.done += 1;
+                    }
+                }
+                walk(tasks, &mut |task| {
+                    count_dates(&mut out, task);
+                    if task.state != TaskState::Done {
+                        for tag in &task.tags {
+                            *tags.entry(tag).or_default() += 1;
+                        }
+                    }
+                });
+            }
+        }
+        out.tracks.push(progress);
+    }
+    for list in archived {
+        walk(&list.tasks, &mut |task| count_dates(&mut out, task));
+    }
+
+    let mut top: Vec<(String, usize)> = tags
+        .into_iter()
+        .map(|(tag, n)| (tag.to_string(), n))
+        .collect();
+    top.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+    top.truncate(TOP_TAGS);
+    out.top_tags = top;
+    out
+}
+
+impl Activity {
+    /// Tasks added and resolved in each of the `weeks` weeks up to and
+    /// including the one holding `today`, oldest first. Weeks start on Monday.
+    pub fn weekly(&self, today: NaiveDate, weeks: usize) -> Vec<(usize, usize)> {
+        let this_week = week_start(today);
+        (0..weeks)
+            .rev()
+            .map(|back| {
+                let start = this_week - Duration::weeks(back as i64);
+                let end = start + Duration::weeks(1);
+                let sum = |days: &BTreeMap<NaiveDate, usize>| -> usize {
+                    days.range(start..end).map(|(_, n)| n).sum()
+                };
+                (sum(&self.added), sum(&self.resolved))
+            })
+            .collect()
+    }
+}
+
+/// The Monday of `date`'s week.
+pub fn week_start(date: NaiveDate) -> NaiveDate {
+    date - Duration::days(i64::from(date.weekday().num_days_from_monday()))
+}
+
+fn walk<'a>(tasks: &'a [Task], f: &mut impl FnMut(&'a Task)) {
+    for task in tasks {
+        f(task);
+        walk(&task.subtasks, f);
+    }
+}
+
+fn count_dates(out: &mut Activity, task: &Task) {
+    for m in &task.metadata {
+        let (days, date) = match m {
+            Metadata::Added(date) => (&mut out.added, date),
+            Metadata::Resolved(date) => (&mut out.resolved, date),
+            _ => continue,
+        };
+        if let Some(day) = parse_day(date) {
+            *days.entry(day).or_default() += 1;
+        }
+    }
+}
+
+/// The date at the start of a `YYYY-MM-DD` value, ignoring anything after it.
+fn parse_day(value: &str) -> Option<NaiveDate> {
+    NaiveDate::parse_from_str(value.get(..10)?, "%Y-%m-%d").ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::{ProjectConfig, ProjectInfo};
+    use crate::parse::{parse_archive, parse_track};
+    use std::path::PathBuf;
+
+    const TRACK: &str = "\
+# Main
+
+## Backlog
+
+- [ ] `M-001` Open #infra #api
+  - added: 2025-05-05
+  - [x] `M-001.1` Done subtask
+    - resolved: 2025-05-07
+- [>] `M-002` Active #infra
+  - added: 2025-05-12
+
+## Done
+
+- [x] `M-003` Finished #infra
+  - added: 2025-05-05
+  - resolved: 2025-05-12
+";
+
+    fn day(s: &str) -> NaiveDate {
+        parse_day(s).unwrap()
+    }
+
+    fn project() -> Project {
+        Project {
+            root: PathBuf::from("/tmp/stats-test"),
+            frame_dir: PathBuf::from("/tmp/stats-test/frame"),
+            config: ProjectConfig {
+                project: ProjectInfo {
+                    name: "Test".into(),
+                },
+                agent: Default::default(),
+                tracks: vec![],
+                clean: Default::default(),
+                ids: Default::default(),
+                ui: Default::default(),
+                recovery: Default::default(),
+                limits: Default::default(),
+                deps: Default::default(),
+                templates: Default::default(),
+                fields: Default::default(),
+                workflow: Default::default(),
+                board: Default::default(),
+                keys: Default::default(),
+            },
+            tracks: vec![("main".to_string(), parse_track(TRACK))],
+            inbox: None,
+        }
+    }
+
+    #[test]
+    fn dates_come_from_tracks_subtasks_and_archives() {
+        let archived = vec![ArchivedTasks {
+            track_id: "main".into(),
+            file: "archive/main.md".into(),
+            tasks: parse_archive("- [x] `M-000` Old\n  - resolved: 2025-05-07\n").tasks,
+        }];
+        let a = activity(&project(), &archived);
+
+        assert_eq!(a.resolved.get(&day("2025-05-07")), Some(&2));
+        assert_eq!(a.resolved.get(&day("2025-05-12")), Some(&1));
+        assert_eq!(a.added.get(&day("2025-05-05")), Some(&2));
+        assert_eq!(
+            a.tracks,
+            [TrackProgress {
+                track_id: "main".into(),
+                done: 1,
+                total: 3,
+            }]
+        );
+        // Done tasks' tags don't count
+        assert_eq!(a.top_tags, [("infra".into(), 2), ("api".into(), 1)]);
+    }
+
+    #[test]
+    fn weeks_run_monday_to_sunday_oldest_first() {
+        let a = activity(&project(), &[]);
+        // 2025-05-14 is a Wednesday; its week starts on the 12th
+        assert_eq!(week_start(day("2025-05-14")), day("2025-05-12"));
+        assert_eq!(a.weekly(day("2025-05-14"), 3), [(0, 0), (2, 1), (1, 1)]);
+    }
+}
@@ -2,7 +2,8 @@ use std::collections::HashMap;
 
 use chrono::Local;
 
-use crate::model::task::{Metadata, Task, TaskState};
+use crate::model::config::{TaskTemplate, WorkflowConfig};
+use crate::model::task::{MOVED_TO_KEY, Metadata, RelationKind, Task, TaskState};
 use crate::model::task_id::{TaskId, Token};
 use crate::model::track::{SectionKind, Track, TrackNode};
 use crate::ops::ids::Mint;
@@ -22,6 +23,8 @@ pub enum TaskError {
     InvalidPosition(String),
     #[error("reparenting would create a cycle")]
     CycleDetected,
+    #[error("{0} cannot be related to itself")]
+    SelfRelation(String),
     #[error("task is already top-level")]
     AlreadyTopLevel,
     #[error("reparenting would exceed maximum nesting depth (3)")]
@@ -72,16 +75,21 @@ pub struct ReparentResult {
 // 2.1 — State transitions
 // ---------------------------------------------------------------------------
 
-/// Cycle state: todo → active → done → todo
-pub fn cycle_state(task: &mut Task) {
-    let new_state = match task.state {
-        TaskState::Todo => TaskState::Active,
-        TaskState::Active => TaskState::Done,
-        TaskState::Done => TaskState::Todo,
-        // Blocked/Parked cycle back to todo
-        TaskState::Blocked => TaskState::Todo,
-        TaskState::Parked => TaskState::Todo,
-    };
+/// Cycle state: todo → active → each `[workflow.states]` state → done → todo
+pub fn cycle_state(task: &mut Task, workflow: &WorkflowConfig) {
+    let mut cycle = vec![TaskState::Todo, TaskState::Active];
+    cycle.extend(
+        workflow
+            .states
+            .values()
+            .map(|s| TaskState::Custom(s.checkbox)),
+    );
+    cycle.push(TaskState::Done);
+    // Blocked, parked and undeclared states cycle back to todo
+    let new_state = cycle
+        .iter()
+        .position(|&s| s == task.state)
+        .map_or(TaskState::Todo, |i| cycle[(i + 1) % cycle.len()]);
     set_state(task, new_state);
 }
 
@@ -229,6 +237,40 @@ pub fn add_subtask_after(
     Ok(sub_id.to_string())
 }
 
+/// Fill in a task just created by [`add_task`] from a `[templates.<name>]`
+/// entry: tags and refs merged in, the note written, and each subtask added
+/// through [`add_subtask`], so it is numbered and dated like any other.
+/// Returns the subtask ids.
+///
+/// The note goes through [`set_note`] and so answers to the same limit as one
+/// written by hand.
+pub fn apply_template(
+    track: &mut Track,
+    task_id: &str,
+    template: &TaskTemplate,
+    token: Option<&Token>,
+    limits: NoteLimits,
+) -> Result<Vec<String>, TaskError> {
+    // The note first: it is the one part that can be refused, and refusing it
+    // before anything else is written leaves the task as it was.
+    if let Some(ref note) = template.note
+        && !note.trim().is_empty()
+    {
+        set_note(track, task_id, note.trim_end().to_string(), limits)?;
+    }
+    for tag in &template.tags {
+        add_tag(track, task_id, tag)?;
+    }
+    if !template.refs.is_empty() {
+        add_paths(track, task_id, PathField::Ref, &template.refs)?;
+    }
+    template
+        .subtasks
+        .iter()
+        .map(|title| add_subtask(track, task_id, title.clone(), token))
+        .collect()
+}
+
 /// Edit a task's title.
 pub fn edit_title(track: &mut Track, task_id: &str, new_title: String) -> Result<(), TaskError> {
     let task = find_task_mut_in_track(track, task_id)
@@ -296,7 +338,39 @@ pub fn remove_tag(track: &mut Track, task_id: &str, tag: &str) -> Result<(), Tas
     Ok(())
 }
 
-/// Add a dependency. `dep_id` is validated to exist somewhere in the provided tracks.
+/// Set custom field `key` to `value`, or drop it with `None`. Returns whether
+/// the task changed. The field keeps its place when it is already there.
+///
+/// No schema here: the caller has the config, and decides what a valid value
+/// is before it gets this far.
+pub fn set_field(
+    track: &mut Track,
+    task_id: &str,
+    key: &str,
+    value: Option<&str>,
+) -> Result<bool, TaskError> {
+    let task = find_task_mut_in_track(track, task_id)
+        .ok_or_else(|| TaskError::NotFound(task_id.to_string()))?;
+    if task.field(key) == value {
+        return Ok(false);
+    }
+    match value {
+        Some(value) => set_metadata(
+            task,
+            Metadata::Custom {
+                key: key.to_string(),
+                value: value.to_string(),
+            },
+        ),
+        None => remove_metadata(task, key),
+    }
+    task.mark_dirty();
+    Ok(true)
+}
+
+/// Add a dependency. `dep_id` is validated to exist somewhere in the provided
+/// tracks, unless it names another project (`lib:LIB-012`) — that one is the
+/// caller's to check, against the registry.
 pub fn add_dep(
     track: &mut Track,
     task_id: &str,
@@ -304,7 +378,9 @@ pub fn add_dep(
     all_tracks: &[(String, Track)],
 ) -> Result<(), TaskError> {
     // Validate the dep target exists
-    if !task_id_exists_in_tracks(dep_id, all_tracks) {
+    if crate::ops::deps::external_ref(dep_id).is_none()
+        && !task_id_exists_in_tracks(dep_id, all_tracks)
+    {
         return Err(TaskError::NotFound(format!("dep target {}", dep_id)));
     }
 
@@ -348,6 +424,67 @@ pub fn remove_dep(track: &mut Track, task_id: &str, dep_id: &str) -> Result<(),
     Ok(())
 }
 
+/// Add a relation from `task_id` to `target_id`.
+///
+/// Whether the target exists is the caller's to check: unlike a dep target, an
+/// archived task is a fine one, and the archives are not in `track`.
+pub fn add_relation(
+    track: &mut Track,
+    task_id: &str,
+    kind: RelationKind,
+    target_id: &str,
+) -> Result<(), TaskError> {
+    let task = find_task_mut_in_track(track, task_id)
+        .ok_or_else(|| TaskError::NotFound(task_id.to_string()))?;
+
+    if task.id.as_deref() == Some(target_id) {
+        return Err(TaskError::SelfRelation(task_id.to_string()));
+    }
+    let existing = task
+        .metadata
+        .iter_mut()
+        .find(|m| matches!(m, Metadata::Relation(k, _) if *k == kind));
+    if let Some(Metadata::Relation(_, ids)) = existing {
+        if !ids.iter().any(|id| id == target_id) {
+            ids.push(target_id.to_string());
+            task.mark_dirty();
+        }
+    } else {
+        task.metadata
+            .push(Metadata::Relation(kind, vec![target_id.to_string()]));
+        task.mark_dirty();
+    }
+    Ok(())
+}
+
+pub fn remove_relation(
+    track: &mut Track,
+    task_id: &str,
+    kind: RelationKind,
+    target_id: &str,
+) -> Result<(), TaskError> {
+    let task = find_task_mut_in_track(track, task_id)
+        .ok_or_else(|| TaskError::NotFound(task_id.to_string()))?;
+
+    let mut changed = false;
+    for m in &mut task.metadata {
+        if let Metadata::Relation(k, ids) = m
+            && *k == kind
+        {
+            let before = ids.len();
+            ids.retain(|id| id != target_id);
+            changed |= ids.len() != before;
+        }
+    }
+    task.metadata
+        .retain(|m| !matches!(m, Metadata::Relation(_, ids) if ids.is_empty()));
+
+    if changed {
+        task.mark_dirty();
+    }
+    Ok(())
+}
+
 /// The two things `[limits]` says about a note write, travelling together so
 /// the ops layer keeps taking one argument as more of them arrive.
 ///
@@ -794,7 +931,8 @@ pub fn move_task(
 /// reorder moves operate on whole top-level subtrees, so a completed task in the
 /// Done section (or a Parked one) is located here — not just the Backlog.
 pub fn top_level_section(track: &Track, task_id: &str) -> Option<SectionKind> {
-    [SectionKind::Backlog, SectionKind::Parked, SectionKind::Done]
+    track
+        .section_kinds()
         .into_iter()
         .find(|&s| is_top_level_in_section(track, task_id, s))
 }
@@ -814,13 +952,27 @@ pub fn top_level_section(track: &Track, task_id: &str) -> Option<SectionKind> {
 /// [`top_level_section`] for the current section and move if they differ.
 ///
 /// Subtasks have no section of their own — they live inside their parent — so
-/// this applies only to top-level tasks.
-pub fn canonical_section(state: TaskState) -> SectionKind {
-    match state {
-        TaskState::Parked => SectionKind::Parked,
-        TaskState::Done => SectionKind::Done,
-        _ => SectionKind::Backlog,
-    }
+/// this applies only to top-level tasks. A custom state's section is whatever
+/// `[workflow.states]` gave it; see [`WorkflowConfig::section_of`].
+pub fn canonical_section(state: TaskState, workflow: &WorkflowConfig) -> SectionKind {
+    workflow.section_of(state)
+}
+
+/// Whether a top-level task in `state` may stay in `section`.
+///
+/// Its canonical section, always. A custom section such as `## Icebox` is also
+/// somewhere an open task can wait — that is what it is for — so it keeps todo,
+/// active and blocked tasks too; only a done or parked one, or a custom state
+/// that names a different section, is moved out of it.
+pub fn section_accepts(section: SectionKind, state: TaskState, workflow: &WorkflowConfig) -> bool {
+    if canonical_section(state, workflow) == section {
+        return true;
+    }
+    matches!(section, SectionKind::Custom(_))
+        && matches!(
+            state,
+            TaskState::Todo | TaskState::Active | TaskState::Blocked
+        )
 }
 
 /// Move `task_id` into the section its state calls for, if it is not there.
@@ -831,12 +983,13 @@ pub fn reconcile_task_section(
     track: &mut Track,
     task_id: &str,
     state: TaskState,
+    workflow: &WorkflowConfig,
 ) -> Option<(SectionKind, SectionKind)> {
     let from = top_level_section(track, task_id)?;
-    let to = canonical_section(state);
-    if from == to {
+    if section_accepts(from, state, workflow) {
         return None;
     }
+    let to = canonical_section(state, workflow);
     move_task_between_sections(track, task_id, from, to);
     Some((from, to))
 }
@@ -913,6 +1066,119 @@ pub fn move_task_to_track(
     })
 }
 
+/// Move a top-level task into a track of **another project**, minting its new
+/// id there and leaving a [tombstone](tombstone_moved_task) where it was.
+///
+/// On top of [`move_task_to_track`], the moved subtree's own `dep:` and
+/// relation lines are rewritten for the project they now live in: an id inside
+/// the subtree follows its renumbering, an id left behind in the source is
+/// qualified as `source_name:ID` — the cross-project form, which still resolves
+/// — and a `target_name:ID` qualifier becomes a plain id, since it now names a
+/// task in the same project.
+///
+/// What in the *source* points at the moved ids is the caller's, for the same
+/// reason as with [`move_task_to_track`]: the map to apply is
+/// `old → target_name:new`.
+pub fn move_task_to_project(
+    source_track: &mut Track,
+    target_track: &mut Track,
+    task_id: &str,
+    position: InsertPosition,
+    target_mint: Mint<'_>,
+    source_name: &str,
+    target_name: &str,
+) -> Result<CrossTrackMoveResult, TaskError> {
+    let (title, added) = find_task_in_track(source_track, task_id)
+        .map(|t| (t.title.clone(), t.added_date()))
+        .ok_or_else(|| TaskError::NotFound(task_id.to_string()))?;
+    let moved = move_task_to_track(source_track, target_track, task_id, position, target_mint)?;
+
+    let renumbered: HashMap<&str, &str> = moved
+        .id_mappings
+        .iter()
+        .map(|(old, new)| (old.as_str(), new.as_str()))
+        .collect();
+    let local_prefix = format!("{target_name}:");
+    fn rewrite(task: &mut Task, renumbered: &HashMap<&str, &str>, source: &str, local: &str) {
+        let mut changed = false;
+        for m in &mut task.metadata {
+            let Some(ids) = m.task_ids_mut() else {
+                continue;
+            };
+            for id in ids.iter_mut() {
+                let new = if let Some(new) = renumbered.get(id.as_str()) {
+                    (*new).to_string()
+                } else if let Some(plain) = id.strip_prefix(local) {
+                    plain.to_string()
+                } else if !id.contains(':') {
+                    format!("{source}:{id}")
+                } else {
+                    continue;
+                };
+                *id = new;
+                changed = true;
+            }
+        }
+        if changed {
+            task.mark_dirty();
+        }
+        for sub in &mut task.subtasks {
+            rewrite(sub, renumbered, source, local);
+        }
+    }
+    if let Some(task) = find_task_mut_in_track(target_track, &moved.new_root_id) {
+        rewrite(task, &renumbered, source_name, &local_prefix);
+    }
+
+    tombstone_moved_task(
+        source_track,
+        task_id,
+        &title,
+        added,
+        &format!("{target_name}:{}", moved.new_root_id),
+    );
+    Ok(moved)
+}
+
+/// Leave a stub where a task that moved to another project used to be: its id
+/// and title, done, with a `moved-to:` line saying where it went. Any copy of
+/// the task still in the track — an interrupted move's — is replaced by it.
+/// `added` is the task's own date, kept so the stub checks clean.
+///
+/// A stub rather than nothing, so the old id still answers `fr show` with a
+/// pointer instead of "not found", and done so that `fr clean` archives it in
+/// time like any finished task rather than keeping it open here forever.
+pub fn tombstone_moved_task(
+    track: &mut Track,
+    task_id: &str,
+    title: &str,
+    added: Option<String>,
+    moved_to: &str,
+) {
+    for section in track.section_kinds() {
+        if let Some(tasks) = track.section_tasks_mut(section) {
+            tasks.retain(|t| t.id.as_deref() != Some(task_id));
+        }
+    }
+    let mut stub = Task::new(
+        TaskState::Done,
+        Some(TaskId::parse(task_id)),
+        title.to_string(),
+    );
+    if let Some(date) = added {
+        stub.metadata.push(Metadata::Added(date));
+    }
+    stub.metadata.push(Metadata::Resolved(today_str()));
+    stub.metadata.push(Metadata::Custom {
+        key: MOVED_TO_KEY.to_string(),
+        value: moved_to.to_string(),
+    });
+    track.ensure_section(SectionKind::Done);
+    if let Some(done) = track.section_tasks_mut(SectionKind::Done) {
+        done.insert(0, stub);
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Section moves
 // ---------------------------------------------------------------------------
@@ -981,7 +1247,7 @@ fn remove_metadata(task: &mut Task, key: &str) {
 /// Any duplicate entries for the key beyond the first are dropped, which is the
 /// same normalization `remove_metadata` + `push` performed.
 pub fn set_metadata(task: &mut Task, meta: Metadata) {
-    let key = meta.key();
+    let key = meta.key().to_string();
     match task.metadata.iter().position(|m| m.key() == key) {
         Some(idx) => {
             task.metadata[idx] = meta;
@@ -1175,7 +1441,7 @@ pub fn renumber_subtasks(task: &mut Task, parent_id: &str, token: Option<&Token>
     }
 }
 
-/// Update all dep references across tracks from old_id to new_id.
+/// Update all dep and relation references across tracks from old_id to new_id.
 pub fn update_dep_references(tracks: &mut [(String, Track)], old_id: &str, new_id: &str) {
     for (_, track) in tracks.iter_mut() {
         for node in &mut track.nodes {
@@ -1186,8 +1452,8 @@ pub fn update_dep_references(tracks: &mut [(String, Track)], old_id: &str, new_i
     }
 }
 
-/// Rewrite every `dep:` reference across `tracks` according to `map`, in **one
-/// pass**.
+/// Rewrite every `dep:` and relation reference across `tracks` according to
+/// `map`, in **one pass**.
 ///
 /// An operation that renumbers a task renumbers its whole subtree, so what it
 /// has to offer here is a list of pairs, not a pair. Calling
@@ -1232,7 +1498,7 @@ fn apply_id_map_to_deps_in_track(track: &mut Track, lookup: &HashMap<&str, &str>
         for task in tasks.iter_mut() {
             let mut changed = false;
             for m in &mut task.metadata {
-                if let Metadata::Dep(deps) = m {
+                if let Some(deps) = m.task_ids_mut() {
                     for dep in deps.iter_mut() {
                         if let Some(new) = lookup.get(dep.as_str()) {
                             *dep = (*new).to_string();
@@ -1293,7 +1559,8 @@ pub fn track_has_dirty_task(track: &Track) -> bool {
         .any(|node| matches!(node, TrackNode::Section { tasks, .. } if any_dirty(tasks)))
 }
 
-/// Update all dep references within a single track from old_id to new_id.
+/// Update all dep and relation references within a single track from old_id to
+/// new_id.
 pub fn update_dep_references_in_track(track: &mut Track, old_id: &str, new_id: &str) {
     for node in &mut track.nodes {
         if let TrackNode::Section { tasks, .. } = node {
@@ -1306,7 +1573,7 @@ fn update_deps_in_tasks(tasks: &mut [Task], old_id: &str, new_id: &str) {
     for task in tasks.iter_mut() {
         let mut changed = false;
         for m in &mut task.metadata {
-            if let Metadata::Dep(deps) = m {
+            if let Some(deps) = m.task_ids_mut() {
                 for dep in deps.iter_mut() {
                     if dep == old_id {
                         *dep = new_id.to_string();
@@ -1368,7 +1635,7 @@ fn find_in_subtasks(parent: &Task, task_id: &str, section: SectionKind) -> Optio
 
 /// Find a task's location across all sections of a track.
 pub fn find_task_location_any_section(track: &Track, task_id: &str) -> Option<TaskLocation> {
-    for kind in &[SectionKind::Backlog, SectionKind::Parked, SectionKind::Done] {
+    for kind in &track.section_kinds() {
         if let Some(loc) = find_task_location(track, task_id, *kind) {
             return Some(loc);
         }
@@ -1732,16 +1999,16 @@ mod tests {
         let task = find_task_mut_in_track(&mut track, "T-001").unwrap();
         assert_eq!(task.state, TaskState::Todo);
 
-        cycle_state(task);
+        cycle_state(task, &WorkflowConfig::default());
         assert_eq!(task.state, TaskState::Active);
         assert!(task.dirty);
 
-        cycle_state(task);
+        cycle_state(task, &WorkflowConfig::default());
         assert_eq!(task.state, TaskState::Done);
         // Should have resolved date
         assert!(task.metadata.iter().any(|m| m.key() == "resolved"));
 
-        cycle_state(task);
+        cycle_state(task, &WorkflowConfig::default());
         assert_eq!(task.state, TaskState::Todo);
         // Resolved should be removed
         assert!(!task.metadata.iter().any(|m| m.key() == "resolved"));
@@ -1854,6 +2121,57 @@ mod tests {
         assert_eq!(parent.subtasks[0].title, "New sub");
     }
 
+    #[test]
+    fn apply_template_fills_in_a_new_task() {
+        let mut track = sample_track();
+        let template = TaskTemplate {
+            track: None,
+            tags: vec!["bug".into(), "core".into()],
+            note: Some("## Repro\n\n## Expected\n".into()),
+            subtasks: vec!["Write a failing test".into(), "Fix".into()],
+            refs: vec!["./doc/bugs.md".into()],
+        };
+        let subs =
+            apply_template(&mut track, "T-001", &template, None, NoteLimits::default()).unwrap();
+        assert_eq!(subs, vec!["T-001.1", "T-001.2"]);
+        let task = find_task_in_track(&track, "T-001").unwrap();
+        // `core` was already there and is not doubled.
+        assert_eq!(task.tags, vec!["core", "bug"]);
+        assert_eq!(paths_of(task, PathField::Ref), vec!["doc/bugs.md"]);
+        assert!(
+            task.metadata
+                .iter()
+                .any(|m| matches!(m, Metadata::Note(n) if n == "## Repro\n\n## Expected"))
+        );
+        assert_eq!(task.subtasks[1].title, "Fix");
+        assert!(
+            task.subtasks[1]
+                .metadata
+                .iter()
+                .any(|m| matches!(m, Metadata::Added(_)))
+        );
+    }
+
+    #[test]
+    fn set_field_replaces_in_place_and_clears() {
+        let mut track = sample_track();
+        assert!(set_field(&mut track, "T-001", "area", Some("parser")).unwrap());
+        assert!(set_field(&mut track, "T-001", "risk", Some("low")).unwrap());
+        assert!(set_field(&mut track, "T-001", "area", Some("lexer")).unwrap());
+        assert!(!set_field(&mut track, "T-001", "area", Some("lexer")).unwrap());
+
+        let task = find_task_in_track(&track, "T-001").unwrap();
+        let keys: Vec<&str> = task.metadata.iter().map(|m| m.key()).collect();
+        let area = keys.iter().position(|k| *k == "area").unwrap();
+        assert_eq!(keys[area + 1], "risk", "area kept its place: {keys:?}");
+        assert_eq!(task.field("area"), Some("lexer"));
+
+        assert!(set_field(&mut track, "T-001", "area", None).unwrap());
+        assert!(!set_field(&mut track, "T-001", "area", None).unwrap());
+        let task = find_task_in_track(&track, "T-001").unwrap();
+        assert_eq!(task.field("area"), None);
+    }
+
     #[test]
     fn test_add_subtask_max_depth() {
         let mut track = sample_track();
@@ -2205,11 +2523,67 @@ mod tests {
     /// section — the failure mode the enumerated form had.
     #[test]
     fn every_state_has_a_canonical_section() {
-        assert_eq!(canonical_section(TaskState::Todo), SectionKind::Backlog);
-        assert_eq!(canonical_section(TaskState::Active), SectionKind::Backlog);
-        assert_eq!(canonical_section(TaskState::Blocked), SectionKind::Backlog);
-        assert_eq!(canonical_section(TaskState::Parked), SectionKind::Parked);
-        assert_eq!(canonical_section(TaskState::Done), SectionKind::Done);
+        let w = WorkflowConfig::default();
+        assert_eq!(canonical_section(TaskState::Todo, &w), SectionKind::Backlog);
+        assert_eq!(
+            canonical_section(TaskState::Active, &w),
+            SectionKind::Backlog
+        );
+        assert_eq!(
+            canonical_section(TaskState::Blocked, &w),
+            SectionKind::Backlog
+        );
+        assert_eq!(
+            canonical_section(TaskState::Parked, &w),
+            SectionKind::Parked
+        );
+        assert_eq!(canonical_section(TaskState::Done, &w), SectionKind::Done);
+        assert_eq!(
+            canonical_section(TaskState::Custom('?'), &w),
+            SectionKind::Backlog,
+            "an undeclared state is open"
+        );
+    }
+
+    /// A declared state goes to its section; a custom section keeps open tasks
+    /// and gives up a done one.
+    #[test]
+    fn custom_states_and_sections_follow_the_workflow() {
+        let w: WorkflowConfig = toml::from_str(
+            "sections = [\"Icebox\"]\n[states.frozen]\nchar = \"*\"\nsection = \"icebox\"\n",
+        )
+        .unwrap();
+        let icebox = SectionKind::from_heading("Icebox").unwrap();
+        let mut track = sample_track();
+
+        let moved = reconcile_task_section(&mut track, "T-001", TaskState::Custom('*'), &w);
+        assert_eq!(moved, Some((SectionKind::Backlog, icebox)));
+        assert_eq!(track.section_tasks(icebox)[0].id.as_deref(), Some("T-001"));
+        assert!(
+            crate::parse::serialize_track(&track).contains("## Icebox\n\n- [ ] `T-001`"),
+            "the section is created with the declared heading:\n{}",
+            crate::parse::serialize_track(&track)
+        );
+
+        assert_eq!(
+            reconcile_task_section(&mut track, "T-001", TaskState::Todo, &w),
+            None,
+            "a todo task may wait in the icebox"
+        );
+        assert_eq!(
+            reconcile_task_section(&mut track, "T-001", TaskState::Done, &w),
+            Some((icebox, SectionKind::Done))
+        );
+    }
+
+    #[test]
+    fn the_state_cycle_passes_through_declared_states() {
+        let w: WorkflowConfig = toml::from_str("[states.review]\nchar = \"?\"\n").unwrap();
+        let mut task = Task::new(TaskState::Active, None, "t".into());
+        cycle_state(&mut task, &w);
+        assert_eq!(task.state, TaskState::Custom('?'));
+        cycle_state(&mut task, &w);
+        assert_eq!(task.state, TaskState::Done);
     }
 
     /// The cell both the CLI and the TUI missed: a task parked out of Done.
@@ -2218,7 +2592,12 @@ mod tests {
         let mut track = sample_track();
         move_task_between_sections(&mut track, "T-001", SectionKind::Backlog, SectionKind::Done);
 
-        let moved = reconcile_task_section(&mut track, "T-001", TaskState::Parked);
+        let moved = reconcile_task_section(
+            &mut track,
+            "T-001",
+            TaskState::Parked,
+            &WorkflowConfig::default(),
+        );
         assert_eq!(moved, Some((SectionKind::Done, SectionKind::Parked)));
         assert_eq!(track.parked()[0].id.as_deref(), Some("T-001"));
         assert!(
@@ -2233,7 +2612,12 @@ mod tests {
     fn reconcile_is_a_no_op_when_the_task_is_already_right() {
         let mut track = sample_track();
         assert_eq!(
-            reconcile_task_section(&mut track, "T-001", TaskState::Todo),
+            reconcile_task_section(
+                &mut track,
+                "T-001",
+                TaskState::Todo,
+                &WorkflowConfig::default()
+            ),
             None,
             "a Backlog task set to todo has nowhere to go"
         );
@@ -2251,7 +2635,12 @@ mod tests {
             .unwrap()
             .to_string();
         assert_eq!(
-            reconcile_task_section(&mut track, &sub_id, TaskState::Done),
+            reconcile_task_section(
+                &mut track,
+                &sub_id,
+                TaskState::Done,
+                &WorkflowConfig::default()
+            ),
             None
         );
         assert_eq!(track.backlog()[2].subtasks.len(), 2, "subtask stayed put");
@@ -2313,7 +2702,12 @@ mod tests {
     fn reconcile_ignores_a_task_that_is_not_there() {
         let mut track = sample_track();
         assert_eq!(
-            reconcile_task_section(&mut track, "NOPE-999", TaskState::Done),
+            reconcile_task_section(
+                &mut track,
+                "NOPE-999",
+                TaskState::Done,
+                &WorkflowConfig::default()
+            ),
             None
         );
     }
@@ -31,6 +31,7 @@ pub enum TrackError {
 #[derive(Debug, Default)]
 pub struct RenameResult {
     pub tasks_renamed: usize,
+    /// `dep:` and relation entries rewritten — both name tasks by ID.
     pub deps_updated: usize,
     pub tracks_affected: usize,
 }
@@ -349,6 +350,8 @@ pub struct TrackStats {
     pub todo: usize,
     pub parked: usize,
     pub done: usize,
+    /// Tasks in any `[workflow.states]` state.
+    pub custom: usize,
 }
 
 fn count_tasks(tasks: &[crate::model::Task], stats: &mut TrackStats, _section: SectionKind) {
@@ -359,6 +362,7 @@ fn count_tasks(tasks: &[crate::model::Task], stats: &mut TrackStats, _section: S
             crate::model::TaskState::Todo => stats.todo += 1,
             crate::model::TaskState::Parked => stats.parked += 1,
             crate::model::TaskState::Done => stats.done += 1,
+            crate::model::TaskState::Custom(_) => stats.custom += 1,
         }
         count_tasks(&task.subtasks, stats, _section);
     }
@@ -697,7 +701,7 @@ pub fn rename_task_ids(
     count
 }
 
-/// Rename dep references in a track. Returns count of deps renamed.
+/// Rename dep and relation references in a track. Returns count renamed.
 fn rename_dep_references(track: &mut Track, old_prefix: &str, new_prefix: &str) -> usize {
     let mut count = 0;
     for node in &mut track.nodes {
@@ -708,7 +712,7 @@ fn rename_dep_references(track: &mut Track, old_prefix: &str, new_prefix: &str)
     count
 }
 
-/// Rename dep references in a list of tasks (recursive).
+/// Rename dep and relation references in a list of tasks (recursive).
 fn rename_deps_in_tasks(
     tasks: &mut [crate::model::Task],
     old_prefix: &str,
@@ -717,7 +721,7 @@ fn rename_deps_in_tasks(
     let mut count = 0;
     for task in tasks.iter_mut() {
         for m in &mut task.metadata {
-            if let crate::model::task::Metadata::Dep(deps) = m {
+            if let Some(deps) = m.task_ids_mut() {
                 for dep in deps.iter_mut() {
                     if let Some(rest) = dep.strip_prefix(old_prefix)
                         && rest.starts_with('-')
@@ -752,7 +756,7 @@ pub fn generate_track_id(name: &str) -> String {
 pub struct PrefixRenameImpact {
     /// Number of task/subtask IDs carrying the old prefix in the target track
     pub task_id_count: usize,
-    /// Number of dep references to old-prefix IDs across other tracks
+    /// Number of dep and relation references to old-prefix IDs across other tracks
     pub dep_ref_count: usize,
     /// Number of other tracks containing affected dep references
     pub affected_track_count: usize,
@@ -823,7 +827,7 @@ fn count_prefix_ids(tasks: &[crate::model::Task], prefix: &str) -> usize {
     count
 }
 
-/// Count dep references matching a prefix in a track (recursive)
+/// Count dep and relation references matching a prefix in a track (recursive)
 fn count_dep_references(track: &Track, prefix: &str) -> usize {
     let mut count = 0;
     for node in &track.nodes {
@@ -839,7 +843,7 @@ fn count_deps_in_tasks(tasks: &[crate::model::Task], prefix: &str) -> usize {
     let mut count = 0;
     for task in tasks {
         for m in &task.metadata {
-            if let crate::model::task::Metadata::Dep(deps) = m {
+            if let Some(deps) = m.task_ids() {
                 for dep in deps {
                     if let Some(rest) = dep.strip_prefix(prefix)
                         && rest.starts_with('-')
@@ -1308,6 +1312,12 @@ file = "tracks/old.md"
             ui: Default::default(),
             recovery: Default::default(),
             limits: Default::default(),
+            deps: Default::default(),
+            templates: Default::default(),
+            fields: Default::default(),
+            workflow: Default::default(),
+            board: Default::default(),
+            keys: Default::default(),
         };
 
         let mut tracks = vec![
@@ -1388,6 +1398,12 @@ file = "tracks/old.md"
             ui: Default::default(),
             recovery: Default::default(),
             limits: Default::default(),
+            deps: Default::default(),
+            templates: Default::default(),
+            fields: Default::default(),
+            workflow: Default::default(),
+            board: Default::default(),
+            keys: Default::default(),
         };
 
         let mut tracks = vec![
@@ -1471,6 +1487,12 @@ file = "tracks/old.md"
             ui: Default::default(),
             recovery: Default::default(),
             limits: Default::default(),
+            deps: Default::default(),
+            templates: Default::default(),
+            fields: Default::default(),
+            workflow: Default::default(),
+            board: Default::default(),
+            keys: Default::default(),
         };
 
         let track_content = "# A\n\n## Backlog\n\n## Done\n";
@@ -1,3 +1,7 @@
+use crate::model::config::ProjectConfig;
+use crate::model::task::{MOVED_TO_KEY, TaskState};
+use crate::model::track::{SectionKind, SectionName};
+
 pub mod archive_parser;
 pub mod archive_serializer;
 pub mod inbox_parser;
@@ -62,6 +66,65 @@ impl LineEnding {
     }
 }
 
+/// What a project's `project.toml` declares that changes how its files read.
+///
+/// A `key: value` line is a custom field only when `[fields]` names the key, a
+/// `## heading` a custom section only when `[workflow] sections` lists it, and
+/// a checkbox character a custom state only when `[workflow.states]` gives it.
+/// Anything undeclared reads as it always has: a line of text, an unknown
+/// heading, a todo. So `Schema::default()` — what [`parse_track`] uses, having
+/// no config to hand — reads a file exactly as frame did before any of these
+/// existed, and a stray line that happens to look like one does not turn into
+/// something nobody asked for.
+#[derive(Debug, Clone, Default)]
+pub struct Schema {
+    /// The keys `[fields]` declares.
+    pub fields: Vec<String>,
+    /// The headings `[workflow] sections` declares.
+    pub sections: Vec<SectionName>,
+    /// The checkbox characters `[workflow.states]` declares.
+    pub states: Vec<char>,
+}
+
+impl Schema {
+    /// What `config` declares.
+    pub fn of(config: &ProjectConfig) -> Schema {
+        Schema {
+            fields: config.fields.keys().cloned().collect(),
+            sections: config.workflow.sections.clone(),
+            states: config
+                .workflow
+                .states
+                .values()
+                .map(|s| s.checkbox)
+                .collect(),
+        }
+    }
+
+    /// Whether a `key: value` line is a custom field. `moved-to` always is:
+    /// frame writes it itself, and every project has to read it back.
+    pub fn declares_field(&self, key: &str) -> bool {
+        key == MOVED_TO_KEY || self.fields.iter().any(|k| k == key)
+    }
+
+    /// The section a `## heading` names, if it is built in or declared.
+    pub fn section(&self, heading: &str) -> Option<SectionKind> {
+        SectionKind::from_heading(heading).filter(|kind| match kind {
+            SectionKind::Custom(name) => self.sections.contains(name),
+            _ => true,
+        })
+    }
+
+    /// The state a checkbox character names, if it is built in or declared.
+    pub fn state(&self, checkbox: char) -> Option<TaskState> {
+        TaskState::from_checkbox_char(checkbox).or_else(|| {
+            self.states
+                .contains(&checkbox)
+                .then_some(TaskState::Custom(checkbox))
+        })
+    }
+}
+
 /// Check if content continues at or beyond `min_indent` after blank lines.
 /// Used by both the task note parser and inbox body parser to decide whether
 /// a blank line is internal (separating paragraphs) or terminal (ending the block).
@@ -102,8 +165,8 @@ pub use archive_parser::parse_archive;
 pub use archive_serializer::serialize_archive;
 pub use inbox_parser::parse_inbox;
 pub use inbox_serializer::serialize_inbox;
-pub use task_parser::{parse_tasks, parse_title_and_tags};
+pub use task_parser::{parse_tasks, parse_tasks_with, parse_title_and_tags};
 pub use task_serializer::serialize_tasks;
 pub(crate) use task_serializer::stranded_would_be_absorbed;
-pub use track_parser::parse_track;
+pub use track_parser::{parse_track, parse_track_with};
 pub use track_serializer::serialize_track;
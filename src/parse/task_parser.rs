@@ -1,6 +1,6 @@
-use crate::model::task::{Metadata, Task, TaskState};
+use crate::model::task::{Metadata, RelationKind, Task, TaskState};
 use crate::model::task_id::TaskId;
-use crate::parse::{count_indent, has_continuation_at_indent};
+use crate::parse::{Schema, count_indent, has_continuation_at_indent};
 
 /// Maximum nesting depth (3 levels: top, sub, sub-sub)
 const MAX_DEPTH: usize = 3;
@@ -12,6 +12,17 @@ pub fn parse_tasks(
     start_idx: usize,
     indent: usize,
     depth: usize,
+) -> (Vec<Task>, usize) {
+    parse_tasks_with(lines, start_idx, indent, depth, &Schema::default())
+}
+
+/// [`parse_tasks`], reading what `schema` declares.
+pub fn parse_tasks_with(
+    lines: &[String],
+    start_idx: usize,
+    indent: usize,
+    depth: usize,
+    schema: &Schema,
 ) -> (Vec<Task>, usize) {
     let mut tasks = Vec::new();
     let mut idx = start_idx;
@@ -33,7 +44,7 @@ pub fn parse_tasks(
         // Check if this line is a task at the expected indent level
         if let Some(task_indent) = task_indent(line) {
             if task_indent == indent {
-                let (mut task, next_idx) = parse_single_task(lines, idx, indent, depth);
+                let (mut task, next_idx) = parse_single_task(lines, idx, indent, depth, schema);
                 task.leading_lines = take_leading(&mut pending, &mut pending_blanks);
                 tasks.push(task);
                 idx = next_idx;
@@ -54,7 +65,8 @@ pub fn parse_tasks(
                 // are read at their real indent, but it is recorded at our depth,
                 // so a rewrite re-emits it somewhere that parses back the same
                 // way. Over-deep nesting is flattened rather than dropped.
-                let (mut task, next_idx) = parse_single_task(lines, idx, task_indent, depth);
+                let (mut task, next_idx) =
+                    parse_single_task(lines, idx, task_indent, depth, schema);
                 task.leading_lines = take_leading(&mut pending, &mut pending_blanks);
                 tasks.push(task);
                 idx = next_idx;
@@ -139,9 +151,10 @@ fn parse_single_task(
     start_idx: usize,
     indent: usize,
     depth: usize,
+    schema: &Schema,
 ) -> (Task, usize) {
     let line = &lines[start_idx];
-    let (state, id, title, tags) = parse_task_line(line, indent);
+    let (state, id, title, tags) = parse_task_line(line, indent, schema);
 
     let mut task = Task {
         state,
@@ -175,8 +188,8 @@ fn parse_single_task(
         }
 
         // Check for metadata line at meta_indent
-        if is_metadata_line(line, meta_indent) {
-            let (meta, next_idx) = parse_metadata(lines, idx, meta_indent);
+        if is_metadata_line(line, meta_indent, schema) {
+            let (meta, next_idx) = parse_metadata(lines, idx, meta_indent, schema);
             task.metadata.push(meta);
             idx = next_idx;
             continue;
@@ -259,7 +272,7 @@ fn parse_single_task(
                 peek += 1;
             }
             if peek < lines.len()
-                && (is_metadata_line(&lines[peek], meta_indent)
+                && (is_metadata_line(&lines[peek], meta_indent, schema)
                     || task_indent(&lines[peek]).is_some_and(|ti| ti == meta_indent))
             {
                 idx += 1;
@@ -282,7 +295,7 @@ fn parse_single_task(
         && ti == meta_indent
         && depth + 1 < MAX_DEPTH
     {
-        let (subtasks, next_idx) = parse_tasks(lines, idx, meta_indent, depth + 1);
+        let (subtasks, next_idx) = parse_tasks_with(lines, idx, meta_indent, depth + 1, schema);
         task.subtasks = subtasks;
         idx = next_idx;
     }
@@ -302,7 +315,11 @@ fn parse_single_task(
 /// fixed `strip_block_indent` slicing `line[4..]` into the middle of a `§`,
 /// which panicked `fr list` outright on a file containing one. That one had no
 /// guard at all; this one has a guard nothing points at.
-fn parse_task_line(line: &str, indent: usize) -> (TaskState, Option<TaskId>, String, Vec<String>) {
+fn parse_task_line(
+    line: &str,
+    indent: usize,
+    schema: &Schema,
+) -> (TaskState, Option<TaskId>, String, Vec<String>) {
     let content = &line[indent..];
 
     // Parse checkbox: `- [X] `
@@ -310,7 +327,7 @@ fn parse_task_line(line: &str, indent: usize) -> (TaskState, Option<TaskId>, Str
         .strip_prefix("- [")
         .and_then(|rest| rest.chars().next())
         .unwrap_or(' ');
-    let state = TaskState::from_checkbox_char(state_char).unwrap_or(TaskState::Todo);
+    let state = schema.state(state_char).unwrap_or(TaskState::Todo);
 
     // Skip past `- [X] `
     debug_assert!(
@@ -425,7 +442,7 @@ fn has_more_tasks_at_indent(lines: &[String], start: usize, indent: usize) -> bo
 }
 
 /// Check if a line is a metadata line at the given indent: `  - key: value`
-fn is_metadata_line(line: &str, indent: usize) -> bool {
+fn is_metadata_line(line: &str, indent: usize, schema: &Schema) -> bool {
     let line_indent = count_indent(line);
     if line_indent != indent {
         return false;
@@ -435,22 +452,39 @@ fn is_metadata_line(line: &str, indent: usize) -> bool {
         return false;
     }
     let after_dash = &content[2..];
-    // Must have a recognized key followed by ':'
+    // Must have a recognized key followed by ':', or a declared field key
+    // followed by ': ' — the space is what keeps `- https://…` a line of text.
     matches!(
         after_dash.split_once(':'),
-        Some((key, _)) if is_metadata_key(key)
+        Some((key, rest)) if is_metadata_key(key)
+            || (schema.declares_field(key) && (rest.is_empty() || rest.starts_with(' ')))
     )
 }
 
 fn is_metadata_key(key: &str) -> bool {
     matches!(
         key.trim(),
-        "dep" | "ref" | "spec" | "note" | "added" | "resolved" | "conflict"
+        "dep"
+            | "relates"
+            | "duplicates"
+            | "supersedes"
+            | "epic"
+            | "ref"
+            | "spec"
+            | "note"
+            | "added"
+            | "resolved"
+            | "conflict"
     )
 }
 
 /// Parse a metadata entry starting at `idx`. Returns the metadata and next line.
-fn parse_metadata(lines: &[String], idx: usize, indent: usize) -> (Metadata, usize) {
+fn parse_metadata(
+    lines: &[String],
+    idx: usize,
+    indent: usize,
+    schema: &Schema,
+) -> (Metadata, usize) {
     let line = &lines[idx];
     let content = line[indent..].trim_start();
     let after_dash = &content[2..]; // skip "- "
@@ -459,6 +493,15 @@ fn parse_metadata(lines: &[String], idx: usize, indent: usize) -> (Metadata, usi
     let key = key.trim();
     let value = value_part.trim();
 
+    if let Some(kind) = RelationKind::from_key(key) {
+        let ids: Vec<String> = value
+            .split(',')
+            .map(|s| s.trim().to_string())
+            .filter(|s| !s.is_empty())
+            .collect();
+        return (Metadata::Relation(kind, ids), idx + 1);
+    }
+
     match key {
         "dep" => {
             let deps: Vec<String> = value
@@ -498,6 +541,13 @@ fn parse_metadata(lines: &[String], idx: usize, indent: usize) -> (Metadata, usi
                 (Metadata::Note(note_text), next_idx)
             }
         }
+        _ if schema.declares_field(key) => (
+            Metadata::Custom {
+                key: key.to_string(),
+                value: value.to_string(),
+            },
+            idx + 1,
+        ),
         _ => {
             // Unknown metadata — treat as a note
             (Metadata::Note(format!("{}: {}", key, value)), idx + 1)
@@ -656,6 +706,80 @@ mod tests {
         assert_eq!(tasks[0].subtasks[2].state, TaskState::Todo);
     }
 
+    /// A key `[fields]` declares is a custom field, and the lines after it are
+    /// still the task's. A colon with no space after it is text, or every URL
+    /// would be a field.
+    #[test]
+    fn test_parse_custom_field() {
+        let schema = Schema {
+            fields: vec!["area".into(), "risk".into()],
+            ..Default::default()
+        };
+        let input = lines(
+            "- [ ] `EFF-014` Test task\n\
+             \x20\x20- area: parser\n\
+             \x20\x20- https://example.com/issue/4\n\
+             \x20\x20- added: 2025-05-10",
+        );
+        let (tasks, _) = parse_tasks_with(&input, 0, 0, 0, &schema);
+        assert_eq!(tasks[0].field("area"), Some("parser"));
+        assert!(tasks[0].field("https").is_none());
+        assert!(
+            tasks[0]
+                .metadata
+                .iter()
+                .all(|m| !matches!(m, Metadata::Added(_))),
+            "the URL line ends the block, as any text does"
+        );
+
+        let input = lines(
+            "- [ ] `EFF-014` Test task\n\
+             \x20\x20- risk: high\n\
+             \x20\x20- added: 2025-05-10",
+        );
+        let (tasks, _) = parse_tasks_with(&input, 0, 0, 0, &schema);
+        assert_eq!(tasks[0].field("risk"), Some("high"));
+        assert!(
+            tasks[0]
+                .metadata
+                .iter()
+                .any(|m| matches!(m, Metadata::Added(d) if d == "2025-05-10"))
+        );
+    }
+
+    /// A key nobody declared is not a field: the line is text, as it was
+    /// before `[fields]` existed, and ends the block like any other text.
+    #[test]
+    fn test_parse_undeclared_field_is_text() {
+        let input = lines(
+            "- [ ] `EFF-014` Test task\n\
+             \x20\x20- owner: bob\n\
+             \x20\x20- added: 2025-05-10",
+        );
+        for schema in [
+            Schema::default(),
+            Schema {
+                fields: vec!["area".into()],
+                ..Default::default()
+            },
+        ] {
+            let (tasks, _) = parse_tasks_with(&input, 0, 0, 0, &schema);
+            assert!(tasks[0].metadata.is_empty(), "{:?}", tasks[0].metadata);
+            assert!(tasks[0].field("owner").is_none());
+        }
+    }
+
+    #[test]
+    fn test_parse_moved_to_needs_no_declaration() {
+        let input = lines(
+            "- [x] `EFF-014` Test task\n\
+             \x20\x20- resolved: 2025-05-10\n\
+             \x20\x20- moved-to: lib:LIB-003",
+        );
+        let (tasks, _) = parse_tasks(&input, 0, 0, 0);
+        assert_eq!(tasks[0].field("moved-to"), Some("lib:LIB-003"));
+    }
+
     #[test]
     fn test_parse_note_block() {
         let input = lines(
@@ -110,12 +110,26 @@ fn serialize_task(task: &Task, indent: usize, lines: &mut Vec<String>) {
             Metadata::Dep(deps) => {
                 lines.push(format!("{}- dep: {}", meta_indent, deps.join(", ")));
             }
+            Metadata::Relation(kind, ids) => {
+                lines.push(format!(
+                    "{}- {}: {}",
+                    meta_indent,
+                    kind.key(),
+                    ids.join(", ")
+                ));
+            }
             Metadata::Ref(refs) => {
                 lines.push(format!("{}- ref: {}", meta_indent, refs.join(", ")));
             }
             Metadata::Spec(specs) => {
                 lines.push(format!("{}- spec: {}", meta_indent, specs.join(", ")));
             }
+            Metadata::Custom { key, value } if value.is_empty() => {
+                lines.push(format!("{}- {}:", meta_indent, key));
+            }
+            Metadata::Custom { key, value } => {
+                lines.push(format!("{}- {}: {}", meta_indent, key, value));
+            }
             Metadata::Note(note) => {
                 // The single-line form `- note: <text>` cannot carry leading or
                 // trailing whitespace: `parse_metadata` trims the value on read,
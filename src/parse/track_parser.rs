@@ -1,8 +1,16 @@
-use crate::model::track::{SectionKind, Track, TrackNode};
-use crate::parse::task_parser::parse_tasks;
+use crate::model::track::{Track, TrackNode};
+use crate::parse::Schema;
+use crate::parse::task_parser::parse_tasks_with;
 
-/// Parse a track file from its source text
+/// Parse a track file from its source text, as a project that declares
+/// nothing would read it.
 pub fn parse_track(source: &str) -> Track {
+    parse_track_with(source, &Schema::default())
+}
+
+/// [`parse_track`], reading what the project's config declares. Every reader
+/// that has the config to hand uses this one.
+pub fn parse_track_with(source: &str, schema: &Schema) -> Track {
     let lines: Vec<String> = source.lines().map(|l| l.to_string()).collect();
     let mut nodes: Vec<TrackNode> = Vec::new();
     let mut title = String::new();
@@ -34,17 +42,12 @@ pub fn parse_track(source: &str) -> Track {
             continue;
         }
 
-        // Check for section header: `## Backlog`, `## Parked`, `## Done`
+        // Check for section header: `## Backlog`, `## Parked`, `## Done`, or
+        // a custom section the project declares
         if let Some(after_hashes) = trimmed.strip_prefix("## ") {
             flush_literal(&mut literal_buf, &mut nodes);
 
-            let section_name = after_hashes.trim();
-            let kind = match section_name.to_lowercase().as_str() {
-                "backlog" => Some(SectionKind::Backlog),
-                "parked" => Some(SectionKind::Parked),
-                "done" => Some(SectionKind::Done),
-                _ => None,
-            };
+            let kind = schema.section(after_hashes);
 
             if let Some(kind) = kind {
                 let header_line = lines[idx].clone();
@@ -58,7 +61,7 @@ pub fn parse_track(source: &str) -> Track {
                 }
 
                 // Parse tasks in this section
-                let (tasks, next_idx) = parse_tasks(&lines, idx, 0, 0);
+                let (tasks, next_idx) = parse_tasks_with(&lines, idx, 0, 0, schema);
                 idx = next_idx;
 
                 // Collect trailing blank lines
@@ -224,4 +227,52 @@ mod tests {
         assert_eq!(section_count, 3);
         assert!(literal_count >= 1); // At least the title/desc block
     }
+
+    /// Only what `[workflow]` declares is a custom section or state; anything
+    /// else reads as it did before there was a `[workflow]`.
+    #[test]
+    fn test_parse_track_reads_only_declared_workflow() {
+        use crate::model::track::{SectionKind, SectionName};
+
+        let source = "\
+# My Track
+
+## Backlog
+
+- [?] `T-001` In review
+- [!] `T-002` Not a declared state
+
+## Icebox
+
+- [ ] `T-003` Set aside
+
+## Someday
+
+- [ ] `T-004` Not a declared section
+
+## Done
+";
+        let icebox = SectionName::new("Icebox").unwrap();
+        let schema = Schema {
+            sections: vec![icebox],
+            states: vec!['?'],
+            ..Default::default()
+        };
+        let track = parse_track_with(source, &schema);
+        let backlog = track.backlog();
+        assert_eq!(backlog[0].state, TaskState::Custom('?'));
+        assert_eq!(backlog[1].state, TaskState::Todo);
+        let kinds = track.section_kinds();
+        assert!(kinds.contains(&SectionKind::Custom(icebox)));
+        assert_eq!(kinds.len(), 3, "{kinds:?}");
+        let unknown = track.unknown_headings();
+        assert_eq!(unknown.len(), 1);
+        assert_eq!(unknown[0].heading, "Someday");
+        assert_eq!(unknown[0].stranded_tasks, 1);
+
+        // Without the config, nothing is custom
+        let track = parse_track(source);
+        assert_eq!(track.backlog()[0].state, TaskState::Todo);
+        assert_eq!(track.section_kinds().len(), 2);
+    }
 }
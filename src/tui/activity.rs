@@ -0,0 +1,264 @@
+//! What changed under the TUI: the tasks a reload from disk added, edited,
+//! moved or removed, found by diffing the tracks we held against the ones we
+//! reloaded.
+//!
+//! The watcher only says "this file changed", and replacing the track in place
+//! shows the result without saying what it was — an agent ticking off tasks in
+//! another terminal looked like nothing happening. Each reload's changes are
+//! kept in an [`ActivityLog`] for the Activity panel, and the rows they touch
+//! are highlighted for [`HIGHLIGHT_FOR`].
+
+use std::collections::{HashMap, VecDeque};
+use std::time::{Duration, Instant};
+
+use chrono::{DateTime, Local};
+
+use crate::model::WorkflowConfig;
+use crate::model::task::{Task, TaskState};
+use crate::model::track::{SectionKind, Track, TrackNode};
+
+/// How long a changed row stays highlighted.
+pub const HIGHLIGHT_FOR: Duration = Duration::from_secs(20);
+
+/// Changes the log keeps, oldest dropped first.
+const LOG_CAP: usize = 200;
+
+/// What happened to one task.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ChangeKind {
+    Added,
+    Removed,
+    /// Its state changed to this one.
+    State(TaskState),
+    /// It moved to this section with its state unchanged.
+    Moved(SectionKind),
+    /// Its title, tags or metadata changed.
+    Edited,
+}
+
+/// One task's change, as the reload that brought it saw it.
+#[derive(Debug, Clone)]
+pub struct ExternalChange {
+    pub at: DateTime<Local>,
+    pub track_id: String,
+    pub task_id: String,
+    pub title: String,
+    pub kind: ChangeKind,
+}
+
+impl ExternalChange {
+    /// `EFF-014 → done`, `EFF-015 added`.
+    pub fn describe(&self, workflow: &WorkflowConfig) -> String {
+        match &self.kind {
+            ChangeKind::Added => format!("{} added", self.task_id),
+            ChangeKind::Removed => format!("{} removed", self.task_id),
+            ChangeKind::State(state) => {
+                format!("{} \u{2192} {}", self.task_id, workflow.state_name(*state))
+            }
+            ChangeKind::Moved(section) => format!(
+                "{} \u{2192} {}",
+                self.task_id,
+                section.to_string().to_lowercase()
+            ),
+            ChangeKind::Edited => format!("{} edited", self.task_id),
+        }
+    }
+}
+
+/// The changes reloads have brought this session, and when each task last
+/// changed.
+#[derive(Debug, Default)]
+pub struct ActivityLog {
+    /// Oldest first.
+    pub changes: VecDeque<ExternalChange>,
+    changed_at: HashMap<String, Instant>,
+}
+
+impl ActivityLog {
+    /// Record a reload's changes.
+    pub fn record(&mut self, changes: Vec<ExternalChange>) {
+        let now = Instant::now();
+        for change in changes {
+            if change.kind != ChangeKind::Removed {
+                self.changed_at.insert(change.task_id.clone(), now);
+            }
+            self.changes.push_back(change);
+        }
+        while self.changes.len() > LOG_CAP {
+            self.changes.pop_front();
+        }
+        self.changed_at.retain(|_, at| at.elapsed() < HIGHLIGHT_FOR);
+    }
+
+    /// The change `back` places from the newest.
+    pub fn newest(&self, back: usize) -> Option<&ExternalChange> {
+        self.changes.iter().rev().nth(back)
+    }
+
+    /// Whether a reload changed the task within [`HIGHLIGHT_FOR`].
+    pub fn is_highlighted(&self, task_id: &str) -> bool {
+        self.changed_at
+            .get(task_id)
+            .is_some_and(|at| at.elapsed() < HIGHLIGHT_FOR)
+    }
+}
+
+/// Activity panel state. The panel lists newest first, so the cursor counts
+/// back from the end of [`ActivityLog::changes`].
+#[derive(Debug, Clone, Default)]
+pub struct ActivityPanelState {
+    pub cursor: usize,
+    pub scroll: usize,
+}
+
+/// The tasks that differ between `old` and `new`, matched by ID: those in
+/// `new` in its order, then those it no longer has. Subtasks count as tasks of
+/// their own, so a parent is only edited when its own line or metadata is.
+/// Tasks without an ID can't be matched and are left out.
+pub fn diff_tracks(track_id: &str, old: &Track, new: &Track) -> Vec<ExternalChange> {
+    let before = index(old);
+    let after = index(new);
+    let at = Local::now();
+    let change = |task: &Task, id: &str, kind| ExternalChange {
+        at,
+        track_id: track_id.to_string(),
+        task_id: id.to_string(),
+        title: task.title.clone(),
+        kind,
+    };
+
+    let mut changes = Vec::new();
+    for (id, (section, task)) in &after.order {
+        let kind = match before.by_id.get(id.as_str()) {
+            None => Some(ChangeKind::Added),
+            Some((_, was)) if was.state != task.state => Some(ChangeKind::State(task.state)),
+            Some((was_section, _)) if was_section != section => Some(ChangeKind::Moved(*section)),
+            Some((_, was))
+                if was.title != task.title
+                    || was.tags != task.tags
+                    || was.metadata != task.metadata =>
+            {
+                Some(ChangeKind::Edited)
+            }
+            Some(_) => None,
+        };
+        if let Some(kind) = kind {
+            changes.push(change(task, id, kind));
+        }
+    }
+    for (id, (_, task)) in &before.order {
+        if !after.by_id.contains_key(id.as_str()) {
+            changes.push(change(task, id, ChangeKind::Removed));
+        }
+    }
+    changes
+}
+
+struct Index<'a> {
+    order: Vec<(String, (SectionKind, &'a Task))>,
+    by_id: HashMap<String, (SectionKind, &'a Task)>,
+}
+
+fn index(track: &Track) -> Index<'_> {
+    let mut order = Vec::new();
+    for node in &track.nodes {
+        if let TrackNode::Section { kind, tasks, .. } = node {
+            walk(*kind, tasks, &mut order);
+        }
+    }
+    let by_id = order.iter().cloned().collect();
+    Index { order, by_id }
+}
+
+fn walk<'a>(
+    section: SectionKind,
+    tasks: &'a [Task],
+    out: &mut Vec<(String, (SectionKind, &'a Task))>,
+) {
+    for task in tasks {
+        if let Some(id) = &task.id {
+            out.push((id.to_string(), (section, task)));
+        }
+        walk(section, &task.subtasks, out);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse::parse_track;
+
+    const BEFORE: &str = "\
+# Main
+
+## Backlog
+
+- [>] `M-001` Build it
+  - [ ] `M-001.1` First step
+- [ ] `M-002` Write docs
+- [ ] `M-003` Maybe later
+- [ ] `M-004` Dropped
+
+## Done
+";
+
+    const AFTER: &str = "\
+# Main
+
+## Backlog
+
+- [x] `M-001` Build it
+  - [x] `M-001.1` First step
+- [ ] `M-002` Write the docs
+- [ ] `M-005` New work
+
+## Parked
+
+- [ ] `M-003` Maybe later
+
+## Done
+";
+
+    #[test]
+    fn changes_are_found_by_task_id() {
+        let changes = diff_tracks("main", &parse_track(BEFORE), &parse_track(AFTER));
+        let found: Vec<(&str, &ChangeKind)> = changes
+            .iter()
+            .map(|c| (c.task_id.as_str(), &c.kind))
+            .collect();
+        assert_eq!(
+            found,
+            [
+                ("M-001", &ChangeKind::State(TaskState::Done)),
+                ("M-001.1", &ChangeKind::State(TaskState::Done)),
+                ("M-002", &ChangeKind::Edited),
+                ("M-005", &ChangeKind::Added),
+                ("M-003", &ChangeKind::Moved(SectionKind::Parked)),
+                ("M-004", &ChangeKind::Removed),
+            ]
+        );
+        let workflow = WorkflowConfig::default();
+        assert_eq!(changes[0].describe(&workflow), "M-001 \u{2192} done");
+        assert_eq!(changes[4].describe(&workflow), "M-003 \u{2192} parked");
+    }
+
+    #[test]
+    fn an_unchanged_track_has_no_changes() {
+        let track = parse_track(BEFORE);
+        assert!(diff_tracks("main", &track, &parse_track(BEFORE)).is_empty());
+    }
+
+    #[test]
+    fn removed_tasks_are_logged_but_not_highlighted() {
+        let mut log = ActivityLog::default();
+        log.record(diff_tracks(
+            "main",
+            &parse_track(BEFORE),
+            &parse_track(AFTER),
+        ));
+        assert_eq!(log.changes.len(), 6);
+        assert!(log.is_highlighted("M-005"));
+        assert!(log.is_highlighted("M-001.1"));
+        assert!(!log.is_highlighted("M-004"));
+    }
+}
@@ -4,8 +4,9 @@ use std::path::{Path, PathBuf};
 use std::time::{Duration, Instant, SystemTime};
 
 use crossterm::event::{
-    self, DisableBracketedPaste, EnableBracketedPaste, Event, KeyEventKind,
-    KeyboardEnhancementFlags, PopKeyboardEnhancementFlags, PushKeyboardEnhancementFlags,
+    self, DisableBracketedPaste, DisableMouseCapture, EnableBracketedPaste, EnableMouseCapture,
+    Event, KeyEventKind, KeyboardEnhancementFlags, PopKeyboardEnhancementFlags,
+    PushKeyboardEnhancementFlags,
 };
 use crossterm::execute;
 use crossterm::terminal::{
@@ -13,20 +14,28 @@ use crossterm::terminal::{
 };
 use ratatui::Terminal;
 use ratatui::backend::CrosstermBackend;
+use ratatui::layout::Rect;
 use ratatui::text::Line;
 
 use regex::Regex;
 
 use crate::io::lock::FileLock;
-use crate::io::project_io::{self, discover_project, load_project, project_at};
+use crate::io::project_io::{self, discover_project, load_project_with, project_at};
 use crate::io::watcher::{FileEvent, FrameWatcher};
+use crate::model::config::Swimlanes;
 use crate::model::{Metadata, Project, SectionKind, Task, TaskState, Track};
-use crate::parse::{parse_inbox, parse_track};
+use crate::parse::{Schema, parse_inbox, parse_track_with};
 
+use super::activity::{ActivityLog, ActivityPanelState, ExternalChange};
+use super::dashboard::DashboardState;
+use super::graph::GraphLayout;
 use super::input;
+use super::jumps::{Direction, JumpList, Location, MarkPending, Marks};
+use super::keymap::Keymap;
 use super::render;
 use super::theme::Theme;
 use super::undo::{Operation, UndoStack};
+use super::undo_history::UndoHistoryState;
 
 /// Which view is currently displayed
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -41,10 +50,15 @@ pub enum View {
     Inbox,
     /// Recently completed tasks
     Recent,
+    /// Activity over time: resolved-per-day heatmap, weekly trend, track
+    /// progress and top tags
+    Stats,
     /// Detail view for a single task
     Detail { track_id: String, task_id: String },
     /// Project-wide search results
     Search,
+    /// Dependency graph of a track or the whole project
+    Graph,
 }
 
 /// Which column the cursor is in on the board view
@@ -52,24 +66,30 @@ pub enum View {
 pub enum BoardColumn {
     Ready,
     InProgress,
+    /// A `[workflow.states]` state, one column each, between In Progress and
+    /// Done in the order project.toml declares them.
+    State(TaskState),
     Done,
+    /// `[[board.columns]]` entry `n`. A project that defines its own columns
+    /// has only these.
+    Config(usize),
 }
 
-impl BoardColumn {
-    pub fn index(self) -> usize {
-        match self {
-            BoardColumn::Ready => 0,
-            BoardColumn::InProgress => 1,
-            BoardColumn::Done => 2,
-        }
-    }
+/// What puts a task in a board column, and what moving a card onto it sets.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ColumnRule {
+    /// `None` takes any state but done and parked.
+    pub state: Option<TaskState>,
+    pub tag: Option<String>,
+}
 
-    pub fn from_index(i: usize) -> Self {
-        match i {
-            0 => BoardColumn::Ready,
-            1 => BoardColumn::InProgress,
-            _ => BoardColumn::Done,
-        }
+impl ColumnRule {
+    fn matches(&self, state: TaskState, tags: &[String]) -> bool {
+        let state_ok = match self.state {
+            Some(wanted) => wanted == state,
+            None => !matches!(state, TaskState::Done | TaskState::Parked),
+        };
+        state_ok && self.tag.as_ref().is_none_or(|tag| tags.contains(tag))
     }
 }
 
@@ -83,9 +103,8 @@ pub enum BoardMode {
 /// A single item in a board column's flat list
 #[derive(Debug, Clone)]
 pub enum BoardItem {
-    TrackHeader {
-        track_name: String,
-    },
+    /// The start of a swimlane: a track's name, or a tag
+    LaneHeader { name: String },
     Task {
         track_id: String,
         task_id: String,
@@ -100,10 +119,10 @@ pub enum BoardItem {
 #[derive(Debug, Clone)]
 pub struct BoardState {
     pub focus_column: BoardColumn,
-    /// Cursor index within each column (independent)
-    pub cursor: [usize; 3],
-    /// Scroll offset for each column (independent)
-    pub scroll: [usize; 3],
+    /// Cursor index within each column (independent), by column index
+    pub cursor: Vec<usize>,
+    /// Scroll offset for each column (independent), by column index
+    pub scroll: Vec<usize>,
     pub mode: BoardMode,
     /// Number of visible columns in the current layout (set by renderer)
     pub visible_columns: usize,
@@ -112,6 +131,42 @@ pub struct BoardState {
     pub column_pins: Vec<BoardColumnPin>,
 }
 
+impl BoardState {
+    /// The cursor in column `col`; 0 for a column not visited yet.
+    pub fn cursor(&self, col: usize) -> usize {
+        self.cursor.get(col).copied().unwrap_or(0)
+    }
+
+    pub fn set_cursor(&mut self, col: usize, pos: usize) {
+        if self.cursor.len() <= col {
+            self.cursor.resize(col + 1, 0);
+        }
+        self.cursor[col] = pos;
+    }
+
+    pub fn scroll_mut(&mut self, col: usize) -> &mut usize {
+        if self.scroll.len() <= col {
+            self.scroll.resize(col + 1, 0);
+        }
+        &mut self.scroll[col]
+    }
+}
+
+/// What a mouse click at a screen cell lands on. The renderer records one per
+/// tab and visible row as it draws, so a click goes where the frame showed it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Hit {
+    /// A tab bar entry: the view it switches to
+    Tab(View),
+    /// Row `n` of a list view, in its cursor's terms: the track view's flat
+    /// index, or the Inbox, Recent or Search cursor
+    Row(usize),
+    /// Item `row` of board column `column` (an index into [`App::board_columns`])
+    Card { column: usize, row: usize },
+    /// Anywhere in board column `column`, for dropping a dragged card
+    Column(usize),
+}
+
 /// Keeps a task visually pinned to its current board column during the grace period
 /// after a state change (e.g. Todo→Active stays in Ready column briefly).
 #[derive(Debug, Clone)]
@@ -135,8 +190,14 @@ pub enum DetailRegion {
     Added,
     Resolved,
     Deps,
+    /// `relates:`, `duplicates:`, `supersedes:` and `epic:`, read-only like
+    /// [`DetailRegion::Fields`]: `fr relate` writes them.
+    Relations,
     Spec,
     Refs,
+    /// Custom `key: value` fields, all of them in one read-only row group.
+    /// `fr set` edits them; the schema that would type an editor is config.
+    Fields,
     Note,
     Subtasks,
 }
@@ -149,6 +210,8 @@ impl DetailRegion {
             DetailRegion::Conflict
                 | DetailRegion::Added
                 | DetailRegion::Resolved
+                | DetailRegion::Relations
+                | DetailRegion::Fields
                 | DetailRegion::Subtasks
         )
     }
@@ -195,6 +258,18 @@ pub struct SearchResults {
     pub return_view: View,
 }
 
+/// Dependency graph view state
+#[derive(Debug, Clone)]
+pub struct GraphViewState {
+    /// The track drawn, or `None` for every active track
+    pub track_id: Option<String>,
+    /// The selected task's id
+    pub cursor: Option<String>,
+    pub scroll_x: usize,
+    pub scroll_y: usize,
+    pub return_view: View,
+}
+
 /// Inline edit history for undo/redo within an editing session
 #[derive(Debug, Clone, Default)]
 pub struct EditHistory {
@@ -1015,8 +1090,8 @@ pub enum FlatItem {
         /// (dimmed, non-selectable, cursor skips over it)
         is_context: bool,
     },
-    /// The "── Parked ──" separator
-    ParkedSeparator,
+    /// The "── Parked ──" separator, or a custom section's
+    SectionSeparator(SectionKind),
     /// Stand-in row during bulk move showing "━━━ N tasks ━━━"
     BulkMoveStandin { count: usize },
     /// Summary row showing "X/Y done" for hidden done subtasks
@@ -1328,6 +1403,11 @@ fn is_permanent(error: &str) -> bool {
         || e.contains("not found")
 }
 
+/// The split layout's list pane share, in percent: default and bounds.
+pub const SPLIT_RATIO_DEFAULT: u16 = 50;
+pub const SPLIT_RATIO_MIN: u16 = 20;
+pub const SPLIT_RATIO_MAX: u16 = 80;
+
 /// Main application state
 pub struct App {
     pub project: Project,
@@ -1337,6 +1417,9 @@ pub struct App {
     /// Set to true after a project switch so the event loop can reinitialize the file watcher
     pub watcher_needs_restart: bool,
     pub theme: Theme,
+    /// `[keys]`, resolved. `run` refuses to start on a table that does not
+    /// resolve, so the default here only stands in for tests.
+    pub keymap: Keymap,
     /// This clone's actor token as read from `.actor` at startup (non-claiming):
     /// `Some("a")` tokened, `Some("null")` primary, `None` unclaimed. Display
     /// only — surfaced compactly on the Tracks overview header.
@@ -1448,6 +1531,13 @@ pub struct App {
     /// parsed only when a merge actually runs, so the ordinary case costs one
     /// `String` per track and no parse.
     pub baselines: HashMap<SaveTarget, String>,
+    /// The user config `project.config` has layered over it, `None` for none.
+    ///
+    /// Read with the project and kept, so that a config the session reads back
+    /// is layered the way the one it started with was — and so that a write
+    /// can take the layer back out: [`Self::project_config`] is what goes into
+    /// `project.toml`, never `project.config` itself.
+    pub user_config: Option<toml::Table>,
     /// True while [`App::with_project_lock`] is holding the project lock.
     ///
     /// `FileLock` is not re-entrant — it is an `flock` on a second open file
@@ -1463,6 +1553,12 @@ pub struct App {
     pub autocomplete: Option<AutocompleteState>,
     /// Screen position (x, y) where the edit text area starts, used to anchor autocomplete dropdown
     pub autocomplete_anchor: Option<(u16, u16)>,
+    /// Click targets from the last frame drawn, later entries on top
+    pub hits: Vec<(Rect, Hit)>,
+    /// The last left click and when it came, for telling a double click
+    pub last_click: Option<(Instant, Hit)>,
+    /// The board column a card is being dragged from
+    pub board_drag: Option<usize>,
     /// Inline edit history for undo/redo within an editing session
     pub edit_history: Option<EditHistory>,
     /// Selection anchor for text selection in edit mode (None = no selection)
@@ -1471,6 +1567,9 @@ pub struct App {
     /// True when in edit mode for a new subtask and no character has been typed yet.
     /// Used to detect `-` as first keystroke for outdent behavior.
     pub edit_is_fresh: bool,
+    /// The `[templates.<name>]` entry "Add from template" picked, applied when
+    /// the new task's title is committed and dropped if it is cancelled.
+    pub new_task_template: Option<String>,
     /// Desired position (among active tracks) for new track insertion.
     /// Set by tracks_add_track / tracks_prepend / tracks_insert_after;
     /// consumed by the NewTrackName confirm handler.
@@ -1501,6 +1600,8 @@ pub struct App {
     pub filter_state: FilterState,
     /// True when 'f' prefix key has been pressed, waiting for second key
     pub filter_pending: bool,
+    /// Set when `B` or `'` has been pressed, waiting for the mark's letter
+    pub mark_pending: Option<MarkPending>,
     /// Selected task IDs in SELECT mode (empty = not in select mode)
     pub selection: HashSet<String>,
     /// Anchor flat-item index for V range select preview (None = not in range select mode)
@@ -1517,6 +1618,18 @@ pub struct App {
     pub prefix_rename: Option<PrefixRenameState>,
     /// Project picker popup state
     pub project_picker: Option<ProjectPickerState>,
+    /// Multi-project dashboard state
+    pub dashboard: Option<DashboardState>,
+    /// Tasks changed on disk by someone else this session
+    pub activity: ActivityLog,
+    /// Activity panel state (overlay listing [`Self::activity`])
+    pub activity_panel: Option<ActivityPanelState>,
+    /// Undo history overlay state
+    pub undo_history: Option<UndoHistoryState>,
+    /// Places jumped away from this session, for `Ctrl+O`/`Ctrl+I`
+    pub jumps: JumpList,
+    /// Named marks (`B a` sets, `' a` jumps; persisted in .state.json)
+    pub marks: Marks,
     /// Debug mode: show raw KeyEvent info in status row
     pub key_debug: bool,
     /// Last raw KeyEvent description (for debug display)
@@ -1533,6 +1646,10 @@ pub struct App {
     pub show_startup_hints: bool,
     /// Effective note wrap setting (override > config > true)
     pub note_wrap: bool,
+    /// Split layout: the Track view beside a detail pane that follows its cursor
+    pub split_pane: bool,
+    /// The list pane's share of the split, in percent
+    pub split_ratio: u16,
     /// Whether to show the recovery log overlay
     pub show_recovery_log: bool,
     /// Scroll offset for recovery log overlay
@@ -1560,6 +1677,11 @@ pub struct App {
 
     /// Project-wide search results (active when in View::Search or after jumping from it)
     pub project_search_results: Option<SearchResults>,
+    /// The dependency graph view's state, while it is open
+    pub graph_view: Option<GraphViewState>,
+    /// The Stats view's counts, gathered when it opens or the project
+    /// reloads rather than every frame, since they read every archive
+    pub stats_activity: Option<crate::ops::stats::Activity>,
     /// History of project search queries (most recent first, max 200)
     pub project_search_history: Vec<String>,
     /// Current project search input text
@@ -1585,6 +1707,7 @@ impl App {
             .collect();
 
         let theme = Theme::from_config(&project.config.ui);
+        let keymap = Keymap::from_config(&project.config.keys).unwrap_or_default();
         let note_wrap = project.config.ui.note_wrap;
 
         // Read-only: surface which clone we're on; never claims a token.
@@ -1629,6 +1752,7 @@ impl App {
             should_quit: false,
             watcher_needs_restart: false,
             theme,
+            keymap,
             actor_token,
             worktree_label: None,
             active_track_ids,
@@ -1672,13 +1796,18 @@ impl App {
             frame_unwritable: false,
             project_gone_since: None,
             baselines: HashMap::new(),
+            user_config: None,
             detail_state: None,
             detail_stack: Vec::new(),
             autocomplete: None,
             autocomplete_anchor: None,
+            hits: Vec::new(),
+            last_click: None,
+            board_drag: None,
             edit_history: None,
             edit_selection_anchor: None,
             edit_is_fresh: false,
+            new_task_template: None,
             new_track_insert_pos: None,
             triage_state: None,
             confirm_state: None,
@@ -1693,6 +1822,7 @@ impl App {
             recent_expanded: HashSet::new(),
             filter_state: FilterState::default(),
             filter_pending: false,
+            mark_pending: None,
             selection: HashSet::new(),
             range_anchor: None,
             last_action: None,
@@ -1701,6 +1831,12 @@ impl App {
             tag_color_popup: None,
             prefix_rename: None,
             project_picker: None,
+            dashboard: None,
+            activity: ActivityLog::default(),
+            activity_panel: None,
+            undo_history: None,
+            jumps: JumpList::default(),
+            marks: Marks::new(),
             key_debug: false,
             last_key_event: None,
             kitty_enabled: false,
@@ -1709,6 +1845,8 @@ impl App {
             tab_scroll: 0,
             show_startup_hints: true,
             note_wrap,
+            split_pane: false,
+            split_ratio: SPLIT_RATIO_DEFAULT,
             show_recovery_log: false,
             recovery_log_scroll: 0,
             recovery_log_lines: Vec::new(),
@@ -1720,6 +1858,8 @@ impl App {
             results_overlay_lines: Vec::new(),
             results_overlay_scroll: 0,
             project_search_results: None,
+            graph_view: None,
+            stats_activity: None,
             project_search_history: Vec::new(),
             project_search_input: String::new(),
             project_search_history_index: None,
@@ -1727,8 +1867,8 @@ impl App {
             project_search_active: false,
             board_state: BoardState {
                 focus_column: BoardColumn::Ready,
-                cursor: [0; 3],
-                scroll: [0; 3],
+                cursor: Vec::new(),
+                scroll: Vec::new(),
                 mode: BoardMode::Cc,
                 visible_columns: 3,
                 column_pins: Vec::new(),
@@ -1869,116 +2009,215 @@ impl App {
         result
     }
 
-    /// Build the three board columns: [Ready, InProgress, Done]
-    pub fn build_board_columns(&self) -> [Vec<BoardItem>; 3] {
+    /// The Board view's columns, left to right: the `[[board.columns]]` if the
+    /// project has any, otherwise Ready, In Progress, one per
+    /// `[workflow.states]` state, and Done.
+    ///
+    /// A `[[board.columns]]` done column is left out when
+    /// `ui.board_done_days` is 0; the built-in Done, always last, is left to
+    /// the renderer to hide.
+    pub fn board_columns(&self) -> Vec<BoardColumn> {
+        let config = &self.project.config;
+        if config.board.columns.is_empty() {
+            let mut columns = vec![BoardColumn::Ready, BoardColumn::InProgress];
+            columns.extend(
+                config
+                    .workflow
+                    .states
+                    .values()
+                    .map(|s| BoardColumn::State(TaskState::Custom(s.checkbox))),
+            );
+            columns.push(BoardColumn::Done);
+            return columns;
+        }
+        (0..config.board.columns.len())
+            .map(BoardColumn::Config)
+            .filter(|&c| {
+                config.ui.board_done_days > 0
+                    || self
+                        .board_column_rule(c)
+                        .is_none_or(|rule| rule.state != Some(TaskState::Done))
+            })
+            .collect()
+    }
+
+    /// What a board column holds. `None` for a `[[board.columns]]` entry
+    /// naming a state the project no longer has: it holds nothing, and takes
+    /// no cards.
+    pub fn board_column_rule(&self, column: BoardColumn) -> Option<ColumnRule> {
+        let state = |state| ColumnRule {
+            state: Some(state),
+            tag: None,
+        };
+        Some(match column {
+            BoardColumn::Ready => state(TaskState::Todo),
+            BoardColumn::InProgress => state(TaskState::Active),
+            BoardColumn::State(custom) => state(custom),
+            BoardColumn::Done => state(TaskState::Done),
+            BoardColumn::Config(i) => {
+                let config = self.project.config.board.columns.get(i)?;
+                let state = match &config.state {
+                    Some(name) => Some(self.project.config.workflow.state_named(name)?),
+                    None => None,
+                };
+                ColumnRule {
+                    state,
+                    tag: config.tag().map(str::to_string),
+                }
+            }
+        })
+    }
+
+    /// The focused column's index into [`App::board_columns`].
+    pub fn board_focus_index(&self) -> usize {
+        self.board_columns()
+            .iter()
+            .position(|c| *c == self.board_state.focus_column)
+            .unwrap_or(0)
+    }
+
+    /// Build the board columns, in [`App::board_columns`] order.
+    ///
+    /// A task goes in the first column that takes it, trying the columns that
+    /// name a tag before the ones that name only a state, so that a `#review`
+    /// column can take review work out of In Progress. A todo column takes
+    /// ready tasks only: todo, with every dep done, and in `## Backlog` — a
+    /// todo task in a custom section such as `## Icebox` has been put aside
+    /// on purpose. A done column takes what was finished in the last
+    /// `ui.board_done_days`, newest first and without lanes.
+    pub fn build_board_columns(&self) -> Vec<Vec<BoardItem>> {
         let cc_mode = self.board_state.mode == BoardMode::Cc;
         let tag_filter = self.filter_state.tag_filter.as_deref();
         let done_days = self.project.config.ui.board_done_days;
+        let lanes = &self.project.config.board.swimlanes;
+
+        let kinds = self.board_columns();
+        let rules: Vec<Option<ColumnRule>> =
+            kinds.iter().map(|&k| self.board_column_rule(k)).collect();
+        let order: Vec<usize> = {
+            let (tagged, untagged): (Vec<usize>, Vec<usize>) = (0..kinds.len())
+                .filter(|&i| rules[i].is_some())
+                .partition(|&i| rules[i].as_ref().is_some_and(|r| r.tag.is_some()));
+            tagged.into_iter().chain(untagged).collect()
+        };
+        // Each column's cards with their lane, and the done columns' with
+        // their resolved date
+        let mut open: Vec<Vec<(usize, String, BoardItem)>> = vec![Vec::new(); kinds.len()];
+        let mut done_items: Vec<(usize, String, BoardItem)> = Vec::new();
 
-        let mut ready: Vec<BoardItem> = Vec::new();
-        let mut in_progress: Vec<BoardItem> = Vec::new();
-        let mut done_items: Vec<(String, BoardItem)> = Vec::new(); // (resolved_date, item)
-
-        for track_id in &self.active_track_ids {
+        for (track_idx, track_id) in self.active_track_ids.iter().enumerate() {
             let track = match Self::find_track_in_project(&self.project, track_id) {
                 Some(t) => t,
                 None => continue,
             };
             let track_name = self.track_name(track_id).to_string();
+            let lane_of = |tags: &[String]| -> Option<(usize, String)> {
+                match lanes {
+                    Swimlanes::Track => Some((track_idx, track_name.clone())),
+                    Swimlanes::Off => None,
+                    Swimlanes::Tags(lane_tags) => Some(
+                        lane_tags
+                            .iter()
+                            .position(|t| tags.contains(t))
+                            .map_or((lane_tags.len(), "other".into()), |i| {
+                                (i, format!("#{}", lane_tags[i]))
+                            }),
+                    ),
+                }
+            };
 
-            let mut has_ready = false;
-            let mut has_active = false;
-
-            for top_task in track.backlog() {
-                for task in Self::flatten_board_tasks(top_task) {
-                    let task_id = match &task.id {
-                        Some(id) => id.to_string(),
-                        None => continue,
-                    };
+            let open_sections = std::iter::once(SectionKind::Backlog).chain(
+                track
+                    .section_kinds()
+                    .into_iter()
+                    .filter(|kind| matches!(kind, SectionKind::Custom(_) | SectionKind::Parked)),
+            );
+            for section in open_sections {
+                for top_task in track.section_tasks(section) {
+                    for task in Self::flatten_board_tasks(top_task) {
+                        let task_id = match &task.id {
+                            Some(id) => id.to_string(),
+                            None => continue,
+                        };
+                        // `## Parked` is on the board for its parked tasks only
+                        if section == SectionKind::Parked && task.state != TaskState::Parked {
+                            continue;
+                        }
 
-                    // task.id already carries the track prefix (e.g. "ST-001"),
-                    // so render it directly without re-prefixing.
-                    let id_display = task_id.clone();
+                        // task.id already carries the track prefix (e.g. "ST-001"),
+                        // so render it directly without re-prefixing.
+                        let id_display = task_id.clone();
 
-                    // Apply tag filter
-                    if let Some(tf) = tag_filter
-                        && !task.tags.iter().any(|t| t == tf)
-                    {
-                        continue;
-                    }
+                        // Apply tag filter
+                        if let Some(tf) = tag_filter
+                            && !task.tags.iter().any(|t| t == tf)
+                        {
+                            continue;
+                        }
 
-                    // Check if this task has a column pin (board grace period) or
-                    // a pending section move. Either keeps the task in its original column.
-                    let pin = self
-                        .board_state
-                        .column_pins
-                        .iter()
-                        .find(|p| p.track_id == *track_id && p.task_id == task_id);
-
-                    let pending_move = self
-                        .pending_moves
-                        .iter()
-                        .find(|pm| pm.track_id == *track_id && pm.task_id == task_id);
-
-                    let effective_state = if let Some(p) = pin {
-                        p.pinned_state
-                    } else {
-                        match pending_move {
-                            Some(pm) if pm.settles_out_of_backlog() => {
-                                pm.old_state.unwrap_or(task.state)
+                        // Check if this task has a column pin (board grace period) or
+                        // a pending section move. Either keeps the task in its original column.
+                        let pin = self
+                            .board_state
+                            .column_pins
+                            .iter()
+                            .find(|p| p.track_id == *track_id && p.task_id == task_id);
+
+                        let pending_move = self
+                            .pending_moves
+                            .iter()
+                            .find(|pm| pm.track_id == *track_id && pm.task_id == task_id);
+
+                        let effective_state = if let Some(p) = pin {
+                            p.pinned_state
+                        } else {
+                            match pending_move {
+                                Some(pm) if pm.settles_out_of_backlog() => {
+                                    pm.old_state.unwrap_or(task.state)
+                                }
+                                _ => task.state,
                             }
-                            _ => task.state,
+                        };
+                        if effective_state == TaskState::Done {
+                            continue;
                         }
-                    };
-
-                    match effective_state {
-                        TaskState::Todo => {
-                            // Check all deps resolved (skip for pending-move tasks, they were already shown)
-                            if pin.is_none()
-                                && pending_move.is_none()
-                                && !self.all_deps_resolved(task)
-                            {
-                                continue;
-                            }
-                            // CC mode filter
-                            if cc_mode && !task.tags.iter().any(|t| t == "cc") {
-                                continue;
-                            }
-                            if !has_ready {
-                                ready.push(BoardItem::TrackHeader {
-                                    track_name: track_name.clone(),
-                                });
-                                has_ready = true;
+
+                        let column = order.iter().copied().find(|&i| {
+                            let Some(rule) = &rules[i] else {
+                                return false;
+                            };
+                            if !rule.matches(effective_state, &task.tags) {
+                                return false;
                             }
-                            ready.push(BoardItem::Task {
-                                track_id: track_id.clone(),
-                                task_id: task_id.clone(),
-                                title: task.title.clone(),
-                                id_display,
-                                state: task.state,
-                                tags: task.tags.clone(),
-                            });
+                            // Ready: skip the checks for pinned and pending-move
+                            // tasks, they were already shown
+                            rule.state != Some(TaskState::Todo)
+                                || (section == SectionKind::Backlog
+                                    && (pin.is_some()
+                                        || pending_move.is_some()
+                                        || self.all_deps_resolved(task)))
+                        });
+                        // An undeclared custom state has no column
+                        let Some(col) = column else {
+                            continue;
+                        };
+                        // CC mode filter
+                        if cc_mode && !task.tags.iter().any(|t| t == "cc") {
+                            continue;
                         }
-                        TaskState::Active => {
-                            if cc_mode && !task.tags.iter().any(|t| t == "cc") {
-                                continue;
-                            }
-                            if !has_active {
-                                in_progress.push(BoardItem::TrackHeader {
-                                    track_name: track_name.clone(),
-                                });
-                                has_active = true;
-                            }
-                            in_progress.push(BoardItem::Task {
+                        let (lane, lane_name) = lane_of(&task.tags).unwrap_or_default();
+                        open[col].push((
+                            lane,
+                            lane_name,
+                            BoardItem::Task {
                                 track_id: track_id.clone(),
                                 task_id: task_id.clone(),
                                 title: task.title.clone(),
                                 id_display,
                                 state: task.state,
                                 tags: task.tags.clone(),
-                            });
-                        }
-                        _ => {}
+                            },
+                        ));
                     }
                 }
             }
@@ -2014,6 +2253,14 @@ impl App {
                             continue;
                         }
 
+                        let Some(col) = order.iter().copied().find(|&i| {
+                            rules[i]
+                                .as_ref()
+                                .is_some_and(|rule| rule.matches(TaskState::Done, &task.tags))
+                        }) else {
+                            continue;
+                        };
+
                         // Check resolved date within done_days
                         let resolved_date = task.metadata.iter().find_map(|m| {
                             if let Metadata::Resolved(d) = m {
@@ -2036,6 +2283,7 @@ impl App {
                         let id_display = task_id.clone();
 
                         done_items.push((
+                            col,
                             resolved_str,
                             BoardItem::Task {
                                 track_id: track_id.clone(),
@@ -2051,11 +2299,31 @@ impl App {
             }
         }
 
-        // Sort done items by resolved date descending
-        done_items.sort_by(|a, b| b.0.cmp(&a.0));
-        let done: Vec<BoardItem> = done_items.into_iter().map(|(_, item)| item).collect();
+        // Lanes in order, each under its header. The sort is stable, so the
+        // cards within a lane keep their track order.
+        let mut columns: Vec<Vec<BoardItem>> = open
+            .into_iter()
+            .map(|mut cards| {
+                cards.sort_by_key(|(lane, _, _)| *lane);
+                let mut items = Vec::new();
+                let mut current: Option<usize> = None;
+                for (lane, lane_name, item) in cards {
+                    if *lanes != Swimlanes::Off && current != Some(lane) {
+                        items.push(BoardItem::LaneHeader { name: lane_name });
+                        current = Some(lane);
+                    }
+                    items.push(item);
+                }
+                items
+            })
+            .collect();
 
-        [ready, in_progress, done]
+        // Sort done items by resolved date descending
+        done_items.sort_by(|a, b| b.1.cmp(&a.1));
+        for (col, _, item) in done_items {
+            columns[col].push(item);
+        }
+        columns
     }
 
     /// Check if all dependency targets of a task are done
@@ -2097,10 +2365,10 @@ impl App {
 
     /// Get the (track_id, task_id) at the current board cursor position
     pub fn board_cursor_task_id(&self) -> Option<(String, String)> {
-        let col_idx = self.board_state.focus_column.index();
+        let col_idx = self.board_focus_index();
         let columns = self.build_board_columns();
-        let column = &columns[col_idx];
-        let cursor = self.board_state.cursor[col_idx];
+        let column = columns.get(col_idx)?;
+        let cursor = self.board_state.cursor(col_idx);
         match column.get(cursor) {
             Some(BoardItem::Task {
                 track_id, task_id, ..
@@ -2111,8 +2379,10 @@ impl App {
 
     /// Count selectable tasks (excludes headers) in a board column
     pub fn board_task_count(&self, columns: &[Vec<BoardItem>], col: BoardColumn) -> usize {
-        columns[col.index()]
-            .iter()
+        let idx = self.board_columns().iter().position(|c| *c == col);
+        idx.and_then(|i| columns.get(i))
+            .into_iter()
+            .flatten()
             .filter(|item| matches!(item, BoardItem::Task { .. }))
             .count()
     }
@@ -2154,6 +2424,47 @@ impl App {
         self.note_wrap = !self.note_wrap;
     }
 
+    /// Turn the split layout on or off
+    pub fn toggle_split(&mut self) {
+        self.split_pane = !self.split_pane;
+        self.status_message = Some(
+            if self.split_pane {
+                "split: on"
+            } else {
+                "split: off"
+            }
+            .into(),
+        );
+    }
+
+    /// Widen (positive) or narrow the list pane of the split by `delta` percent
+    pub fn resize_split(&mut self, delta: i16) {
+        if !self.split_pane {
+            return;
+        }
+        self.split_ratio = self
+            .split_ratio
+            .saturating_add_signed(delta)
+            .clamp(SPLIT_RATIO_MIN, SPLIT_RATIO_MAX);
+    }
+
+    /// The track whose list the split layout shows, when it applies: the Track
+    /// view itself, or a Detail view opened from it, where the detail pane has
+    /// the focus.
+    pub fn split_track(&self) -> Option<usize> {
+        if !self.split_pane {
+            return None;
+        }
+        match &self.view {
+            View::Track(idx) => Some(*idx),
+            View::Detail { .. } => match self.detail_state.as_ref()?.return_view {
+                ReturnView::Track(idx) => Some(idx),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
     /// Start flashing a task (highlight after undo/redo navigation)
     pub fn flash_task(&mut self, task_id: &str) {
         self.flash_task_id = Some(task_id.to_string());
@@ -2632,6 +2943,91 @@ impl App {
             {
                 let state = self.get_track_state(&target_track_id);
                 state.cursor = i;
+                self.jumps.jumped = true;
+                return true;
+            }
+        }
+        false
+    }
+
+    /// Where the cursor is, as the jump list keeps it. `None` in the views
+    /// that are lenses on another (Search, Graph), which the list skips.
+    pub fn location(&self) -> Option<Location> {
+        Some(match &self.view {
+            View::Track(idx) => Location::Track {
+                track_id: self.active_track_ids.get(*idx)?.clone(),
+                task_id: self.cursor_task_id().map(|(_, task_id, _)| task_id),
+            },
+            View::Detail { track_id, task_id } => Location::Detail {
+                track_id: track_id.clone(),
+                task_id: task_id.clone(),
+            },
+            View::Tracks => Location::Tracks,
+            View::Board => Location::Board,
+            View::Inbox => Location::Inbox,
+            View::Recent => Location::Recent,
+            View::Stats => Location::Stats,
+            View::Search | View::Graph => return None,
+        })
+    }
+
+    /// Take the cursor to a place from the jump list. False when its track is
+    /// no longer active, or its detail's task is gone; a list place whose task
+    /// is gone still opens its track.
+    pub fn go_to(&mut self, location: &Location) -> bool {
+        let track_idx = match location {
+            Location::Track { track_id, .. } | Location::Detail { track_id, .. } => {
+                self.active_track_ids.iter().position(|id| id == track_id)
+            }
+            _ => None,
+        };
+        let view = match (location, track_idx) {
+            (Location::Track { .. } | Location::Detail { .. }, None) => return false,
+            (Location::Detail { track_id, task_id }, Some(_))
+                if Self::find_track_in_project(&self.project, track_id)
+                    .and_then(|t| crate::ops::task_ops::find_task_in_track(t, task_id))
+                    .is_none() =>
+            {
+                return false;
+            }
+            (_, Some(idx)) => View::Track(idx),
+            (Location::Tracks, _) => View::Tracks,
+            (Location::Board, _) => View::Board,
+            (Location::Inbox, _) => View::Inbox,
+            (Location::Recent, _) => View::Recent,
+            (Location::Stats, _) => View::Stats,
+        };
+        self.close_detail_fully();
+        self.project_search_results = None;
+        if view == View::Tracks {
+            self.tracks_name_col_min = 0;
+        }
+        self.view = view;
+        match location {
+            Location::Track {
+                task_id: Some(task_id),
+                ..
+            } => {
+                self.jump_to_task(task_id);
+            }
+            Location::Detail { track_id, task_id } => {
+                self.open_detail(track_id.clone(), task_id.clone());
+            }
+            _ => {}
+        }
+        true
+    }
+
+    /// `Ctrl+O`/`Ctrl+I`: go to the nearest place on the list that way, past
+    /// any that no longer exist. False when there is none.
+    pub fn travel(&mut self, direction: Direction) -> bool {
+        let here = self.location();
+        while let Some(to) = self.jumps.pop(direction) {
+            if Some(&to) != here.as_ref() && self.go_to(&to) {
+                if let Some(here) = here {
+                    self.jumps.arrived(direction, here);
+                }
+                self.jumps.travelled = true;
                 return true;
             }
         }
@@ -2665,35 +3061,68 @@ impl App {
         }
     }
 
-    /// Build the inverse dependency index: for each task ID, which tasks depend on it.
-    pub fn build_dep_index(project: &Project) -> HashMap<String, Vec<String>> {
-        let mut index: HashMap<String, Vec<String>> = HashMap::new();
-        for (_, track) in &project.tracks {
-            for node in &track.nodes {
-                if let crate::model::TrackNode::Section { tasks, .. } = node {
-                    Self::collect_deps_recursive(tasks, &mut index);
-                }
-            }
-        }
-        index
+    /// Open the dependency graph on the current view's track — the whole
+    /// project from a view without one — with the task under the cursor
+    /// selected.
+    pub fn open_graph(&mut self) {
+        let (track_id, cursor) = match &self.view {
+            View::Track(idx) => (
+                self.active_track_ids.get(*idx).cloned(),
+                self.cursor_task_id().map(|(_, task_id, _)| task_id),
+            ),
+            View::Detail { track_id, task_id } => (Some(track_id.clone()), Some(task_id.clone())),
+            View::Board => (None, self.board_cursor_task_id().map(|(_, id)| id)),
+            View::Graph => return,
+            _ => (None, None),
+        };
+        self.graph_view = Some(GraphViewState {
+            track_id,
+            cursor,
+            scroll_x: 0,
+            scroll_y: 0,
+            return_view: self.view.clone(),
+        });
+        self.view = View::Graph;
+        self.graph_select_default();
     }
 
-    fn collect_deps_recursive(tasks: &[Task], index: &mut HashMap<String, Vec<String>>) {
-        for task in tasks {
-            if let Some(task_id) = &task.id {
-                for m in &task.metadata {
-                    if let Metadata::Dep(deps) = m {
-                        for dep_id in deps {
-                            index
-                                .entry(dep_id.clone())
-                                .or_default()
-                                .push(task_id.to_string());
-                        }
-                    }
-                }
-            }
-            Self::collect_deps_recursive(&task.subtasks, index);
+    /// Lay out the graph the graph view is showing.
+    pub fn graph_layout(&self) -> GraphLayout {
+        let track_ids: Vec<&str> = match self
+            .graph_view
+            .as_ref()
+            .and_then(|gv| gv.track_id.as_deref())
+        {
+            Some(track_id) => vec![track_id],
+            None => self.active_track_ids.iter().map(String::as_str).collect(),
+        };
+        GraphLayout::build(&self.project, &track_ids)
+    }
+
+    /// Keep the graph's selection on a task that's in it, falling back to the
+    /// top of the leftmost column.
+    pub fn graph_select_default(&mut self) {
+        let layout = self.graph_layout();
+        let Some(gv) = self.graph_view.as_mut() else {
+            return;
+        };
+        if gv
+            .cursor
+            .as_deref()
+            .is_some_and(|id| layout.index_of(id).is_some())
+        {
+            return;
         }
+        gv.cursor = layout
+            .columns
+            .first()
+            .and_then(|column| column.iter().flatten().next())
+            .map(|&node| layout.nodes[node].id.clone());
+    }
+
+    /// Build the inverse dependency index: for each task ID, which tasks depend on it.
+    pub fn build_dep_index(project: &Project) -> HashMap<String, Vec<String>> {
+        crate::ops::deps::dependents_index(project)
     }
 
     /// Open the dep popup for a given task
@@ -2984,7 +3413,7 @@ impl App {
         if let Ok(mtime) = std::fs::metadata(&path).and_then(|m| m.modified()) {
             self.track_mtimes.insert(track_id.to_string(), mtime);
         }
-        let track = parse_track(&text);
+        let track = parse_track_with(&text, &Schema::of(&self.project.config));
         if self.project.tracks.iter().any(|(id, _)| id == track_id) {
             self.replace_track(track_id, track.clone());
         } else if !archived {
@@ -3342,7 +3771,7 @@ impl App {
                     .inbox
                     .as_ref()
                     .map(|i| ("inbox.md".to_string(), crate::parse::serialize_inbox(i))),
-                SaveTarget::Config => toml::to_string_pretty(&self.project.config)
+                SaveTarget::Config => toml::to_string_pretty(&self.project_config())
                     .ok()
                     .map(|text| ("project.toml".to_string(), text)),
             };
@@ -3567,6 +3996,26 @@ impl App {
         }
     }
 
+    /// `project.toml`'s text as this session reads it: with
+    /// [`Self::user_config`] layered over it, as `project.config` was.
+    fn layered(&self, text: &str) -> Result<crate::model::ProjectConfig, project_io::ProjectError> {
+        crate::io::user_config::parse_layered_with(text, self.user_config.as_ref())
+    }
+
+    /// The session's config as `project.toml` should hold it: `project.config`
+    /// with the user config taken back out, against the text it was layered
+    /// over. Every write of the config from memory starts here.
+    fn project_config(&self) -> crate::model::ProjectConfig {
+        let Some(user) = &self.user_config else {
+            return self.project.config.clone();
+        };
+        let text = self
+            .baselines
+            .get(&SaveTarget::Config)
+            .map_or("", String::as_str);
+        crate::io::user_config::unlayer(&self.project.config, text, user)
+    }
+
     /// Write `project.toml`. **Assumes the project lock is held.**
     ///
     /// Our changes are applied to the document that is **on disk**, rather than
@@ -3608,7 +4057,11 @@ impl App {
     fn save_config_locked(&mut self) -> Result<(), Box<dyn std::error::Error>> {
         let frame_dir = self.project.frame_dir.clone();
         let ancestor = self.baselines.get(&SaveTarget::Config).cloned();
+        // Every side is the project's own config, memory with the user's layer
+        // taken back out: a tag colour the user config supplies is then in
+        // none of the three, and cannot be written into the file.
         let parse = |text: &str| toml::from_str::<crate::model::ProjectConfig>(text).ok();
+        let ours = self.project_config();
 
         let (base, theirs, mut doc) = match crate::io::config_io::read_config(&frame_dir) {
             // The ancestor falls back to *their* config rather than to a struct
@@ -3639,10 +4092,7 @@ impl App {
                     // No file and no ancestor: the struct is the only copy there
                     // is, so writing it destroys nothing and restores a project
                     // that loads. The one call this fallback still has.
-                    crate::io::config_io::write_config_from_struct(
-                        &frame_dir,
-                        &self.project.config,
-                    )?;
+                    crate::io::config_io::write_config_from_struct(&frame_dir, &ours)?;
                     self.last_save_at = Some(Instant::now());
                     self.frame_unwritable = false;
                     return Ok(());
@@ -3652,8 +4102,7 @@ impl App {
             Err(e) => return Err(e.into()),
         };
 
-        let result =
-            crate::ops::reconcile::reconcile_config(&base, &self.project.config, &theirs, &mut doc);
+        let result = crate::ops::reconcile::reconcile_config(&base, &ours, &theirs, &mut doc);
         crate::io::config_io::write_config(&frame_dir, &doc)?;
         self.last_save_at = Some(Instant::now());
         self.frame_unwritable = false;
@@ -3662,7 +4111,7 @@ impl App {
         // next merge treats as the ancestor. Re-parsing it rather than tracking
         // a merged struct alongside is what keeps the two from drifting.
         let text = doc.to_string();
-        if let Ok(config) = toml::from_str::<crate::model::ProjectConfig>(&text) {
+        if let Ok(config) = self.layered(&text) {
             self.adopt_config(config);
         }
         self.baselines.insert(SaveTarget::Config, text);
@@ -3693,6 +4142,13 @@ impl App {
         let current = self.current_track_id().map(|s| s.to_string());
         self.project.config = config;
         self.theme = Theme::from_config(&self.project.config.ui);
+        match Keymap::from_config(&self.project.config.keys) {
+            Ok(keymap) => self.keymap = keymap,
+            Err(e) => {
+                self.status_message = Some(format!("[keys]: {e} — keeping the old bindings"));
+                self.status_is_error = true;
+            }
+        }
 
         let live: Vec<(String, String)> = self
             .project
@@ -3717,7 +4173,8 @@ impl App {
             if let Ok(mtime) = std::fs::metadata(&path).and_then(|m| m.modified()) {
                 self.track_mtimes.insert(id.clone(), mtime);
             }
-            self.project.tracks.push((id.clone(), parse_track(&text)));
+            let track = parse_track_with(&text, &Schema::of(&self.project.config));
+            self.project.tracks.push((id.clone(), track));
             self.baselines.insert(SaveTarget::Track(id.clone()), text);
         }
 
@@ -4188,8 +4645,9 @@ impl App {
             && let Some(base_text) = self.baselines.get(target).cloned()
             && let Some(ours) = Self::find_track_in_project(&self.project, track_id)
         {
-            let base = parse_track(&base_text);
-            let theirs = parse_track(text);
+            let schema = Schema::of(&self.project.config);
+            let base = parse_track_with(&base_text, &schema);
+            let theirs = parse_track_with(text, &schema);
             let result = crate::ops::reconcile::reconcile_track(&base, ours, &theirs);
 
             for conflict in &result.conflicts {
@@ -4211,7 +4669,9 @@ impl App {
 
             let took = result.took_theirs;
             let deleted = result.deleted;
+            let changes = crate::tui::activity::diff_tracks(track_id, ours, &result.track);
             self.replace_track(track_id, result.track);
+            self.record_external_changes(changes);
             if took > 0 || deleted > 0 {
                 self.announce_merge(target, took + deleted);
             }
@@ -4251,6 +4711,24 @@ impl App {
         );
     }
 
+    /// Log what a reload changed and highlight the rows it touched, saying so
+    /// in the status line unless something there already speaks for it.
+    fn record_external_changes(&mut self, changes: Vec<ExternalChange>) {
+        if changes.is_empty() {
+            return;
+        }
+        if self.status_message.is_none() {
+            self.status_message = Some(match changes.as_slice() {
+                [one] => format!(
+                    "{} (external) \u{00B7} W for activity",
+                    one.describe(&self.project.config.workflow)
+                ),
+                many => format!("{} external changes \u{00B7} W for activity", many.len()),
+            });
+        }
+        self.activity.record(changes);
+    }
+
     /// Tell the user their copy absorbed someone else's changes.
     fn announce_merge(&mut self, target: &SaveTarget, changed: usize) {
         self.status_message = Some(format!(
@@ -4278,13 +4756,14 @@ impl App {
             let Some((base, (theirs, mut doc))) = prepared else {
                 return;
             };
+            // Unlayered like the other two; see `save_config_locked`.
             let result = crate::ops::reconcile::reconcile_config(
                 &base,
-                &self.project.config,
+                &self.project_config(),
                 &theirs,
                 &mut doc,
             );
-            if let Ok(config) = toml::from_str::<crate::model::ProjectConfig>(&doc.to_string()) {
+            if let Ok(config) = self.layered(&doc.to_string()) {
                 self.adopt_config(config);
             }
             // Their version has been dealt with, so it is the ancestor now —
@@ -4301,7 +4780,7 @@ impl App {
         let Ok(text) = std::fs::read_to_string(path) else {
             return;
         };
-        let Ok(config) = toml::from_str::<crate::model::ProjectConfig>(&text) else {
+        let Ok(config) = self.layered(&text) else {
             return;
         };
         self.adopt_config(config);
@@ -4311,6 +4790,7 @@ impl App {
     /// Reload changed files from disk. Returns the edit target's task_id if it was externally modified.
     pub fn reload_changed_files(&mut self, paths: &[std::path::PathBuf]) -> Option<String> {
         let mut edited_task_conflict = None;
+        self.stats_activity = None;
 
         // Determine which task is being edited (if any)
         let editing_task_id = match &self.edit_target {
@@ -4326,6 +4806,9 @@ impl App {
             _ => None,
         };
 
+        // The tracks we held before each reload, to say what it changed
+        let mut replaced: Vec<(String, Track)> = Vec::new();
+
         for path in paths {
             let file_name = match path.file_name().and_then(|n| n.to_str()) {
                 Some(name) => name.to_string(),
@@ -4382,7 +4865,7 @@ impl App {
                     continue;
                 }
 
-                let new_track = parse_track(&text);
+                let new_track = parse_track_with(&text, &Schema::of(&self.project.config));
 
                 // Check if the edited task was modified externally
                 if editing_track_id.as_deref() == Some(&track_id)
@@ -4416,7 +4899,7 @@ impl App {
                     .iter_mut()
                     .find(|(id, _)| id == &track_id)
                 {
-                    entry.1 = new_track;
+                    replaced.push((track_id.clone(), std::mem::replace(&mut entry.1, new_track)));
                 }
                 if let Ok(mtime) = std::fs::metadata(path).and_then(|m| m.modified()) {
                     self.track_mtimes.insert(track_id.clone(), mtime);
@@ -4435,6 +4918,15 @@ impl App {
             self.save_track_logged(track_id);
         }
 
+        // Diffed after the IDs are in, so a task written without one is
+        // still seen as added
+        for (track_id, old) in &replaced {
+            if let Some(new) = Self::find_track_in_project(&self.project, track_id) {
+                let changes = crate::tui::activity::diff_tracks(track_id, old, new);
+                self.record_external_changes(changes);
+            }
+        }
+
         // Push sync marker to undo stack
         self.undo_stack.push_sync_marker();
 
@@ -4472,15 +4964,34 @@ impl App {
                 false,
             ),
             (Metadata::Dep(Vec::new()), DetailRegion::Deps, true),
+            (
+                Metadata::Relation(crate::model::RelationKind::Relates, Vec::new()),
+                DetailRegion::Relations,
+                false,
+            ),
             (Metadata::Spec(Vec::new()), DetailRegion::Spec, true),
             (Metadata::Ref(Vec::new()), DetailRegion::Refs, true),
+            (
+                Metadata::Custom {
+                    key: String::new(),
+                    value: String::new(),
+                },
+                DetailRegion::Fields,
+                false,
+            ),
             (Metadata::Note(String::new()), DetailRegion::Note, true),
         ];
         metadata_regions.sort_by_key(|(sample, _, _)| sample.rank());
 
         for (sample, region, always) in metadata_regions {
-            let key = sample.key();
-            if always || task.metadata.iter().any(|m| m.key() == key) {
+            // By variant rather than by key: every custom field shares one row.
+            let variant = std::mem::discriminant(&sample);
+            if always
+                || task
+                    .metadata
+                    .iter()
+                    .any(|m| std::mem::discriminant(m) == variant)
+            {
                 regions.push(region);
             }
         }
@@ -4502,6 +5013,8 @@ impl App {
             DetailRegion::Conflict => true, // only in regions list if present
             DetailRegion::Added => true,    // only in regions list if present
             DetailRegion::Resolved => true, // only in regions list if present
+            DetailRegion::Fields => true,   // only in regions list if present
+            DetailRegion::Relations => true, // only in regions list if present
             DetailRegion::Subtasks => true, // only in regions list if present
             DetailRegion::Deps => task
                 .metadata
@@ -4618,19 +5131,21 @@ impl App {
             &grace_ids,
         );
 
-        // Parked section (if non-empty)
-        let parked = track.parked();
-        if !parked.is_empty() {
-            items.push(FlatItem::ParkedSeparator);
-            flatten_tasks(
-                parked,
-                SectionKind::Parked,
-                0,
-                &mut items,
-                expanded,
-                &[],
-                &grace_ids,
-            );
+        // Parked and any custom sections (if non-empty), each under its own
+        // separator
+        let below_backlog = std::iter::once(SectionKind::Parked).chain(
+            track
+                .section_kinds()
+                .into_iter()
+                .filter(|kind| matches!(kind, SectionKind::Custom(_))),
+        );
+        for kind in below_backlog {
+            let tasks = track.section_tasks(kind);
+            if tasks.is_empty() {
+                continue;
+            }
+            items.push(FlatItem::SectionSeparator(kind));
+            flatten_tasks(tasks, kind, 0, &mut items, expanded, &[], &grace_ids);
         }
 
         // Done tasks are NOT shown in track view (they're in Recent)
@@ -4683,9 +5198,10 @@ pub fn task_expand_key(task: &Task, section: SectionKind, path: &[usize]) -> Str
         id.to_string()
     } else {
         let section_str = match section {
-            SectionKind::Backlog => "b",
-            SectionKind::Parked => "p",
-            SectionKind::Done => "d",
+            SectionKind::Backlog => "b".to_string(),
+            SectionKind::Parked => "p".to_string(),
+            SectionKind::Done => "d".to_string(),
+            SectionKind::Custom(name) => format!("c:{}", name.as_str().to_lowercase()),
         };
         format!(
             "_{}_{}",
@@ -4937,7 +5453,7 @@ fn apply_filter(items: &mut Vec<FlatItem>, track: &Track, filter: &FilterState,
                 context[i] = true;
             }
         }
-        // ParkedSeparator: keep if any parked task is kept (handled below)
+        // SectionSeparator: keep if any task of its section is kept (handled below)
     }
 
     // Keep DoneSummary if its parent task is kept
@@ -4956,19 +5472,13 @@ fn apply_filter(items: &mut Vec<FlatItem>, track: &Track, filter: &FilterState,
         }
     }
 
-    // Keep ParkedSeparator only if at least one Parked task is kept
+    // Keep a SectionSeparator only if at least one task of its section is kept
     for (i, item) in items.iter().enumerate() {
-        if matches!(item, FlatItem::ParkedSeparator) {
-            let has_parked = items[i + 1..].iter().enumerate().any(|(j, fi)| {
-                matches!(
-                    fi,
-                    FlatItem::Task {
-                        section: SectionKind::Parked,
-                        ..
-                    }
-                ) && keep[i + 1 + j]
+        if let FlatItem::SectionSeparator(kind) = item {
+            let has_kept = items[i + 1..].iter().enumerate().any(|(j, fi)| {
+                matches!(fi, FlatItem::Task { section, .. } if section == kind) && keep[i + 1 + j]
             });
-            keep[i] = has_parked;
+            keep[i] = has_kept;
         }
     }
 
@@ -5023,10 +5533,12 @@ fn mark_ancestors_kept(
     }
 }
 
-/// Restore UI state from .state.json
+/// Restore UI state from .state.json, and the undo history beside it
 pub fn restore_ui_state(app: &mut App) {
     use crate::io::state::read_ui_state;
 
+    restore_undo_history(app);
+
     let ui_state = match read_ui_state(&app.project.frame_dir) {
         Some(s) => s,
         None => return,
@@ -5038,6 +5550,7 @@ pub fn restore_ui_state(app: &mut App) {
         "board" => app.view = View::Board,
         "inbox" => app.view = View::Inbox,
         "recent" => app.view = View::Recent,
+        "stats" => app.view = View::Stats,
         "track" => {
             if let Some(idx) = app
                 .active_track_ids
@@ -5057,8 +5570,10 @@ pub fn restore_ui_state(app: &mut App) {
             _ => BoardMode::Cc,
         };
     }
-    if let Some(col) = ui_state.board_focus_column {
-        app.board_state.focus_column = BoardColumn::from_index(col);
+    if let Some(col) = ui_state.board_focus_column
+        && let Some(&column) = app.board_columns().get(col)
+    {
+        app.board_state.focus_column = column;
     }
 
     // Restore per-track state
@@ -5081,6 +5596,15 @@ pub fn restore_ui_state(app: &mut App) {
     if let Some(wrap_override) = ui_state.note_wrap_override {
         app.note_wrap = wrap_override;
     }
+
+    // Restore marks
+    app.marks = ui_state.marks;
+
+    // Restore the split layout
+    app.split_pane = ui_state.split_pane;
+    if let Some(ratio) = ui_state.split_ratio {
+        app.split_ratio = ratio.clamp(SPLIT_RATIO_MIN, SPLIT_RATIO_MAX);
+    }
 }
 
 /// Save UI state to .state.json
@@ -5093,6 +5617,12 @@ pub fn save_ui_state(app: &App) {
             .as_ref()
             .map(|sr| sr.return_view.clone())
             .unwrap_or(View::Recent)
+    } else if app.view == View::Graph {
+        // Likewise the graph, which is a lens on the view it was opened from
+        app.graph_view
+            .as_ref()
+            .map(|gv| gv.return_view.clone())
+            .unwrap_or(View::Recent)
     } else {
         app.view.clone()
     };
@@ -5106,7 +5636,8 @@ pub fn save_ui_state(app: &App) {
         View::Board => ("board".to_string(), String::new()),
         View::Inbox => ("inbox".to_string(), String::new()),
         View::Recent => ("recent".to_string(), String::new()),
-        View::Search => ("recent".to_string(), String::new()),
+        View::Stats => ("stats".to_string(), String::new()),
+        View::Search | View::Graph => ("recent".to_string(), String::new()),
     };
 
     let mut tracks = HashMap::new();
@@ -5140,10 +5671,47 @@ pub fn save_ui_state(app: &App) {
         note_wrap_override,
         project_search_history: app.project_search_history.clone(),
         board_mode,
-        board_focus_column: Some(app.board_state.focus_column.index()),
+        board_focus_column: Some(app.board_focus_index()),
+        split_pane: app.split_pane,
+        split_ratio: (app.split_ratio != SPLIT_RATIO_DEFAULT).then_some(app.split_ratio),
+        marks: app.marks.clone(),
     };
 
     let _ = write_ui_state(&app.project.frame_dir, &ui_state);
+    save_undo_history(app);
+}
+
+/// Write the undo history to `.undo.json`.
+///
+/// Not while a save is outstanding: the history would be fingerprinted
+/// against files that don't hold what it was recorded on. The copy from before
+/// the failure stays, and still matches the files it was saved with.
+fn save_undo_history(app: &App) {
+    if !app.unsaved.is_empty() {
+        return;
+    }
+    let _ = crate::tui::undo_history::save_history(
+        &app.project.frame_dir,
+        &app.project.config,
+        &app.undo_stack,
+    );
+}
+
+/// Take up the undo history the last session left, less the steps whose files
+/// have changed since — saying so when there were any.
+fn restore_undo_history(app: &mut App) {
+    let Some((stack, dropped)) =
+        crate::tui::undo_history::load_history(&app.project.frame_dir, &app.project.config)
+    else {
+        return;
+    };
+    app.undo_stack = stack;
+    if dropped > 0 {
+        app.status_message = Some(format!(
+            "Dropped {dropped} undo step{} from last session: files changed since",
+            if dropped == 1 { "" } else { "s" }
+        ));
+    }
 }
 
 /// Set the terminal window/tab title via OSC 0.
@@ -5190,7 +5758,17 @@ pub fn run(project_dir_override: Option<&str>) -> Result<(), Box<dyn std::error:
             }
         },
     };
-    let mut project = load_project(&root)?;
+    let user_config = crate::io::user_config::read()?;
+    let mut project = load_project_with(&root, user_config.as_ref())?;
+    // Checked here rather than left to `App::new`, so that a key bound twice
+    // is a message at the prompt and not a binding that silently never fires
+    // — and a board column naming a state nobody has, not an empty column.
+    Keymap::from_config(&project.config.keys).map_err(|e| format!("[keys]: {e}"))?;
+    project
+        .config
+        .board
+        .check(&project.config.workflow)
+        .map_err(|e| format!("[board]: {e}"))?;
 
     // Auto-assign IDs and dates so all tasks are interactive from the start.
     // Startup must not auto-claim a token; an unclaimed clone mints nothing
@@ -5235,6 +5813,7 @@ pub fn run(project_dir_override: Option<&str>) -> Result<(), Box<dyn std::error:
     crate::io::registry::touch_tui(&project.root);
 
     let mut app = App::new(project);
+    app.user_config = user_config;
 
     // Restore saved UI state
     restore_ui_state(&mut app);
@@ -5280,6 +5859,7 @@ pub fn run(project_dir_override: Option<&str>) -> Result<(), Box<dyn std::error:
         let _ = io::stdout().flush();
         let _ = disable_raw_mode();
         let _ = execute!(io::stdout(), DisableBracketedPaste);
+        let _ = execute!(io::stdout(), DisableMouseCapture);
         let _ = execute!(io::stdout(), PopKeyboardEnhancementFlags);
         let _ = execute!(io::stdout(), LeaveAlternateScreen);
         original_hook(panic_info);
@@ -5340,6 +5920,7 @@ pub fn run(project_dir_override: Option<&str>) -> Result<(), Box<dyn std::error:
     clear_window_title();
     disable_raw_mode()?;
     let _ = execute!(terminal.backend_mut(), DisableBracketedPaste);
+    let _ = execute!(terminal.backend_mut(), DisableMouseCapture);
     let _ = execute!(terminal.backend_mut(), PopKeyboardEnhancementFlags);
     execute!(terminal.backend_mut(), LeaveAlternateScreen)?;
     terminal.show_cursor()?;
@@ -5523,7 +6104,14 @@ fn run_project_picker(notice: Option<String>) -> Result<(), Box<dyn std::error::
 
     let mut picker = ProjectPickerState::new(reg.projects, None);
     picker.notice = notice;
-    let theme = super::theme::Theme::default();
+    // No project, so no project.toml: the user config's `[ui]` is all there is
+    let ui = crate::io::user_config::read()
+        .ok()
+        .flatten()
+        .and_then(|user| user.get("ui").cloned())
+        .and_then(|ui| ui.try_into::<crate::model::UiConfig>().ok())
+        .unwrap_or_default();
+    let theme = super::theme::Theme::from_config(&ui);
 
     let selected_path = loop {
         terminal.draw(|frame| {
@@ -5535,6 +6123,9 @@ fn run_project_picker(notice: Option<String>) -> Result<(), Box<dyn std::error::
                 area,
             );
             render::project_picker::render_project_picker_standalone(frame, &picker, &theme, area);
+            if theme.mono {
+                render::monochrome(frame.buffer_mut(), &theme);
+            }
         })?;
 
         if crossterm::event::poll(Duration::from_millis(250))?
@@ -5664,7 +6255,19 @@ fn run_event_loop(
     mut watcher: Option<FrameWatcher>,
 ) -> Result<LoopExit, Box<dyn std::error::Error>> {
     let mut save_counter = 0u32;
+    let mut mouse_captured = false;
     loop {
+        // Mouse capture follows `[ui] mouse` as it stands now: a config reload
+        // or a project switch can turn it on or off under a running session.
+        if app.project.config.ui.mouse != mouse_captured {
+            mouse_captured = app.project.config.ui.mouse;
+            let _ = if mouse_captured {
+                execute!(terminal.backend_mut(), EnableMouseCapture)
+            } else {
+                execute!(terminal.backend_mut(), DisableMouseCapture)
+            };
+        }
+
         // Reinitialize file watcher after project switch
         if app.watcher_needs_restart {
             app.watcher_needs_restart = false;
@@ -5756,6 +6359,7 @@ fn run_event_loop(
                     input::handle_paste(app, &text);
                     true
                 }
+                Event::Mouse(mouse) => input::handle_mouse(app, mouse),
                 _ => false,
             };
 
@@ -6041,6 +6645,12 @@ pub(crate) fn app_on_disk(dir: &std::path::Path) -> App {
         ui: UiConfig::default(),
         recovery: Default::default(),
         limits: Default::default(),
+        deps: Default::default(),
+        templates: Default::default(),
+        fields: Default::default(),
+        workflow: Default::default(),
+        board: Default::default(),
+        keys: Default::default(),
     };
     let project = crate::model::project::Project {
         root: dir.to_path_buf(),
@@ -6198,6 +6808,14 @@ mod tests {
             crate::model::Metadata::Conflict("both-edited".into()),
             crate::model::Metadata::Spec(vec!["s.md".into()]),
             crate::model::Metadata::Dep(vec!["T-1".into()]),
+            crate::model::Metadata::Custom {
+                key: "risk".into(),
+                value: "high".into(),
+            },
+            crate::model::Metadata::Custom {
+                key: "area".into(),
+                value: "parser".into(),
+            },
         ];
 
         assert_eq!(
@@ -6211,6 +6829,7 @@ mod tests {
                 DetailRegion::Deps,
                 DetailRegion::Spec,
                 DetailRegion::Refs,
+                DetailRegion::Fields,
                 DetailRegion::Note,
             ]
         );
@@ -6391,6 +7010,12 @@ mod tests {
             ui: UiConfig::default(),
             recovery: Default::default(),
             limits: Default::default(),
+            deps: Default::default(),
+            templates: Default::default(),
+            fields: Default::default(),
+            workflow: Default::default(),
+            board: Default::default(),
+            keys: Default::default(),
         };
         let project = Project {
             root: std::path::PathBuf::from("/tmp/test"),
@@ -6402,7 +7027,8 @@ mod tests {
         let mut app = App::new(project);
         // Switch to All mode (default is Cc which filters for #cc tags)
         app.board_state.mode = BoardMode::All;
-        let [ready, in_progress, done] = app.build_board_columns();
+        let [ready, in_progress, done]: [Vec<BoardItem>; 3] =
+            app.build_board_columns().try_into().unwrap();
 
         // Collect task IDs from each column
         let ready_ids: Vec<&str> = ready
@@ -6486,6 +7112,12 @@ mod tests {
             ui: UiConfig::default(),
             recovery: Default::default(),
             limits: Default::default(),
+            deps: Default::default(),
+            templates: Default::default(),
+            fields: Default::default(),
+            workflow: Default::default(),
+            board: Default::default(),
+            keys: Default::default(),
         };
         let project = Project {
             root: std::path::PathBuf::from("/tmp/test"),
@@ -6496,7 +7128,8 @@ mod tests {
         };
         let mut app = App::new(project);
         app.board_state.mode = BoardMode::All;
-        let [ready, in_progress, _done] = app.build_board_columns();
+        let [ready, in_progress, _done]: [Vec<BoardItem>; 3] =
+            app.build_board_columns().try_into().unwrap();
 
         // Map task_id -> id_display across both populated columns.
         let displays: std::collections::HashMap<String, String> = ready
@@ -6532,6 +7165,227 @@ mod tests {
         );
     }
 
+    #[test]
+    fn declared_workflow_states_get_their_own_board_columns() {
+        use crate::model::project::Project;
+        use crate::parse::{Schema, parse_track_with};
+
+        let text = "\
+# Test Track
+
+## Backlog
+
+- [ ] `T-001` Ready task
+- [=] `T-002` Under review
+
+## Icebox
+
+- [ ] `T-003` Set aside
+- [>] `T-004` Started before the freeze
+
+## Done
+";
+        let config: crate::model::ProjectConfig = toml::from_str(
+            r#"
+[project]
+name = "test"
+
+[[tracks]]
+id = "test"
+name = "Test"
+state = "active"
+file = "tracks/test.md"
+
+[workflow]
+sections = ["Icebox"]
+
+[workflow.states.review]
+char = "="
+"#,
+        )
+        .unwrap();
+        let track = parse_track_with(text, &Schema::of(&config));
+        let project = Project {
+            root: std::path::PathBuf::from("/tmp/test"),
+            frame_dir: std::path::PathBuf::from("/tmp/test/frame"),
+            config,
+            tracks: vec![("test".into(), track)],
+            inbox: None,
+        };
+        let mut app = App::new(project);
+        app.board_state.mode = BoardMode::All;
+
+        let review = BoardColumn::State(TaskState::Custom('='));
+        assert_eq!(
+            app.board_columns(),
+            vec![
+                BoardColumn::Ready,
+                BoardColumn::InProgress,
+                review,
+                BoardColumn::Done
+            ]
+        );
+        let ids = |col: &[BoardItem]| -> Vec<String> {
+            col.iter()
+                .filter_map(|item| match item {
+                    BoardItem::Task { task_id, .. } => Some(task_id.clone()),
+                    _ => None,
+                })
+                .collect()
+        };
+        let columns = app.build_board_columns();
+        // A todo task in a custom section was put aside: it is not Ready
+        assert_eq!(ids(&columns[0]), ["T-001"]);
+        assert_eq!(ids(&columns[1]), ["T-004"]);
+        assert_eq!(ids(&columns[2]), ["T-002"]);
+        assert_eq!(app.board_task_count(&columns, review), 1);
+    }
+
+    #[test]
+    fn board_columns_and_swimlanes_come_from_the_board_table() {
+        use crate::model::project::Project;
+        use crate::parse::parse_track;
+
+        let track = parse_track(
+            "\
+# Test Track
+
+## Backlog
+
+- [ ] `T-001` Ready task #api
+- [-] `T-002` Stuck
+- [>] `T-003` Being reviewed #review
+- [>] `T-004` Being written #api
+- [>] `T-005` Also being written
+
+## Done
+",
+        );
+        let config: crate::model::ProjectConfig = toml::from_str(
+            r#"
+[project]
+name = "test"
+
+[[tracks]]
+id = "test"
+name = "Test"
+state = "active"
+file = "tracks/test.md"
+
+[board]
+swimlanes = ["api"]
+
+[[board.columns]]
+name = "Blocked"
+state = "blocked"
+
+[[board.columns]]
+name = "Ready"
+state = "todo"
+
+[[board.columns]]
+name = "Doing"
+state = "active"
+
+[[board.columns]]
+name = "Review"
+tag = "review"
+"#,
+        )
+        .unwrap();
+        let project = Project {
+            root: std::path::PathBuf::from("/tmp/test"),
+            frame_dir: std::path::PathBuf::from("/tmp/test/frame"),
+            config,
+            tracks: vec![("test".into(), track)],
+            inbox: None,
+        };
+        let mut app = App::new(project);
+        app.board_state.mode = BoardMode::All;
+
+        assert_eq!(
+            app.board_columns(),
+            (0..4).map(BoardColumn::Config).collect::<Vec<_>>()
+        );
+        let items = |col: &[BoardItem]| -> Vec<String> {
+            col.iter()
+                .map(|item| match item {
+                    BoardItem::Task { task_id, .. } => task_id.clone(),
+                    BoardItem::LaneHeader { name } => format!("[{name}]"),
+                })
+                .collect()
+        };
+        let columns = app.build_board_columns();
+        assert_eq!(items(&columns[0]), ["[other]", "T-002"]);
+        assert_eq!(items(&columns[1]), ["[#api]", "T-001"]);
+        // A tag column takes its tasks before a state column can
+        assert_eq!(items(&columns[2]), ["[#api]", "T-004", "[other]", "T-005"]);
+        assert_eq!(items(&columns[3]), ["[other]", "T-003"]);
+    }
+
+    #[test]
+    fn moving_a_card_sets_the_columns_state_and_tag_in_one_undo() {
+        use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+        let tmp = tempfile::TempDir::new().unwrap();
+        let mut app = app_on_disk(tmp.path());
+        app.project.config.board = toml::from_str(
+            r#"
+[[columns]]
+name = "Ready"
+state = "todo"
+
+[[columns]]
+name = "Review"
+state = "active"
+tag = "review"
+
+[[columns]]
+name = "Blocked"
+state = "blocked"
+"#,
+        )
+        .unwrap();
+        app.view = View::Board;
+        app.board_state.mode = BoardMode::All;
+        app.board_state.visible_columns = 3;
+        // As the renderer leaves it: on the card, below the track's lane header
+        app.board_state.focus_column = BoardColumn::Config(0);
+        app.board_state.set_cursor(0, 1);
+        let shift = |app: &mut App, c| {
+            crate::tui::input::handle_key(app, KeyEvent::new(KeyCode::Char(c), KeyModifiers::SHIFT))
+        };
+        let task = |app: &mut App| {
+            let task = &app.find_track_mut("a").unwrap().backlog()[0];
+            (task.state, task.tags.clone())
+        };
+
+        shift(&mut app, 'L');
+        assert_eq!(
+            task(&mut app),
+            (TaskState::Active, vec!["review".to_string()])
+        );
+        assert_eq!(app.board_state.focus_column, BoardColumn::Config(1));
+        assert_eq!(app.board_cursor_task_id().unwrap().1, "A-001");
+
+        // Leaving the Review column takes its tag away
+        shift(&mut app, 'L');
+        assert_eq!(task(&mut app), (TaskState::Blocked, vec![]));
+        assert!(
+            std::fs::read_to_string(tmp.path().join("frame/tracks/a.md"))
+                .unwrap()
+                .contains("- [-] `A-001` One\n")
+        );
+
+        press(&mut app, 'z');
+        assert_eq!(
+            task(&mut app),
+            (TaskState::Active, vec!["review".to_string()])
+        );
+        press(&mut app, 'z');
+        assert_eq!(task(&mut app), (TaskState::Todo, vec![]));
+    }
+
     // --- inverse dep index resolves tokened ids on both ends ---
 
     #[test]
@@ -6574,6 +7428,12 @@ mod tests {
             ui: UiConfig::default(),
             recovery: Default::default(),
             limits: Default::default(),
+            deps: Default::default(),
+            templates: Default::default(),
+            fields: Default::default(),
+            workflow: Default::default(),
+            board: Default::default(),
+            keys: Default::default(),
         };
         let project = Project {
             root: std::path::PathBuf::from("/tmp/test"),
@@ -6942,6 +7802,41 @@ name = \"theirs\"
         );
     }
 
+    /// Memory holds `project.toml` with the user config layered over it, and
+    /// `project.toml` is committed: a person's colours written into it would be
+    /// everyone's. No write of the config from memory carries them — the merge,
+    /// the rescue copy at exit, or the rebuild with nothing to rebuild from.
+    #[test]
+    fn the_user_config_is_never_written_into_project_toml() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let mut app = app_with_config_file(tmp.path());
+        let user: toml::Table =
+            toml::from_str("[ui]\nnote_wrap = false\n\n[ui.tag_colors]\nmine = \"#00FF00\"\n")
+                .unwrap();
+        app.project.config =
+            crate::io::user_config::parse_layered_with(CONFIG_WITH_COMMENTS, Some(&user)).unwrap();
+        app.user_config = Some(user);
+        rename_track_in_memory(&mut app);
+        let leaked = |text: &str| text.contains("mine") || text.contains("note_wrap = false");
+
+        app.save_config_logged();
+        let text = std::fs::read_to_string(config_path(&app)).unwrap();
+        assert!(text.contains("Renamed") && !leaked(&text), "{text}");
+        assert!(!app.project.config.ui.note_wrap, "memory is still layered");
+
+        app.project.config.tracks[0].name = "Renamed again".into();
+        app.record_save_failure(SaveTarget::Config, &"Read-only file system".to_string());
+        let rescue = app.dump_unsaved();
+        let text = std::fs::read_to_string(&rescue.written[0].1).unwrap();
+        assert!(text.contains("Renamed again") && !leaked(&text), "{text}");
+
+        std::fs::remove_file(config_path(&app)).unwrap();
+        app.baselines.remove(&SaveTarget::Config);
+        app.save_config_logged();
+        let text = std::fs::read_to_string(config_path(&app)).unwrap();
+        assert!(text.contains("Renamed again") && !leaked(&text), "{text}");
+    }
+
     /// The pre-flight. Every `with_project_lock` caller writes the config as one
     /// half of its change, so a refusal *inside* the body would leave the other
     /// half done — the track file moved with the config still calling it active,
@@ -7341,6 +8236,35 @@ urgent = \"#112233\"
         );
     }
 
+    /// A reload says what it changed: the rows it touched are highlighted and
+    /// the changes are logged for the Activity panel.
+    #[test]
+    fn an_external_change_is_logged_and_highlighted() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let mut app = app_on_disk(tmp.path());
+        let path = app.project.frame_dir.join("tracks/a.md");
+
+        std::fs::write(
+            &path,
+            "# A\n\n## Backlog\n\n- [>] `A-001` One\n- [ ] `A-002` Two, from an agent\n\n## Done\n",
+        )
+        .unwrap();
+        app.reload_changed_files(std::slice::from_ref(&path));
+
+        let logged: Vec<String> = app
+            .activity
+            .changes
+            .iter()
+            .map(|c| c.describe(&app.project.config.workflow))
+            .collect();
+        assert_eq!(logged, ["A-001 \u{2192} active", "A-002 added"]);
+        assert!(app.activity.is_highlighted("A-001"));
+        assert_eq!(
+            app.status_message.as_deref(),
+            Some("2 external changes \u{00B7} W for activity")
+        );
+    }
+
     /// A successful save retires the entry, so the guard stops firing and normal
     /// reload behaviour resumes.
     #[test]
@@ -8056,7 +8980,7 @@ urgent = \"#112233\"
         .unwrap();
         std::fs::write(frame_dir.join("inbox.md"), "# Inbox\n").unwrap();
         std::fs::write(frame_dir.join("project.toml"), config).unwrap();
-        let project = crate::io::project_io::load_project(root).unwrap();
+        let project = crate::io::project_io::load_project_with(root, None).unwrap();
         App::new(project)
     }
 
@@ -8469,6 +9393,248 @@ a = "A"
         );
     }
 
+    /// A `[keys]` binding takes over the action and leaves its old key idle.
+    #[test]
+    fn a_rebound_key_runs_the_action_and_the_old_one_does_nothing() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let mut app = app_on_disk(tmp.path());
+        let keys = toml::from_str("mark_done = \"X\"\n").unwrap();
+        app.keymap = Keymap::from_config(&keys).unwrap();
+        app.view = View::Detail {
+            track_id: "a".into(),
+            task_id: "A-001".into(),
+        };
+        let state = |app: &App| {
+            let track = App::find_track_in_project(&app.project, "a").unwrap();
+            crate::ops::task_ops::find_task_in_track(track, "A-001")
+                .unwrap()
+                .state
+        };
+
+        press(&mut app, 'x');
+        assert_eq!(state(&app), crate::model::task::TaskState::Todo);
+        press(&mut app, 'X');
+        assert_eq!(state(&app), crate::model::task::TaskState::Done);
+    }
+
+    /// Undo outlives the session: a step taken before quitting can be undone
+    /// after reopening — until the file it wrote changes without it.
+    #[test]
+    fn undo_history_outlives_the_session() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        // A fresh session over what is on disk; `app_on_disk` would rewrite it
+        let reopen = |from: &App| {
+            let mut project = from.project.clone();
+            let text = std::fs::read_to_string(project.frame_dir.join("tracks/a.md")).unwrap();
+            project.tracks = vec![("a".into(), crate::parse::parse_track(&text))];
+            let mut app = App::new(project);
+            restore_ui_state(&mut app);
+            app.view = View::Track(0);
+            app
+        };
+        let state = |app: &App| {
+            let track = App::find_track_in_project(&app.project, "a").unwrap();
+            crate::ops::task_ops::find_task_in_track(track, "A-001")
+                .unwrap()
+                .state
+        };
+
+        let mut app = app_on_disk(tmp.path());
+        app.view = View::Track(0);
+        press(&mut app, 'b');
+        assert_eq!(state(&app), crate::model::task::TaskState::Blocked);
+        save_ui_state(&app);
+
+        let mut next = reopen(&app);
+        assert_eq!(next.undo_stack.undo_depth(), 1);
+        save_ui_state(&next);
+        press(&mut next, 'z');
+        assert_eq!(state(&next), crate::model::task::TaskState::Todo);
+
+        // Someone else rewrites the track before the next session
+        std::fs::write(
+            next.project.frame_dir.join("tracks/a.md"),
+            "# A\n\n## Backlog\n\n- [>] `A-001` One\n\n## Done\n",
+        )
+        .unwrap();
+        let stale = reopen(&next);
+        assert_eq!(stale.undo_stack.undo_depth(), 0);
+        assert_eq!(
+            stale.status_message.as_deref(),
+            Some("Dropped 1 undo step from last session: files changed since")
+        );
+    }
+
+    /// The split layout and its ratio come back with the next session.
+    #[test]
+    fn the_split_layout_is_remembered() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let mut app = app_on_disk(tmp.path());
+        press(&mut app, '}');
+        assert_eq!(
+            app.split_ratio, SPLIT_RATIO_DEFAULT,
+            "no split, nothing to resize"
+        );
+
+        press(&mut app, '|');
+        press(&mut app, '}');
+        press(&mut app, '}');
+        assert!(app.split_pane);
+        assert_eq!(app.split_ratio, SPLIT_RATIO_DEFAULT + 10);
+        save_ui_state(&app);
+
+        let mut next = app_on_disk(tmp.path());
+        restore_ui_state(&mut next);
+        assert!(next.split_pane);
+        assert_eq!(next.split_ratio, SPLIT_RATIO_DEFAULT + 10);
+    }
+
+    /// `app_on_disk` with A-002 and A-003 below A-001.
+    fn app_with_three_tasks(dir: &std::path::Path) -> App {
+        use crate::model::task::{Task, TaskState};
+        let mut app = app_on_disk(dir);
+        let backlog = app
+            .find_track_mut("a")
+            .unwrap()
+            .section_tasks_mut(SectionKind::Backlog)
+            .unwrap();
+        for (id, title) in [("A-002", "Two"), ("A-003", "Three")] {
+            backlog.push(Task::new(TaskState::Todo, Some(id.into()), title.into()));
+        }
+        app.view = View::Track(0);
+        app
+    }
+
+    /// [`press`], with modifiers or a key that isn't a character.
+    fn press_key(
+        app: &mut App,
+        code: crossterm::event::KeyCode,
+        mods: crossterm::event::KeyModifiers,
+    ) {
+        crate::tui::input::handle_key(app, crossterm::event::KeyEvent::new(code, mods));
+    }
+
+    fn ctrl(app: &mut App, c: char) {
+        press_key(
+            app,
+            crossterm::event::KeyCode::Char(c),
+            crossterm::event::KeyModifiers::CONTROL,
+        );
+    }
+
+    fn shift(app: &mut App, c: char) {
+        press_key(
+            app,
+            crossterm::event::KeyCode::Char(c),
+            crossterm::event::KeyModifiers::SHIFT,
+        );
+    }
+
+    fn enter(app: &mut App) {
+        press_key(
+            app,
+            crossterm::event::KeyCode::Enter,
+            crossterm::event::KeyModifiers::NONE,
+        );
+    }
+
+    fn at(app: &App) -> Option<Location> {
+        app.location()
+    }
+
+    /// Jump away with `J`, open the task, and `Ctrl+O` retraces both steps;
+    /// `Ctrl+I` walks them again.
+    #[test]
+    fn ctrl_o_and_ctrl_i_retrace_the_jumps() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let mut app = app_with_three_tasks(tmp.path());
+        let track = |task_id: &str| Location::Track {
+            track_id: "a".into(),
+            task_id: Some(task_id.into()),
+        };
+        let detail = Location::Detail {
+            track_id: "a".into(),
+            task_id: "A-003".into(),
+        };
+
+        shift(&mut app, 'J');
+        "A-003".chars().for_each(|c| press(&mut app, c));
+        enter(&mut app);
+        assert_eq!(at(&app), Some(track("A-003")));
+        enter(&mut app);
+        assert_eq!(at(&app), Some(detail.clone()));
+
+        ctrl(&mut app, 'o');
+        assert_eq!(at(&app), Some(track("A-003")));
+        ctrl(&mut app, 'o');
+        assert_eq!(at(&app), Some(track("A-001")));
+        ctrl(&mut app, 'o');
+        assert_eq!(
+            app.status_message.as_deref(),
+            Some("jump list: nothing further back")
+        );
+
+        ctrl(&mut app, 'i');
+        ctrl(&mut app, 'i');
+        assert_eq!(at(&app), Some(detail));
+    }
+
+    /// Moving the cursor is not a jump, and a view switch is.
+    #[test]
+    fn only_jumps_and_view_changes_are_recorded() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let mut app = app_with_three_tasks(tmp.path());
+        press(&mut app, 'j');
+        press(&mut app, 'j');
+        assert_eq!(app.jumps.len(Direction::Back), 0);
+
+        press(&mut app, 'i');
+        assert_eq!(app.view, View::Inbox);
+        ctrl(&mut app, 'o');
+        assert_eq!(
+            at(&app),
+            Some(Location::Track {
+                track_id: "a".into(),
+                task_id: Some("A-003".into()),
+            })
+        );
+    }
+
+    /// `B a` names the task under the cursor, `' a` goes back to it from
+    /// anywhere, `''` returns — and the mark is there next session.
+    #[test]
+    fn marks_jump_to_their_task_and_are_remembered() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let mut app = app_with_three_tasks(tmp.path());
+        press(&mut app, 'j');
+        shift(&mut app, 'B');
+        press(&mut app, 'a');
+        assert_eq!(
+            app.status_message.as_deref(),
+            Some("mark 'a \u{2192} A-002")
+        );
+
+        press(&mut app, 'r');
+        press(&mut app, '\'');
+        press(&mut app, 'a');
+        assert_eq!(
+            app.cursor_task_id().map(|(_, id, _)| id).as_deref(),
+            Some("A-002")
+        );
+        press(&mut app, '\'');
+        press(&mut app, '\'');
+        assert_eq!(app.view, View::Recent);
+
+        press(&mut app, '\'');
+        press(&mut app, 'b');
+        assert_eq!(app.status_message.as_deref(), Some("no mark 'b"));
+
+        save_ui_state(&app);
+        let mut next = app_on_disk(tmp.path());
+        restore_ui_state(&mut next);
+        assert_eq!(next.marks.get(&'a').map(String::as_str), Some("A-002"));
+    }
+
     /// The view-dependent hole: reopening outside the Board and Recent views had
     /// no section move at all, so the task stayed in `## Done` as `[ ]`.
     #[test]
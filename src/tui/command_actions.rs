@@ -1,7 +1,9 @@
+use std::borrow::Cow;
+
 use crate::tui::app::{App, View};
 
 /// Which views an action is available in
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum ViewContext {
     TrackView,
     DetailView,
@@ -32,7 +34,7 @@ pub enum ActionCategory {
 pub struct PaletteAction {
     pub id: &'static str,
     pub label: String,
-    pub shortcut: Option<&'static str>,
+    pub shortcut: Option<Cow<'static, str>>,
     pub contexts: &'static [ViewContext],
     pub category: ActionCategory,
 }
@@ -121,7 +123,7 @@ pub fn filter_actions(query: &str, actions: &[PaletteAction]) -> Vec<ScoredActio
     let mut results: Vec<ScoredAction> = actions
         .iter()
         .filter_map(|a| {
-            let shortcut = a.shortcut.unwrap_or("");
+            let shortcut = a.shortcut.as_deref().unwrap_or("");
             let combined = if shortcut.is_empty() {
                 a.label.clone()
             } else {
@@ -173,6 +175,9 @@ pub fn filter_actions(query: &str, actions: &[PaletteAction]) -> Vec<ScoredActio
 // Action registry
 // ---------------------------------------------------------------------------
 
+/// Label prefix of the "Add from template" actions; the rest is the name.
+const TEMPLATE_LABEL: &str = "Add from template: ";
+
 /// Get the current ViewContext from the app's view
 pub fn current_context(view: &View) -> ViewContext {
     match view {
@@ -182,7 +187,7 @@ pub fn current_context(view: &View) -> ViewContext {
         View::Recent => ViewContext::RecentView,
         View::Tracks => ViewContext::TracksView,
         View::Board => ViewContext::Global,
-        View::Search => ViewContext::Global,
+        View::Search | View::Graph | View::Stats => ViewContext::Global,
     }
 }
 
@@ -198,6 +203,9 @@ pub fn available_actions(app: &App) -> Vec<PaletteAction> {
             if action.id == "delete_task" && !app.selection.is_empty() {
                 action.label = format!("Delete {} tasks", app.selection.len());
             }
+            if let Some(keys) = app.keymap.shortcut(ctx, action.id) {
+                action.shortcut = Some(keys.to_string().into());
+            }
             actions.push(action);
         }
     }
@@ -214,7 +222,7 @@ pub fn available_actions(app: &App) -> Vec<PaletteAction> {
             } else {
                 format!("Retry saving {n} files")
             },
-            shortcut: Some("R"),
+            shortcut: Some("R".into()),
             contexts: &[ViewContext::Global],
             category: ActionCategory::System,
         });
@@ -290,6 +298,19 @@ pub fn available_actions(app: &App) -> Vec<PaletteAction> {
         }
     }
 
+    // Dynamic: "Add from template: {name}" for each `[templates.<name>]`
+    if ctx == ViewContext::TrackView {
+        for name in app.project.config.templates.keys() {
+            actions.push(PaletteAction {
+                id: "add_from_template",
+                label: format!("{}{}", TEMPLATE_LABEL, name),
+                shortcut: None,
+                contexts: &[ViewContext::TrackView],
+                category: ActionCategory::Create,
+            });
+        }
+    }
+
     // Dynamic: "Switch to track: {name}" for each active track
     for (i, track_id) in app.active_track_ids.iter().enumerate() {
         let name = app.track_name(track_id);
@@ -311,7 +332,7 @@ pub fn available_actions(app: &App) -> Vec<PaletteAction> {
             shortcut: if shortcut_str.is_empty() {
                 None
             } else {
-                Some(shortcut_str)
+                Some(shortcut_str.into())
             },
             contexts: &[ViewContext::Global],
             category: ActionCategory::Navigate,
@@ -328,111 +349,174 @@ fn action_matches_context(action: &PaletteAction, ctx: ViewContext) -> bool {
         .any(|c| *c == ctx || *c == ViewContext::Global)
 }
 
-fn static_actions() -> Vec<PaletteAction> {
+pub(crate) fn static_actions() -> Vec<PaletteAction> {
     vec![
         // -- Global actions --
         PaletteAction {
             id: "next_track",
             label: "Next track".into(),
-            shortcut: Some("Tab"),
+            shortcut: Some("Tab".into()),
             contexts: &[ViewContext::Global],
             category: ActionCategory::Navigate,
         },
         PaletteAction {
             id: "open_inbox",
             label: "Open Inbox".into(),
-            shortcut: Some("i"),
+            shortcut: Some("i".into()),
             contexts: &[ViewContext::Global],
             category: ActionCategory::Navigate,
         },
         PaletteAction {
             id: "open_recent",
             label: "Open Recent".into(),
-            shortcut: Some("r"),
+            shortcut: Some("r".into()),
+            contexts: &[ViewContext::Global],
+            category: ActionCategory::Navigate,
+        },
+        PaletteAction {
+            id: "open_stats",
+            label: "Open Stats".into(),
+            shortcut: Some("Y".into()),
             contexts: &[ViewContext::Global],
             category: ActionCategory::Navigate,
         },
         PaletteAction {
             id: "open_tracks",
             label: "Open Tracks".into(),
-            shortcut: Some("0"),
+            shortcut: Some("0".into()),
             contexts: &[ViewContext::Global],
             category: ActionCategory::Navigate,
         },
         PaletteAction {
             id: "open_board",
             label: "Open Board".into(),
-            shortcut: Some("K"),
+            shortcut: Some("K".into()),
+            contexts: &[ViewContext::Global],
+            category: ActionCategory::Navigate,
+        },
+        PaletteAction {
+            id: "open_graph",
+            label: "Open dependency graph".into(),
+            shortcut: Some("E".into()),
             contexts: &[ViewContext::Global],
             category: ActionCategory::Navigate,
         },
         PaletteAction {
             id: "search",
             label: "Search".into(),
-            shortcut: Some("/"),
+            shortcut: Some("/".into()),
             contexts: &[ViewContext::Global],
             category: ActionCategory::Search,
         },
         PaletteAction {
             id: "project_search",
             label: "Project search".into(),
-            shortcut: Some("S"),
+            shortcut: Some("S".into()),
             contexts: &[ViewContext::Global],
             category: ActionCategory::Search,
         },
         PaletteAction {
             id: "jump_to_task",
             label: "Jump to task by ID".into(),
-            shortcut: Some("J"),
+            shortcut: Some("J".into()),
             contexts: &[ViewContext::Global],
             category: ActionCategory::Search,
         },
+        PaletteAction {
+            id: "jump_back",
+            label: "Jump back".into(),
+            shortcut: Some("Ctrl+O".into()),
+            contexts: &[ViewContext::Global],
+            category: ActionCategory::Navigate,
+        },
+        PaletteAction {
+            id: "jump_forward",
+            label: "Jump forward".into(),
+            shortcut: Some("Ctrl+I".into()),
+            contexts: &[ViewContext::Global],
+            category: ActionCategory::Navigate,
+        },
+        PaletteAction {
+            id: "set_mark",
+            label: "Set mark on task".into(),
+            shortcut: Some("B".into()),
+            contexts: &[ViewContext::Global],
+            category: ActionCategory::Navigate,
+        },
+        PaletteAction {
+            id: "go_to_mark",
+            label: "Go to mark".into(),
+            shortcut: Some("'".into()),
+            contexts: &[ViewContext::Global],
+            category: ActionCategory::Navigate,
+        },
         PaletteAction {
             id: "show_deps",
             label: "Show dependencies".into(),
-            shortcut: Some("D"),
+            shortcut: Some("D".into()),
             contexts: &[ViewContext::TrackView, ViewContext::DetailView],
             category: ActionCategory::Navigate,
         },
         PaletteAction {
             id: "tag_colors",
             label: "Edit tag colors".into(),
-            shortcut: Some("T"),
+            shortcut: Some("T".into()),
             contexts: &[ViewContext::Global],
             category: ActionCategory::Navigate,
         },
         PaletteAction {
             id: "projects",
             label: "Switch project".into(),
-            shortcut: Some("P"),
+            shortcut: Some("P".into()),
+            contexts: &[ViewContext::Global],
+            category: ActionCategory::Navigate,
+        },
+        PaletteAction {
+            id: "dashboard",
+            label: "Project dashboard".into(),
+            shortcut: Some("O".into()),
+            contexts: &[ViewContext::Global],
+            category: ActionCategory::Navigate,
+        },
+        PaletteAction {
+            id: "activity",
+            label: "External changes (activity)".into(),
+            shortcut: Some("W".into()),
+            contexts: &[ViewContext::Global],
+            category: ActionCategory::Navigate,
+        },
+        PaletteAction {
+            id: "undo_history",
+            label: "Undo history".into(),
+            shortcut: Some("U".into()),
             contexts: &[ViewContext::Global],
             category: ActionCategory::Navigate,
         },
         PaletteAction {
             id: "toggle_help",
             label: "Toggle help".into(),
-            shortcut: Some("?"),
+            shortcut: Some("?".into()),
             contexts: &[ViewContext::Global],
             category: ActionCategory::Navigate,
         },
         PaletteAction {
             id: "undo",
             label: "Undo".into(),
-            shortcut: Some("z/u"),
+            shortcut: Some("z/u".into()),
             contexts: &[ViewContext::Global],
             category: ActionCategory::System,
         },
         PaletteAction {
             id: "redo",
             label: "Redo".into(),
-            shortcut: Some("Z"),
+            shortcut: Some("Z".into()),
             contexts: &[ViewContext::Global],
             category: ActionCategory::System,
         },
         PaletteAction {
             id: "quit",
             label: "Quit".into(),
-            shortcut: Some("QQ"),
+            shortcut: Some("QQ".into()),
             contexts: &[ViewContext::Global],
             category: ActionCategory::System,
         },
@@ -440,42 +524,42 @@ fn static_actions() -> Vec<PaletteAction> {
         PaletteAction {
             id: "cycle_state",
             label: "Cycle state".into(),
-            shortcut: Some("Space"),
+            shortcut: Some("Space".into()),
             contexts: &[ViewContext::TrackView, ViewContext::DetailView],
             category: ActionCategory::State,
         },
         PaletteAction {
             id: "set_todo",
             label: "Set todo".into(),
-            shortcut: Some("o"),
+            shortcut: Some("o".into()),
             contexts: &[ViewContext::TrackView, ViewContext::DetailView],
             category: ActionCategory::State,
         },
         PaletteAction {
             id: "mark_done",
             label: "Mark done".into(),
-            shortcut: Some("x"),
+            shortcut: Some("x".into()),
             contexts: &[ViewContext::TrackView, ViewContext::DetailView],
             category: ActionCategory::State,
         },
         PaletteAction {
             id: "set_blocked",
             label: "Set blocked".into(),
-            shortcut: Some("b"),
+            shortcut: Some("b".into()),
             contexts: &[ViewContext::TrackView, ViewContext::DetailView],
             category: ActionCategory::State,
         },
         PaletteAction {
             id: "set_parked",
             label: "Set parked".into(),
-            shortcut: Some("~"),
+            shortcut: Some("~".into()),
             contexts: &[ViewContext::TrackView, ViewContext::DetailView],
             category: ActionCategory::State,
         },
         PaletteAction {
             id: "toggle_cc",
             label: "Toggle cc tag".into(),
-            shortcut: Some("c"),
+            shortcut: Some("c".into()),
             contexts: &[ViewContext::TrackView, ViewContext::DetailView],
             category: ActionCategory::State,
         },
@@ -496,21 +580,21 @@ fn static_actions() -> Vec<PaletteAction> {
         PaletteAction {
             id: "add_task_bottom",
             label: "Add task (bottom)".into(),
-            shortcut: Some("a"),
+            shortcut: Some("a".into()),
             contexts: &[ViewContext::TrackView],
             category: ActionCategory::Create,
         },
         PaletteAction {
             id: "append_to_group",
             label: "Append task to end of group".into(),
-            shortcut: Some("="),
+            shortcut: Some("=".into()),
             contexts: &[ViewContext::TrackView],
             category: ActionCategory::Create,
         },
         PaletteAction {
             id: "insert_after",
             label: "Insert after cursor".into(),
-            shortcut: Some("-"),
+            shortcut: Some("-".into()),
             contexts: &[
                 ViewContext::TrackView,
                 ViewContext::InboxView,
@@ -521,7 +605,7 @@ fn static_actions() -> Vec<PaletteAction> {
         PaletteAction {
             id: "push_to_top",
             label: "Push to top".into(),
-            shortcut: Some("p"),
+            shortcut: Some("p".into()),
             contexts: &[
                 ViewContext::TrackView,
                 ViewContext::InboxView,
@@ -532,21 +616,21 @@ fn static_actions() -> Vec<PaletteAction> {
         PaletteAction {
             id: "add_subtask",
             label: "Add subtask".into(),
-            shortcut: Some("A"),
+            shortcut: Some("A".into()),
             contexts: &[ViewContext::TrackView],
             category: ActionCategory::Create,
         },
         PaletteAction {
             id: "edit_title",
             label: "Edit title".into(),
-            shortcut: Some("e"),
+            shortcut: Some("e".into()),
             contexts: &[ViewContext::TrackView, ViewContext::InboxView],
             category: ActionCategory::Edit,
         },
         PaletteAction {
             id: "edit_tags",
             label: "Edit tags".into(),
-            shortcut: Some("t"),
+            shortcut: Some("t".into()),
             contexts: &[
                 ViewContext::TrackView,
                 ViewContext::DetailView,
@@ -557,14 +641,14 @@ fn static_actions() -> Vec<PaletteAction> {
         PaletteAction {
             id: "move_task",
             label: "Move task".into(),
-            shortcut: Some("m"),
+            shortcut: Some("m".into()),
             contexts: &[ViewContext::TrackView, ViewContext::InboxView],
             category: ActionCategory::Move,
         },
         PaletteAction {
             id: "move_to_track",
             label: "Move to track".into(),
-            shortcut: Some("M"),
+            shortcut: Some("M".into()),
             contexts: &[ViewContext::TrackView, ViewContext::DetailView],
             category: ActionCategory::Move,
         },
@@ -585,84 +669,84 @@ fn static_actions() -> Vec<PaletteAction> {
         PaletteAction {
             id: "filter_active",
             label: "Filter: active only".into(),
-            shortcut: Some("fa"),
+            shortcut: Some("fa".into()),
             contexts: &[ViewContext::TrackView],
             category: ActionCategory::Filter,
         },
         PaletteAction {
             id: "filter_todo",
             label: "Filter: todo only".into(),
-            shortcut: Some("fo"),
+            shortcut: Some("fo".into()),
             contexts: &[ViewContext::TrackView],
             category: ActionCategory::Filter,
         },
         PaletteAction {
             id: "filter_blocked",
             label: "Filter: blocked only".into(),
-            shortcut: Some("fb"),
+            shortcut: Some("fb".into()),
             contexts: &[ViewContext::TrackView],
             category: ActionCategory::Filter,
         },
         PaletteAction {
             id: "filter_ready",
             label: "Filter: ready (deps met)".into(),
-            shortcut: Some("fr"),
+            shortcut: Some("fr".into()),
             contexts: &[ViewContext::TrackView],
             category: ActionCategory::Filter,
         },
         PaletteAction {
             id: "filter_tag",
             label: "Filter: by tag".into(),
-            shortcut: Some("ft"),
+            shortcut: Some("ft".into()),
             contexts: &[ViewContext::TrackView],
             category: ActionCategory::Filter,
         },
         PaletteAction {
             id: "clear_state_filter",
             label: "Clear state filter".into(),
-            shortcut: Some("f Space"),
+            shortcut: Some("f Space".into()),
             contexts: &[ViewContext::TrackView],
             category: ActionCategory::Filter,
         },
         PaletteAction {
             id: "clear_all_filters",
             label: "Clear all filters".into(),
-            shortcut: Some("ff"),
+            shortcut: Some("ff".into()),
             contexts: &[ViewContext::TrackView],
             category: ActionCategory::Filter,
         },
         PaletteAction {
             id: "toggle_select",
             label: "Toggle select".into(),
-            shortcut: Some("v"),
+            shortcut: Some("v".into()),
             contexts: &[ViewContext::TrackView],
             category: ActionCategory::Select,
         },
         PaletteAction {
             id: "range_select",
             label: "Range select".into(),
-            shortcut: Some("V"),
+            shortcut: Some("V".into()),
             contexts: &[ViewContext::TrackView],
             category: ActionCategory::Select,
         },
         PaletteAction {
             id: "select_all",
             label: "Select all".into(),
-            shortcut: Some("Ctrl+A"),
+            shortcut: Some("Ctrl+A".into()),
             contexts: &[ViewContext::TrackView],
             category: ActionCategory::Select,
         },
         PaletteAction {
             id: "select_none",
             label: "Select none".into(),
-            shortcut: Some("N"),
+            shortcut: Some("N".into()),
             contexts: &[ViewContext::TrackView],
             category: ActionCategory::Select,
         },
         PaletteAction {
             id: "open_detail",
             label: "Open detail".into(),
-            shortcut: Some("Enter"),
+            shortcut: Some("Enter".into()),
             contexts: &[ViewContext::TrackView, ViewContext::RecentView],
             category: ActionCategory::Navigate,
         },
@@ -683,14 +767,14 @@ fn static_actions() -> Vec<PaletteAction> {
         PaletteAction {
             id: "set_cc_focus",
             label: "Set cc-focus".into(),
-            shortcut: Some("C"),
+            shortcut: Some("C".into()),
             contexts: &[ViewContext::TrackView, ViewContext::TracksView],
             category: ActionCategory::Manage,
         },
         PaletteAction {
             id: "repeat_action",
             label: "Repeat last action".into(),
-            shortcut: Some("."),
+            shortcut: Some(".".into()),
             contexts: &[ViewContext::TrackView, ViewContext::DetailView],
             category: ActionCategory::System,
         },
@@ -698,49 +782,70 @@ fn static_actions() -> Vec<PaletteAction> {
         PaletteAction {
             id: "edit_region",
             label: "Edit region".into(),
-            shortcut: Some("e/Enter"),
+            shortcut: Some("e/Enter".into()),
             contexts: &[ViewContext::DetailView],
             category: ActionCategory::Edit,
         },
         PaletteAction {
             id: "edit_refs",
             label: "Edit refs".into(),
-            shortcut: Some("@"),
+            shortcut: Some("@".into()),
             contexts: &[ViewContext::DetailView],
             category: ActionCategory::Edit,
         },
         PaletteAction {
             id: "edit_deps",
             label: "Edit dependencies".into(),
-            shortcut: Some("d"),
+            shortcut: Some("d".into()),
             contexts: &[ViewContext::DetailView],
             category: ActionCategory::Edit,
         },
         PaletteAction {
             id: "edit_note",
             label: "Edit note".into(),
-            shortcut: Some("n"),
+            shortcut: Some("n".into()),
             contexts: &[ViewContext::DetailView, ViewContext::InboxView],
             category: ActionCategory::Edit,
         },
         PaletteAction {
             id: "edit_note_from_start",
             label: "Edit note from start".into(),
-            shortcut: Some("N"),
+            shortcut: Some("N".into()),
             contexts: &[ViewContext::DetailView, ViewContext::InboxView],
             category: ActionCategory::Edit,
         },
         PaletteAction {
             id: "toggle_note_wrap",
             label: "Toggle note wrap".into(),
-            shortcut: Some("w"),
+            shortcut: Some("w".into()),
             contexts: &[ViewContext::DetailView],
             category: ActionCategory::Edit,
         },
+        PaletteAction {
+            id: "toggle_split",
+            label: "Toggle split layout".into(),
+            shortcut: Some("|".into()),
+            contexts: &[ViewContext::TrackView, ViewContext::DetailView],
+            category: ActionCategory::Navigate,
+        },
+        PaletteAction {
+            id: "split_wider",
+            label: "Widen the list pane".into(),
+            shortcut: Some("}".into()),
+            contexts: &[ViewContext::TrackView, ViewContext::DetailView],
+            category: ActionCategory::Navigate,
+        },
+        PaletteAction {
+            id: "split_narrower",
+            label: "Narrow the list pane".into(),
+            shortcut: Some("{".into()),
+            contexts: &[ViewContext::TrackView, ViewContext::DetailView],
+            category: ActionCategory::Navigate,
+        },
         PaletteAction {
             id: "back_to_track",
             label: "Back to track".into(),
-            shortcut: Some("Esc"),
+            shortcut: Some("Esc".into()),
             contexts: &[ViewContext::DetailView],
             category: ActionCategory::Navigate,
         },
@@ -748,21 +853,21 @@ fn static_actions() -> Vec<PaletteAction> {
         PaletteAction {
             id: "add_inbox_item",
             label: "Add item (bottom)".into(),
-            shortcut: Some("a/="),
+            shortcut: Some("a/=".into()),
             contexts: &[ViewContext::InboxView],
             category: ActionCategory::Create,
         },
         PaletteAction {
             id: "delete_inbox_item",
             label: "Delete item".into(),
-            shortcut: Some("x"),
+            shortcut: Some("x".into()),
             contexts: &[ViewContext::InboxView],
             category: ActionCategory::State,
         },
         PaletteAction {
             id: "begin_triage",
             label: "Begin triage".into(),
-            shortcut: Some("Enter"),
+            shortcut: Some("Enter".into()),
             contexts: &[ViewContext::InboxView],
             category: ActionCategory::Move,
         },
@@ -770,21 +875,21 @@ fn static_actions() -> Vec<PaletteAction> {
         PaletteAction {
             id: "reopen_todo",
             label: "Reopen as todo".into(),
-            shortcut: Some("Space"),
+            shortcut: Some("Space".into()),
             contexts: &[ViewContext::RecentView],
             category: ActionCategory::State,
         },
         PaletteAction {
             id: "expand_subtasks",
             label: "Expand subtasks".into(),
-            shortcut: Some("l"),
+            shortcut: Some("l".into()),
             contexts: &[ViewContext::RecentView],
             category: ActionCategory::Navigate,
         },
         PaletteAction {
             id: "collapse_subtasks",
             label: "Collapse subtasks".into(),
-            shortcut: Some("h"),
+            shortcut: Some("h".into()),
             contexts: &[ViewContext::RecentView],
             category: ActionCategory::Navigate,
         },
@@ -792,35 +897,35 @@ fn static_actions() -> Vec<PaletteAction> {
         PaletteAction {
             id: "open_track",
             label: "Open track".into(),
-            shortcut: Some("Enter"),
+            shortcut: Some("Enter".into()),
             contexts: &[ViewContext::TracksView],
             category: ActionCategory::Navigate,
         },
         PaletteAction {
             id: "add_track",
             label: "Add new track".into(),
-            shortcut: Some("a/="),
+            shortcut: Some("a/=".into()),
             contexts: &[ViewContext::TracksView],
             category: ActionCategory::Create,
         },
         PaletteAction {
             id: "edit_track_name",
             label: "Edit track name".into(),
-            shortcut: Some("e"),
+            shortcut: Some("e".into()),
             contexts: &[ViewContext::TracksView],
             category: ActionCategory::Edit,
         },
         PaletteAction {
             id: "shelve_activate",
             label: "Shelve / activate".into(),
-            shortcut: Some("s"),
+            shortcut: Some("s".into()),
             contexts: &[ViewContext::TracksView],
             category: ActionCategory::Manage,
         },
         PaletteAction {
             id: "reorder_track",
             label: "Reorder track".into(),
-            shortcut: Some("m"),
+            shortcut: Some("m".into()),
             contexts: &[ViewContext::TracksView],
             category: ActionCategory::Move,
         },
@@ -942,8 +1047,22 @@ impl CommandPaletteState {
         scored
             .action
             .shortcut
+            .as_deref()
             .and_then(|s| s.parse::<usize>().ok().map(|n| n - 1))
     }
+
+    /// For "add_from_template" actions, the template name from the label
+    pub fn selected_template(&self) -> Option<String> {
+        let scored = self.results.get(self.selected)?;
+        if scored.action.id != "add_from_template" {
+            return None;
+        }
+        scored
+            .action
+            .label
+            .strip_prefix(TEMPLATE_LABEL)
+            .map(str::to_string)
+    }
 }
 
 #[cfg(test)]
@@ -1052,14 +1171,14 @@ mod tests {
             PaletteAction {
                 id: "done",
                 label: "Mark done".into(),
-                shortcut: Some("x"),
+                shortcut: Some("x".into()),
                 contexts: &[ViewContext::Global],
                 category: ActionCategory::State,
             },
             PaletteAction {
                 id: "todo",
                 label: "Set todo".into(),
-                shortcut: Some("o"),
+                shortcut: Some("o".into()),
                 contexts: &[ViewContext::Global],
                 category: ActionCategory::State,
             },
@@ -1078,14 +1197,14 @@ mod tests {
             PaletteAction {
                 id: "fa",
                 label: "Filter: active only".into(),
-                shortcut: Some("fa"),
+                shortcut: Some("fa".into()),
                 contexts: &[ViewContext::Global],
                 category: ActionCategory::Filter,
             },
             PaletteAction {
                 id: "fb",
                 label: "Filter: blocked only".into(),
-                shortcut: Some("fb"),
+                shortcut: Some("fb".into()),
                 contexts: &[ViewContext::Global],
                 category: ActionCategory::Filter,
             },
@@ -1145,7 +1264,7 @@ mod tests {
         let actions = vec![PaletteAction {
             id: "cjk",
             label: "任務".into(), // 2 CJK chars, display_width=4 but char_count=2
-            shortcut: Some("x"),
+            shortcut: Some("x".into()),
             contexts: &[ViewContext::Global],
             category: ActionCategory::State,
         }];
@@ -1160,7 +1279,7 @@ mod tests {
         let actions = vec![PaletteAction {
             id: "emoji",
             label: "🚀 Launch".into(),
-            shortcut: Some("L"),
+            shortcut: Some("L".into()),
             contexts: &[ViewContext::Global],
             category: ActionCategory::State,
         }];
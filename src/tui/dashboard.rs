@@ -0,0 +1,243 @@
+//! The dashboard: every registered project on one screen.
+//!
+//! Each project is loaded from disk when the dashboard opens and boiled down
+//! to a [`ProjectSummary`] — how much is in flight, ready and blocked across
+//! its active tracks, its cc-focus track, and the first few tasks `fr ready`
+//! would list. Nothing is kept loaded; opening one switches the TUI to it the
+//! way the project picker does.
+
+use std::path::Path;
+
+use crate::io::project_io;
+use crate::io::registry::{self, ProjectEntry, ProjectRegistry};
+use crate::model::task::{Metadata, Task, TaskState};
+use crate::model::{Project, SectionKind};
+use crate::ops::deps::{self, ExternalDeps};
+use crate::ops::task_ops;
+
+/// Ready tasks listed under each project.
+pub const READY_SHOWN: usize = 3;
+
+/// One project's row on the dashboard.
+#[derive(Debug, Clone)]
+pub struct ProjectSummary {
+    pub name: String,
+    pub path: String,
+    /// Why the project couldn't be loaded, in which case the counts are zero.
+    pub error: Option<String>,
+    pub active: usize,
+    pub ready: usize,
+    pub blocked: usize,
+    /// The cc-focus track's name.
+    pub focus: Option<String>,
+    /// The first [`READY_SHOWN`] ready tasks, focus track first: `(id, title)`.
+    pub next: Vec<(String, String)>,
+}
+
+/// Dashboard state
+#[derive(Debug, Clone)]
+pub struct DashboardState {
+    pub projects: Vec<ProjectSummary>,
+    pub cursor: usize,
+    pub scroll: usize,
+    /// Path of the project the TUI has open
+    pub current_path: String,
+}
+
+impl DashboardState {
+    /// Load and summarise every registered project other than a worktree —
+    /// the same project on another branch — most recently opened first.
+    pub fn load(current_path: &Path) -> Self {
+        Self::load_from(&registry::read_registry(), current_path)
+    }
+
+    pub fn load_from(reg: &ProjectRegistry, current_path: &Path) -> Self {
+        let mut entries: Vec<&ProjectEntry> = reg
+            .projects
+            .iter()
+            .filter(|e| e.worktree_of.is_none())
+            .collect();
+        entries.sort_by_key(|e| std::cmp::Reverse(e.last_accessed_tui));
+        let projects = entries
+            .into_iter()
+            .map(
+                // Summarised from its tasks alone, so without the user config
+                |entry| match project_io::load_project_with(Path::new(&entry.path), None) {
+                    Ok(project) => summarize(&entry.name, &entry.path, &project),
+                    Err(e) => ProjectSummary {
+                        name: entry.name.clone(),
+                        path: entry.path.clone(),
+                        error: Some(e.to_string()),
+                        active: 0,
+                        ready: 0,
+                        blocked: 0,
+                        focus: None,
+                        next: Vec::new(),
+                    },
+                },
+            )
+            .collect();
+        let current_path = current_path.to_string_lossy().to_string();
+        DashboardState {
+            projects,
+            cursor: 0,
+            scroll: 0,
+            current_path,
+        }
+    }
+
+    pub fn selected(&self) -> Option<&ProjectSummary> {
+        self.projects.get(self.cursor)
+    }
+}
+
+/// Count one project's work across its active tracks, the cc-focus track
+/// first. Ready is what `fr ready` means by it: a todo task in a backlog
+/// whose deps, in this project or another, are all done.
+pub fn summarize(name: &str, path: &str, project: &Project) -> ProjectSummary {
+    let focus = project.config.agent.cc_focus.as_deref();
+    let mut track_ids: Vec<&str> = project
+        .config
+        .tracks
+        .iter()
+        .filter(|tc| tc.state == "active")
+        .map(|tc| tc.id.as_str())
+        .collect();
+    track_ids.sort_by_key(|id| Some(*id) != focus);
+
+    let external = ExternalDeps::load(project);
+    let mut summary = ProjectSummary {
+        name: name.to_string(),
+        path: path.to_string(),
+        error: None,
+        active: 0,
+        ready: 0,
+        blocked: 0,
+        focus: focus.and_then(|id| {
+            project
+                .config
+                .tracks
+                .iter()
+                .find(|tc| tc.id == id)
+                .map(|tc| tc.name.clone())
+        }),
+        next: Vec::new(),
+    };
+    for track_id in track_ids {
+        let Some((_, track)) = project.tracks.iter().find(|(id, _)| id == track_id) else {
+            continue;
+        };
+        for section in [SectionKind::Backlog, SectionKind::Parked] {
+            count(
+                track.section_tasks(section),
+                section == SectionKind::Backlog,
+                project,
+                &external,
+                &mut summary,
+            );
+        }
+    }
+    summary
+}
+
+fn count(
+    tasks: &[Task],
+    in_backlog: bool,
+    project: &Project,
+    external: &ExternalDeps,
+    summary: &mut ProjectSummary,
+) {
+    for task in tasks {
+        match task.state {
+            TaskState::Active => summary.active += 1,
+            TaskState::Blocked => summary.blocked += 1,
+            TaskState::Todo if in_backlog && !waits_on_deps(task, project, external) => {
+                summary.ready += 1;
+                if summary.next.len() < READY_SHOWN
+                    && let Some(id) = &task.id
+                {
+                    summary.next.push((id.to_string(), task.title.clone()));
+                }
+            }
+            _ => {}
+        }
+        count(&task.subtasks, in_backlog, project, external, summary);
+    }
+}
+
+fn waits_on_deps(task: &Task, project: &Project, external: &ExternalDeps) -> bool {
+    task.metadata.iter().any(|m| match m {
+        Metadata::Dep(ids) => ids.iter().any(|id| {
+            if deps::external_ref(id).is_some() {
+                return external.is_open(id);
+            }
+            project.tracks.iter().any(|(_, track)| {
+                task_ops::find_task_in_track(track, id).is_some_and(|t| t.state != TaskState::Done)
+            })
+        }),
+        _ => false,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn project_on_disk(dir: &Path, name: &str, track_md: &str) -> ProjectEntry {
+        let frame = dir.join("frame");
+        std::fs::create_dir_all(frame.join("tracks")).unwrap();
+        std::fs::write(
+            frame.join("project.toml"),
+            format!(
+                "[project]\nname = \"{name}\"\n\n[agent]\ncc_focus = \"main\"\n\n\
+                 [[tracks]]\nid = \"main\"\nname = \"Main\"\nstate = \"active\"\n\
+                 file = \"tracks/main.md\"\n"
+            ),
+        )
+        .unwrap();
+        std::fs::write(frame.join("tracks/main.md"), track_md).unwrap();
+        ProjectEntry {
+            name: name.into(),
+            path: dir.to_string_lossy().to_string(),
+            last_accessed_tui: None,
+            last_accessed_cli: None,
+            worktree_of: None,
+        }
+    }
+
+    #[test]
+    fn a_summary_counts_active_ready_and_blocked_work() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let entry = project_on_disk(
+            tmp.path(),
+            "api",
+            "# Main\n\n## Backlog\n\n\
+             - [>] `M-001` Building\n\
+             - [ ] `M-002` Next up\n\
+             - [ ] `M-003` Waits on M-001\n  - dep: M-001\n\
+             - [-] `M-004` Stuck\n\
+             - [ ] `M-005` Also ready\n\n## Done\n",
+        );
+        let missing = ProjectEntry {
+            name: "gone".into(),
+            path: tmp.path().join("nowhere").to_string_lossy().to_string(),
+            ..entry.clone()
+        };
+        let reg = ProjectRegistry {
+            projects: vec![entry, missing],
+        };
+
+        let state = DashboardState::load_from(&reg, tmp.path());
+        let api = &state.projects[0];
+        assert_eq!((api.active, api.ready, api.blocked), (1, 2, 1));
+        assert_eq!(api.focus.as_deref(), Some("Main"));
+        assert_eq!(
+            api.next,
+            [
+                ("M-002".to_string(), "Next up".to_string()),
+                ("M-005".to_string(), "Also ready".to_string())
+            ]
+        );
+        assert!(state.projects[1].error.is_some());
+    }
+}
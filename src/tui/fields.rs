@@ -117,6 +117,8 @@ pub(crate) fn field_to_buffer(task: &Task, region: DetailRegion) -> String {
         DetailRegion::Conflict
         | DetailRegion::Added
         | DetailRegion::Resolved
+        | DetailRegion::Relations
+        | DetailRegion::Fields
         | DetailRegion::Subtasks => String::new(),
     }
 }
@@ -145,6 +147,7 @@ pub(crate) fn apply_buffer(task: &mut Task, region: DetailRegion, buffer: &str)
         DetailRegion::Deps => set_list(task, parse_ids(buffer), "dep", Metadata::Dep),
         DetailRegion::Spec => set_list(task, parse_paths(buffer), "spec", Metadata::Spec),
         DetailRegion::Refs => set_list(task, parse_paths(buffer), "ref", Metadata::Ref),
+        #[allow(clippy::nonminimal_bool)]
         DetailRegion::Note => {
             let current = field_to_buffer(task, DetailRegion::Note);
             let has_note = task.metadata.iter().any(|m| matches!(m, Metadata::Note(_)));
@@ -162,6 +165,8 @@ pub(crate) fn apply_buffer(task: &mut Task, region: DetailRegion, buffer: &str)
         DetailRegion::Conflict
         | DetailRegion::Added
         | DetailRegion::Resolved
+        | DetailRegion::Relations
+        | DetailRegion::Fields
         | DetailRegion::Subtasks => false,
     }
 }
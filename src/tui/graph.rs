@@ -0,0 +1,286 @@
+//! The dependency graph view's layout.
+//!
+//! [`deps::dep_graph`] gives the tasks and the edges between them; this puts
+//! them on a grid the way a layered drawing does. Work flows left to right: a
+//! task sits one column to the right of the rightmost task it depends on, so
+//! what nothing waits on is at the left and what it unblocks fans out to the
+//! right. An edge spanning several columns passes through a placeholder in
+//! each column between, which keeps it clear of the boxes there, and the order
+//! within each column comes from a few sweeps that move every task towards the
+//! average row of its neighbours — enough to untangle most crossings without
+//! trying to remove them all.
+//!
+//! A cycle has no left-to-right order. Its closing edge is kept for moving
+//! around the graph but is not drawn; `fr check` is what reports the cycle.
+
+use std::collections::{HashMap, VecDeque};
+
+use crate::model::Project;
+use crate::ops::deps::{self, GraphNode};
+
+/// Ordering sweeps, each one down the columns and back.
+const SWEEPS: usize = 4;
+
+/// A laid-out dependency graph.
+#[derive(Debug, Clone, Default)]
+pub struct GraphLayout {
+    /// The tasks, as [`deps::dep_graph`] lists them.
+    pub nodes: Vec<GraphNode>,
+    /// `(dependency, dependent)`, as indices into `nodes`.
+    pub edges: Vec<(usize, usize)>,
+    /// Each column top to bottom: a task, by index into `nodes`, or `None`
+    /// where an edge passes through.
+    pub columns: Vec<Vec<Option<usize>>>,
+    /// Each node's `(column, row)`.
+    pub place: Vec<(usize, usize)>,
+    /// The drawn edges, each as the `(column, row)` slots it runs through from
+    /// the dependency to the dependent.
+    pub paths: Vec<Vec<(usize, usize)>>,
+}
+
+impl GraphLayout {
+    /// Lay out the dependencies with an end in `track_ids`.
+    pub fn build(project: &Project, track_ids: &[&str]) -> Self {
+        let graph = deps::dep_graph(project, track_ids);
+        let index: HashMap<&str, usize> = graph
+            .nodes
+            .iter()
+            .enumerate()
+            .map(|(i, node)| (node.id.as_str(), i))
+            .collect();
+        let edges: Vec<(usize, usize)> = graph
+            .edges
+            .iter()
+            .map(|(from, to)| (index[from.as_str()], index[to.as_str()]))
+            .filter(|(from, to)| from != to)
+            .collect();
+        Self::lay_out(graph.nodes, edges)
+    }
+
+    fn lay_out(nodes: Vec<GraphNode>, edges: Vec<(usize, usize)>) -> Self {
+        let n = nodes.len();
+        let layer = layers(n, &edges);
+
+        // Vertices: the nodes, then a placeholder per column a long edge
+        // crosses. `chains` are the drawn edges, vertex by vertex.
+        let mut vertex_layer = layer.clone();
+        let mut preds: Vec<Vec<usize>> = vec![Vec::new(); n];
+        let mut succs: Vec<Vec<usize>> = vec![Vec::new(); n];
+        let mut chains: Vec<Vec<usize>> = Vec::new();
+        for &(from, to) in &edges {
+            if layer[to] <= layer[from] {
+                continue;
+            }
+            let mut chain = vec![from];
+            for l in layer[from] + 1..layer[to] {
+                vertex_layer.push(l);
+                preds.push(Vec::new());
+                succs.push(Vec::new());
+                chain.push(vertex_layer.len() - 1);
+            }
+            chain.push(to);
+            for pair in chain.windows(2) {
+                succs[pair[0]].push(pair[1]);
+                preds[pair[1]].push(pair[0]);
+            }
+            chains.push(chain);
+        }
+
+        let depth = vertex_layer.iter().max().map_or(0, |&l| l + 1);
+        let mut order: Vec<Vec<usize>> = vec![Vec::new(); depth];
+        for (v, &l) in vertex_layer.iter().enumerate() {
+            order[l].push(v);
+        }
+        let mut pos = vec![0usize; vertex_layer.len()];
+        let update = |order: &[usize], pos: &mut [usize]| {
+            for (row, &v) in order.iter().enumerate() {
+                pos[v] = row;
+            }
+        };
+        for column in &order {
+            update(column, &mut pos);
+        }
+        for _ in 0..SWEEPS {
+            for column in order.iter_mut().skip(1) {
+                sort_by_neighbours(column, &preds, &pos);
+                update(column, &mut pos);
+            }
+            for column in order.iter_mut().rev().skip(1) {
+                sort_by_neighbours(column, &succs, &pos);
+                update(column, &mut pos);
+            }
+        }
+
+        let columns = order
+            .iter()
+            .map(|column| column.iter().map(|&v| (v < n).then_some(v)).collect())
+            .collect();
+        let place = (0..n).map(|v| (vertex_layer[v], pos[v])).collect();
+        let paths = chains
+            .iter()
+            .map(|chain| chain.iter().map(|&v| (vertex_layer[v], pos[v])).collect())
+            .collect();
+        GraphLayout {
+            nodes,
+            edges,
+            columns,
+            place,
+            paths,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    pub fn index_of(&self, id: &str) -> Option<usize> {
+        self.nodes.iter().position(|node| node.id == id)
+    }
+
+    /// The tasks `node` depends on.
+    pub fn dependencies(&self, node: usize) -> Vec<usize> {
+        self.edges
+            .iter()
+            .filter(|&&(_, to)| to == node)
+            .map(|&(from, _)| from)
+            .collect()
+    }
+
+    /// The tasks that depend on `node`.
+    pub fn dependents(&self, node: usize) -> Vec<usize> {
+        self.edges
+            .iter()
+            .filter(|&&(from, _)| from == node)
+            .map(|&(_, to)| to)
+            .collect()
+    }
+
+    /// Of `candidates`, the one whose row is nearest `node`'s — the one an
+    /// edge from it runs most nearly straight to.
+    pub fn nearest(&self, node: usize, candidates: &[usize]) -> Option<usize> {
+        let row = self.place[node].1;
+        candidates
+            .iter()
+            .copied()
+            .min_by_key(|&c| (self.place[c].1.abs_diff(row), self.place[c].1))
+    }
+
+    /// The task `step` rows down `node`'s column (up, for a negative step),
+    /// skipping the slots edges pass through.
+    pub fn in_column(&self, node: usize, step: isize) -> Option<usize> {
+        let (column, row) = self.place[node];
+        let tasks = self.columns[column].iter().flatten().copied();
+        let above: Vec<usize> = tasks.clone().filter(|&t| self.place[t].1 < row).collect();
+        let below: Vec<usize> = tasks.filter(|&t| self.place[t].1 > row).collect();
+        match step {
+            s if s > 0 => below.get(s as usize - 1).copied(),
+            s if s < 0 => above.iter().rev().nth(s.unsigned_abs() - 1).copied(),
+            _ => Some(node),
+        }
+    }
+}
+
+/// Each node's column: one right of its rightmost dependency. Nodes on a cycle
+/// are taken in list order once nothing else is left, and the edge that
+/// closes the cycle points backwards.
+fn layers(n: usize, edges: &[(usize, usize)]) -> Vec<usize> {
+    let mut indegree = vec![0usize; n];
+    let mut succs: Vec<Vec<usize>> = vec![Vec::new(); n];
+    for &(from, to) in edges {
+        indegree[to] += 1;
+        succs[from].push(to);
+    }
+    let mut layer = vec![0usize; n];
+    let mut done = vec![false; n];
+    let mut queue: VecDeque<usize> = (0..n).filter(|&v| indegree[v] == 0).collect();
+    loop {
+        while let Some(u) = queue.pop_front() {
+            if std::mem::replace(&mut done[u], true) {
+                continue;
+            }
+            for &v in &succs[u] {
+                if done[v] {
+                    continue;
+                }
+                layer[v] = layer[v].max(layer[u] + 1);
+                indegree[v] -= 1;
+                if indegree[v] == 0 {
+                    queue.push_back(v);
+                }
+            }
+        }
+        match (0..n).find(|&v| !done[v]) {
+            Some(v) => queue.push_back(v),
+            None => break,
+        }
+    }
+    layer
+}
+
+/// Sort a column by the average row of each vertex's neighbours in the
+/// column beside it. A vertex with none there keeps its own row as its key.
+fn sort_by_neighbours(column: &mut [usize], neighbours: &[Vec<usize>], pos: &[usize]) {
+    let key = |v: usize| -> f64 {
+        let around = &neighbours[v];
+        if around.is_empty() {
+            pos[v] as f64
+        } else {
+            around.iter().map(|&u| pos[u] as f64).sum::<f64>() / around.len() as f64
+        }
+    };
+    column.sort_by(|&a, &b| key(a).total_cmp(&key(b)));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn node(id: &str) -> GraphNode {
+        GraphNode {
+            id: id.into(),
+            track_id: Some("t".into()),
+            title: Some(id.into()),
+            state: None,
+        }
+    }
+
+    fn layout(ids: &[&str], edges: &[(usize, usize)]) -> GraphLayout {
+        GraphLayout::lay_out(ids.iter().map(|id| node(id)).collect(), edges.to_vec())
+    }
+
+    #[test]
+    fn work_flows_left_to_right() {
+        // infra -> api -> ui, and infra -> ui directly
+        let g = layout(&["ui", "api", "infra"], &[(2, 1), (1, 0), (2, 0)]);
+        assert_eq!(g.place[2].0, 0);
+        assert_eq!(g.place[1].0, 1);
+        assert_eq!(g.place[0].0, 2);
+        // The long edge passes through the middle column
+        assert_eq!(g.columns[1].len(), 2);
+        assert!(g.columns[1].contains(&None));
+        let long = g.paths.iter().find(|p| p.len() == 3).unwrap();
+        assert_eq!((long[0].0, long[2].0), (0, 2));
+    }
+
+    #[test]
+    fn a_fan_out_stays_beside_its_source() {
+        // a fans out to c and d; b, with nothing to do with them, to e
+        let g = layout(&["a", "b", "c", "d", "e"], &[(1, 4), (0, 2), (0, 3)]);
+        assert_eq!(g.dependents(0), [2, 3]);
+        assert_eq!(g.dependencies(4), [1]);
+        // e follows b to the lower row, below a's two dependents
+        assert!(g.place[4].1 > g.place[2].1 && g.place[4].1 > g.place[3].1);
+        assert_eq!(g.nearest(0, &g.dependents(0)), Some(2));
+        assert_eq!(g.in_column(2, 1), Some(3));
+        assert_eq!(g.in_column(2, -1), None);
+    }
+
+    #[test]
+    fn a_cycle_is_laid_out_without_its_closing_edge() {
+        let g = layout(&["a", "b"], &[(0, 1), (1, 0)]);
+        assert_ne!(g.place[0].0, g.place[1].0);
+        assert_eq!(g.paths.len(), 1);
+        // Both edges are still there to move along
+        assert_eq!(g.dependents(1), [0]);
+    }
+}
@@ -6,6 +6,7 @@ use crate::ops::ids::Mint;
 use crate::ops::task_ops::{self};
 
 use crate::tui::app::{App, DetailRegion, EditHistory, EditTarget, Mode, StateFilter, View};
+use crate::tui::jumps::{Direction, MarkPending};
 use crate::tui::undo::Operation;
 
 use super::*;
@@ -29,6 +30,7 @@ pub(super) fn handle_command(app: &mut App, key: KeyEvent) {
                 if let Some(scored) = cp.results.get(cp.selected) {
                     let action_id = scored.action.id.to_string();
                     let track_index = cp.selected_track_index();
+                    app.new_task_template = cp.selected_template();
                     app.mode = Mode::Navigate;
                     dispatch_palette_action(app, &action_id, track_index);
                 } else {
@@ -105,6 +107,10 @@ pub(super) fn dispatch_palette_action(app: &mut App, action_id: &str, track_inde
             app.close_detail_fully();
             app.view = View::Recent;
         }
+        "open_stats" => {
+            app.close_detail_fully();
+            app.view = View::Stats;
+        }
         "open_tracks" => {
             app.close_detail_fully();
             app.tracks_name_col_min = 0;
@@ -115,6 +121,10 @@ pub(super) fn dispatch_palette_action(app: &mut App, action_id: &str, track_inde
             app.project_search_results = None;
             app.view = View::Board;
         }
+        "open_graph" => {
+            app.project_search_results = None;
+            app.open_graph();
+        }
         "search" => {
             app.mode = Mode::Search;
             app.search_input.clear();
@@ -134,6 +144,18 @@ pub(super) fn dispatch_palette_action(app: &mut App, action_id: &str, track_inde
         "jump_to_task" => {
             begin_jump_to(app);
         }
+        "jump_back" => {
+            travel(app, Direction::Back);
+        }
+        "jump_forward" => {
+            travel(app, Direction::Forward);
+        }
+        "set_mark" => {
+            app.mark_pending = Some(MarkPending::Set);
+        }
+        "go_to_mark" => {
+            app.mark_pending = Some(MarkPending::Go);
+        }
         "show_deps" => {
             if matches!(app.view, View::Track(_)) {
                 open_dep_popup_from_track_view(app);
@@ -147,6 +169,15 @@ pub(super) fn dispatch_palette_action(app: &mut App, action_id: &str, track_inde
         "projects" => {
             open_project_picker(app);
         }
+        "dashboard" => {
+            open_dashboard(app);
+        }
+        "activity" => {
+            app.activity_panel = Some(Default::default());
+        }
+        "undo_history" => {
+            open_undo_history(app);
+        }
         "toggle_help" => {
             app.show_help = !app.show_help;
             app.help_scroll = 0;
@@ -162,6 +193,15 @@ pub(super) fn dispatch_palette_action(app: &mut App, action_id: &str, track_inde
                 .into(),
             );
         }
+        "toggle_split" => {
+            app.toggle_split();
+        }
+        "split_wider" => {
+            app.resize_split(5);
+        }
+        "split_narrower" => {
+            app.resize_split(-5);
+        }
         "undo" => {
             perform_undo(app);
         }
@@ -216,6 +256,15 @@ pub(super) fn dispatch_palette_action(app: &mut App, action_id: &str, track_inde
                 add_task_action(app, AddPosition::Bottom);
             }
         }
+        "add_from_template" => {
+            if app.new_task_template.is_some() {
+                add_task_action(app, AddPosition::Bottom);
+            }
+            // Nothing to title, so nothing for the template to wait on.
+            if app.mode != Mode::Edit {
+                app.new_task_template = None;
+            }
+        }
         "append_to_group" => {
             append_sibling_action(app);
         }
@@ -792,6 +841,46 @@ pub(super) fn palette_check_project(app: &mut App) {
                 check::CheckWarning::LostTask { track_id, task_id } => {
                     format!("  [{}] {} has #lost tag", track_id, task_id)
                 }
+                check::CheckWarning::BlockedWithoutCause { track_id, task_id } => {
+                    format!(
+                        "  [{}] {} is blocked with no open dep or note",
+                        track_id, task_id
+                    )
+                }
+                check::CheckWarning::ExternalProjectUnreachable { project, .. } => {
+                    format!("  project '{}' unreachable; its deps not checked", project)
+                }
+                check::CheckWarning::InvalidField {
+                    track_id,
+                    task_id,
+                    key,
+                    reason,
+                    ..
+                } => {
+                    format!("  [{}] {} {}: {}", track_id, task_id, key, reason)
+                }
+                check::CheckWarning::UndeclaredState {
+                    track_id,
+                    task_id,
+                    checkbox,
+                } => {
+                    format!(
+                        "  [{}] {} has undeclared state [{}]",
+                        track_id, task_id, checkbox
+                    )
+                }
+                check::CheckWarning::DanglingRelation {
+                    track_id,
+                    task_id,
+                    relation,
+                    target,
+                } => format!(
+                    "  [{}] {} has dangling {}: {}",
+                    track_id,
+                    task_id,
+                    relation.key(),
+                    target
+                ),
                 check::CheckWarning::ChildIdNotUnderParent {
                     track_id,
                     task_id,
@@ -10,6 +10,7 @@ use crate::tui::app::{
     App, DetailRegion, DetailState, FlatItem, Mode, PendingMove, RepeatableAction, TrackExit, View,
     resolve_task_from_flat,
 };
+use crate::tui::jumps::Location;
 use crate::tui::undo::{Operation, UndoNavTarget};
 use std::io::Write;
 use std::process::{Command, Stdio};
@@ -212,6 +213,41 @@ pub(super) fn normalize_key(mut key: KeyEvent) -> KeyEvent {
     key
 }
 
+/// Where an input event started, to tell afterwards whether it jumped.
+pub(super) struct JumpFrom {
+    location: Option<Location>,
+    frame_dir: std::path::PathBuf,
+}
+
+impl JumpFrom {
+    pub(super) fn here(app: &App) -> Self {
+        JumpFrom {
+            location: app.location(),
+            frame_dir: app.project.frame_dir.clone(),
+        }
+    }
+
+    /// Record the starting place if the event changed view, or jumped within
+    /// one. Walking the list itself, and switching projects, record nothing.
+    pub(super) fn settle(self, app: &mut App) {
+        let jumped = std::mem::take(&mut app.jumps.jumped);
+        if std::mem::take(&mut app.jumps.travelled) || app.project.frame_dir != self.frame_dir {
+            return;
+        }
+        let Some(from) = self.location else {
+            return;
+        };
+        let to = app.location();
+        let moved = match &to {
+            Some(to) => !from.same_view(to) || (jumped && from != *to),
+            None => true,
+        };
+        if moved {
+            app.jumps.record(from);
+        }
+    }
+}
+
 /// Drain any pending watcher events for a specific track (already handled via mtime).
 /// Reloads remaining pending paths for other files.
 pub(super) fn drain_pending_for_track(app: &mut App, handled_track_id: &str) {
@@ -326,6 +362,7 @@ pub(super) fn perform_undo(app: &mut App) {
     // pending move, cancel the pending move so undo reverts both in one press.
     if let Some(op) = app.undo_stack.peek_last_undo() {
         match op {
+            #[allow(clippy::collapsible_match)]
             Operation::StateChange {
                 track_id,
                 task_id,
@@ -470,18 +507,27 @@ pub(super) fn apply_nav_side_effects(app: &mut App, nav: &UndoNavTarget, is_undo
                 }
                 Some(Operation::Bulk(ops)) => {
                     for sub_op in ops {
-                        if let Operation::CrossTrackMove {
-                            source_track_id,
-                            target_track_id,
-                            ..
-                        } = sub_op
-                        {
-                            if source_track_id != track_id {
-                                extra_tracks.push(source_track_id.clone());
+                        match sub_op {
+                            Operation::CrossTrackMove {
+                                source_track_id,
+                                target_track_id,
+                                ..
+                            } => {
+                                if source_track_id != track_id {
+                                    extra_tracks.push(source_track_id.clone());
+                                }
+                                if target_track_id != track_id {
+                                    extra_tracks.push(target_track_id.clone());
+                                }
                             }
-                            if target_track_id != track_id {
-                                extra_tracks.push(target_track_id.clone());
+                            // `[deps] auto_block` can change a dependent on
+                            // another track in the same step.
+                            Operation::StateChange {
+                                track_id: other, ..
+                            } if other != track_id && !extra_tracks.contains(other) => {
+                                extra_tracks.push(other.clone());
                             }
+                            _ => {}
                         }
                     }
                 }
@@ -996,6 +1042,8 @@ pub(super) enum StateAction {
     SetTodo,
     ToggleBlocked,
     ToggleParked,
+    /// Straight to a state, as dropping a card on a board column does
+    Set(crate::model::TaskState),
 }
 
 /// Schedule the section move a state change calls for, and report the section
@@ -1023,8 +1071,9 @@ pub(super) fn schedule_section_move(
 ) -> Option<SectionKind> {
     let track = App::find_track_in_project(&app.project, track_id)?;
     let from = task_ops::top_level_section(track, task_id)?;
-    let to = task_ops::canonical_section(new_state);
-    if from != to {
+    let workflow = &app.project.config.workflow;
+    if !task_ops::section_accepts(from, new_state, workflow) {
+        let to = task_ops::canonical_section(new_state, workflow);
         app.pending_moves.push(PendingMove {
             from,
             to,
@@ -1063,6 +1112,7 @@ pub(super) fn task_state_action(app: &mut App, action: StateAction) {
         return;
     };
 
+    let workflow = app.project.config.workflow.clone();
     let track = match app.find_track_mut(&track_id) {
         Some(t) => t,
         None => return,
@@ -1083,11 +1133,12 @@ pub(super) fn task_state_action(app: &mut App, action: StateAction) {
     });
 
     match action {
-        StateAction::Cycle => task_ops::cycle_state(task),
+        StateAction::Cycle => task_ops::cycle_state(task, &workflow),
         StateAction::Done => task_ops::set_done(task),
         StateAction::SetTodo => task_ops::set_state(task, crate::model::task::TaskState::Todo),
         StateAction::ToggleBlocked => task_ops::set_blocked(task),
         StateAction::ToggleParked => task_ops::set_parked(task),
+        StateAction::Set(state) => task_ops::set_state(task, state),
     }
 
     let new_state = task.state;
@@ -1137,14 +1188,24 @@ pub(super) fn task_state_action(app: &mut App, action: StateAction) {
                 });
         }
 
-        app.undo_stack.push(Operation::StateChange {
+        let op = Operation::StateChange {
             track_id: track_id.clone(),
             task_id: task_id.clone(),
             old_state,
             new_state,
             old_resolved: old_resolved.clone(),
             new_resolved,
-        });
+        };
+        // `[deps] auto_block`: finishing a task can unblock the ones waiting
+        // on it, and reopening it can block them again. One undo takes the
+        // whole step back.
+        let auto = auto_block_after(app, &track_id);
+        if auto.is_empty() {
+            app.undo_stack.push(op);
+        } else {
+            app.undo_stack
+                .push(Operation::Bulk(std::iter::once(op).chain(auto).collect()));
+        }
 
         // Record repeatable action
         app.last_action = Some(match action {
@@ -1157,6 +1218,7 @@ pub(super) fn task_state_action(app: &mut App, action: StateAction) {
             StateAction::ToggleParked => {
                 RepeatableAction::SetState(crate::model::TaskState::Parked)
             }
+            StateAction::Set(state) => RepeatableAction::SetState(state),
         });
 
         // The section move — one call, every direction. `push_undo` is true
@@ -1207,6 +1269,43 @@ pub(super) fn task_state_action(app: &mut App, action: StateAction) {
     app.save_track_logged(&track_id);
 }
 
+/// Run the `[deps] auto_block` pass after a state change on `track_id`, saving
+/// the other tracks it touched — the caller saves `track_id` itself — and
+/// saying what it did in the status bar. Returns one undo entry per change.
+pub(super) fn auto_block_after(app: &mut App, track_id: &str) -> Vec<Operation> {
+    if !app.project.config.deps.auto_block {
+        return Vec::new();
+    }
+    let changes = crate::ops::deps::auto_block(&mut app.project);
+    if changes.is_empty() {
+        return Vec::new();
+    }
+    let mut saved = vec![track_id.to_string()];
+    let mut said = Vec::new();
+    let mut ops = Vec::new();
+    for change in changes {
+        if !saved.contains(&change.track_id) {
+            app.save_track_logged(&change.track_id);
+            saved.push(change.track_id.clone());
+        }
+        let to = match change.to {
+            crate::model::TaskState::Blocked => "blocked",
+            _ => "todo",
+        };
+        said.push(format!("{} → {}", change.task_id, to));
+        ops.push(Operation::StateChange {
+            track_id: change.track_id,
+            task_id: change.task_id,
+            old_state: change.from,
+            new_state: change.to,
+            old_resolved: None,
+            new_resolved: None,
+        });
+    }
+    app.status_message = Some(format!("auto: {}", said.join(", ")));
+    ops
+}
+
 // ---------------------------------------------------------------------------
 // CC tag / CC focus
 
@@ -1364,6 +1463,7 @@ pub(super) fn move_cursor(app: &mut App, delta: i32) {
             new_cursor = new_cursor.clamp(0, count as i32 - 1);
             app.recent_cursor = new_cursor as usize;
         }
+        View::Graph | View::Stats => {}
         View::Search => {
             if let Some(ref mut sr) = app.project_search_results {
                 let count = sr.items.len();
@@ -1381,40 +1481,42 @@ pub(super) fn move_cursor(app: &mut App, delta: i32) {
     }
 }
 
-/// Move cursor within the focused board column, skipping TrackHeader items.
+/// Move cursor within the focused board column, skipping LaneHeader items.
 pub(super) fn board_move_cursor(app: &mut App, delta: i32) {
     use crate::tui::app::BoardItem;
-    let col = app.board_state.focus_column.index();
+    let col = app.board_focus_index();
     let columns = app.build_board_columns();
-    let column = &columns[col];
+    let Some(column) = columns.get(col) else {
+        return;
+    };
     if column.is_empty() {
         return;
     }
     let count = column.len();
-    let mut pos = app.board_state.cursor[col] as i32 + delta;
+    let mut pos = app.board_state.cursor(col) as i32 + delta;
     pos = pos.clamp(0, count as i32 - 1);
     let mut pos = pos as usize;
 
     // Skip non-selectable headers
     if delta > 0 {
-        while pos < count && matches!(column[pos], BoardItem::TrackHeader { .. }) {
+        while pos < count && matches!(column[pos], BoardItem::LaneHeader { .. }) {
             pos += 1;
         }
         if pos >= count {
             // Find the last selectable item
             pos = count.saturating_sub(1);
-            while pos > 0 && matches!(column[pos], BoardItem::TrackHeader { .. }) {
+            while pos > 0 && matches!(column[pos], BoardItem::LaneHeader { .. }) {
                 pos -= 1;
             }
         }
     } else if delta < 0 {
-        while pos > 0 && matches!(column[pos], BoardItem::TrackHeader { .. }) {
+        while pos > 0 && matches!(column[pos], BoardItem::LaneHeader { .. }) {
             pos -= 1;
         }
         // If landed on header at position 0, try moving forward
-        if matches!(column[pos], BoardItem::TrackHeader { .. }) {
+        if matches!(column[pos], BoardItem::LaneHeader { .. }) {
             pos += 1;
-            while pos < count && matches!(column[pos], BoardItem::TrackHeader { .. }) {
+            while pos < count && matches!(column[pos], BoardItem::LaneHeader { .. }) {
                 pos += 1;
             }
             if pos >= count {
@@ -1423,7 +1525,7 @@ pub(super) fn board_move_cursor(app: &mut App, delta: i32) {
         }
     }
 
-    app.board_state.cursor[col] = pos;
+    app.board_state.set_cursor(col, pos);
 }
 
 /// Move cursor to the next/previous top-level task (depth 0) in the current view.
@@ -1553,7 +1655,7 @@ pub(super) fn is_paragraph_boundary(item: &FlatItem) -> bool {
         FlatItem::Task {
             depth, is_context, ..
         } => *depth == 0 && !*is_context,
-        FlatItem::ParkedSeparator => false,
+        FlatItem::SectionSeparator(_) => false,
         FlatItem::BulkMoveStandin { .. } => false,
         FlatItem::DoneSummary { .. } => false,
     }
@@ -1562,7 +1664,7 @@ pub(super) fn is_paragraph_boundary(item: &FlatItem) -> bool {
 /// Check if a flat item is non-selectable (separator, context row, or done summary)
 pub(super) fn is_non_selectable(item: &FlatItem) -> bool {
     match item {
-        FlatItem::ParkedSeparator => true,
+        FlatItem::SectionSeparator(_) => true,
         FlatItem::Task { is_context, .. } => *is_context,
         FlatItem::BulkMoveStandin { .. } => false,
         FlatItem::DoneSummary { .. } => true,
@@ -1637,6 +1739,7 @@ pub(super) fn jump_to_top(app: &mut App) {
             app.recent_cursor = 0;
             app.recent_scroll = 0;
         }
+        View::Graph | View::Stats => {}
         View::Search => {
             if let Some(ref mut sr) = app.project_search_results {
                 sr.cursor = 0;
@@ -1652,15 +1755,17 @@ pub(super) fn jump_to_top(app: &mut App) {
 /// Jump to the first selectable item in the focused board column
 pub(super) fn board_jump_top(app: &mut App) {
     use crate::tui::app::BoardItem;
-    let col = app.board_state.focus_column.index();
+    let col = app.board_focus_index();
     let columns = app.build_board_columns();
-    let column = &columns[col];
+    let Some(column) = columns.get(col) else {
+        return;
+    };
     let first = column
         .iter()
         .position(|item| matches!(item, BoardItem::Task { .. }))
         .unwrap_or(0);
-    app.board_state.cursor[col] = first;
-    app.board_state.scroll[col] = 0;
+    app.board_state.set_cursor(col, first);
+    *app.board_state.scroll_mut(col) = 0;
 }
 
 pub(super) fn jump_to_bottom(app: &mut App) {
@@ -1717,6 +1822,7 @@ pub(super) fn jump_to_bottom(app: &mut App) {
                 app.recent_cursor = count - 1;
             }
         }
+        View::Graph | View::Stats => {}
         View::Search => {
             if let Some(ref mut sr) = app.project_search_results
                 && !sr.items.is_empty()
@@ -1733,9 +1839,11 @@ pub(super) fn jump_to_bottom(app: &mut App) {
 /// Jump to the last selectable item in the focused board column
 pub(super) fn board_jump_bottom(app: &mut App) {
     use crate::tui::app::BoardItem;
-    let col = app.board_state.focus_column.index();
+    let col = app.board_focus_index();
     let columns = app.build_board_columns();
-    let column = &columns[col];
+    let Some(column) = columns.get(col) else {
+        return;
+    };
     if column.is_empty() {
         return;
     }
@@ -1743,7 +1851,7 @@ pub(super) fn board_jump_bottom(app: &mut App) {
         .iter()
         .rposition(|item| matches!(item, BoardItem::Task { .. }))
         .unwrap_or(0);
-    app.board_state.cursor[col] = last;
+    app.board_state.set_cursor(col, last);
 }
 
 /// Rebuild active_track_ids from config and clamp tracks_cursor.
@@ -1758,7 +1866,7 @@ pub(super) fn rebuild_active_track_ids(app: &mut App) {
 mod tests {
     use super::*;
     use crate::tui::app::app_on_disk;
-    use crate::tui::input::{command, confirm};
+    use crate::tui::input::{command, confirm, edit};
 
     fn track_text(app: &App) -> String {
         std::fs::read_to_string(app.project.frame_dir.join("tracks/a.md")).unwrap()
@@ -1791,6 +1899,113 @@ mod tests {
         );
     }
 
+    /// With `[deps] auto_block`, finishing a task unblocks the one waiting on
+    /// it, and one undo takes both back.
+    #[test]
+    fn auto_block_unblocks_a_dependent_and_undoes_in_one_step() {
+        use crate::model::task::{Task, TaskState};
+        let tmp = tempfile::TempDir::new().unwrap();
+        let mut app = app_on_disk(tmp.path());
+        app.project.config.deps.auto_block = true;
+        let mut waiting = Task::new(TaskState::Blocked, Some("A-002".into()), "Two".into());
+        waiting.metadata.push(Metadata::Dep(vec!["A-001".into()]));
+        app.find_track_mut("a")
+            .unwrap()
+            .section_tasks_mut(SectionKind::Backlog)
+            .unwrap()
+            .push(waiting);
+        assert!(app.jump_to_task("A-001"));
+
+        let state_of = |app: &App, id: &str| {
+            let track = App::find_track_in_project(&app.project, "a").unwrap();
+            task_ops::find_task_in_track(track, id).unwrap().state
+        };
+
+        task_state_action(&mut app, StateAction::Done);
+        assert_eq!(state_of(&app, "A-002"), TaskState::Todo);
+        assert_eq!(app.status_message.as_deref(), Some("auto: A-002 → todo"));
+
+        perform_undo(&mut app);
+        assert_eq!(state_of(&app, "A-001"), TaskState::Todo);
+        assert_eq!(state_of(&app, "A-002"), TaskState::Blocked);
+    }
+
+    /// "Add from template" fills the new task in when its title is committed,
+    /// and undo and redo take the whole task, not just its title.
+    #[test]
+    fn a_task_added_from_a_template_undoes_and_redoes_whole() {
+        use crate::model::config::TaskTemplate;
+        let tmp = tempfile::TempDir::new().unwrap();
+        let mut app = app_on_disk(tmp.path());
+        app.project
+            .config
+            .ids
+            .prefixes
+            .insert("a".into(), "A".into());
+        app.project.config.templates.insert(
+            "bug".into(),
+            TaskTemplate {
+                tags: vec!["bug".into()],
+                note: Some("Steps to reproduce:".into()),
+                subtasks: vec!["Failing test".into()],
+                ..TaskTemplate::default()
+            },
+        );
+        assert!(app.jump_to_task("A-001"));
+
+        app.new_task_template = Some("bug".into());
+        command::dispatch_palette_action(&mut app, "add_from_template", None);
+        assert_eq!(app.mode, Mode::Edit);
+        app.edit_buffer = "Crash on save".into();
+        edit::confirm_edit(&mut app);
+
+        let text = track_text(&app);
+        assert!(text.contains("Crash on save #bug"), "{text}");
+        assert!(text.contains("Steps to reproduce:"), "{text}");
+        assert!(text.contains("Failing test"), "{text}");
+        assert!(app.new_task_template.is_none());
+
+        perform_undo(&mut app);
+        assert!(!track_text(&app).contains("Crash on save"));
+        perform_redo(&mut app);
+        let text = track_text(&app);
+        assert!(text.contains("Failing test"), "{text}");
+    }
+
+    /// A template picked for an add that was cancelled is dropped with it, not
+    /// left to dress up the next plain add.
+    #[test]
+    fn a_cancelled_template_add_leaves_the_next_add_plain() {
+        use crate::model::config::TaskTemplate;
+        let tmp = tempfile::TempDir::new().unwrap();
+        let mut app = app_on_disk(tmp.path());
+        app.project
+            .config
+            .ids
+            .prefixes
+            .insert("a".into(), "A".into());
+        app.project.config.templates.insert(
+            "bug".into(),
+            TaskTemplate {
+                tags: vec!["bug".into()],
+                ..TaskTemplate::default()
+            },
+        );
+        assert!(app.jump_to_task("A-001"));
+
+        app.new_task_template = Some("bug".into());
+        command::dispatch_palette_action(&mut app, "add_from_template", None);
+        edit::cancel_edit(&mut app);
+        assert!(app.new_task_template.is_none());
+
+        command::dispatch_palette_action(&mut app, "add_task_bottom", None);
+        app.edit_buffer = "Plain".into();
+        edit::confirm_edit(&mut app);
+        let text = track_text(&app);
+        assert!(text.contains("Plain"), "{text}");
+        assert!(!text.contains("#bug"), "{text}");
+    }
+
     /// A task that really is in the Done section keeps the date for the grace
     /// period — the Done column and the Recent view both sort on it, and the
     /// row is still there until the move fires.
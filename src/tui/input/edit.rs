@@ -30,6 +30,42 @@ fn subtask_index(app: &App, track_id: &str, parent_id: &str, task_id: &str) -> u
         .unwrap_or(0)
 }
 
+/// Fill in a new top-level task from the template "Add from template" picked,
+/// once its title is committed, and return the undo entry for the whole add.
+///
+/// An `Import` of the finished task rather than a `TaskAdd`: redo replays a
+/// `TaskAdd` as a bare title, which would drop everything the template added.
+/// `None` when there is no template, or it could not be applied — the task
+/// then stands as typed and gets the ordinary entry.
+fn finish_templated_add(
+    app: &mut App,
+    template: Option<&str>,
+    track_id: &str,
+    task_id: &str,
+    position: usize,
+) -> Option<Operation> {
+    let template = app.project.config.templates.get(template?)?.clone();
+    let limits = &app.project.config.limits;
+    let note_limits = task_ops::NoteLimits {
+        max_bytes: limits.note_max_bytes.map(|b| b.bytes() as usize),
+        repeat_bytes: limits.note_repeat_bytes.map(|b| b.bytes() as usize),
+    };
+    let token = app.resolve_mint_namespace().ok()?;
+    let track = app.find_track_mut(track_id)?;
+    if let Err(e) = task_ops::apply_template(track, task_id, &template, token.as_ref(), note_limits)
+    {
+        app.status_message = Some(format!("template not applied: {}", e));
+        return None;
+    }
+    let task = task_ops::find_task_in_track(app.find_track_mut(track_id)?, task_id)?.clone();
+    Some(Operation::Import {
+        track_id: track_id.to_string(),
+        position,
+        count: 1,
+        tasks: vec![task],
+    })
+}
+
 /// Toggle the `cc` tag on the task under the cursor (track view only).
 pub(super) fn toggle_cc_tag(app: &mut App) {
     let (track_id, task_id) = if let View::Detail { track_id, task_id } = &app.view {
@@ -1102,6 +1138,7 @@ pub(super) fn confirm_edit(app: &mut App) {
             track_id,
             parent_id,
         } => {
+            let template = app.new_task_template.take();
             // Use mtime to detect external changes (independent of watcher timing)
             let changed = app.track_changed_on_disk(&track_id);
 
@@ -1182,12 +1219,20 @@ pub(super) fn confirm_edit(app: &mut App) {
                                     .position(|t| t.id.as_deref() == Some(&task_id))
                             })
                             .unwrap_or(0);
-                        app.undo_stack.push(Operation::TaskAdd {
+                        let op = finish_templated_add(
+                            app,
+                            template.as_deref(),
+                            &track_id,
+                            &task_id,
+                            pos_idx,
+                        )
+                        .unwrap_or(Operation::TaskAdd {
                             track_id: track_id.clone(),
                             task_id: task_id.clone(),
                             position_index: pos_idx,
                             title: title.clone(),
                         });
+                        app.undo_stack.push(op);
                     }
                     app.save_track_logged(&track_id);
                 }
@@ -1217,12 +1262,20 @@ pub(super) fn confirm_edit(app: &mut App) {
                         })
                         .unwrap_or(0);
 
-                    app.undo_stack.push(Operation::TaskAdd {
+                    let op = finish_templated_add(
+                        app,
+                        template.as_deref(),
+                        &track_id,
+                        &task_id,
+                        pos_idx,
+                    )
+                    .unwrap_or(Operation::TaskAdd {
                         track_id: track_id.clone(),
                         task_id: task_id.clone(),
                         position_index: pos_idx,
                         title: title.clone(),
                     });
+                    app.undo_stack.push(op);
                 }
                 app.save_track_logged(&track_id);
             }
@@ -1518,7 +1571,8 @@ pub(super) fn confirm_edit(app: &mut App) {
 
             // Load the new track into memory
             if let Ok(text) = std::fs::read_to_string(&track_path) {
-                let track = crate::parse::parse_track(&text);
+                let schema = crate::parse::Schema::of(&app.project.config);
+                let track = crate::parse::parse_track_with(&text, &schema);
                 app.project.tracks.push((track_id.clone(), track));
             }
 
@@ -1719,6 +1773,7 @@ pub(super) fn cancel_edit(app: &mut App) {
     };
     app.autocomplete = None;
     app.edit_is_fresh = false;
+    app.new_task_template = None;
 
     match target {
         // If we were creating a new task, remove the placeholder
@@ -2991,8 +3046,8 @@ pub(super) fn cancel_detail_edit(app: &mut App) {
 /// Switch to the next/prev tab. Direction: 1 = forward, -1 = backward.
 pub(super) fn switch_tab(app: &mut App, direction: i32) {
     let total_tracks = app.active_track_ids.len();
-    // Visual tab order: Track(0)..Track(N-1), Tracks, Board, Inbox, Recent
-    let total_views = total_tracks + 4;
+    // Visual tab order: Track(0)..Track(N-1), Tracks, Board, Inbox, Recent, Stats
+    let total_views = total_tracks + 5;
 
     let current_idx = match &app.view {
         View::Track(i) => *i,
@@ -3006,7 +3061,8 @@ pub(super) fn switch_tab(app: &mut App, direction: i32) {
         View::Tracks => total_tracks,
         View::Board => total_tracks + 1,
         View::Inbox => total_tracks + 2,
-        View::Recent | View::Search => total_tracks + 3,
+        View::Recent | View::Search | View::Graph => total_tracks + 3,
+        View::Stats => total_tracks + 4,
     };
     // Close detail view if open
     app.close_detail_fully();
@@ -3020,7 +3076,8 @@ pub(super) fn switch_tab(app: &mut App, direction: i32) {
         i if i == total_tracks => View::Tracks,
         i if i == total_tracks + 1 => View::Board,
         i if i == total_tracks + 2 => View::Inbox,
-        _ => View::Recent,
+        i if i == total_tracks + 3 => View::Recent,
+        _ => View::Stats,
     };
 
     // Refresh match count for the new view
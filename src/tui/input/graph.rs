@@ -0,0 +1,80 @@
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+use crate::tui::app::{App, View};
+
+/// Handle a key in the dependency graph view. Returns whether the key was the
+/// graph's; the rest — help, tabs, quitting — go on to the usual handling.
+///
+/// Movement follows the edges: `h` goes to a task the selection depends on,
+/// `l` to one that depends on it, each time the one whose box sits nearest
+/// the selection's row. `j`/`k` move within the column.
+pub(super) fn handle_graph_key(app: &mut App, key: KeyEvent) -> bool {
+    let layout = app.graph_layout();
+    let Some(gv) = app.graph_view.as_mut() else {
+        return false;
+    };
+    let selected = gv.cursor.as_deref().and_then(|id| layout.index_of(id));
+    let step = |node: usize| match key.code {
+        KeyCode::Char('h') | KeyCode::Left => layout.nearest(node, &layout.dependencies(node)),
+        KeyCode::Char('l') | KeyCode::Right => layout.nearest(node, &layout.dependents(node)),
+        KeyCode::Char('j') | KeyCode::Down => layout.in_column(node, 1),
+        KeyCode::Char('k') | KeyCode::Up => layout.in_column(node, -1),
+        KeyCode::Char('g') => layout.columns[layout.place[node].0]
+            .iter()
+            .flatten()
+            .copied()
+            .next(),
+        KeyCode::Char('G') => layout.columns[layout.place[node].0]
+            .iter()
+            .flatten()
+            .copied()
+            .last(),
+        _ => None,
+    };
+
+    match (key.modifiers, key.code) {
+        (
+            KeyModifiers::NONE | KeyModifiers::SHIFT,
+            KeyCode::Char('h' | 'j' | 'k' | 'l' | 'g' | 'G')
+            | KeyCode::Left
+            | KeyCode::Right
+            | KeyCode::Up
+            | KeyCode::Down,
+        ) => {
+            if let Some(next) = selected.and_then(step) {
+                gv.cursor = Some(layout.nodes[next].id.clone());
+            }
+            true
+        }
+        (KeyModifiers::NONE, KeyCode::Enter) => {
+            let Some(node) = selected.map(|i| &layout.nodes[i]) else {
+                return true;
+            };
+            if node.track_id.is_none() || !app.jump_to_task(&node.id) {
+                app.status_message = Some(format!("{} is not in an active track", node.id));
+                app.status_is_error = true;
+                return true;
+            }
+            app.graph_view = None;
+            true
+        }
+        (KeyModifiers::NONE, KeyCode::Esc) | (KeyModifiers::SHIFT, KeyCode::Char('E')) => {
+            app.view = app
+                .graph_view
+                .take()
+                .map_or(View::Recent, |gv| gv.return_view);
+            true
+        }
+        (KeyModifiers::NONE, KeyCode::Char('a')) => {
+            gv.track_id = match gv.track_id {
+                Some(_) => None,
+                None => selected.and_then(|i| layout.nodes[i].track_id.clone()),
+            };
+            gv.scroll_x = 0;
+            gv.scroll_y = 0;
+            app.graph_select_default();
+            true
+        }
+        _ => false,
+    }
+}
@@ -2,6 +2,8 @@ mod command;
 mod common;
 mod confirm;
 mod edit;
+mod graph;
+mod mouse;
 mod move_mode;
 mod navigate;
 mod popups;
@@ -11,7 +13,7 @@ mod select;
 mod tracks;
 mod triage;
 
-use crossterm::event::{KeyCode, KeyEvent};
+use crossterm::event::{KeyCode, KeyEvent, MouseEvent};
 
 use super::app::{App, DetailRegion, Mode};
 
@@ -26,6 +28,8 @@ use confirm::*;
 #[allow(unused_imports)]
 use edit::*;
 #[allow(unused_imports)]
+use graph::*;
+#[allow(unused_imports)]
 use move_mode::*;
 #[allow(unused_imports)]
 use navigate::*;
@@ -46,8 +50,23 @@ use triage::*;
 pub use common::{multiline_selection_range, selection_cols_for_line};
 pub use recent::{RecentEntry, build_recent_entries};
 
-/// Handle a key event in the current mode
+/// Handle a key event in the current mode, and leave the place it jumped away
+/// from on the jump list
 pub fn handle_key(app: &mut App, key: KeyEvent) {
+    let from = JumpFrom::here(app);
+    dispatch_key(app, key);
+    from.settle(app);
+}
+
+/// Handle a mouse event; a click that opens a task is a jump like `Enter`
+pub fn handle_mouse(app: &mut App, event: MouseEvent) -> bool {
+    let from = JumpFrom::here(app);
+    let handled = mouse::handle_mouse(app, event);
+    from.settle(app);
+    handled
+}
+
+fn dispatch_key(app: &mut App, key: KeyEvent) {
     // Ignore bare modifier key presses (Shift, Ctrl, Alt, etc.)
     if matches!(key.code, KeyCode::Modifier(_)) {
         return;
@@ -0,0 +1,303 @@
+use std::time::{Duration, Instant};
+
+use crossterm::event::{KeyCode, KeyEvent, MouseButton, MouseEvent, MouseEventKind};
+
+use crate::tui::app::{App, Hit, Mode, View};
+
+use super::*;
+
+/// Two clicks on the same target closer together than this are a double click.
+const DOUBLE_CLICK: Duration = Duration::from_millis(400);
+
+/// Handle a mouse event. Returns whether it did anything, so the event loop
+/// can treat it as it treats a key press.
+///
+/// Clicks go to whatever the last frame drew under the pointer — see
+/// [`App::hits`] — and, like the wheel, only in Navigate mode with nothing on
+/// top of the view.
+/// The wheel is the arrow keys, so it scrolls the way they do: the cursor
+/// moves and the view follows it, margins and all.
+pub fn handle_mouse(app: &mut App, event: MouseEvent) -> bool {
+    if app.mode != Mode::Navigate {
+        return false;
+    }
+    let hit = hit_at(app, event.column, event.row);
+    match event.kind {
+        _ if covered(app) => false,
+        MouseEventKind::ScrollDown | MouseEventKind::ScrollUp => {
+            if let Some(Hit::Card { column, .. } | Hit::Column(column)) = hit {
+                focus_board_column(app, column);
+            }
+            let code = if event.kind == MouseEventKind::ScrollDown {
+                KeyCode::Down
+            } else {
+                KeyCode::Up
+            };
+            super::handle_key(app, KeyEvent::from(code));
+            true
+        }
+        MouseEventKind::Down(MouseButton::Left) => match hit {
+            Some(hit) => {
+                click(app, hit);
+                true
+            }
+            None => false,
+        },
+        MouseEventKind::Up(MouseButton::Left) => match (app.board_drag.take(), hit) {
+            (Some(from), Some(Hit::Card { column, .. } | Hit::Column(column)))
+                if column != from =>
+            {
+                board_move_card(app, column);
+                true
+            }
+            _ => false,
+        },
+        _ => false,
+    }
+}
+
+/// The topmost click target under a screen cell.
+fn hit_at(app: &App, x: u16, y: u16) -> Option<Hit> {
+    app.hits
+        .iter()
+        .rev()
+        .find(|(rect, _)| {
+            x >= rect.x && x < rect.x + rect.width && y >= rect.y && y < rect.y + rect.height
+        })
+        .map(|(_, hit)| hit.clone())
+}
+
+/// Whether an overlay or popup is drawn over the view, hiding what was
+/// recorded under it.
+fn covered(app: &App) -> bool {
+    app.show_help
+        || app.show_recovery_log
+        || app.show_results_overlay
+        || app.conflict_text.is_some()
+        || app.project_picker.is_some()
+        || app.dashboard.is_some()
+        || app.activity_panel.is_some()
+        || app.undo_history.is_some()
+        || app.tag_color_popup.is_some()
+        || app.dep_popup.is_some()
+        || app.command_palette.is_some()
+        || app.prefix_rename.as_ref().is_some_and(|pr| pr.confirming)
+}
+
+fn click(app: &mut App, hit: Hit) {
+    let double = app
+        .last_click
+        .take()
+        .is_some_and(|(at, last)| last == hit && at.elapsed() < DOUBLE_CLICK);
+    if !double {
+        app.last_click = Some((Instant::now(), hit.clone()));
+    }
+
+    match hit {
+        Hit::Tab(view) => switch_to(app, view),
+        Hit::Row(row) => {
+            select_row(app, row);
+            if double {
+                open_detail(app);
+            }
+        }
+        Hit::Card { column, row } => {
+            focus_board_column(app, column);
+            app.board_state.set_cursor(column, row);
+            if double {
+                board_open_detail(app);
+            } else {
+                app.board_drag = Some(column);
+            }
+        }
+        Hit::Column(column) => focus_board_column(app, column),
+    }
+}
+
+/// Switch views the way the view's key does.
+fn switch_to(app: &mut App, view: View) {
+    app.close_detail_fully();
+    app.project_search_results = None;
+    if view == View::Tracks {
+        app.tracks_name_col_min = 0;
+    }
+    app.view = view;
+    update_match_count(app);
+}
+
+/// Put the current list view's cursor on `row`.
+fn select_row(app: &mut App, row: usize) {
+    match app.view {
+        View::Track(idx) => {
+            if let Some(track_id) = app.active_track_ids.get(idx).cloned() {
+                app.get_track_state(&track_id).cursor = row;
+            }
+        }
+        View::Inbox => app.inbox_cursor = row,
+        View::Recent => app.recent_cursor = row,
+        View::Search => {
+            if let Some(sr) = &mut app.project_search_results {
+                sr.cursor = row;
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Open the row under the cursor, as Enter does. An Inbox item has no detail
+/// view, so a double click there only selects it.
+fn open_detail(app: &mut App) {
+    match app.view {
+        View::Track(_) => handle_enter(app),
+        View::Recent => open_recent_detail(app),
+        View::Search => search_result_jump(app),
+        _ => {}
+    }
+}
+
+fn focus_board_column(app: &mut App, column: usize) {
+    if let Some(&kind) = app.board_columns().get(column) {
+        app.board_state.focus_column = kind;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crossterm::event::KeyModifiers;
+    use ratatui::Terminal;
+    use ratatui::backend::TestBackend;
+
+    use super::*;
+    use crate::model::TaskState;
+    use crate::tui::app::{BoardColumn, BoardMode, app_on_disk};
+    use crate::tui::render;
+    use crate::tui::render::test_helpers::{SIMPLE_TRACK_MD, TERM_H, TERM_W, app_with_track};
+
+    /// Draw a frame, which records the click targets, and return the screen
+    /// as text.
+    fn draw(app: &mut App) -> Vec<String> {
+        let mut terminal = Terminal::new(TestBackend::new(TERM_W, TERM_H)).unwrap();
+        terminal.draw(|frame| render::render(frame, app)).unwrap();
+        let buf = terminal.backend().buffer();
+        buf.content
+            .chunks(buf.area.width as usize)
+            .map(|row| row.iter().map(|cell| cell.symbol()).collect())
+            .collect()
+    }
+
+    /// The screen cell where `text` first appears.
+    fn find(screen: &[String], text: &str) -> (u16, u16) {
+        screen
+            .iter()
+            .enumerate()
+            .find_map(|(y, line)| {
+                let byte = line.find(text)?;
+                Some((line[..byte].chars().count() as u16, y as u16))
+            })
+            .unwrap_or_else(|| panic!("{text:?} is not on screen"))
+    }
+
+    fn mouse(app: &mut App, kind: MouseEventKind, (column, row): (u16, u16)) -> bool {
+        handle_mouse(
+            app,
+            MouseEvent {
+                kind,
+                column,
+                row,
+                modifiers: KeyModifiers::NONE,
+            },
+        )
+    }
+
+    fn click(app: &mut App, at: (u16, u16)) -> bool {
+        mouse(app, MouseEventKind::Down(MouseButton::Left), at)
+    }
+
+    #[test]
+    fn a_click_selects_the_row_and_a_double_click_opens_it() {
+        let mut app = app_with_track(SIMPLE_TRACK_MD);
+        let screen = draw(&mut app);
+        let second = find(&screen, "Second task");
+
+        assert!(click(&mut app, second));
+        assert_eq!(app.cursor_task_id().unwrap().1, "T-2");
+        assert!(matches!(app.view, View::Track(0)));
+
+        assert!(click(&mut app, second));
+        assert!(matches!(&app.view, View::Detail { task_id, .. } if task_id == "T-2"));
+    }
+
+    #[test]
+    fn a_click_on_a_tab_switches_to_it() {
+        let mut app = app_with_track(SIMPLE_TRACK_MD);
+        let screen = draw(&mut app);
+
+        assert!(click(&mut app, find(&screen, "\u{2261}")));
+        assert_eq!(app.view, View::Board);
+
+        let screen = draw(&mut app);
+        assert!(click(&mut app, find(&screen, "\u{2713}")));
+        assert_eq!(app.view, View::Recent);
+    }
+
+    #[test]
+    fn the_wheel_moves_the_cursor_like_the_arrow_keys() {
+        let mut app = app_with_track(SIMPLE_TRACK_MD);
+        let screen = draw(&mut app);
+        let first = find(&screen, "First task");
+
+        assert!(mouse(&mut app, MouseEventKind::ScrollDown, first));
+        assert_eq!(app.cursor_task_id().unwrap().1, "T-2");
+        assert!(mouse(&mut app, MouseEventKind::ScrollUp, first));
+        assert_eq!(app.cursor_task_id().unwrap().1, "T-1");
+    }
+
+    #[test]
+    fn clicks_and_the_wheel_under_an_overlay_do_nothing() {
+        let mut app = app_with_track(SIMPLE_TRACK_MD);
+        let screen = draw(&mut app);
+        app.show_help = true;
+
+        assert!(!click(&mut app, find(&screen, "Second task")));
+        let first = find(&screen, "First task");
+        assert!(!mouse(&mut app, MouseEventKind::ScrollDown, first));
+        assert_eq!(app.cursor_task_id().unwrap().1, "T-1");
+    }
+
+    #[test]
+    fn dropping_a_card_on_a_column_gives_the_task_its_state() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let mut app = app_on_disk(tmp.path());
+        app.view = View::Board;
+        app.board_state.mode = BoardMode::All;
+        let screen = draw(&mut app);
+
+        assert!(click(&mut app, find(&screen, "A-001")));
+        let in_progress = find(&screen, "In Progress");
+        assert!(mouse(
+            &mut app,
+            MouseEventKind::Up(MouseButton::Left),
+            (in_progress.0, in_progress.1 + 3)
+        ));
+
+        let task = &app.find_track_mut("a").unwrap().backlog()[0];
+        assert_eq!(task.state, TaskState::Active);
+        assert_eq!(app.board_state.focus_column, BoardColumn::InProgress);
+        assert_eq!(app.board_cursor_task_id().unwrap().1, "A-001");
+        assert!(
+            std::fs::read_to_string(tmp.path().join("frame/tracks/a.md"))
+                .unwrap()
+                .contains("- [>] `A-001` One")
+        );
+
+        // A drop where the drag began changes nothing
+        let screen = draw(&mut app);
+        let card = find(&screen, "A-001");
+        assert!(click(&mut app, card));
+        assert!(!mouse(
+            &mut app,
+            MouseEventKind::Up(MouseButton::Left),
+            card
+        ));
+    }
+}
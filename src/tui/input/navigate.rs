@@ -1,9 +1,15 @@
 use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 
+use crate::model::TaskState;
+use crate::ops::task_ops;
 use crate::tui::app::{
-    App, AutocompleteKind, AutocompleteState, DetailRegion, EditHistory, EditTarget, FlatItem,
-    Mode, StateFilter, View,
+    App, AutocompleteKind, AutocompleteState, BoardItem, DetailRegion, EditHistory, EditTarget,
+    FlatItem, Mode, StateFilter, View,
 };
+use crate::tui::command_actions::current_context;
+use crate::tui::jumps::{Direction, MarkPending, is_mark_name};
+use crate::tui::keymap::Rebound;
+use crate::tui::undo::Operation;
 
 use super::*;
 
@@ -58,6 +64,24 @@ pub(super) fn handle_navigate(app: &mut App, key: KeyEvent) {
         return;
     }
 
+    // Dashboard intercepts all keys
+    if app.dashboard.is_some() {
+        handle_dashboard_key(app, key);
+        return;
+    }
+
+    // Activity panel intercepts all keys
+    if app.activity_panel.is_some() {
+        handle_activity_panel_key(app, key);
+        return;
+    }
+
+    // Undo history intercepts all keys
+    if app.undo_history.is_some() {
+        handle_undo_history_key(app, key);
+        return;
+    }
+
     // Tag color popup intercepts all keys
     if app.tag_color_popup.is_some() {
         handle_tag_color_popup_key(app, key);
@@ -110,6 +134,14 @@ pub(super) fn handle_navigate(app: &mut App, key: KeyEvent) {
         return;
     }
 
+    // Mark prefix keys: 'B' or '\'' was pressed, now handle the mark's letter
+    if let Some(pending) = app.mark_pending.take() {
+        app.status_message = None;
+        app.status_is_error = false;
+        handle_mark_key(app, pending, key);
+        return;
+    }
+
     // Clear any transient status message on keypress
     app.status_message = None;
     app.status_is_error = false;
@@ -138,6 +170,21 @@ pub(super) fn handle_navigate(app: &mut App, key: KeyEvent) {
         }
     }
 
+    // `[keys]`: a rebound key runs its action the way the palette would, and a
+    // default key its action has left does nothing.
+    match app.keymap.rebound(current_context(&app.view), &key) {
+        Some(Rebound::Action(action_id)) => {
+            dispatch_palette_action(app, action_id, None);
+            return;
+        }
+        Some(Rebound::Freed) => return,
+        None => {}
+    }
+
+    if app.view == View::Graph && handle_graph_key(app, key) {
+        return;
+    }
+
     match (key.modifiers, key.code) {
         // Quit: Ctrl+Q
         (m, KeyCode::Char('q')) if m.contains(KeyModifiers::CONTROL) => {
@@ -334,6 +381,11 @@ pub(super) fn handle_navigate(app: &mut App, key: KeyEvent) {
             app.project_search_results = None;
             app.view = View::Recent;
         }
+        (KeyModifiers::SHIFT, KeyCode::Char('Y')) => {
+            app.close_detail_fully();
+            app.project_search_results = None;
+            app.view = View::Stats;
+        }
         (KeyModifiers::NONE, KeyCode::Char('0') | KeyCode::Char('`')) => {
             app.close_detail_fully();
             app.project_search_results = None;
@@ -348,6 +400,12 @@ pub(super) fn handle_navigate(app: &mut App, key: KeyEvent) {
             app.view = View::Board;
         }
 
+        // Dependency graph: E
+        (KeyModifiers::SHIFT, KeyCode::Char('E')) => {
+            app.project_search_results = None;
+            app.open_graph();
+        }
+
         // Retry outstanding saves now, rather than waiting out the backoff.
         // Only bound when something is actually waiting, so `R` stays free for
         // a future binding in the ordinary case.
@@ -427,6 +485,12 @@ pub(super) fn handle_navigate(app: &mut App, key: KeyEvent) {
                 expand_or_enter(app);
             }
         }
+        // Board: move the card to the column on either side
+        (KeyModifiers::SHIFT, KeyCode::Char(c @ ('H' | 'L')))
+            if matches!(app.view, View::Board) =>
+        {
+            board_move_card_by(app, if c == 'L' { 1 } else { -1 });
+        }
         (KeyModifiers::NONE, KeyCode::Left | KeyCode::Char('h')) => {
             if matches!(app.view, View::Board) {
                 board_switch_column(app, -1);
@@ -586,6 +650,16 @@ pub(super) fn handle_navigate(app: &mut App, key: KeyEvent) {
             );
         }
 
+        // Split layout: list beside a detail pane (track and detail views)
+        (KeyModifiers::NONE, KeyCode::Char('|'))
+            if matches!(app.view, View::Track(_) | View::Detail { .. }) =>
+        {
+            app.toggle_split();
+        }
+        (KeyModifiers::NONE, KeyCode::Char(c @ ('{' | '}'))) if app.split_track().is_some() => {
+            app.resize_split(if c == '}' { 5 } else { -5 });
+        }
+
         // Copy the current task's markdown to the clipboard (detail view only)
         (KeyModifiers::NONE, KeyCode::Char('y')) if matches!(app.view, View::Detail { .. }) => {
             copy_detail_task_to_clipboard(app);
@@ -696,6 +770,38 @@ pub(super) fn handle_navigate(app: &mut App, key: KeyEvent) {
             open_project_picker(app);
         }
 
+        // Dashboard: O (Shift+O, available from any view)
+        (KeyModifiers::SHIFT, KeyCode::Char('O')) => {
+            open_dashboard(app);
+        }
+
+        // Activity panel: W (Shift+W, available from any view)
+        (KeyModifiers::SHIFT, KeyCode::Char('W')) => {
+            app.activity_panel = Some(Default::default());
+        }
+
+        // Undo history: U (Shift+U, available from any view)
+        (KeyModifiers::SHIFT, KeyCode::Char('U')) => {
+            open_undo_history(app);
+        }
+
+        // Jump list: Ctrl+O back, Ctrl+I forward. Ctrl+I only arrives as
+        // itself where the terminal can tell it from Tab.
+        (m, KeyCode::Char('o')) if m.contains(KeyModifiers::CONTROL) => {
+            travel(app, Direction::Back);
+        }
+        (m, KeyCode::Char('i')) if m.contains(KeyModifiers::CONTROL) => {
+            travel(app, Direction::Forward);
+        }
+
+        // Marks: B then a letter sets one on the task, ' then a letter jumps
+        (KeyModifiers::SHIFT, KeyCode::Char('B')) => {
+            app.mark_pending = Some(MarkPending::Set);
+        }
+        (KeyModifiers::NONE, KeyCode::Char('\'')) => {
+            app.mark_pending = Some(MarkPending::Go);
+        }
+
         // Command palette: > (Shift+. reports as NONE or SHIFT depending on terminal)
         (_, KeyCode::Char('>')) => {
             open_command_palette(app);
@@ -706,7 +812,7 @@ pub(super) fn handle_navigate(app: &mut App, key: KeyEvent) {
 }
 
 /// Jump from search results to the selected task/inbox item
-fn search_result_jump(app: &mut App) {
+pub(super) fn search_result_jump(app: &mut App) {
     let item = match &app.project_search_results {
         Some(sr) => match sr.items.get(sr.cursor) {
             Some(item) => item.clone(),
@@ -811,6 +917,71 @@ pub(super) fn begin_filter_tag_select(app: &mut App) {
     app.autocomplete = Some(ac);
 }
 
+/// Go back or forward along the jump list.
+pub(super) fn travel(app: &mut App, direction: Direction) {
+    if !app.travel(direction) {
+        app.status_message = Some(
+            match direction {
+                Direction::Back => "jump list: nothing further back",
+                Direction::Forward => "jump list: nothing further forward",
+            }
+            .to_string(),
+        );
+    }
+}
+
+/// The letter after `B` or `'`. `''` goes back, as `Ctrl+O` does.
+fn handle_mark_key(app: &mut App, pending: MarkPending, key: KeyEvent) {
+    let KeyCode::Char(c) = key.code else {
+        return;
+    };
+    match pending {
+        MarkPending::Go if c == '\'' => travel(app, Direction::Back),
+        _ if !is_mark_name(c) => {
+            app.status_message = Some(format!(
+                "marks are named a\u{2013}z or A\u{2013}Z, not '{c}'"
+            ));
+            app.status_is_error = true;
+        }
+        MarkPending::Set => set_mark(app, c),
+        MarkPending::Go => go_to_mark(app, c),
+    }
+}
+
+/// Name the task under the cursor with a mark, replacing the mark's old task.
+fn set_mark(app: &mut App, name: char) {
+    let task_id = match &app.view {
+        View::Detail { task_id, .. } => Some(task_id.clone()),
+        View::Board => app.board_cursor_task_id().map(|(_, task_id)| task_id),
+        _ => app.cursor_task_id().map(|(_, task_id, _)| task_id),
+    };
+    let Some(task_id) = task_id else {
+        app.status_message = Some("no task under the cursor to mark".to_string());
+        app.status_is_error = true;
+        return;
+    };
+    app.status_message = Some(format!("mark '{name} \u{2192} {task_id}"));
+    app.marks.insert(name, task_id);
+}
+
+/// Jump to a mark's task: its row in the track, or its detail when the row
+/// isn't in the list (a Done task).
+fn go_to_mark(app: &mut App, name: char) {
+    let Some(task_id) = app.marks.get(&name).cloned() else {
+        app.status_message = Some(format!("no mark '{name}"));
+        app.status_is_error = true;
+        return;
+    };
+    let Some(track_id) = app.find_task_track_id(&task_id) else {
+        app.status_message = Some(format!("mark '{name}: {task_id} is not in an active track"));
+        app.status_is_error = true;
+        return;
+    };
+    if !app.jump_to_task(&task_id) {
+        app.open_detail(track_id, task_id);
+    }
+}
+
 /// Begin jump-to-task prompt: enter Edit mode with task ID autocomplete
 pub(super) fn begin_jump_to(app: &mut App) {
     let candidates = app.collect_active_track_task_ids();
@@ -958,12 +1129,126 @@ pub(super) fn count_recent_tasks(app: &App) -> usize {
 
 /// Switch to adjacent board column (direction: -1 = left, 1 = right)
 fn board_switch_column(app: &mut App, direction: i32) {
-    use crate::tui::app::BoardColumn;
+    let max_col = (app.board_state.visible_columns as i32 - 1).max(0);
+    let cur = app.board_focus_index() as i32;
+    let new = (cur + direction).clamp(0, max_col);
+    if let Some(&column) = app.board_columns().get(new as usize) {
+        app.board_state.focus_column = column;
+    }
+}
 
+/// Move the card under the board cursor to the adjacent column
+/// (direction: -1 = left, 1 = right)
+fn board_move_card_by(app: &mut App, direction: i32) {
     let max_col = (app.board_state.visible_columns as i32 - 1).max(0);
-    let cur = app.board_state.focus_column.index() as i32;
+    let cur = app.board_focus_index() as i32;
     let new = (cur + direction).clamp(0, max_col);
-    app.board_state.focus_column = BoardColumn::from_index(new as usize);
+    if new != cur {
+        board_move_card(app, new as usize);
+    }
+}
+
+/// Move the card under the board cursor onto column `column`: the task takes
+/// the column's state and tag, and loses the tag of the column it leaves.
+/// Each change goes through the action its key runs, with that action's undo
+/// entry; the entries are folded into one, so one undo takes the move back.
+///
+/// The card lands at once, and the focus goes with it — no grace-period pin
+/// to its old column, since being in the new one is the point of the move.
+pub(super) fn board_move_card(app: &mut App, column: usize) {
+    let kinds = app.board_columns();
+    let from = app.board_focus_index();
+    let (Some(&target), Some(&source)) = (kinds.get(column), kinds.get(from)) else {
+        return;
+    };
+    let Some(rule) = app.board_column_rule(target) else {
+        return;
+    };
+    let Some((track_id, task_id)) = app.board_cursor_task_id() else {
+        return;
+    };
+    let Some((old_state, old_tags)) = App::find_track_in_project(&app.project, &track_id)
+        .and_then(|track| task_ops::find_task_in_track(track, &task_id))
+        .map(|task| (task.state, task.tags.clone()))
+    else {
+        return;
+    };
+
+    // A column naming only a tag takes open tasks: a finished or parked one
+    // moved there is reopened
+    let state = match rule.state {
+        Some(state) => state,
+        None if matches!(old_state, TaskState::Done | TaskState::Parked) => TaskState::Todo,
+        None => old_state,
+    };
+    let since = app.undo_stack.generation();
+    if state != old_state {
+        task_state_action(app, StateAction::Set(state));
+    }
+
+    let leaving = app
+        .board_column_rule(source)
+        .and_then(|r| r.tag)
+        .filter(|tag| rule.tag.as_ref() != Some(tag));
+    let mut tags = old_tags.clone();
+    tags.retain(|t| leaving.as_ref() != Some(t));
+    if let Some(tag) = &rule.tag
+        && !tags.contains(tag)
+    {
+        tags.push(tag.clone());
+    }
+    if tags != old_tags {
+        let joined = |tags: &[String]| {
+            tags.iter()
+                .map(|t| format!("#{}", t))
+                .collect::<Vec<_>>()
+                .join(" ")
+        };
+        let op = Operation::FieldEdit {
+            track_id: track_id.clone(),
+            task_id: task_id.clone(),
+            field: "tags".to_string(),
+            old_value: joined(&old_tags),
+            new_value: joined(&tags),
+        };
+        if let Some(task) = app
+            .find_track_mut(&track_id)
+            .and_then(|track| task_ops::find_task_mut_in_track(track, &task_id))
+        {
+            task.tags = tags;
+            task.mark_dirty();
+        }
+        if state != old_state {
+            app.undo_stack.amend(since, op);
+        } else {
+            app.undo_stack.push(op);
+            app.flash_task(&task_id);
+        }
+        app.save_track_logged(&track_id);
+    }
+
+    app.board_state
+        .column_pins
+        .retain(|p| !(p.track_id == track_id && p.task_id == task_id));
+    // Follow the card to where it landed — which, for a task that still
+    // matches a tag column, need not be the column it was moved to
+    let landed = app
+        .build_board_columns()
+        .iter()
+        .enumerate()
+        .find_map(|(col, items)| {
+            let row = items.iter().position(|item| {
+                matches!(item, BoardItem::Task { track_id: t, task_id: id, .. }
+                    if *t == track_id && *id == task_id)
+            })?;
+            Some((col, row))
+        });
+    if let Some((col, row)) = landed
+        && let Some(&kind) = kinds.get(col)
+    {
+        app.board_state.focus_column = kind;
+        app.board_state.set_cursor(col, row);
+    }
 }
 
 /// Toggle board mode between CC and All
@@ -974,8 +1259,8 @@ fn board_toggle_mode(app: &mut App) {
         BoardMode::All => BoardMode::Cc,
     };
     // Reset cursors since the lists change
-    app.board_state.cursor = [0; 3];
-    app.board_state.scroll = [0; 3];
+    app.board_state.cursor.clear();
+    app.board_state.scroll.clear();
 }
 
 /// Open detail view from board cursor
@@ -999,7 +1284,7 @@ fn copy_detail_task_to_clipboard(app: &mut App) {
     app.status_message = Some("Copied task to clipboard".into());
 }
 
-fn board_open_detail(app: &mut App) {
+pub(super) fn board_open_detail(app: &mut App) {
     if let Some((track_id, task_id)) = app.board_cursor_task_id() {
         app.open_detail(track_id, task_id);
     }
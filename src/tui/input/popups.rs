@@ -481,8 +481,9 @@ pub(super) fn tag_color_clear(app: &mut App) {
     app.save_config_logged();
 
     // Update theme: remove the explicit mapping so it falls back to hardcoded defaults
-    // But we need to check if there's a hardcoded default; if so, keep it
-    let default_theme = crate::tui::theme::Theme::default();
+    // But we need to check if there's a hardcoded default; if so, keep it —
+    // in the palette this session runs with, which need not be the dark one
+    let default_theme = crate::tui::theme::Theme::from_config(&app.project.config.ui);
     if let Some(default_color) = default_theme.tag_colors.get(&tag) {
         app.theme.tag_colors.insert(tag.clone(), *default_color);
     } else {
@@ -533,95 +534,8 @@ pub(super) fn handle_project_picker_key(app: &mut App, key: KeyEvent) {
         (_, KeyCode::Enter) => {
             if let Some(entry) = picker.selected_entry() {
                 let path = entry.path.clone();
-                let root = std::path::PathBuf::from(&path);
-                if !root.join("frame").exists() {
-                    app.status_message = Some(format!("project not found at {}", path));
+                if !open_project(app, &path) {
                     app.project_picker = None;
-                    return;
-                }
-                // Switch project: load the new project
-                match crate::io::project_io::load_project(&root) {
-                    Ok(mut project) => {
-                        // Ensure IDs and dates (no auto-claim; an unclaimed clone
-                        // mints nothing per the strict null policy)
-                        let scope = crate::io::actors::id_scope(&project.frame_dir);
-                        let modified = crate::ops::clean::ensure_ids_and_dates(&mut project, scope);
-                        if !modified.is_empty() {
-                            let _lock =
-                                crate::io::lock::FileLock::acquire_default(&project.frame_dir).ok();
-                            for track_id in &modified {
-                                if let Some(tc) =
-                                    project.config.tracks.iter().find(|tc| tc.id == *track_id)
-                                {
-                                    let file = &tc.file;
-                                    if let Some(track) = project
-                                        .tracks
-                                        .iter()
-                                        .find(|(id, _)| id == track_id)
-                                        .map(|(_, t)| t)
-                                    {
-                                        // Mid project-switch: the old `App` is
-                                        // gone and the new one does not exist,
-                                        // so record rather than drop.
-                                        if let Err(e) = crate::io::project_io::save_track(
-                                            &project.frame_dir,
-                                            file,
-                                            track,
-                                        ) {
-                                            crate::io::recovery::log_recovery(
-                                                &project.frame_dir,
-                                                crate::io::recovery::RecoveryEntry {
-                                                    timestamp: chrono::Utc::now(),
-                                                    category:
-                                                        crate::io::recovery::RecoveryCategory::Write,
-                                                    description: format!(
-                                                        "project switch: track {track_id} save failed"
-                                                    ),
-                                                    fields: vec![(
-                                                        "Error".to_string(),
-                                                        e.to_string(),
-                                                    )],
-                                                    body: String::new(),
-                                                },
-                                            );
-                                        }
-                                    }
-                                }
-                            }
-                        }
-
-                        // Touch TUI timestamp
-                        crate::io::registry::register_project(
-                            &project.config.project.name,
-                            &project.root,
-                        );
-                        crate::io::registry::touch_tui(&project.root);
-
-                        // Save old UI state before switching
-                        crate::tui::app::save_ui_state(app);
-
-                        // Replace app with a fresh App for the new project
-                        *app = App::new(project);
-                        app.watcher_needs_restart = true;
-                        // Switching *between* worktrees of one clone is the case
-                        // this exists for, so it has to be re-resolved here and
-                        // not just at startup.
-                        app.worktree_label =
-                            crate::io::git::linked_worktree_label(&app.project.frame_dir);
-
-                        // Update terminal window title
-                        crate::tui::app::set_window_title(
-                            &app.project.config.project.name,
-                            app.worktree_label.as_deref(),
-                        );
-
-                        // Restore UI state for the new project
-                        crate::tui::app::restore_ui_state(app);
-                    }
-                    Err(e) => {
-                        app.status_message = Some(format!("error loading project: {}", e));
-                        app.project_picker = None;
-                    }
                 }
             }
         }
@@ -635,6 +549,258 @@ pub(super) fn handle_project_picker_key(app: &mut App, key: KeyEvent) {
     }
 }
 
+// ---------------------------------------------------------------------------
+// Dashboard
+
+pub(super) fn open_dashboard(app: &mut App) {
+    crate::io::registry::heal_worktrees();
+    app.dashboard = Some(crate::tui::dashboard::DashboardState::load(
+        &app.project.root,
+    ));
+}
+
+pub(super) fn handle_dashboard_key(app: &mut App, key: KeyEvent) {
+    let Some(dash) = &mut app.dashboard else {
+        return;
+    };
+    let last = dash.projects.len().saturating_sub(1);
+
+    match (key.modifiers, key.code) {
+        (_, KeyCode::Esc) | (KeyModifiers::SHIFT, KeyCode::Char('O')) => {
+            app.dashboard = None;
+        }
+        (_, KeyCode::Up) | (_, KeyCode::Char('k')) => {
+            dash.cursor = dash.cursor.saturating_sub(1);
+        }
+        (_, KeyCode::Down) | (_, KeyCode::Char('j')) => {
+            dash.cursor = (dash.cursor + 1).min(last);
+        }
+        (_, KeyCode::Char('g')) | (_, KeyCode::Home) => {
+            dash.cursor = 0;
+        }
+        (_, KeyCode::Char('G')) | (_, KeyCode::End) => {
+            dash.cursor = last;
+        }
+        (_, KeyCode::Char('r')) => {
+            let cursor = dash.cursor;
+            open_dashboard(app);
+            if let Some(dash) = &mut app.dashboard {
+                dash.cursor = cursor.min(dash.projects.len().saturating_sub(1));
+            }
+        }
+        (_, KeyCode::Enter) => {
+            if let Some(path) = dash.selected().map(|p| p.path.clone()) {
+                // The open project needs no switch, just closing over it
+                if path == dash.current_path || !open_project(app, &path) {
+                    app.dashboard = None;
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Undo history
+
+pub(super) fn open_undo_history(app: &mut App) {
+    // The cursor starts on the present: the newest step still done
+    app.undo_history = Some(crate::tui::undo_history::UndoHistoryState {
+        cursor: app.undo_stack.redo_depth(),
+        scroll: 0,
+    });
+}
+
+pub(super) fn handle_undo_history_key(app: &mut App, key: KeyEvent) {
+    let Some(history) = &mut app.undo_history else {
+        return;
+    };
+    // Undone steps, done steps and the start
+    let last = app.undo_stack.redo_depth() + app.undo_stack.undo_depth();
+
+    match (key.modifiers, key.code) {
+        (_, KeyCode::Esc) | (KeyModifiers::SHIFT, KeyCode::Char('U')) => {
+            app.undo_history = None;
+        }
+        (_, KeyCode::Up) | (_, KeyCode::Char('k')) => {
+            history.cursor = history.cursor.saturating_sub(1);
+        }
+        (_, KeyCode::Down) | (_, KeyCode::Char('j')) => {
+            history.cursor = (history.cursor + 1).min(last);
+        }
+        (_, KeyCode::Char('g')) | (_, KeyCode::Home) => {
+            history.cursor = 0;
+        }
+        (_, KeyCode::Char('G')) | (_, KeyCode::End) => {
+            history.cursor = last;
+        }
+        (_, KeyCode::Enter) => {
+            let target = history.cursor;
+            app.undo_history = None;
+            undo_history_jump(app, target);
+        }
+        _ => {}
+    }
+}
+
+/// Take the project to history row `target`, one undo or redo at a time so
+/// each step saves and navigates as it would from the keyboard. Stops at a
+/// step that won't go — a change made outside the TUI — and says so.
+fn undo_history_jump(app: &mut App, target: usize) {
+    use super::common::{perform_redo, perform_undo};
+
+    loop {
+        let present = app.undo_stack.redo_depth();
+        let before = (app.undo_stack.undo_depth(), present);
+        if target > present {
+            perform_undo(app);
+        } else if target < present {
+            perform_redo(app);
+        } else {
+            return;
+        }
+        if (app.undo_stack.undo_depth(), app.undo_stack.redo_depth()) == before {
+            app.status_message =
+                Some("Stopped at a change made outside the TUI: undo can't cross it".to_string());
+            return;
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Activity panel
+
+pub(super) fn handle_activity_panel_key(app: &mut App, key: KeyEvent) {
+    let Some(panel) = &mut app.activity_panel else {
+        return;
+    };
+    let last = app.activity.changes.len().saturating_sub(1);
+
+    match (key.modifiers, key.code) {
+        (_, KeyCode::Esc) | (KeyModifiers::SHIFT, KeyCode::Char('W')) => {
+            app.activity_panel = None;
+        }
+        (_, KeyCode::Up) | (_, KeyCode::Char('k')) => {
+            panel.cursor = panel.cursor.saturating_sub(1);
+        }
+        (_, KeyCode::Down) | (_, KeyCode::Char('j')) => {
+            panel.cursor = (panel.cursor + 1).min(last);
+        }
+        (_, KeyCode::Char('g')) | (_, KeyCode::Home) => {
+            panel.cursor = 0;
+        }
+        (_, KeyCode::Char('G')) | (_, KeyCode::End) => {
+            panel.cursor = last;
+        }
+        (_, KeyCode::Enter) => {
+            let Some(task_id) = app.activity.newest(panel.cursor).map(|c| c.task_id.clone()) else {
+                return;
+            };
+            if app.jump_to_task(&task_id) {
+                app.activity_panel = None;
+            } else {
+                app.status_message = Some(format!("{task_id} is no longer in an active track"));
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Switch the TUI to the project at `path`: save this one's UI state, load
+/// the other and restore its own. Says why in the status line and returns
+/// false when the project can't be opened, leaving the current one as it was.
+pub(super) fn open_project(app: &mut App, path: &str) -> bool {
+    let root = std::path::PathBuf::from(path);
+    if !root.join("frame").exists() {
+        app.status_message = Some(format!("project not found at {}", path));
+        return false;
+    }
+    // Switch project: load the new project
+    let loaded = crate::io::user_config::read()
+        .and_then(|user| {
+            let project = crate::io::project_io::load_project_with(&root, user.as_ref())?;
+            Ok((project, user))
+        })
+        .map_err(|e| e.to_string())
+        .and_then(|(project, user)| {
+            crate::tui::keymap::Keymap::from_config(&project.config.keys)
+                .map_err(|e| format!("[keys]: {e}"))?;
+            Ok((project, user))
+        });
+    match loaded {
+        Ok((mut project, user_config)) => {
+            // Ensure IDs and dates (no auto-claim; an unclaimed clone
+            // mints nothing per the strict null policy)
+            let scope = crate::io::actors::id_scope(&project.frame_dir);
+            let modified = crate::ops::clean::ensure_ids_and_dates(&mut project, scope);
+            if !modified.is_empty() {
+                let _lock = crate::io::lock::FileLock::acquire_default(&project.frame_dir).ok();
+                for track_id in &modified {
+                    if let Some(tc) = project.config.tracks.iter().find(|tc| tc.id == *track_id) {
+                        let file = &tc.file;
+                        if let Some(track) = project
+                            .tracks
+                            .iter()
+                            .find(|(id, _)| id == track_id)
+                            .map(|(_, t)| t)
+                        {
+                            // Mid project-switch: the old `App` is
+                            // gone and the new one does not exist,
+                            // so record rather than drop.
+                            if let Err(e) =
+                                crate::io::project_io::save_track(&project.frame_dir, file, track)
+                            {
+                                crate::io::recovery::log_recovery(
+                                    &project.frame_dir,
+                                    crate::io::recovery::RecoveryEntry {
+                                        timestamp: chrono::Utc::now(),
+                                        category: crate::io::recovery::RecoveryCategory::Write,
+                                        description: format!(
+                                            "project switch: track {track_id} save failed"
+                                        ),
+                                        fields: vec![("Error".to_string(), e.to_string())],
+                                        body: String::new(),
+                                    },
+                                );
+                            }
+                        }
+                    }
+                }
+            }
+
+            // Touch TUI timestamp
+            crate::io::registry::register_project(&project.config.project.name, &project.root);
+            crate::io::registry::touch_tui(&project.root);
+
+            // Save old UI state before switching
+            crate::tui::app::save_ui_state(app);
+
+            // Replace app with a fresh App for the new project
+            *app = App::new(project);
+            app.user_config = user_config;
+            app.watcher_needs_restart = true;
+            // Switching *between* worktrees of one clone is the case
+            // this exists for, so it has to be re-resolved here and
+            // not just at startup.
+            app.worktree_label = crate::io::git::linked_worktree_label(&app.project.frame_dir);
+
+            // Update terminal window title
+            crate::tui::app::set_window_title(
+                &app.project.config.project.name,
+                app.worktree_label.as_deref(),
+            );
+
+            // Restore UI state for the new project
+            crate::tui::app::restore_ui_state(app);
+            true
+        }
+        Err(e) => {
+            app.status_message = Some(format!("error loading project: {}", e));
+            false
+        }
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Recovery log overlay
 
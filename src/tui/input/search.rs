@@ -66,6 +66,7 @@ pub(super) fn handle_search(app: &mut App, key: KeyEvent) {
         }
 
         // History navigation: Up = older
+        #[allow(clippy::collapsible_match)]
         (_, KeyCode::Up) => {
             if !app.search_history.is_empty() {
                 match app.search_history_index {
@@ -154,6 +155,7 @@ fn handle_project_search_prompt(app: &mut App, key: KeyEvent) {
         }
 
         // History: Up = older
+        #[allow(clippy::collapsible_match)]
         (_, KeyCode::Up) => {
             if !app.project_search_history.is_empty() {
                 match app.project_search_history_index {
@@ -567,6 +569,12 @@ fn build_snippet_for_field(field: &MatchField, task: Option<&Task>, re: &Regex)
             }
             None
         }
+        MatchField::Field => task.metadata.iter().find_map(|m| match m {
+            Metadata::Custom { key, value } if re.is_match(value) => {
+                Some(format!("{}: {}", key, value))
+            }
+            _ => None,
+        }),
         _ => None,
     }
 }
@@ -612,7 +620,9 @@ pub(super) fn repeat_last_action(app: &mut App) {
                     crate::model::TaskState::Blocked => StateAction::ToggleBlocked,
                     crate::model::TaskState::Todo => StateAction::SetTodo,
                     crate::model::TaskState::Parked => StateAction::ToggleParked,
-                    crate::model::TaskState::Active => StateAction::Cycle,
+                    crate::model::TaskState::Active | crate::model::TaskState::Custom(_) => {
+                        StateAction::Cycle
+                    }
                 };
                 let saved = app.last_action.take();
                 task_state_action(app, sa);
@@ -937,6 +947,7 @@ pub(super) fn repeat_bulk_cycle(app: &mut App) {
     };
 
     let selected: Vec<String> = app.selection.iter().cloned().collect();
+    let workflow = app.project.config.workflow.clone();
     let mut ops: Vec<Operation> = Vec::new();
     let mut any_changed = false;
 
@@ -959,7 +970,7 @@ pub(super) fn repeat_bulk_cycle(app: &mut App) {
             }
         });
 
-        task_ops::cycle_state(task);
+        task_ops::cycle_state(task, &workflow);
 
         let new_state = task.state;
         let new_resolved = task.metadata.iter().find_map(|m| {
@@ -991,6 +1002,7 @@ pub(super) fn repeat_bulk_cycle(app: &mut App) {
     }
 
     if any_changed {
+        ops.extend(auto_block_after(app, &track_id));
         app.undo_stack.push(Operation::Bulk(ops));
         app.save_track_logged(&track_id);
     }
@@ -1035,7 +1047,7 @@ pub(super) fn execute_search_dir(app: &mut App, direction: i32) {
         View::Tracks => search_in_tracks_view(app, &re, direction),
         View::Inbox => search_in_inbox(app, &re, direction),
         View::Recent => search_in_recent(app, &re, direction),
-        View::Board | View::Search => {} // View search not applicable
+        View::Board | View::Search | View::Graph | View::Stats => {} // View search not applicable
     }
 }
 
@@ -1159,7 +1171,7 @@ pub(super) fn auto_expand_for_task(app: &mut App, track_id: &str, target_task_id
         };
 
         let mut keys = Vec::new();
-        for section_kind in [SectionKind::Backlog, SectionKind::Parked, SectionKind::Done] {
+        for section_kind in track.section_kinds() {
             let tasks = track.section_tasks(section_kind);
             if let Some(path) = find_task_path(tasks, target_task_id) {
                 for depth in 0..path.len().saturating_sub(1) {
@@ -1631,7 +1643,7 @@ pub(super) fn count_matches_for_pattern(app: &App, re: &Regex) -> usize {
             }
             matched_done_ids.len()
         }
-        View::Board | View::Search => 0,
+        View::Board | View::Search | View::Graph | View::Stats => 0,
     }
 }
 
@@ -1657,6 +1669,7 @@ pub(super) fn count_matches_in_task(task: &Task, re: &Regex) -> usize {
     // Check metadata fields
     for meta in &task.metadata {
         match meta {
+            #[allow(clippy::collapsible_match)]
             Metadata::Note(text) => {
                 if re.is_match(text) {
                     return 1;
@@ -1676,6 +1689,9 @@ pub(super) fn count_matches_in_task(task: &Task, re: &Regex) -> usize {
                     }
                 }
             }
+            Metadata::Custom { value, .. } if re.is_match(value) => {
+                return 1;
+            }
             _ => {}
         }
     }
@@ -542,8 +542,8 @@ pub(super) fn bulk_state_change(app: &mut App, target_state: crate::model::TaskS
                 task_ops::set_state(task, crate::model::TaskState::Todo)
             }
             crate::model::TaskState::Parked => task_ops::set_parked(task),
-            crate::model::TaskState::Active => {
-                task_ops::set_state(task, crate::model::TaskState::Active)
+            state @ (crate::model::TaskState::Active | crate::model::TaskState::Custom(_)) => {
+                task_ops::set_state(task, state)
             }
         }
 
@@ -578,6 +578,7 @@ pub(super) fn bulk_state_change(app: &mut App, target_state: crate::model::TaskS
     }
 
     if any_changed {
+        ops.extend(auto_block_after(app, &track_id));
         app.undo_stack.push(Operation::Bulk(ops));
         app.save_track_logged(&track_id);
 
@@ -1168,6 +1168,12 @@ mod cross_track_dep_tests {
             ui: UiConfig::default(),
             recovery: Default::default(),
             limits: Default::default(),
+            deps: Default::default(),
+            templates: Default::default(),
+            fields: Default::default(),
+            workflow: Default::default(),
+            board: Default::default(),
+            keys: Default::default(),
         };
         let project = crate::model::project::Project {
             root: dir.to_path_buf(),
@@ -0,0 +1,181 @@
+//! Where you have been: the jump list `Ctrl+O`/`Ctrl+I` walk, and the named
+//! marks `B`/`'` set and visit.
+//!
+//! `J`, the dependency popup, search results and the Detail view all take the
+//! cursor somewhere else, and nothing remembered where it came from — following
+//! a dependency chain three deep and coming back meant retracing it by hand.
+//! Every change of view, and every jump within one, now leaves the place it
+//! left on the list, the way a browser's history does: going back and then
+//! jumping somewhere new forgets the places ahead.
+
+use std::collections::BTreeMap;
+
+/// Places the list keeps, oldest dropped first.
+const LIST_CAP: usize = 100;
+
+/// A place the cursor can be taken back to. Tracks are held by ID rather than
+/// tab index, so a place outlives the tabs being reordered.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Location {
+    /// A track's list, on a task when the cursor was on one.
+    Track {
+        track_id: String,
+        task_id: Option<String>,
+    },
+    Detail {
+        track_id: String,
+        task_id: String,
+    },
+    Tracks,
+    Board,
+    Inbox,
+    Recent,
+    Stats,
+}
+
+impl Location {
+    /// Whether both are in one view: the same track's list, the same task's
+    /// detail, or the same other view. Moving the cursor within a view is not
+    /// a jump.
+    pub fn same_view(&self, other: &Location) -> bool {
+        match (self, other) {
+            (Location::Track { track_id: a, .. }, Location::Track { track_id: b, .. }) => a == b,
+            _ => self == other,
+        }
+    }
+}
+
+/// Which way along the list.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    Back,
+    Forward,
+}
+
+/// The places behind and ahead of the cursor.
+#[derive(Debug, Default)]
+pub struct JumpList {
+    /// Oldest first; `Ctrl+O` takes the last.
+    back: Vec<Location>,
+    /// Nearest last; `Ctrl+I` takes the last.
+    forward: Vec<Location>,
+    /// Set by [`crate::tui::app::App::jump_to_task`], so a jump within one
+    /// view counts when the key that made it is done.
+    pub jumped: bool,
+    /// Set while walking the list, so walking it doesn't record.
+    pub travelled: bool,
+}
+
+impl JumpList {
+    /// Leave `from` behind a jump. The places ahead are forgotten.
+    pub fn record(&mut self, from: Location) {
+        self.forward.clear();
+        if self.back.last() != Some(&from) {
+            self.back.push(from);
+        }
+        if self.back.len() > LIST_CAP {
+            self.back.remove(0);
+        }
+    }
+
+    /// The next place in `direction`, taken off the list.
+    pub fn pop(&mut self, direction: Direction) -> Option<Location> {
+        match direction {
+            Direction::Back => self.back.pop(),
+            Direction::Forward => self.forward.pop(),
+        }
+    }
+
+    /// Arrived by going `direction`: `left` is where the other way now leads.
+    pub fn arrived(&mut self, direction: Direction, left: Location) {
+        let behind = match direction {
+            Direction::Back => &mut self.forward,
+            Direction::Forward => &mut self.back,
+        };
+        if behind.last() != Some(&left) {
+            behind.push(left);
+        }
+    }
+
+    pub fn len(&self, direction: Direction) -> usize {
+        match direction {
+            Direction::Back => self.back.len(),
+            Direction::Forward => self.forward.len(),
+        }
+    }
+}
+
+/// Named marks: a letter for a task, kept in `.state.json`. A mark names the
+/// task by ID alone, so it follows the task to another track or section.
+pub type Marks = BTreeMap<char, String>;
+
+/// The second key of a mark command, once `B` or `'` has been pressed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MarkPending {
+    Set,
+    Go,
+}
+
+/// Whether `c` can name a mark.
+pub fn is_mark_name(c: char) -> bool {
+    c.is_ascii_alphabetic()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn track(task_id: &str) -> Location {
+        Location::Track {
+            track_id: "main".into(),
+            task_id: Some(task_id.into()),
+        }
+    }
+
+    #[test]
+    fn back_and_forward_retrace_the_jumps() {
+        let mut list = JumpList::default();
+        list.record(track("M-001"));
+        list.record(track("M-002"));
+
+        // At M-003: back to M-002, then M-001, then forward again
+        let to = list.pop(Direction::Back).unwrap();
+        assert_eq!(to, track("M-002"));
+        list.arrived(Direction::Back, track("M-003"));
+        let to = list.pop(Direction::Back).unwrap();
+        assert_eq!(to, track("M-001"));
+        list.arrived(Direction::Back, track("M-002"));
+        assert_eq!(list.len(Direction::Back), 0);
+
+        let to = list.pop(Direction::Forward).unwrap();
+        assert_eq!(to, track("M-002"));
+        list.arrived(Direction::Forward, track("M-001"));
+        assert_eq!(list.len(Direction::Back), 1);
+        assert_eq!(list.len(Direction::Forward), 1);
+    }
+
+    #[test]
+    fn a_new_jump_forgets_the_places_ahead() {
+        let mut list = JumpList::default();
+        list.record(track("M-001"));
+        list.pop(Direction::Back);
+        list.arrived(Direction::Back, track("M-002"));
+        assert_eq!(list.len(Direction::Forward), 1);
+
+        list.record(track("M-001"));
+        list.record(track("M-001"));
+        assert_eq!(list.len(Direction::Forward), 0);
+        assert_eq!(list.len(Direction::Back), 1, "repeats collapse");
+    }
+
+    #[test]
+    fn moving_within_a_track_is_not_a_change_of_view() {
+        assert!(track("M-001").same_view(&track("M-002")));
+        let detail = |task_id: &str| Location::Detail {
+            track_id: "main".into(),
+            task_id: task_id.into(),
+        };
+        assert!(!detail("M-001").same_view(&detail("M-002")));
+        assert!(!track("M-001").same_view(&Location::Board));
+    }
+}
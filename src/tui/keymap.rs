@@ -0,0 +1,521 @@
+//! `[keys]`: the command palette's actions, on keys of the user's choosing.
+//!
+//! A binding names an action by its palette id and gives it one or more key
+//! chords — `mark_done = "d"`, `undo = ["z", "ctrl+z"]` — for every view the
+//! action works in, or for one view under `[keys.<view>]`. A rebound key runs
+//! the action exactly as choosing it from the palette does.
+//!
+//! **A binding replaces the action's default keys** in the views it covers:
+//! the old key does nothing there unless another binding claims it, which is
+//! what lets two actions swap keys. Defaults that are key sequences (`QQ`,
+//! `fa`) are not single keys and stay as they are.
+//!
+//! The table is checked once, when the session starts. Two actions on one key,
+//! a key that still belongs to an action nobody rebound, or one the views use
+//! to move around would leave one of them silently never firing, so each is
+//! refused with both names instead.
+
+use std::collections::HashMap;
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use indexmap::IndexMap;
+
+use crate::model::config::KeyBinding;
+use crate::tui::command_actions::{PaletteAction, ViewContext, static_actions};
+
+/// Every context a key can be looked up in; `Global` stands for the views
+/// with no actions of their own (Board, Search).
+const CONTEXTS: &[ViewContext] = &[
+    ViewContext::TrackView,
+    ViewContext::DetailView,
+    ViewContext::InboxView,
+    ViewContext::RecentView,
+    ViewContext::TracksView,
+    ViewContext::Global,
+];
+
+/// `[keys.<view>]` names.
+const VIEWS: &[(&str, ViewContext)] = &[
+    ("track", ViewContext::TrackView),
+    ("detail", ViewContext::DetailView),
+    ("inbox", ViewContext::InboxView),
+    ("recent", ViewContext::RecentView),
+    ("tracks", ViewContext::TracksView),
+];
+
+/// Keys the views move around with, or that start a key sequence. Binding one
+/// would take it away from every view.
+const RESERVED: &[&str] = &[
+    "Up", "Down", "Left", "Right", "Home", "End", "PageUp", "PageDown", "j", "k", "h", "l", "g",
+    "G", "1", "2", "3", "4", "5", "6", "7", "8", "9", "Esc", ">", "Q", "f", "Ctrl+q", "Ctrl+d",
+];
+
+#[derive(Debug, PartialEq, thiserror::Error)]
+pub enum KeymapError {
+    #[error("unknown action '{0}' (doc/tui.md lists them)")]
+    UnknownAction(String),
+    #[error("unknown view [keys.{0}] — views are track, detail, inbox, recent and tracks")]
+    UnknownView(String),
+    #[error("{action} is not an action in the {view} view")]
+    NotInView { action: String, view: &'static str },
+    #[error("{action}: '{key}' is not a key")]
+    BadKey { action: String, key: String },
+    #[error("'{key}' is bound to both {first} and {second} in the {view} view")]
+    Conflict {
+        key: String,
+        first: &'static str,
+        second: &'static str,
+        view: &'static str,
+    },
+    #[error(
+        "'{key}' is {owner}'s key in the {view} view — rebind {owner} too, to bind it to {action}"
+    )]
+    Taken {
+        key: String,
+        action: &'static str,
+        owner: &'static str,
+        view: &'static str,
+    },
+    #[error("'{key}' moves around the views and cannot be bound to {action}")]
+    Reserved { key: String, action: &'static str },
+}
+
+/// A single key press: a key and the modifiers held with it.
+///
+/// Shift is folded into the character for character keys, as the key
+/// handlers see it after `normalize_key`: `X`, not `Shift+x`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Chord {
+    modifiers: KeyModifiers,
+    code: KeyCode,
+}
+
+impl Chord {
+    fn new(mut modifiers: KeyModifiers, code: KeyCode) -> Self {
+        if matches!(code, KeyCode::Char(_) | KeyCode::BackTab) {
+            modifiers.remove(KeyModifiers::SHIFT);
+        }
+        modifiers &= KeyModifiers::CONTROL | KeyModifiers::ALT | KeyModifiers::SHIFT;
+        Chord { modifiers, code }
+    }
+
+    pub fn from_key(key: &KeyEvent) -> Self {
+        Chord::new(key.modifiers, key.code)
+    }
+
+    /// `x`, `X`, `Space`, `ctrl+a`, `alt+shift+Up`, `F2`. Modifier and key
+    /// names are case-insensitive; a lone character is taken as written.
+    pub fn parse(text: &str) -> Option<Self> {
+        let mut chars = text.chars();
+        if let (Some(c), None) = (chars.next(), chars.next()) {
+            return Some(Chord::new(KeyModifiers::NONE, KeyCode::Char(c)));
+        }
+        // `ctrl++` is Ctrl with the `+` key
+        let (mods, key) = match text.strip_suffix("++") {
+            Some(mods) => (mods, "+"),
+            None => match text.rsplit_once('+') {
+                Some((mods, key)) => (mods, key),
+                None => ("", text),
+            },
+        };
+        let mut modifiers = KeyModifiers::NONE;
+        for name in mods.split('+').filter(|m| !m.is_empty()) {
+            modifiers |= match name.to_ascii_lowercase().as_str() {
+                "ctrl" | "control" => KeyModifiers::CONTROL,
+                "alt" | "meta" | "option" => KeyModifiers::ALT,
+                "shift" => KeyModifiers::SHIFT,
+                _ => return None,
+            };
+        }
+        let mut key_chars = key.chars();
+        let code = match (key_chars.next(), key_chars.next()) {
+            (Some(c), None) => {
+                let shift = modifiers.contains(KeyModifiers::SHIFT);
+                if shift {
+                    KeyCode::Char(c.to_ascii_uppercase())
+                } else if modifiers.is_empty() {
+                    KeyCode::Char(c)
+                } else {
+                    // Terminals report Ctrl+A as Ctrl with `a`
+                    KeyCode::Char(c.to_ascii_lowercase())
+                }
+            }
+            _ => match key.to_ascii_lowercase().as_str() {
+                "space" => KeyCode::Char(' '),
+                "enter" | "return" => KeyCode::Enter,
+                "tab" if modifiers.contains(KeyModifiers::SHIFT) => KeyCode::BackTab,
+                "tab" => KeyCode::Tab,
+                "esc" | "escape" => KeyCode::Esc,
+                "backspace" => KeyCode::Backspace,
+                "delete" | "del" => KeyCode::Delete,
+                "insert" => KeyCode::Insert,
+                "home" => KeyCode::Home,
+                "end" => KeyCode::End,
+                "pageup" => KeyCode::PageUp,
+                "pagedown" => KeyCode::PageDown,
+                "up" => KeyCode::Up,
+                "down" => KeyCode::Down,
+                "left" => KeyCode::Left,
+                "right" => KeyCode::Right,
+                name => match name.strip_prefix('f').and_then(|n| n.parse::<u8>().ok()) {
+                    Some(n) if (1..=12).contains(&n) => KeyCode::F(n),
+                    _ => return None,
+                },
+            },
+        };
+        Some(Chord::new(modifiers, code))
+    }
+
+    /// The chord the way the help overlay and palette write keys.
+    pub fn label(&self) -> String {
+        let mut out = String::new();
+        if self.modifiers.contains(KeyModifiers::CONTROL) {
+            out.push_str("Ctrl+");
+        }
+        if self.modifiers.contains(KeyModifiers::ALT) {
+            out.push_str("Alt+");
+        }
+        if self.modifiers.contains(KeyModifiers::SHIFT) {
+            out.push_str("Shift+");
+        }
+        match self.code {
+            KeyCode::Char(' ') => out.push_str("Space"),
+            KeyCode::Char(c) if self.modifiers.contains(KeyModifiers::CONTROL) => {
+                out.push(c.to_ascii_uppercase())
+            }
+            KeyCode::Char(c) => out.push(c),
+            KeyCode::BackTab => out.push_str("S-Tab"),
+            KeyCode::F(n) => out.push_str(&format!("F{n}")),
+            KeyCode::PageUp => out.push_str("PageUp"),
+            KeyCode::PageDown => out.push_str("PageDown"),
+            code => out.push_str(&format!("{code:?}")),
+        }
+        out
+    }
+}
+
+/// The single keys an action's palette shortcut names: `z/u` is two, `QQ`
+/// none — it is a sequence, not a key.
+fn default_chords(action: &PaletteAction) -> Vec<Chord> {
+    let Some(shortcut) = action.shortcut.as_deref() else {
+        return Vec::new();
+    };
+    if shortcut == "/" {
+        return vec![Chord::new(KeyModifiers::NONE, KeyCode::Char('/'))];
+    }
+    shortcut.split('/').filter_map(Chord::parse).collect()
+}
+
+fn view_name(ctx: ViewContext) -> &'static str {
+    VIEWS
+        .iter()
+        .find(|(_, c)| *c == ctx)
+        .map_or("board and search", |(name, _)| name)
+}
+
+fn in_context(action: &PaletteAction, ctx: ViewContext) -> bool {
+    action
+        .contexts
+        .iter()
+        .any(|c| *c == ctx || *c == ViewContext::Global)
+}
+
+/// What a key does where `[keys]` has changed it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Rebound {
+    /// Bound to this action.
+    Action(&'static str),
+    /// An action's default key, left behind by a binding: it does nothing.
+    Freed,
+}
+
+/// `[keys]`, resolved per view.
+#[derive(Debug, Clone, Default)]
+pub struct Keymap {
+    keys: HashMap<(ViewContext, Chord), Rebound>,
+    /// A rebound action's keys, as the palette shows them.
+    shortcuts: HashMap<(ViewContext, String), String>,
+    /// The same, keyed by the default shortcut text, for the help overlay.
+    relabels: HashMap<(ViewContext, String), String>,
+}
+
+impl Keymap {
+    pub fn from_config(keys: &IndexMap<String, KeyBinding>) -> Result<Self, KeymapError> {
+        let actions = static_actions();
+        let find = |id: &str| {
+            actions
+                .iter()
+                .find(|a| a.id == id)
+                .ok_or_else(|| KeymapError::UnknownAction(id.into()))
+        };
+        let chords = |action: &PaletteAction, binding: &KeyBinding| {
+            let keys: Vec<&String> = match binding {
+                KeyBinding::Key(key) => vec![key],
+                KeyBinding::Keys(keys) => keys.iter().collect(),
+                KeyBinding::View(_) => {
+                    return Err(KeymapError::BadKey {
+                        action: action.id.into(),
+                        key: "a table".into(),
+                    });
+                }
+            };
+            keys.into_iter()
+                .map(|key| {
+                    Chord::parse(key).ok_or_else(|| KeymapError::BadKey {
+                        action: action.id.into(),
+                        key: key.clone(),
+                    })
+                })
+                .collect::<Result<Vec<_>, _>>()
+        };
+
+        // (view or every view, action, keys); a view's own binding wins
+        let mut bindings: Vec<(Option<ViewContext>, &PaletteAction, Vec<Chord>)> = Vec::new();
+        for (name, binding) in keys {
+            let KeyBinding::View(table) = binding else {
+                let action = find(name)?;
+                bindings.push((None, action, chords(action, binding)?));
+                continue;
+            };
+            let ctx = VIEWS
+                .iter()
+                .find(|(view, _)| view == name)
+                .map(|(_, ctx)| *ctx)
+                .ok_or_else(|| KeymapError::UnknownView(name.clone()))?;
+            for (id, binding) in table {
+                let action = find(id)?;
+                if !in_context(action, ctx) {
+                    return Err(KeymapError::NotInView {
+                        action: id.clone(),
+                        view: view_name(ctx),
+                    });
+                }
+                bindings.push((Some(ctx), action, chords(action, binding)?));
+            }
+        }
+
+        let reserved: Vec<Chord> = RESERVED.iter().filter_map(|k| Chord::parse(k)).collect();
+        let mut map = Keymap::default();
+        for &ctx in CONTEXTS {
+            let mut bound: Vec<(&PaletteAction, &[Chord])> = Vec::new();
+            for action in actions.iter().filter(|a| in_context(a, ctx)) {
+                let mine = |scope: Option<ViewContext>| {
+                    bindings
+                        .iter()
+                        .find(|(s, a, _)| *s == scope && a.id == action.id)
+                };
+                if let Some((_, _, keys)) = mine(Some(ctx)).or_else(|| mine(None)) {
+                    bound.push((action, keys));
+                }
+            }
+
+            for (action, keys) in &bound {
+                for key in keys.iter() {
+                    if let Some(Rebound::Action(first)) =
+                        map.keys.insert((ctx, *key), Rebound::Action(action.id))
+                        && first != action.id
+                    {
+                        return Err(KeymapError::Conflict {
+                            key: key.label(),
+                            first,
+                            second: action.id,
+                            view: view_name(ctx),
+                        });
+                    }
+                }
+            }
+
+            for (action, keys) in &bound {
+                let defaults = default_chords(action);
+                for key in keys.iter() {
+                    if reserved.contains(key) && !defaults.contains(key) {
+                        return Err(KeymapError::Reserved {
+                            key: key.label(),
+                            action: action.id,
+                        });
+                    }
+                    let owner = actions.iter().find(|other| {
+                        other.id != action.id
+                            && in_context(other, ctx)
+                            && default_chords(other).contains(key)
+                            && !bound.iter().any(|(b, _)| b.id == other.id)
+                    });
+                    if let Some(owner) = owner {
+                        return Err(KeymapError::Taken {
+                            key: key.label(),
+                            action: action.id,
+                            owner: owner.id,
+                            view: view_name(ctx),
+                        });
+                    }
+                }
+                let label = keys.iter().map(Chord::label).collect::<Vec<_>>().join("/");
+                if let Some(shortcut) = &action.shortcut {
+                    map.relabels
+                        .insert((ctx, shortcut.to_string()), label.clone());
+                }
+                map.shortcuts.insert((ctx, action.id.to_string()), label);
+            }
+
+            for (action, _) in &bound {
+                for key in default_chords(action) {
+                    map.keys.entry((ctx, key)).or_insert(Rebound::Freed);
+                }
+            }
+        }
+        Ok(map)
+    }
+
+    /// What `key` does in `ctx`, where a binding has changed it; `None` where
+    /// the built-in handling stands.
+    pub fn rebound(&self, ctx: ViewContext, key: &KeyEvent) -> Option<Rebound> {
+        self.keys.get(&(ctx, Chord::from_key(key))).copied()
+    }
+
+    /// A rebound action's keys, for the palette.
+    pub fn shortcut(&self, ctx: ViewContext, action_id: &str) -> Option<&str> {
+        self.shortcuts
+            .get(&(ctx, action_id.to_string()))
+            .map(String::as_str)
+    }
+
+    /// What the help overlay should show instead of `default` — an action's
+    /// default shortcut — now that it is rebound.
+    pub fn relabel(&self, ctx: ViewContext, default: &str) -> Option<&str> {
+        self.relabels
+            .get(&(ctx, default.to_string()))
+            .map(String::as_str)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn keymap(toml: &str) -> Result<Keymap, KeymapError> {
+        #[derive(serde::Deserialize)]
+        struct Doc {
+            keys: IndexMap<String, KeyBinding>,
+        }
+        let doc: Doc = toml::from_str(toml).unwrap();
+        Keymap::from_config(&doc.keys)
+    }
+
+    fn press(c: char) -> KeyEvent {
+        KeyEvent::new(KeyCode::Char(c), KeyModifiers::NONE)
+    }
+
+    #[test]
+    fn chords_parse_the_way_terminals_report_them() {
+        let chord = |text| Chord::parse(text).unwrap();
+        assert_eq!(
+            chord("X"),
+            Chord::new(KeyModifiers::SHIFT, KeyCode::Char('X'))
+        );
+        assert_eq!(chord("shift+x"), chord("X"));
+        assert_eq!(
+            chord("Ctrl+A"),
+            Chord::new(KeyModifiers::CONTROL, KeyCode::Char('a'))
+        );
+        assert_eq!(chord("ctrl++").code, KeyCode::Char('+'));
+        assert_eq!(chord("space").code, KeyCode::Char(' '));
+        assert_eq!(chord("shift+tab").code, KeyCode::BackTab);
+        assert_eq!(chord("F5").code, KeyCode::F(5));
+        assert!(Chord::parse("QQ").is_none());
+        assert!(Chord::parse("hyper+x").is_none());
+        assert_eq!(chord("ctrl+z").label(), "Ctrl+Z");
+        assert_eq!(chord("alt+w").label(), "Alt+w");
+    }
+
+    #[test]
+    fn a_binding_moves_the_action_and_frees_its_old_key() {
+        let map = keymap(
+            r#"
+[keys]
+mark_done = "d"
+edit_deps = "x"
+
+[keys.detail]
+mark_done = ["X", "ctrl+d"]
+"#,
+        );
+        // Ctrl+D is the key debug overlay
+        assert!(matches!(map, Err(KeymapError::Reserved { .. })), "{map:?}");
+
+        let map = keymap(
+            r#"
+[keys]
+mark_done = "d"
+edit_deps = "x"
+
+[keys.detail]
+mark_done = "X"
+"#,
+        )
+        .unwrap();
+        let track = ViewContext::TrackView;
+        let detail = ViewContext::DetailView;
+        assert_eq!(
+            map.rebound(track, &press('d')),
+            Some(Rebound::Action("mark_done"))
+        );
+        assert_eq!(map.rebound(track, &press('x')), Some(Rebound::Freed));
+        // In the detail view the two swap keys, and mark_done moves again
+        assert_eq!(
+            map.rebound(detail, &press('x')),
+            Some(Rebound::Action("edit_deps"))
+        );
+        assert_eq!(map.rebound(detail, &press('d')), Some(Rebound::Freed));
+        assert_eq!(
+            map.rebound(
+                detail,
+                &KeyEvent::new(KeyCode::Char('X'), KeyModifiers::SHIFT)
+            ),
+            Some(Rebound::Action("mark_done"))
+        );
+        // Keys nobody touched are left to the view
+        assert_eq!(map.rebound(track, &press('o')), None);
+
+        assert_eq!(map.shortcut(track, "mark_done"), Some("d"));
+        assert_eq!(map.shortcut(detail, "mark_done"), Some("X"));
+        assert_eq!(map.relabel(detail, "d"), Some("x"));
+    }
+
+    #[test]
+    fn conflicts_are_refused_with_both_names() {
+        let err = keymap("[keys]\nmark_done = \"b\"\nset_todo = \"b\"\n").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "'b' is bound to both set_todo and mark_done in the track view"
+        );
+        let err = keymap("[keys]\nmark_done = \"b\"\n").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "'b' is set_blocked's key in the track view — rebind set_blocked too, to bind it to mark_done"
+        );
+        // A global action reaches every view, and every view's keys
+        let err = keymap("[keys]\nopen_board = \"o\"\n").unwrap_err();
+        assert!(
+            matches!(
+                err,
+                KeymapError::Taken {
+                    owner: "set_todo",
+                    ..
+                }
+            ),
+            "{err}"
+        );
+        assert!(matches!(
+            keymap("[keys]\nmark_down = \"d\"\n").unwrap_err(),
+            KeymapError::UnknownAction(_)
+        ));
+        assert!(matches!(
+            keymap("[keys.inbox]\nmark_done = \"d\"\n").unwrap_err(),
+            KeymapError::NotInView { .. }
+        ));
+        assert!(matches!(
+            keymap("[keys]\nundo = \"ctrl+\"\n").unwrap_err(),
+            KeymapError::BadKey { .. }
+        ));
+    }
+}
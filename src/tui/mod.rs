@@ -1,10 +1,16 @@
+pub mod activity;
 pub mod app;
 pub mod command_actions;
+pub mod dashboard;
 pub mod fields;
+pub mod graph;
 pub mod input;
+pub mod jumps;
+pub mod keymap;
 pub mod render;
 pub mod theme;
 pub mod undo;
+pub mod undo_history;
 pub mod wrap;
 
 pub use app::run;
@@ -0,0 +1,160 @@
+use ratatui::Frame;
+use ratatui::layout::Rect;
+use ratatui::style::{Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, Clear, Paragraph};
+
+use crate::tui::activity::ChangeKind;
+use crate::tui::app::App;
+use crate::util::unicode;
+
+/// Width of the time column, `14:02:11` and a gap.
+const TIME_W: usize = 10;
+/// Width of the change column, `EFF-014.2 → done` and a gap.
+const CHANGE_W: usize = 24;
+
+/// Render the Activity panel: the tasks reloads changed this session, newest
+/// first.
+pub fn render_activity_panel(frame: &mut Frame, app: &mut App, area: Rect) {
+    let theme = &app.theme;
+    let bg = theme.background;
+    let Some(panel) = app.activity_panel.as_ref() else {
+        return;
+    };
+
+    let margin_x = 4u16.min(area.width / 8);
+    let margin_y = 2u16.min(area.height / 8);
+    let popup_area = Rect::new(
+        area.x + margin_x,
+        area.y + margin_y,
+        area.width.saturating_sub(margin_x * 2),
+        area.height.saturating_sub(margin_y * 2),
+    );
+    frame.render_widget(Clear, popup_area);
+
+    let count = app.activity.changes.len();
+    let block = Block::default()
+        .title(Span::styled(
+            format!(
+                " Activity \u{2014} {count} external change{} ",
+                if count == 1 { "" } else { "s" }
+            ),
+            Style::default()
+                .fg(theme.text_bright)
+                .bg(bg)
+                .add_modifier(Modifier::BOLD),
+        ))
+        .title_bottom(Span::styled(
+            " Enter jump \u{00B7} Esc close ",
+            Style::default().fg(theme.dim).bg(bg),
+        ))
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(theme.dim).bg(bg))
+        .style(Style::default().bg(bg));
+    let inner = block.inner(popup_area);
+    frame.render_widget(block, popup_area);
+
+    if count == 0 {
+        frame.render_widget(
+            Paragraph::new(Span::styled(
+                "No external changes this session.",
+                Style::default().fg(theme.dim).bg(bg),
+            )),
+            inner,
+        );
+        return;
+    }
+
+    let height = inner.height as usize;
+    let width = inner.width as usize;
+    let mut scroll = panel.scroll;
+    if panel.cursor < scroll {
+        scroll = panel.cursor;
+    } else if panel.cursor >= scroll + height {
+        scroll = panel.cursor + 1 - height;
+    }
+
+    let workflow = &app.project.config.workflow;
+    let mut lines = Vec::new();
+    for (i, change) in app
+        .activity
+        .changes
+        .iter()
+        .rev()
+        .enumerate()
+        .skip(scroll)
+        .take(height)
+    {
+        let row_bg = if i == panel.cursor {
+            theme.selection_bg
+        } else {
+            bg
+        };
+        let style = |fg| Style::default().fg(fg).bg(row_bg);
+        let change_color = match &change.kind {
+            ChangeKind::State(state) => theme.state_color(*state),
+            ChangeKind::Added => theme.green,
+            ChangeKind::Removed => theme.red,
+            ChangeKind::Moved(_) | ChangeKind::Edited => theme.text_bright,
+        };
+        let described = format!("{} ", pad(&change.describe(workflow), CHANGE_W - 1));
+        let track = app.track_name(&change.track_id).to_string();
+        let rest = width.saturating_sub(TIME_W + unicode::display_width(&described));
+        let title_w = rest.saturating_sub(unicode::display_width(&track) + 2);
+        let title = pad(&change.title, title_w);
+        lines.push(Line::from(vec![
+            Span::styled(
+                format!(" {:<w$}", change.at.format("%H:%M:%S"), w = TIME_W - 1),
+                style(theme.dim),
+            ),
+            Span::styled(described, style(change_color)),
+            Span::styled(title, style(theme.text)),
+            Span::styled(format!("  {track}"), style(theme.dim)),
+        ]));
+    }
+    frame.render_widget(Paragraph::new(lines).style(Style::default().bg(bg)), inner);
+    if let Some(panel) = app.activity_panel.as_mut() {
+        panel.scroll = scroll;
+    }
+}
+
+/// `s` cut or padded to exactly `width` cells.
+fn pad(s: &str, width: usize) -> String {
+    let cut = unicode::truncate_to_width(s, width);
+    let w = unicode::display_width(&cut);
+    format!("{cut}{}", " ".repeat(width.saturating_sub(w)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse::parse_track;
+    use crate::tui::activity::{ActivityPanelState, diff_tracks};
+    use crate::tui::render::test_helpers::*;
+
+    #[test]
+    fn the_panel_lists_changes_newest_first() {
+        let mut app = app_with_track("# Test Track\n\n## Backlog\n\n## Done\n");
+        let before = parse_track("# T\n\n## Backlog\n\n- [>] `T-1` Build it\n\n## Done\n");
+        let done = parse_track("# T\n\n## Backlog\n\n- [x] `T-1` Build it\n\n## Done\n");
+        let added = parse_track(
+            "# T\n\n## Backlog\n\n- [x] `T-1` Build it\n- [ ] `T-2` Then this\n\n## Done\n",
+        );
+        app.activity.record(diff_tracks("test", &before, &done));
+        app.activity.record(diff_tracks("test", &done, &added));
+        app.activity_panel = Some(ActivityPanelState::default());
+
+        let output = render_to_string(TERM_W, TERM_H, |frame, area| {
+            render_activity_panel(frame, &mut app, area);
+        });
+
+        assert!(
+            output.contains("Activity \u{2014} 2 external changes"),
+            "{output}"
+        );
+        let added_at = output.find("T-2 added").expect("added row");
+        let done_at = output.find("T-1 \u{2192} done").expect("done row");
+        assert!(added_at < done_at, "newest first: {output}");
+        assert!(output.contains("Then this"));
+    }
+}
@@ -4,124 +4,77 @@ use ratatui::style::{Modifier, Style};
 use ratatui::text::{Line, Span};
 use ratatui::widgets::Paragraph;
 
-use crate::tui::app::{App, BoardColumn, BoardItem, BoardMode};
+use crate::tui::app::{App, BoardColumn, BoardItem, BoardMode, ColumnRule, Hit};
 use crate::util::unicode;
 
 use super::detail_view::state_flash_colors;
 use super::push_highlighted_spans;
 
-/// Render the board view: three-column kanban layout
+/// Render the board view: a kanban layout of Ready, In Progress, any
+/// `[workflow]` state columns, and Done — or of the `[[board.columns]]`
 pub fn render_board_view(frame: &mut Frame, app: &mut App, area: Rect) {
     let columns = app.build_board_columns();
-    let done_days = app.project.config.ui.board_done_days;
+    let mut kinds = app.board_columns();
     let total_width = area.width as usize;
 
+    // Done stays off the board entirely when board_done_days is 0
+    if app.project.config.ui.board_done_days == 0 {
+        kinds.retain(|k| *k != BoardColumn::Done);
+    }
+
+    if kinds.is_empty() {
+        let empty =
+            Paragraph::new(" No columns: [board] has only done columns, and board_done_days is 0")
+                .style(Style::default().fg(app.theme.dim).bg(app.theme.background));
+        frame.render_widget(empty, area);
+        return;
+    }
+    // A column left off the board can't keep the focus
+    if !kinds.contains(&app.board_state.focus_column) {
+        app.board_state.focus_column = if kinds.contains(&BoardColumn::InProgress) {
+            BoardColumn::InProgress
+        } else {
+            kinds[0]
+        };
+    }
+
     // Determine layout mode based on width
     if total_width < 50 {
         app.board_state.visible_columns = 1;
-        render_single_column(frame, app, area, &columns, done_days);
-    } else if total_width < 77 || done_days == 0 {
-        app.board_state.visible_columns = 2;
-        render_two_columns(frame, app, area, &columns, done_days);
-    } else {
-        app.board_state.visible_columns = 3;
-        render_three_columns(frame, app, area, &columns);
+        render_single_column(frame, app, area, &columns);
+        return;
     }
-}
-
-/// Three-column layout (Ready | In Progress | Done)
-fn render_three_columns(
-    frame: &mut Frame,
-    app: &mut App,
-    area: Rect,
-    columns: &[Vec<BoardItem>; 3],
-) {
-    let col_areas = Layout::horizontal([
-        Constraint::Ratio(1, 3),
-        Constraint::Ratio(1, 3),
-        Constraint::Ratio(1, 3),
-    ])
-    .split(area);
-
-    render_column(
-        frame,
-        app,
-        col_areas[0],
-        &columns[0],
-        BoardColumn::Ready,
-        None,
-    );
-    render_column(
-        frame,
-        app,
-        col_areas[1],
-        &columns[1],
-        BoardColumn::InProgress,
-        None,
-    );
-    render_column(
-        frame,
-        app,
-        col_areas[2],
-        &columns[2],
-        BoardColumn::Done,
-        None,
-    );
-}
-
-/// Two-column layout (Ready | In Progress), Done hidden
-fn render_two_columns(
-    frame: &mut Frame,
-    app: &mut App,
-    area: Rect,
-    columns: &[Vec<BoardItem>; 3],
-    done_days: u32,
-) {
-    let col_areas =
-        Layout::horizontal([Constraint::Ratio(1, 2), Constraint::Ratio(1, 2)]).split(area);
-
-    let done_count = columns[2]
-        .iter()
-        .filter(|item| matches!(item, BoardItem::Task { .. }))
-        .count();
-    let done_hint = if done_days > 0 && done_count > 0 {
-        Some(format!("\u{2192} Done ({})", done_count))
+    let fit = if total_width < 77 {
+        2
     } else {
-        None
+        3 + (total_width - 77) / 26
     };
+    let shown = fit.min(kinds.len());
+    app.board_state.visible_columns = shown;
+    if app.board_focus_index() >= shown {
+        app.board_state.focus_column = kinds[shown - 1];
+    }
 
-    render_column(
-        frame,
-        app,
-        col_areas[0],
-        &columns[0],
-        BoardColumn::Ready,
-        None,
-    );
-    render_column(
-        frame,
-        app,
-        col_areas[1],
-        &columns[1],
-        BoardColumn::InProgress,
-        done_hint.as_deref(),
-    );
+    // Columns that don't fit are named in the last visible column's header
+    let hint = kinds.get(shown).and_then(|&hidden| {
+        let count = app.board_task_count(&columns, hidden);
+        (count > 0).then(|| format!("\u{2192} {} ({})", column_name(app, hidden), count))
+    });
+
+    let col_areas = Layout::horizontal(vec![Constraint::Ratio(1, shown as u32); shown]).split(area);
+    for (i, &kind) in kinds.iter().take(shown).enumerate() {
+        let suffix = if i + 1 == shown {
+            hint.as_deref()
+        } else {
+            None
+        };
+        render_column(frame, app, col_areas[i], &columns[i], kind, suffix);
+    }
 }
 
 /// Single-column layout (show only focused column)
-fn render_single_column(
-    frame: &mut Frame,
-    app: &mut App,
-    area: Rect,
-    columns: &[Vec<BoardItem>; 3],
-    done_days: u32,
-) {
-    let focus = app.board_state.focus_column;
-    // Limit navigation: if done_days == 0, don't allow Done column focus
-    if done_days == 0 && focus == BoardColumn::Done {
-        app.board_state.focus_column = BoardColumn::InProgress;
-    }
-    let col_idx = app.board_state.focus_column.index();
+fn render_single_column(frame: &mut Frame, app: &mut App, area: Rect, columns: &[Vec<BoardItem>]) {
+    let col_idx = app.board_focus_index();
     render_column(
         frame,
         app,
@@ -132,6 +85,17 @@ fn render_single_column(
     );
 }
 
+/// A column's header name: the workflow state's name for a state column
+fn column_name(app: &App, column: BoardColumn) -> String {
+    match column {
+        BoardColumn::Ready => "Ready".into(),
+        BoardColumn::InProgress => "In Progress".into(),
+        BoardColumn::State(state) => app.project.config.workflow.state_name(state),
+        BoardColumn::Done => "Done".into(),
+        BoardColumn::Config(i) => app.project.config.board.columns[i].name.clone(),
+    }
+}
+
 /// Render a single board column into the given area
 fn render_column(
     frame: &mut Frame,
@@ -143,7 +107,11 @@ fn render_column(
 ) {
     let bg = app.theme.background;
     let is_focused = app.board_state.focus_column == column;
-    let col_idx = column.index();
+    let col_idx = app
+        .board_columns()
+        .iter()
+        .position(|c| *c == column)
+        .unwrap_or(0);
     let col_width = area.width as usize;
 
     // Count selectable tasks
@@ -159,16 +127,19 @@ fn render_column(
     };
 
     // Column header
-    let (col_name, col_color) = match column {
-        BoardColumn::Ready => (
-            "Ready",
-            app.theme.state_color(crate::model::TaskState::Todo),
-        ),
-        BoardColumn::InProgress => (
-            "In Progress",
-            app.theme.state_color(crate::model::TaskState::Active),
-        ),
-        BoardColumn::Done => ("Done", app.theme.state_color(crate::model::TaskState::Done)),
+    let col_name = column_name(app, column);
+    let col_color = match column {
+        BoardColumn::Ready => app.theme.state_color(crate::model::TaskState::Todo),
+        BoardColumn::InProgress => app.theme.state_color(crate::model::TaskState::Active),
+        BoardColumn::State(state) => app.theme.state_color(state),
+        BoardColumn::Done => app.theme.state_color(crate::model::TaskState::Done),
+        BoardColumn::Config(_) => match app.board_column_rule(column) {
+            Some(ColumnRule {
+                state: Some(state), ..
+            }) => app.theme.state_color(state),
+            Some(ColumnRule { tag: Some(tag), .. }) => app.theme.tag_color(&tag),
+            _ => app.theme.text,
+        },
     };
 
     let header_bg = if is_focused {
@@ -190,8 +161,8 @@ fn render_column(
         header_style,
     )];
 
-    // Show mode on Ready column only
-    if column == BoardColumn::Ready {
+    // Show mode on the first column only
+    if col_idx == 0 {
         header_spans.push(Span::styled(
             format!(" {} ", mode_label),
             Style::default().fg(app.theme.dim).bg(header_bg),
@@ -233,51 +204,64 @@ fn render_column(
 
     // Clamp cursor
     if !items.is_empty() {
-        let cursor = app.board_state.cursor[col_idx].min(items.len().saturating_sub(1));
-        app.board_state.cursor[col_idx] = cursor;
+        let cursor = app
+            .board_state
+            .cursor(col_idx)
+            .min(items.len().saturating_sub(1));
+        app.board_state.set_cursor(col_idx, cursor);
         // Ensure cursor is on a selectable item
-        if matches!(items.get(cursor), Some(BoardItem::TrackHeader { .. })) {
+        if matches!(items.get(cursor), Some(BoardItem::LaneHeader { .. })) {
             // Try to move to next task
             if let Some(next) = items[cursor..]
                 .iter()
                 .position(|item| matches!(item, BoardItem::Task { .. }))
             {
-                app.board_state.cursor[col_idx] = cursor + next;
+                app.board_state.set_cursor(col_idx, cursor + next);
             }
         }
     } else {
-        app.board_state.cursor[col_idx] = 0;
+        app.board_state.set_cursor(col_idx, 0);
     }
 
-    let cursor = app.board_state.cursor[col_idx];
+    let cursor = app.board_state.cursor(col_idx);
     let search_re = app.active_search_re();
 
     // Body height (area minus header and separator)
     let body_height = area.height.saturating_sub(2) as usize;
 
+    // The whole column takes a dropped card; the cards on it are recorded below
+    app.hits.push((area, Hit::Column(col_idx)));
+
     // Empty state
     if items.is_empty() {
         let cc_mode = app.board_state.mode == BoardMode::Cc;
         let msg = match column {
             BoardColumn::Ready => {
                 if cc_mode {
-                    "No #cc tasks ready \u{2014} press c for all"
+                    "No #cc tasks ready \u{2014} press c for all".to_string()
                 } else {
-                    "No ready tasks"
+                    "No ready tasks".to_string()
                 }
             }
             BoardColumn::InProgress => {
                 if cc_mode {
-                    "No #cc tasks active"
+                    "No #cc tasks active".to_string()
+                } else {
+                    "Nothing active".to_string()
+                }
+            }
+            BoardColumn::State(_) | BoardColumn::Config(_) => {
+                if cc_mode {
+                    format!("No #cc tasks in {}", col_name)
                 } else {
-                    "Nothing active"
+                    format!("Nothing in {}", col_name)
                 }
             }
             BoardColumn::Done => {
                 if cc_mode {
-                    "No #cc tasks completed recently"
+                    "No #cc tasks completed recently".to_string()
                 } else {
-                    "No tasks completed recently"
+                    "No tasks completed recently".to_string()
                 }
             }
         };
@@ -290,13 +274,14 @@ fn render_column(
         // wrap to multiple lines, so scroll must be computed in display-line
         // space (not item-space) to keep the cursor card visible.
         let mut card_lines: Vec<Line> = Vec::new();
+        let mut card_rows: Vec<Option<usize>> = Vec::new();
         let mut cursor_start_line = 0usize;
         let mut cursor_end_line = 0usize;
         for (idx, item) in items.iter().enumerate() {
             match item {
-                BoardItem::TrackHeader { track_name } => {
-                    // Dimmed track separator
-                    let label = format!(" {}", track_name);
+                BoardItem::LaneHeader { name } => {
+                    // Dimmed lane separator
+                    let label = format!(" {}", name);
                     let truncated = super::truncate_with_ellipsis(&label, col_width);
                     card_lines.push(Line::from(Span::styled(
                         truncated,
@@ -316,6 +301,7 @@ fn render_column(
                     }
                     let is_cursor = is_focused && idx == cursor;
                     let is_flash = app.is_flashing(task_id);
+                    let is_changed = app.activity.is_highlighted(task_id);
                     let (flash_bg, flash_border) = state_flash_colors(*state, &app.theme);
                     let row_bg = if is_flash {
                         flash_bg
@@ -354,6 +340,12 @@ fn render_column(
                                 "\u{2502}",
                                 Style::default().fg(app.theme.selection_border).bg(row_bg),
                             ));
+                        } else if is_changed {
+                            // Changed on disk by someone else, a moment ago
+                            spans.push(Span::styled(
+                                "\u{258E}",
+                                Style::default().fg(app.theme.cyan).bg(row_bg),
+                            ));
                         } else {
                             spans.push(Span::styled(" ", Style::default().bg(row_bg)));
                         }
@@ -477,10 +469,12 @@ fn render_column(
                     }
                 }
             }
+            let is_task = matches!(item, BoardItem::Task { .. });
+            card_rows.resize(card_lines.len(), is_task.then_some(idx));
         }
 
         // Scroll adjustment in display-line space (with a scrolloff margin).
-        let scroll = &mut app.board_state.scroll[col_idx];
+        let scroll = app.board_state.scroll_mut(col_idx);
         *scroll = super::scroll::adjust_scroll(
             *scroll,
             body_height,
@@ -490,6 +484,12 @@ fn render_column(
         );
         let scroll_val = *scroll;
 
+        let body = Rect::new(area.x, area.y + 2, area.width, body_height as u16);
+        super::record_rows(app, body, scroll_val, &card_rows, |row| Hit::Card {
+            column: col_idx,
+            row,
+        });
+
         // Apply scroll: skip `scroll_val` lines, take `body_height`
         for line in card_lines.into_iter().skip(scroll_val).take(body_height) {
             lines.push(line);
@@ -137,7 +137,7 @@ pub fn render_command_palette(frame: &mut Frame, app: &App, area: Rect) {
             );
 
             // Right-align shortcut with matched character highlights
-            let shortcut_text = scored.action.shortcut.unwrap_or("");
+            let shortcut_text = scored.action.shortcut.as_deref().unwrap_or("");
             let label_len = 3 + label_chars.len(); // indicator + label
             let shortcut_len = unicode::display_width(shortcut_text);
             let total_needed = label_len + 1 + shortcut_len; // +1 for min gap
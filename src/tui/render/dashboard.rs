@@ -0,0 +1,243 @@
+use ratatui::Frame;
+use ratatui::layout::Rect;
+use ratatui::style::{Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Clear, Paragraph};
+
+use crate::io::registry::abbreviate_path;
+use crate::tui::app::App;
+use crate::tui::dashboard::ProjectSummary;
+use crate::util::unicode;
+
+/// Render the dashboard over the content area: a card per registered
+/// project with its counts, focus track and next ready tasks.
+pub fn render_dashboard(frame: &mut Frame, app: &mut App, area: Rect) {
+    let theme = &app.theme;
+    let Some(dash) = app.dashboard.as_ref() else {
+        return;
+    };
+    let bg = Style::default().bg(theme.background);
+    let dim = Style::default().fg(theme.dim).bg(theme.background);
+    let width = area.width as usize;
+
+    let mut lines: Vec<Line> = vec![Line::from(vec![
+        Span::styled(
+            " Projects",
+            Style::default()
+                .fg(theme.text_bright)
+                .bg(theme.background)
+                .add_modifier(Modifier::BOLD),
+        ),
+        Span::styled(
+            format!(
+                "  {} registered \u{00B7} Enter open \u{00B7} r reload \u{00B7} Esc close",
+                dash.projects.len()
+            ),
+            dim,
+        ),
+    ])];
+    lines.push(Line::from(""));
+    if dash.projects.is_empty() {
+        lines.push(Line::from(Span::styled(
+            " No registered projects — `fr projects add` registers one",
+            dim,
+        )));
+    }
+
+    // Where the selected card starts and ends, to scroll it into view
+    let mut selected = (0, 0);
+    for (i, project) in dash.projects.iter().enumerate() {
+        let is_cursor = i == dash.cursor;
+        let start = lines.len();
+        card_lines(
+            app,
+            project,
+            project.path == dash.current_path,
+            is_cursor,
+            width,
+            &mut lines,
+        );
+        if is_cursor {
+            selected = (start, lines.len());
+        }
+        lines.push(Line::from(""));
+    }
+
+    let height = area.height as usize;
+    let Some(dash) = app.dashboard.as_mut() else {
+        return;
+    };
+    if selected.0 < dash.scroll + 2 {
+        dash.scroll = selected.0.saturating_sub(2);
+    } else if selected.1 > dash.scroll + height {
+        dash.scroll = selected.1 - height;
+    }
+    let scroll = dash.scroll;
+    let visible: Vec<Line> = lines.into_iter().skip(scroll).take(height).collect();
+
+    frame.render_widget(Clear, area);
+    frame.render_widget(Paragraph::new(visible).style(bg), area);
+}
+
+fn card_lines(
+    app: &App,
+    project: &ProjectSummary,
+    is_current: bool,
+    is_cursor: bool,
+    width: usize,
+    lines: &mut Vec<Line<'static>>,
+) {
+    let theme = &app.theme;
+    let row_bg = if is_cursor {
+        theme.selection_bg
+    } else {
+        theme.background
+    };
+    let style = |fg| Style::default().fg(fg).bg(row_bg);
+    // Pad a line out to the full width, so the selection shows as a band
+    let pad = |mut spans: Vec<Span<'static>>| {
+        let used: usize = spans
+            .iter()
+            .map(|s| unicode::display_width(&s.content))
+            .sum();
+        spans.push(Span::styled(
+            " ".repeat(width.saturating_sub(used)),
+            Style::default().bg(row_bg),
+        ));
+        Line::from(spans)
+    };
+
+    let marker = if is_cursor { " \u{25B8} " } else { "   " };
+    let mut head = vec![
+        Span::styled(marker, style(theme.highlight)),
+        Span::styled(
+            project.name.clone(),
+            style(if is_cursor {
+                theme.highlight
+            } else {
+                theme.text_bright
+            })
+            .add_modifier(Modifier::BOLD),
+        ),
+    ];
+    if is_current {
+        head.push(Span::styled(" (open)", style(theme.dim)));
+    }
+    if project.error.is_none() {
+        head.push(Span::styled("   ", style(theme.text)));
+        head.push(Span::styled(
+            format!("{} active", project.active),
+            style(theme.highlight),
+        ));
+        head.push(Span::styled(" \u{00B7} ", style(theme.dim)));
+        head.push(Span::styled(
+            format!("{} ready", project.ready),
+            style(theme.text),
+        ));
+        head.push(Span::styled(" \u{00B7} ", style(theme.dim)));
+        head.push(Span::styled(
+            format!("{} blocked", project.blocked),
+            style(if project.blocked > 0 {
+                theme.red
+            } else {
+                theme.text
+            }),
+        ));
+        if let Some(focus) = &project.focus {
+            head.push(Span::styled(
+                format!("   \u{2605} {focus}"),
+                style(theme.yellow),
+            ));
+        }
+    }
+    lines.push(pad(head));
+    lines.push(pad(vec![Span::styled(
+        format!("   {}", abbreviate_path(&project.path)),
+        style(theme.dim),
+    )]));
+
+    if let Some(error) = &project.error {
+        let text = unicode::truncate_to_width(error, width.saturating_sub(5));
+        lines.push(pad(vec![Span::styled(
+            format!("     {text}"),
+            style(theme.red),
+        )]));
+        return;
+    }
+    if project.next.is_empty() {
+        lines.push(pad(vec![Span::styled(
+            "     nothing ready",
+            style(theme.dim),
+        )]));
+    }
+    for (id, title) in &project.next {
+        let title = unicode::truncate_to_width(title, width.saturating_sub(id.len() + 8));
+        lines.push(pad(vec![
+            Span::styled(format!("     {id}"), style(theme.selection_id)),
+            Span::styled(format!("  {title}"), style(theme.text)),
+        ]));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tui::dashboard::DashboardState;
+    use crate::tui::render::test_helpers::*;
+
+    fn summary(name: &str, path: &str) -> ProjectSummary {
+        ProjectSummary {
+            name: name.into(),
+            path: path.into(),
+            error: None,
+            active: 0,
+            ready: 0,
+            blocked: 0,
+            focus: None,
+            next: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn cards_show_counts_focus_and_next_ready_tasks() {
+        let mut app = app_with_track("# Test Track\n\n## Backlog\n\n## Done\n");
+        app.dashboard = Some(DashboardState {
+            projects: vec![
+                ProjectSummary {
+                    active: 2,
+                    ready: 5,
+                    blocked: 1,
+                    focus: Some("Main".into()),
+                    next: vec![("M-002".into(), "Next up".into())],
+                    ..summary("api", "/work/api")
+                },
+                summary("web", "/work/web"),
+                ProjectSummary {
+                    error: Some("project.toml: missing".into()),
+                    ..summary("gone", "/work/gone")
+                },
+            ],
+            cursor: 1,
+            scroll: 0,
+            current_path: "/work/api".into(),
+        });
+        let output = render_to_string(TERM_W, TERM_H, |frame, area| {
+            render_dashboard(frame, &mut app, area);
+        });
+
+        assert!(output.contains("3 registered"), "{output}");
+        assert!(
+            output.contains(
+                "api (open)   2 active \u{00B7} 5 ready \u{00B7} 1 blocked   \u{2605} Main"
+            ),
+            "{output}"
+        );
+        assert!(output.contains("M-002  Next up"));
+        assert!(
+            output.contains(" \u{25B8} web"),
+            "cursor marks web: {output}"
+        );
+        assert!(output.contains("nothing ready"));
+        assert!(output.contains("project.toml: missing"));
+    }
+}
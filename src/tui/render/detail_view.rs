@@ -22,6 +22,28 @@ use super::push_highlighted_spans;
 
 /// Render the detail view for a single task
 pub fn render_detail_view(frame: &mut Frame, app: &mut App, area: Rect) {
+    render_detail(frame, app, area, false);
+}
+
+/// Render a task read-only, as the split layout's detail pane shows the task
+/// under the list's cursor: no region cursor, and the Detail view's own state
+/// left as it was.
+pub fn render_detail_preview(
+    frame: &mut Frame,
+    app: &mut App,
+    area: Rect,
+    track_id: String,
+    task_id: String,
+) {
+    let view = app.view.clone();
+    let detail_state = app.detail_state.take();
+    app.open_detail(track_id, task_id);
+    render_detail(frame, app, area, true);
+    app.view = view;
+    app.detail_state = detail_state;
+}
+
+fn render_detail(frame: &mut Frame, app: &mut App, area: Rect, preview: bool) {
     let (track_id, task_id) = match &app.view {
         View::Detail { track_id, task_id } => (track_id.clone(), task_id.clone()),
         _ => return,
@@ -101,6 +123,9 @@ pub fn render_detail_view(frame: &mut Frame, app: &mut App, area: Rect) {
     let bright_style = Style::default().fg(app.theme.text_bright).bg(bg);
     let dim_style = Style::default().fg(app.theme.dim).bg(bg);
     let region_indicator_style = Style::default().fg(app.theme.highlight).bg(bg);
+    // A preview takes no input, so it has no region for a cursor to be on
+    let region_indicator =
+        |is_active: bool, style: Style, bg| region_indicator(is_active && !preview, style, bg);
     let project_root = app.project.root.clone();
     // Red for a `ref:`/`spec:` value that will not do what it says — no file
     // behind it, or a file only this working copy can see. `fr check` calls the
@@ -437,6 +462,64 @@ pub fn render_detail_view(frame: &mut Frame, app: &mut App, area: Rect) {
         }
     }
 
+    // --- Relations region ---
+    // The task's own relations, then those other tasks declare naming it. Only
+    // the first is in this task's file; both answer "what is this linked to".
+    {
+        let region_start = body_lines.len();
+        let is_active = current_region == DetailRegion::Relations;
+        let own = task.metadata.iter().filter_map(|m| match m {
+            Metadata::Relation(kind, ids) => {
+                Some((kind.key(), ids.iter().map(String::as_str).collect()))
+            }
+            _ => None,
+        });
+        let backlinks = task
+            .id
+            .as_ref()
+            .map(|id| crate::ops::relations::backlinks(&app.project, id.as_str()))
+            .unwrap_or_default();
+        let inverse = crate::ops::relations::group_backlinks(&backlinks)
+            .into_iter()
+            .map(|(kind, sources)| (kind.inverse_label(), sources));
+        let rows: Vec<(&str, Vec<&str>)> = own.chain(inverse).collect();
+        for (i, (label, ids)) in rows.iter().enumerate() {
+            if is_active && i == 0 {
+                body_active_line = Some(body_lines.len());
+            }
+            let mut spans: Vec<Span> = vec![
+                region_indicator(is_active && i == 0, region_indicator_style, bg),
+                Span::styled(format!("{}: ", label), dim_style),
+            ];
+            for (j, id) in ids.iter().enumerate() {
+                if j > 0 {
+                    spans.push(Span::styled(", ", dim_style));
+                }
+                push_highlighted_spans(
+                    &mut spans,
+                    id,
+                    text_style,
+                    highlight_style,
+                    search_re.as_ref(),
+                );
+                if let Some(state) = find_task_state_across_tracks(app, id) {
+                    spans.push(Span::styled(
+                        format!(" {}", state_symbol(state)),
+                        Style::default().fg(app.theme.state_color(state)).bg(bg),
+                    ));
+                }
+            }
+            let indent = unicode::display_width(label) + 3;
+            body_lines.extend(wrap_styled_spans(spans, width, indent, bg));
+        }
+        if body_lines.len() > region_start {
+            region_line_ranges.insert(
+                DetailRegion::Relations,
+                (region_start, body_lines.len() - 1),
+            );
+        }
+    }
+
     // --- Spec region ---
     {
         let region_start = body_lines.len();
@@ -576,6 +659,37 @@ pub fn render_detail_view(frame: &mut Frame, app: &mut App, area: Rect) {
         }
     }
 
+    // --- Custom fields region ---
+    {
+        let region_start = body_lines.len();
+        let is_active = current_region == DetailRegion::Fields;
+        let custom = task.metadata.iter().filter_map(|m| match m {
+            Metadata::Custom { key, value } => Some((key, value)),
+            _ => None,
+        });
+        for (i, (key, value)) in custom.enumerate() {
+            if is_active && i == 0 {
+                body_active_line = Some(body_lines.len());
+            }
+            let mut spans: Vec<Span> = vec![
+                region_indicator(is_active && i == 0, region_indicator_style, bg),
+                Span::styled(format!("{}: ", key), dim_style),
+            ];
+            push_highlighted_spans(
+                &mut spans,
+                value,
+                text_style,
+                highlight_style,
+                search_re.as_ref(),
+            );
+            let indent = unicode::display_width(key) + 5;
+            body_lines.extend(wrap_styled_spans(spans, width, indent, bg));
+        }
+        if body_lines.len() > region_start {
+            region_line_ranges.insert(DetailRegion::Fields, (region_start, body_lines.len() - 1));
+        }
+    }
+
     // Blank line before note
     body_lines.push(Line::from(""));
 
@@ -1248,7 +1362,7 @@ pub fn render_detail_view(frame: &mut Frame, app: &mut App, area: Rect) {
         let screen_y = body_area.y + line_idx.saturating_sub(scroll) as u16;
         let screen_x = body_area.x + prefix_w + word_offset;
         app.autocomplete_anchor = Some((screen_x, screen_y));
-    } else if app.mode == Mode::Triage {
+    } else if app.mode == Mode::Triage && !preview {
         // Cross-track move from detail view: anchor autocomplete to title in header area
         let screen_y = header_area.y + header_active_line.unwrap_or(0) as u16;
         let screen_x = header_area.x + 4;
@@ -1757,6 +1871,7 @@ pub fn state_flash_colors(
         TaskState::Parked => (Color::Rgb(0x4A, 0x3A, 0x15), theme.yellow), // amber bg, yellow border
         TaskState::Todo => (Color::Rgb(0x3A, 0x1A, 0x58), theme.purple), // purple bg, purple border
         TaskState::Done => (Color::Rgb(0x1A, 0x2A, 0x55), theme.blue),   // blue bg, blue border
+        TaskState::Custom(_) => (Color::Rgb(0x15, 0x3A, 0x4A), theme.cyan), // teal bg, cyan border
     }
 }
 
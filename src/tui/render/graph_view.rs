@@ -0,0 +1,413 @@
+use std::collections::HashMap;
+
+use ratatui::Frame;
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::style::{Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::Paragraph;
+
+use crate::model::TaskState;
+use crate::tui::app::App;
+use crate::tui::graph::GraphLayout;
+use crate::util::unicode;
+
+use super::helpers::state_symbol;
+
+/// A task's box: its border, with the id in the top edge, around one line.
+const BOX_W: usize = 24;
+const BOX_H: usize = 3;
+/// Columns between boxes, where the edges turn.
+const GAP_X: usize = 6;
+/// Rows between boxes.
+const GAP_Y: usize = 1;
+
+// Which ways a line leaves a cell, for picking its box-drawing glyph
+const UP: u8 = 1;
+const DOWN: u8 = 2;
+const LEFT: u8 = 4;
+const RIGHT: u8 = 8;
+
+/// Render the dependency graph: each task a box coloured by its state, each
+/// dependency a line from the task that has to finish to the one it unblocks.
+pub fn render_graph_view(frame: &mut Frame, app: &mut App, area: Rect) {
+    let layout = app.graph_layout();
+    let bg = app.theme.background;
+    let Some(gv) = app.graph_view.as_ref() else {
+        return;
+    };
+    let scope = match gv.track_id.as_deref() {
+        Some(track_id) => app.track_name(track_id).to_string(),
+        None => "all tracks".to_string(),
+    };
+    let toggle = if gv.track_id.is_some() {
+        "a: all tracks"
+    } else {
+        "a: this track"
+    };
+    let header = Line::from(vec![
+        Span::styled(
+            format!(" Dependencies: {scope}"),
+            Style::default()
+                .fg(app.theme.text_bright)
+                .bg(bg)
+                .add_modifier(Modifier::BOLD),
+        ),
+        Span::styled(
+            format!("  {} tasks  \u{00B7}  {toggle}", layout.nodes.len()),
+            Style::default().fg(app.theme.dim).bg(bg),
+        ),
+    ]);
+    frame.render_widget(
+        Paragraph::new(header).style(Style::default().bg(bg)),
+        Rect { height: 1, ..area },
+    );
+    if area.height < 3 {
+        return;
+    }
+    let canvas = Rect {
+        y: area.y + 2,
+        height: area.height - 2,
+        ..area
+    };
+
+    if layout.is_empty() {
+        let empty = Paragraph::new(format!(" No dependencies in {scope}"))
+            .style(Style::default().fg(app.theme.dim).bg(bg));
+        frame.render_widget(empty, canvas);
+        return;
+    }
+
+    let selected = gv.cursor.as_deref().and_then(|id| layout.index_of(id));
+    let (scroll_x, scroll_y) = scroll_to(
+        (gv.scroll_x, gv.scroll_y),
+        selected.map(|node| layout.place[node]),
+        canvas,
+    );
+    if let Some(gv) = app.graph_view.as_mut() {
+        gv.scroll_x = scroll_x;
+        gv.scroll_y = scroll_y;
+    }
+
+    let mut drawing = Drawing {
+        area: canvas,
+        scroll: (scroll_x, scroll_y),
+        buf: frame.buffer_mut(),
+    };
+    drawing.fill(Style::default().bg(bg));
+    draw_edges(&mut drawing, app, &layout, selected);
+    for node in 0..layout.nodes.len() {
+        draw_box(&mut drawing, app, &layout, node, selected == Some(node));
+    }
+}
+
+/// The scroll offsets that keep the selected box in view, moving as little
+/// as they can from where they were.
+fn scroll_to(
+    (mut x, mut y): (usize, usize),
+    place: Option<(usize, usize)>,
+    canvas: Rect,
+) -> (usize, usize) {
+    let Some((column, row)) = place else {
+        return (x, y);
+    };
+    let (left, top) = origin(column, row);
+    let (width, height) = (canvas.width as usize, canvas.height as usize);
+    if left < x {
+        x = left;
+    } else if left + BOX_W + 1 > x + width {
+        x = (left + BOX_W + 1).saturating_sub(width);
+    }
+    if top < y {
+        y = top;
+    } else if top + BOX_H > y + height {
+        y = (top + BOX_H).saturating_sub(height);
+    }
+    (x, y)
+}
+
+/// The top-left cell of a slot's box, in graph coordinates.
+fn origin(column: usize, row: usize) -> (usize, usize) {
+    (1 + column * (BOX_W + GAP_X), row * (BOX_H + GAP_Y))
+}
+
+/// The part of the graph that shows through the canvas.
+struct Drawing<'a> {
+    area: Rect,
+    scroll: (usize, usize),
+    buf: &'a mut Buffer,
+}
+
+impl Drawing<'_> {
+    fn fill(&mut self, style: Style) {
+        self.buf.set_style(self.area, style);
+        for y in self.area.top()..self.area.bottom() {
+            for x in self.area.left()..self.area.right() {
+                self.buf[(x, y)].set_symbol(" ");
+            }
+        }
+    }
+
+    /// Put `text` at graph cell `(x, y)`, clipped to the canvas.
+    fn put(&mut self, x: usize, y: usize, text: &str, style: Style) {
+        let Some(row) = y.checked_sub(self.scroll.1) else {
+            return;
+        };
+        if row >= self.area.height as usize {
+            return;
+        }
+        let mut col = x as isize - self.scroll.0 as isize;
+        for ch in text.chars() {
+            let width = unicode::char_display_width(ch) as isize;
+            if col >= 0 && col + width <= self.area.width as isize {
+                let cell = &mut self.buf[(self.area.x + col as u16, self.area.y + row as u16)];
+                cell.set_char(ch);
+                cell.set_style(style);
+            }
+            col += width;
+        }
+    }
+}
+
+fn draw_box(drawing: &mut Drawing, app: &App, layout: &GraphLayout, node: usize, selected: bool) {
+    let theme = &app.theme;
+    let task = &layout.nodes[node];
+    let (left, top) = {
+        let (column, row) = layout.place[node];
+        origin(column, row)
+    };
+    let (color, symbol, title) = match (task.state, task.title.as_deref()) {
+        (Some(TaskState::Done), Some(title)) => (theme.dim, state_symbol(TaskState::Done), title),
+        (Some(state), Some(title)) => (theme.state_color(state), state_symbol(state), title),
+        _ => (theme.dim, "[?]".to_string(), "not found"),
+    };
+    let (border, body) = if selected {
+        (
+            Style::default()
+                .fg(theme.highlight)
+                .bg(theme.selection_bg)
+                .add_modifier(Modifier::BOLD),
+            Style::default().fg(color).bg(theme.selection_bg),
+        )
+    } else {
+        (
+            Style::default().fg(color).bg(theme.background),
+            Style::default().fg(color).bg(theme.background),
+        )
+    };
+
+    let inner = BOX_W - 2;
+    let id = unicode::truncate_to_width(&task.id, inner - 3);
+    let rule = inner - 3 - unicode::display_width(&id);
+    drawing.put(
+        left,
+        top,
+        &format!("\u{250C}\u{2500} {id} {}\u{2510}", "\u{2500}".repeat(rule)),
+        border,
+    );
+    let text = unicode::truncate_to_width(&format!("{symbol} {title}"), inner - 2);
+    let pad = inner - 2 - unicode::display_width(&text);
+    drawing.put(left, top + 1, "\u{2502}", border);
+    drawing.put(
+        left + 1,
+        top + 1,
+        &format!(" {text}{} ", " ".repeat(pad)),
+        body,
+    );
+    drawing.put(left + BOX_W - 1, top + 1, "\u{2502}", border);
+    drawing.put(
+        left,
+        top + 2,
+        &format!("\u{2514}{}\u{2518}", "\u{2500}".repeat(inner)),
+        border,
+    );
+}
+
+/// Lay the edges down on a grid of line directions, then draw each cell
+/// with the glyph its directions make, so edges that meet join up.
+fn draw_edges(drawing: &mut Drawing, app: &App, layout: &GraphLayout, selected: Option<usize>) {
+    let mut lines: HashMap<(usize, usize), (u8, bool)> = HashMap::new();
+    let mut arrows: HashMap<(usize, usize), bool> = HashMap::new();
+    let mut link = |from: (usize, usize), to: (usize, usize), hot: bool| {
+        let (a, b) = if from.0 == to.0 {
+            if from.1 < to.1 {
+                (DOWN, UP)
+            } else {
+                (UP, DOWN)
+            }
+        } else if from.0 < to.0 {
+            (RIGHT, LEFT)
+        } else {
+            (LEFT, RIGHT)
+        };
+        for (cell, bit) in [(from, a), (to, b)] {
+            let entry = lines.entry(cell).or_default();
+            entry.0 |= bit;
+            entry.1 |= hot;
+        }
+    };
+
+    for (path, &(from, to)) in layout.paths.iter().zip(
+        layout
+            .edges
+            .iter()
+            .filter(|&&(from, to)| layout.place[from].0 < layout.place[to].0),
+    ) {
+        let hot = selected.is_some_and(|s| s == from || s == to);
+        for (i, pair) in path.windows(2).enumerate() {
+            let (start_x, start_y) = origin(pair[0].0, pair[0].1);
+            let (end_x, end_y) = origin(pair[1].0, pair[1].1);
+            let (start_y, end_y) = (start_y + 1, end_y + 1);
+            // A placeholder is a line straight through its box's width
+            let from_x = if i == 0 { start_x + BOX_W } else { start_x };
+            let turn = start_x + BOX_W + GAP_X / 2;
+            let into = end_x - 1;
+            let last = i + 2 == path.len();
+
+            let mut x = from_x;
+            while x < turn {
+                link((x, start_y), (x + 1, start_y), hot);
+                x += 1;
+            }
+            let mut y = start_y;
+            while y != end_y {
+                let next = if y < end_y { y + 1 } else { y - 1 };
+                link((turn, y), (turn, next), hot);
+                y = next;
+            }
+            let end = if last { into } else { end_x };
+            while x < end {
+                link((x, end_y), (x + 1, end_y), hot);
+                x += 1;
+            }
+            if last {
+                *arrows.entry((into, end_y)).or_default() |= hot;
+            }
+        }
+    }
+
+    let style = |hot: bool| {
+        let fg = if hot {
+            app.theme.highlight
+        } else {
+            app.theme.dim
+        };
+        Style::default().fg(fg).bg(app.theme.background)
+    };
+    for (&(x, y), &(bits, hot)) in &lines {
+        drawing.put(x, y, glyph(bits), style(hot));
+    }
+    for (&(x, y), &hot) in &arrows {
+        drawing.put(x, y, "\u{25B6}", style(hot));
+    }
+}
+
+fn glyph(bits: u8) -> &'static str {
+    match bits {
+        b if b == UP | DOWN | LEFT | RIGHT => "\u{253C}",
+        b if b == UP | DOWN | RIGHT => "\u{251C}",
+        b if b == UP | DOWN | LEFT => "\u{2524}",
+        b if b == LEFT | RIGHT | DOWN => "\u{252C}",
+        b if b == LEFT | RIGHT | UP => "\u{2534}",
+        b if b == DOWN | RIGHT => "\u{250C}",
+        b if b == DOWN | LEFT => "\u{2510}",
+        b if b == UP | RIGHT => "\u{2514}",
+        b if b == UP | LEFT => "\u{2518}",
+        b if b & (UP | DOWN) != 0 && b & (LEFT | RIGHT) == 0 => "\u{2502}",
+        _ => "\u{2500}",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+    use super::*;
+    use crate::tui::app::View;
+    use crate::tui::input::handle_key;
+    use crate::tui::render::test_helpers::*;
+
+    const DEPS_MD: &str = "\
+# Test Track
+
+## Backlog
+
+- [-] `T-1` Provision cluster
+- [ ] `T-2` Deploy api
+  - dep: T-1
+- [ ] `T-3` Deploy worker
+  - dep: T-1
+- [ ] `T-4` Launch
+  - dep: T-2, T-3
+- [ ] `T-5` Unrelated
+
+## Done
+";
+
+    fn press(app: &mut App, code: KeyCode) {
+        handle_key(app, KeyEvent::new(code, KeyModifiers::NONE));
+    }
+
+    fn render(app: &mut App) -> String {
+        render_to_string(100, TERM_H, |frame, area| {
+            render_graph_view(frame, app, area);
+        })
+    }
+
+    #[test]
+    fn the_graph_draws_boxes_and_edges_left_to_right() {
+        let mut app = app_with_track(DEPS_MD);
+        app.open_graph();
+        assert_eq!(app.view, View::Graph);
+
+        let output = render(&mut app);
+        assert!(output.contains("Dependencies: Test"));
+        assert!(output.contains("4 tasks"), "T-5 has no edges: {output}");
+        let line = output
+            .lines()
+            .find(|l| l.contains("Provision"))
+            .expect("T-1 is drawn");
+        let (before, after) = line.split_once("Provision").unwrap();
+        assert!(before.contains("[-]"));
+        assert!(after.contains('\u{25B6}'), "an edge runs right: {line}");
+        assert!(line.find("Provision") < line.find("Deploy"));
+        assert!(!output.contains("Unrelated"));
+    }
+
+    #[test]
+    fn keys_follow_the_edges_and_enter_jumps_to_the_task() {
+        let mut app = app_with_track(DEPS_MD);
+        app.get_track_state("test").cursor = 1;
+        app.open_graph();
+        let cursor = |app: &App| app.graph_view.as_ref().unwrap().cursor.clone().unwrap();
+        assert_eq!(cursor(&app), "T-2", "the track's cursor task is selected");
+
+        press(&mut app, KeyCode::Char('l'));
+        assert_eq!(cursor(&app), "T-4");
+        press(&mut app, KeyCode::Char('h'));
+        press(&mut app, KeyCode::Char('j'));
+        assert_eq!(cursor(&app), "T-3");
+        press(&mut app, KeyCode::Char('h'));
+        assert_eq!(cursor(&app), "T-1");
+
+        press(&mut app, KeyCode::Enter);
+        assert_eq!(app.view, View::Track(0));
+        assert!(app.graph_view.is_none());
+        assert_eq!(app.cursor_task_id().unwrap().1, "T-1");
+    }
+
+    #[test]
+    fn esc_returns_to_the_view_the_graph_was_opened_from() {
+        let mut app = app_with_track(DEPS_MD);
+        app.view = View::Board;
+        app.open_graph();
+        assert!(app.graph_view.as_ref().unwrap().track_id.is_none());
+        press(&mut app, KeyCode::Char('a'));
+        assert_eq!(
+            app.graph_view.as_ref().unwrap().track_id.as_deref(),
+            Some("test")
+        );
+        press(&mut app, KeyCode::Esc);
+        assert_eq!(app.view, View::Board);
+    }
+}
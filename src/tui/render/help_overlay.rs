@@ -5,6 +5,8 @@ use ratatui::text::{Line, Span};
 use ratatui::widgets::{Block, Borders, Clear, Paragraph};
 
 use crate::tui::app::{App, View};
+use crate::tui::command_actions::{ViewContext, current_context};
+use crate::tui::keymap::Keymap;
 
 /// A single entry in a help column
 enum HelpEntry {
@@ -38,7 +40,10 @@ pub fn render_help_overlay(frame: &mut Frame, app: &mut App, area: Rect) {
     lines.push(Line::from(""));
 
     // Build left and right column entries based on view
-    let (left, right) = build_columns(&app.view);
+    let (mut left, mut right) = build_columns(&app.view);
+    let ctx = current_context(&app.view);
+    relabel(&mut left, &app.keymap, ctx);
+    relabel(&mut right, &app.keymap, ctx);
 
     // Merge columns into lines
     let max_rows = left.len().max(right.len());
@@ -208,6 +213,19 @@ fn render_entry(
     }
 }
 
+/// Show a rebound action on the keys `[keys]` put it on. Only an entry that
+/// is exactly an action's shortcut is one action's; `x/b/o/~` and the like
+/// describe other modes.
+fn relabel(entries: &mut [HelpEntry], keymap: &Keymap, ctx: ViewContext) {
+    for entry in entries {
+        if let HelpEntry::Binding(key, _) = entry
+            && let Some(keys) = keymap.relabel(ctx, key)
+        {
+            *key = keys.to_string();
+        }
+    }
+}
+
 fn build_columns(view: &View) -> (Vec<HelpEntry>, Vec<HelpEntry>) {
     match view {
         View::Track(_) => build_track_columns(),
@@ -217,6 +235,8 @@ fn build_columns(view: &View) -> (Vec<HelpEntry>, Vec<HelpEntry>) {
         View::Recent => build_recent_columns(),
         View::Board => build_board_columns(),
         View::Search => build_search_columns(),
+        View::Graph => build_graph_columns(),
+        View::Stats => build_stats_columns(),
     }
 }
 
@@ -238,6 +258,8 @@ fn views_entries(include_tab: bool) -> Vec<HelpEntry> {
     entries.push(HelpEntry::Binding("r".into(), "Recent".into()));
     entries.push(HelpEntry::Binding("K".into(), "Board".into()));
     entries.push(HelpEntry::Binding("S".into(), "Project search".into()));
+    entries.push(HelpEntry::Binding("E".into(), "Dependency graph".into()));
+    entries.push(HelpEntry::Binding("Y".into(), "Stats".into()));
     entries
 }
 
@@ -249,6 +271,8 @@ fn other_entries(include_deps: bool, include_cc: bool, include_repeat: bool) ->
         HelpEntry::Binding("/".into(), "Search".into()),
         HelpEntry::Binding(">".into(), "Command palette".into()),
         HelpEntry::Binding("J".into(), "Jump to task".into()),
+        HelpEntry::Binding("Ctrl+O/I".into(), "Jump back / forward".into()),
+        HelpEntry::Binding("B/'".into(), "Set / go to mark".into()),
     ];
     if include_deps {
         entries.push(HelpEntry::Binding("D".into(), "Show deps".into()));
@@ -261,8 +285,11 @@ fn other_entries(include_deps: bool, include_cc: bool, include_repeat: bool) ->
     }
     entries.push(HelpEntry::Binding("T".into(), "Tag colors".into()));
     entries.push(HelpEntry::Binding("P".into(), "Projects".into()));
+    entries.push(HelpEntry::Binding("O".into(), "Dashboard".into()));
+    entries.push(HelpEntry::Binding("W".into(), "External changes".into()));
     entries.push(HelpEntry::Binding("z/u".into(), "Undo".into()));
     entries.push(HelpEntry::Binding("Z".into(), "Redo".into()));
+    entries.push(HelpEntry::Binding("U".into(), "Undo history".into()));
     entries.push(HelpEntry::Binding("?".into(), "Help".into()));
     entries.push(HelpEntry::Binding("QQ".into(), "Quit".into()));
     entries
@@ -277,6 +304,8 @@ fn build_track_columns() -> (Vec<HelpEntry>, Vec<HelpEntry>) {
         HelpEntry::Binding("g/G".into(), "Top / bottom".into()),
         HelpEntry::Binding("Enter".into(), "Open detail".into()),
         HelpEntry::Binding("Esc".into(), "Back / close".into()),
+        HelpEntry::Binding("|".into(), "Split with detail".into()),
+        HelpEntry::Binding("{/}".into(), "Narrow / widen list".into()),
         HelpEntry::Blank,
         HelpEntry::Header("Task State".into()),
         HelpEntry::Binding("Space".into(), "Cycle state".into()),
@@ -332,6 +361,7 @@ fn build_detail_columns() -> (Vec<HelpEntry>, Vec<HelpEntry>) {
         HelpEntry::Binding("Tab/S-Tab".into(), "Next / prev region".into()),
         HelpEntry::Binding("g/G".into(), "Top / bottom".into()),
         HelpEntry::Binding("Esc".into(), "Back / close".into()),
+        HelpEntry::Binding("|".into(), "Split with list".into()),
         HelpEntry::Blank,
         HelpEntry::Header("Task State".into()),
         HelpEntry::Binding("Space".into(), "Cycle state".into()),
@@ -442,6 +472,12 @@ fn build_recent_columns() -> (Vec<HelpEntry>, Vec<HelpEntry>) {
     (left, right)
 }
 
+fn build_stats_columns() -> (Vec<HelpEntry>, Vec<HelpEntry>) {
+    let left = views_entries(true);
+    let right = other_entries(false, false, false);
+    (left, right)
+}
+
 fn build_search_columns() -> (Vec<HelpEntry>, Vec<HelpEntry>) {
     let mut left = vec![
         HelpEntry::Header("Navigation".into()),
@@ -463,6 +499,26 @@ fn build_search_columns() -> (Vec<HelpEntry>, Vec<HelpEntry>) {
     (left, right)
 }
 
+fn build_graph_columns() -> (Vec<HelpEntry>, Vec<HelpEntry>) {
+    let mut left = vec![
+        HelpEntry::Header("Navigation".into()),
+        HelpEntry::Binding("h/l".into(), "Dependency / dependent".into()),
+        HelpEntry::Binding("\u{25B2}\u{25BC}/jk".into(), "Move in column".into()),
+        HelpEntry::Binding("g/G".into(), "Top / bottom of column".into()),
+        HelpEntry::Binding("Enter".into(), "Jump to task".into()),
+        HelpEntry::Binding("Esc/E".into(), "Close graph".into()),
+        HelpEntry::Blank,
+    ];
+    left.extend(views_entries(true));
+
+    let right = vec![
+        HelpEntry::Header("Graph".into()),
+        HelpEntry::Binding("a".into(), "This track / all tracks".into()),
+    ];
+
+    (left, right)
+}
+
 fn build_board_columns() -> (Vec<HelpEntry>, Vec<HelpEntry>) {
     let mut left = vec![
         HelpEntry::Header("Navigation".into()),
@@ -484,6 +540,7 @@ fn build_board_columns() -> (Vec<HelpEntry>, Vec<HelpEntry>) {
 
     let mut right = vec![
         HelpEntry::Header("Board".into()),
+        HelpEntry::Binding("H/L".into(), "Move card left / right".into()),
         HelpEntry::Binding("c".into(), "Toggle CC/All mode".into()),
         HelpEntry::Binding("e".into(), "Edit title".into()),
         HelpEntry::Binding("t".into(), "Edit tags".into()),
@@ -541,6 +598,20 @@ mod tests {
         assert_snapshot!(output);
     }
 
+    #[test]
+    fn help_shows_rebound_keys() {
+        let mut app = app_with_track(SIMPLE_TRACK_MD);
+        let keys = toml::from_str("mark_done = \"X\"\nundo = [\"u\", \"ctrl+z\"]\n").unwrap();
+        app.keymap = Keymap::from_config(&keys).unwrap();
+        app.show_help = true;
+        let output = render_to_string(TERM_W, 60, |frame, area| {
+            render_help_overlay(frame, &mut app, area);
+        });
+        assert!(output.contains(" X          Mark done"), "{output}");
+        assert!(output.contains(" u/Ctrl+Z   Undo"), "{output}");
+        assert!(!output.contains(" x          Mark done"), "{output}");
+    }
+
     fn help_at(h: u16, scroll: usize) -> (String, usize) {
         let mut app = app_with_track(SIMPLE_TRACK_MD);
         app.show_help = true;
@@ -4,14 +4,8 @@ use crate::model::task::{Metadata, Task, TaskState};
 use crate::util::unicode;
 
 /// State symbols for each task state (markdown checkbox style)
-pub(super) fn state_symbol(state: TaskState) -> &'static str {
-    match state {
-        TaskState::Todo => "[ ]",
-        TaskState::Active => "[>]",
-        TaskState::Blocked => "[-]",
-        TaskState::Done => "[x]",
-        TaskState::Parked => "[~]",
-    }
+pub(super) fn state_symbol(state: TaskState) -> String {
+    format!("[{}]", state.checkbox_char())
 }
 
 /// Get abbreviated ID (e.g., "EFF-014.2" -> ".2")
@@ -4,7 +4,7 @@ use ratatui::style::{Modifier, Style};
 use ratatui::text::{Line, Span};
 use ratatui::widgets::Paragraph;
 
-use crate::tui::app::{App, EditTarget, Mode};
+use crate::tui::app::{App, EditTarget, Hit, Mode};
 use crate::tui::input::{multiline_selection_range, selection_cols_for_line};
 use crate::tui::wrap;
 use crate::util::unicode;
@@ -351,6 +351,9 @@ pub fn render_inbox_view(frame: &mut Frame, app: &mut App, area: Rect) {
         app.autocomplete_anchor = Some((screen_x, screen_y));
     }
 
+    let line_rows: Vec<Option<usize>> = display_lines.iter().map(|(i, _)| *i).collect();
+    super::record_rows(app, area, scroll, &line_rows, Hit::Row);
+
     // Apply scroll and collect visible lines
     let lines: Vec<Line> = display_lines
         .into_iter()
@@ -1,9 +1,12 @@
+pub mod activity_panel;
 pub mod autocomplete;
 pub mod board_view;
 pub mod command_palette;
 pub mod conflict_popup;
+pub mod dashboard;
 pub mod dep_popup;
 pub mod detail_view;
+pub mod graph_view;
 pub mod help_overlay;
 mod helpers;
 pub mod inbox_view;
@@ -14,22 +17,27 @@ pub mod recovery_overlay;
 pub mod results_overlay;
 pub mod scroll;
 pub mod search_view;
+pub mod split_view;
+pub mod stats_view;
 pub mod status_row;
 pub mod tab_bar;
 pub mod tag_color_popup;
 pub mod track_view;
 pub mod tracks_view;
+pub mod undo_history;
 
 use ratatui::Frame;
+use ratatui::buffer::Buffer;
 use ratatui::layout::{Constraint, Direction, Layout, Rect};
-use ratatui::style::{Modifier, Style};
+use ratatui::style::{Color, Modifier, Style};
 use ratatui::text::{Line, Span};
 use ratatui::widgets::{Block, Borders, Clear, Paragraph};
 use regex::Regex;
 
 use crate::util::unicode;
 
-use super::app::{App, TriageStep, View};
+use super::app::{App, Hit, TriageStep, View};
+use super::theme::Theme;
 
 /// Main render function — dispatches to sub-renderers
 pub fn render(frame: &mut Frame, app: &mut App) {
@@ -49,6 +57,13 @@ pub fn render(frame: &mut Frame, app: &mut App) {
         ])
         .split(area);
 
+    // Click targets are recorded afresh as the frame is drawn
+    app.hits.clear();
+    // Stats are gathered again the next time the view opens
+    if app.view != View::Stats {
+        app.stats_activity = None;
+    }
+
     // Render tab bar
     tab_bar::render_tab_bar(frame, app, chunks[0]);
 
@@ -58,6 +73,7 @@ pub fn render(frame: &mut Frame, app: &mut App) {
     // Render content area (clone view to avoid borrow conflict)
     let view = app.view.clone();
     match &view {
+        _ if app.split_track().is_some() => split_view::render_split_view(frame, app, chunks[1]),
         View::Track(_) => track_view::render_track_view(frame, app, chunks[1]),
         View::Detail { .. } => detail_view::render_detail_view(frame, app, chunks[1]),
         View::Board => board_view::render_board_view(frame, app, chunks[1]),
@@ -70,9 +86,11 @@ pub fn render(frame: &mut Frame, app: &mut App) {
         View::Recent => {
             recent_view::render_recent_view(frame, app, chunks[1]);
         }
+        View::Stats => stats_view::render_stats_view(frame, app, chunks[1]),
         View::Search => {
             search_view::render_search_view(frame, app, chunks[1]);
         }
+        View::Graph => graph_view::render_graph_view(frame, app, chunks[1]),
     }
 
     // Dep popup overlay (rendered on top of content)
@@ -95,6 +113,21 @@ pub fn render(frame: &mut Frame, app: &mut App) {
         project_picker::render_project_picker(frame, app, chunks[1]);
     }
 
+    // Dashboard overlay
+    if app.dashboard.is_some() {
+        dashboard::render_dashboard(frame, app, chunks[1]);
+    }
+
+    // Activity panel overlay
+    if app.activity_panel.is_some() {
+        activity_panel::render_activity_panel(frame, app, chunks[1]);
+    }
+
+    // Undo history overlay
+    if app.undo_history.is_some() {
+        undo_history::render_undo_history(frame, app, chunks[1]);
+    }
+
     // Help overlay (rendered on top of everything)
     if app.show_help {
         help_overlay::render_help_overlay(frame, app, frame.area());
@@ -140,6 +173,40 @@ pub fn render(frame: &mut Frame, app: &mut App) {
 
     // Status row
     status_row::render_status_row(frame, app, chunks[2]);
+
+    if app.theme.mono {
+        monochrome(frame.buffer_mut(), &app.theme);
+    }
+}
+
+/// Monochrome, done once over the finished frame rather than in every view:
+/// each theme colour becomes the attributes it stands for, and every colour —
+/// the theme's or one a view chose itself — becomes the terminal's own.
+pub fn monochrome(buf: &mut Buffer, theme: &Theme) {
+    for cell in &mut buf.content {
+        cell.modifier |= theme.mono_modifier(cell.fg, cell.bg);
+        cell.fg = Color::Reset;
+        cell.bg = Color::Reset;
+    }
+}
+
+/// Record a click target for every visible line of a list view. `line_rows`
+/// names the row each display line belongs to, before scrolling; `None` for a
+/// line that is no row's (a header, a blank line).
+fn record_rows(
+    app: &mut App,
+    area: Rect,
+    scroll: usize,
+    line_rows: &[Option<usize>],
+    hit: impl Fn(usize) -> Hit,
+) {
+    let visible = line_rows.iter().skip(scroll).take(area.height as usize);
+    for (y, row) in visible.enumerate() {
+        if let Some(row) = row {
+            let line = Rect::new(area.x, area.y + y as u16, area.width, 1);
+            app.hits.push((line, hit(*row)));
+        }
+    }
 }
 
 /// Render the triage position-selection popup, if in the SelectPosition step.
@@ -312,6 +379,33 @@ mod tests {
         assert_snapshot!(output);
     }
 
+    /// Monochrome leaves no colour on screen, and the cursor still shows.
+    #[test]
+    fn monochrome_draws_with_attributes_only() {
+        let mut app = app_with_track(SIMPLE_TRACK_MD);
+        let ui = crate::model::UiConfig {
+            theme: crate::model::ThemeName::Mono,
+            ..Default::default()
+        };
+        app.theme = Theme::resolve(&ui, Default::default());
+        let mut terminal =
+            ratatui::Terminal::new(ratatui::backend::TestBackend::new(TERM_W, TERM_H)).unwrap();
+        terminal.draw(|frame| render(frame, &mut app)).unwrap();
+        let buf = terminal.backend().buffer();
+
+        assert!(
+            buf.content
+                .iter()
+                .all(|cell| cell.fg == Color::Reset && cell.bg == Color::Reset)
+        );
+        assert!(
+            buf.content
+                .iter()
+                .any(|cell| cell.modifier.contains(Modifier::REVERSED)),
+            "the cursor row is drawn reversed"
+        );
+    }
+
     #[test]
     fn truncate_with_ellipsis_short() {
         assert_eq!(truncate_with_ellipsis("hello", 10), "hello");
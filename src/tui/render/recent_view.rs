@@ -5,7 +5,7 @@ use ratatui::text::{Line, Span};
 use ratatui::widgets::Paragraph;
 
 use crate::model::task::{Task, TaskState};
-use crate::tui::app::App;
+use crate::tui::app::{App, Hit};
 use crate::tui::input::build_recent_entries;
 use crate::util::unicode;
 
@@ -32,6 +32,8 @@ pub fn render_recent_view(frame: &mut Frame, app: &mut App, area: Rect) {
     let mut lines: Vec<Line> = Vec::new();
     let mut current_date = String::new();
     let mut cursor_line: Option<usize> = None;
+    // The entry each line belongs to, for mouse clicks
+    let mut line_rows: Vec<Option<usize>> = Vec::new();
 
     for (flat_idx, entry) in entries.iter().enumerate() {
         // Date header (group by date)
@@ -49,6 +51,7 @@ pub fn render_recent_view(frame: &mut Frame, app: &mut App, area: Rect) {
             )));
         }
 
+        line_rows.resize(lines.len(), None);
         let is_cursor = flat_idx == cursor;
         if is_cursor {
             cursor_line = Some(lines.len());
@@ -204,6 +207,7 @@ pub fn render_recent_view(frame: &mut Frame, app: &mut App, area: Rect) {
                 is_cursor,
             );
         }
+        line_rows.resize(lines.len(), Some(flat_idx));
     }
 
     // Auto-adjust scroll to keep cursor visible (with a scrolloff margin).
@@ -218,6 +222,7 @@ pub fn render_recent_view(frame: &mut Frame, app: &mut App, area: Rect) {
         );
     }
     app.recent_scroll = scroll;
+    super::record_rows(app, area, scroll, &line_rows, Hit::Row);
 
     // Apply scroll
     let visible_lines: Vec<Line> = lines
@@ -279,13 +284,7 @@ fn render_subtask_tree<'a>(
         ));
 
         // State bracket showing actual state
-        let state_str = match task.state {
-            TaskState::Todo => "[ ] ",
-            TaskState::Active => "[>] ",
-            TaskState::Blocked => "[-] ",
-            TaskState::Done => "[x] ",
-            TaskState::Parked => "[~] ",
-        };
+        let state_str = format!("[{}] ", task.state.checkbox_char());
         spans.push(Span::styled(
             state_str,
             Style::default()
@@ -5,7 +5,7 @@ use ratatui::text::{Line, Span};
 use ratatui::widgets::Paragraph;
 
 use crate::ops::search::MatchField;
-use crate::tui::app::{App, SearchResultKind};
+use crate::tui::app::{App, Hit, SearchResultKind};
 use crate::util::unicode;
 
 use super::push_highlighted_spans;
@@ -38,6 +38,8 @@ pub fn render_search_view(frame: &mut Frame, app: &mut App, area: Rect) {
     let mut cursor_line: Option<usize> = None;
     let mut cursor_line_end: Option<usize> = None;
     let mut current_group_idx = 0;
+    // The result each line belongs to, for mouse clicks
+    let mut line_rows: Vec<Option<usize>> = Vec::new();
 
     let highlight_style = Style::default()
         .fg(app.theme.highlight)
@@ -67,6 +69,7 @@ pub fn render_search_view(frame: &mut Frame, app: &mut App, area: Rect) {
             current_group_idx += 1;
         }
 
+        line_rows.resize(lines.len(), None);
         let is_cursor = item_idx == cursor;
         if is_cursor {
             cursor_line = Some(lines.len());
@@ -101,11 +104,12 @@ pub fn render_search_view(frame: &mut Frame, app: &mut App, area: Rect) {
             ));
         } else if let Some(state) = &item.state {
             let state_char = match state {
-                crate::model::TaskState::Todo => "[ ]",
-                crate::model::TaskState::Active => "[>]",
-                crate::model::TaskState::Done => "[x]",
-                crate::model::TaskState::Blocked => "[b]",
-                crate::model::TaskState::Parked => "[~]",
+                crate::model::TaskState::Todo => "[ ]".to_string(),
+                crate::model::TaskState::Active => "[>]".to_string(),
+                crate::model::TaskState::Done => "[x]".to_string(),
+                crate::model::TaskState::Blocked => "[b]".to_string(),
+                crate::model::TaskState::Parked => "[~]".to_string(),
+                crate::model::TaskState::Custom(c) => format!("[{c}]"),
             };
             let color = app.theme.state_color(*state);
             let style = if is_archive {
@@ -226,6 +230,7 @@ pub fn render_search_view(frame: &mut Frame, app: &mut App, area: Rect) {
         if is_cursor {
             cursor_line_end = Some(lines.len().saturating_sub(1));
         }
+        line_rows.resize(lines.len(), Some(item_idx));
     }
 
     // Auto-scroll to keep the cursor item's full extent visible (with margin).
@@ -245,6 +250,8 @@ pub fn render_search_view(frame: &mut Frame, app: &mut App, area: Rect) {
         0
     };
 
+    super::record_rows(app, area, scroll_offset, &line_rows, Hit::Row);
+
     // Render visible lines
     let visible_lines: Vec<Line> = lines
         .into_iter()
@@ -274,6 +281,7 @@ fn field_label_str(field: &MatchField) -> &'static str {
         MatchField::Dep => "dep",
         MatchField::Ref => "ref",
         MatchField::Spec => "spec",
+        MatchField::Field => "field",
         MatchField::Body => "body",
     }
 }
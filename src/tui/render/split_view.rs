@@ -0,0 +1,113 @@
+use ratatui::Frame;
+use ratatui::layout::{Constraint, Direction, Layout, Rect};
+use ratatui::style::Style;
+use ratatui::widgets::{Block, Borders, Paragraph};
+
+use crate::tui::app::{App, Mode, View};
+
+use super::{detail_view, track_view};
+
+/// Width from which the panes sit side by side; narrower, they are stacked.
+const SIDE_BY_SIDE_MIN_WIDTH: u16 = 100;
+
+/// Render the split layout: the track list, a rule, and a detail pane.
+///
+/// In the Track view the detail pane follows the list's cursor read-only. In a
+/// Detail view opened from it the roles swap: the detail pane is the Detail
+/// view, with the focus, and the list is drawn as it stands, taking no input.
+pub fn render_split_view(frame: &mut Frame, app: &mut App, area: Rect) {
+    let Some(track_idx) = app.split_track() else {
+        return;
+    };
+    let (list_area, rule_area, detail_area, side_by_side) = split_areas(area, app.split_ratio);
+
+    let rule = Block::default()
+        .borders(if side_by_side {
+            Borders::LEFT
+        } else {
+            Borders::TOP
+        })
+        .border_style(Style::default().fg(app.theme.dim).bg(app.theme.background));
+    frame.render_widget(rule, rule_area);
+
+    if matches!(app.view, View::Detail { .. }) {
+        let view = std::mem::replace(&mut app.view, View::Track(track_idx));
+        let mode = std::mem::replace(&mut app.mode, Mode::Navigate);
+        track_view::render_track_view(frame, app, list_area);
+        app.view = view;
+        app.mode = mode;
+        detail_view::render_detail_view(frame, app, detail_area);
+        return;
+    }
+
+    track_view::render_track_view(frame, app, list_area);
+    match app.cursor_task_id() {
+        Some((track_id, task_id, _)) => {
+            detail_view::render_detail_preview(frame, app, detail_area, track_id, task_id);
+        }
+        None => {
+            let empty = Paragraph::new(" No task under the cursor")
+                .style(Style::default().fg(app.theme.dim).bg(app.theme.background));
+            frame.render_widget(empty, detail_area);
+        }
+    }
+}
+
+/// Divide `area` into the list pane, a one-cell rule, and the detail pane,
+/// giving the list `ratio` percent. Returns whether the panes are side by side.
+pub fn split_areas(area: Rect, ratio: u16) -> (Rect, Rect, Rect, bool) {
+    let side_by_side = area.width >= SIDE_BY_SIDE_MIN_WIDTH;
+    let direction = if side_by_side {
+        Direction::Horizontal
+    } else {
+        Direction::Vertical
+    };
+    let chunks = Layout::default()
+        .direction(direction)
+        .constraints([
+            Constraint::Percentage(ratio),
+            Constraint::Length(1),
+            Constraint::Min(1),
+        ])
+        .split(area);
+    (chunks[0], chunks[1], chunks[2], side_by_side)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tui::render::test_helpers::*;
+
+    #[test]
+    fn split_shows_the_task_under_the_cursor() {
+        let mut app = app_with_track(SIMPLE_TRACK_MD);
+        app.split_pane = true;
+        app.get_track_state("test").cursor = 1;
+
+        let output = render_to_string(TERM_W, TERM_H, |frame, area| {
+            render_split_view(frame, &mut app, area);
+        });
+        let (list, detail) = output.split_once('\u{2500}').expect("stacked, with a rule");
+        assert!(list.contains("First task") && list.contains("Second task"));
+        assert!(detail.contains("T-2 Second task"));
+        assert!(
+            !detail.contains('\u{258E}'),
+            "a preview has no region cursor"
+        );
+        assert_eq!(app.view, View::Track(0), "and leaves the view as it was");
+        assert!(app.detail_state.is_none());
+    }
+
+    #[test]
+    fn wide_terminals_put_the_panes_side_by_side() {
+        let (list, _, detail, side) = split_areas(Rect::new(0, 0, 120, 20), 40);
+        assert!(side);
+        assert_eq!((list.width, list.height), (48, 20));
+        assert_eq!(detail.x, 49);
+
+        let (list, _, detail, side) = split_areas(Rect::new(0, 0, 80, 21), 50);
+        assert!(!side);
+        assert_eq!(list.width, 80);
+        assert!(detail.y > list.y);
+    }
+}
@@ -0,0 +1,364 @@
+use chrono::{Datelike, Duration, NaiveDate};
+use ratatui::Frame;
+use ratatui::layout::{Constraint, Direction, Layout, Rect};
+use ratatui::style::{Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Bar, BarChart, BarGroup, LineGauge, Paragraph, Sparkline};
+
+use crate::io::project_io::archived_task_lists;
+use crate::ops::stats::{self, Activity};
+use crate::tui::app::App;
+use crate::util::unicode;
+
+/// The heatmap's weeks, at most: a year, plus the partial week it started in.
+const MAX_WEEKS: usize = 53;
+/// Width of the weekday labels left of the heatmap.
+const DAY_LABEL_W: usize = 5;
+/// Width of the labels left of the sparklines and gauges.
+const LABEL_W: usize = 14;
+
+/// Render the Stats view: a heatmap of tasks resolved per day, added and
+/// resolved per week, each active track's progress and the open work's top
+/// tags.
+pub fn render_stats_view(frame: &mut Frame, app: &mut App, area: Rect) {
+    if app.stats_activity.is_none() {
+        app.stats_activity = Some(gather_activity(app));
+    }
+    let today = chrono::Local::now().date_naive();
+    let app = &*app;
+    if let Some(activity) = &app.stats_activity {
+        render_stats(frame, app, activity, area, today);
+    }
+}
+
+/// Count the project's activity, archives included.
+fn gather_activity(app: &App) -> Activity {
+    let archived = archived_task_lists(
+        &app.project.frame_dir,
+        &crate::parse::Schema::of(&app.project.config),
+    );
+    stats::activity(&app.project, &archived)
+}
+
+fn render_stats(frame: &mut Frame, app: &App, activity: &Activity, area: Rect, today: NaiveDate) {
+    let bg = Style::default().bg(app.theme.background);
+    frame.render_widget(Paragraph::new("").style(bg), area);
+
+    let rows = Layout::vertical([
+        Constraint::Length(1), // heading
+        Constraint::Length(9), // month row, seven days, blank
+        Constraint::Length(6), // two sparklines with labels
+        Constraint::Min(0),    // tracks and tags
+    ])
+    .split(area);
+
+    let weeks = ((area.width as usize).saturating_sub(DAY_LABEL_W + 1) / 2).clamp(1, MAX_WEEKS);
+    let first = stats::week_start(today) - Duration::weeks(weeks as i64 - 1);
+    let in_range: usize = activity.resolved.range(first..=today).map(|(_, n)| n).sum();
+    let this_week: usize = activity
+        .resolved
+        .range(stats::week_start(today)..=today)
+        .map(|(_, n)| n)
+        .sum();
+    let heading = Line::from(vec![
+        Span::styled(
+            " Resolved per day",
+            Style::default()
+                .fg(app.theme.text_bright)
+                .bg(app.theme.background)
+                .add_modifier(Modifier::BOLD),
+        ),
+        Span::styled(
+            format!("  {in_range} in {weeks} weeks \u{00B7} {this_week} this week"),
+            Style::default().fg(app.theme.dim).bg(app.theme.background),
+        ),
+    ]);
+    frame.render_widget(Paragraph::new(heading).style(bg), rows[0]);
+    frame.render_widget(
+        Paragraph::new(heatmap_lines(app, activity, first, weeks, today)).style(bg),
+        rows[1],
+    );
+    render_weekly(frame, app, activity, rows[2], today);
+
+    let bottom = Layout::horizontal([Constraint::Percentage(55), Constraint::Percentage(45)])
+        .spacing(2)
+        .split(rows[3]);
+    render_tracks(frame, app, activity, bottom[0]);
+    render_tags(frame, app, activity, bottom[1]);
+}
+
+/// The heatmap, a column per week and a row per weekday, shaded by how many
+/// tasks were resolved that day against the busiest day shown.
+fn heatmap_lines<'a>(
+    app: &App,
+    activity: &Activity,
+    first: NaiveDate,
+    weeks: usize,
+    today: NaiveDate,
+) -> Vec<Line<'a>> {
+    let theme = &app.theme;
+    let dim = Style::default().fg(theme.dim).bg(theme.background);
+    let busiest = activity
+        .resolved
+        .range(first..=today)
+        .map(|(_, &n)| n)
+        .max()
+        .unwrap_or(0);
+
+    // Month names over the week each month's first Monday falls in
+    let mut months = " ".repeat(DAY_LABEL_W);
+    let mut taken = 0;
+    for week in 0..weeks {
+        let monday = first + Duration::weeks(week as i64);
+        let col = DAY_LABEL_W + week * 2;
+        if monday.day() <= 7 && col >= taken {
+            let name = monday.format("%b").to_string();
+            months.push_str(&" ".repeat(col - unicode::display_width(&months)));
+            months.push_str(&name);
+            taken = col + name.len() + 1;
+        }
+    }
+    let mut lines = vec![Line::from(Span::styled(months, dim))];
+
+    for weekday in 0..7 {
+        let label = match weekday {
+            0 => " Mon ",
+            2 => " Wed ",
+            4 => " Fri ",
+            _ => "     ",
+        };
+        let mut spans = vec![Span::styled(label, dim)];
+        for week in 0..weeks {
+            let day = first + Duration::days(week as i64 * 7 + weekday);
+            if day > today {
+                break;
+            }
+            let count = activity.resolved.get(&day).copied().unwrap_or(0);
+            let (glyph, style) = match shade(count, busiest) {
+                0 => ("\u{00B7}", dim),
+                level => (
+                    ["\u{2591}", "\u{2592}", "\u{2593}", "\u{2588}"][level - 1],
+                    Style::default().fg(theme.green).bg(theme.background),
+                ),
+            };
+            spans.push(Span::styled(format!("{glyph} "), style));
+        }
+        lines.push(Line::from(spans));
+    }
+    lines
+}
+
+/// 0 for nothing, else 1 to 4 by quarter of the busiest day.
+fn shade(count: usize, busiest: usize) -> usize {
+    if count == 0 || busiest == 0 {
+        0
+    } else {
+        (count * 4).div_ceil(busiest).clamp(1, 4)
+    }
+}
+
+/// Added and resolved per week, one sparkline each over the same weeks.
+fn render_weekly(frame: &mut Frame, app: &App, activity: &Activity, area: Rect, today: NaiveDate) {
+    let theme = &app.theme;
+    let rows = Layout::vertical([
+        Constraint::Length(1),
+        Constraint::Length(2),
+        Constraint::Length(2),
+        Constraint::Length(1),
+    ])
+    .split(area);
+    frame.render_widget(
+        Paragraph::new(Span::styled(
+            " Added and resolved per week",
+            Style::default()
+                .fg(theme.text_bright)
+                .bg(theme.background)
+                .add_modifier(Modifier::BOLD),
+        )),
+        rows[0],
+    );
+
+    let weeks = (area.width as usize).saturating_sub(LABEL_W).max(1);
+    let weekly = activity.weekly(today, weeks);
+    let series = [
+        (
+            "added",
+            weekly.iter().map(|w| w.0 as u64).collect::<Vec<_>>(),
+            theme.blue,
+        ),
+        (
+            "resolved",
+            weekly.iter().map(|w| w.1 as u64).collect(),
+            theme.green,
+        ),
+    ];
+    for (row, (name, data, color)) in rows[1..3].iter().zip(series) {
+        let [label, chart] =
+            Layout::horizontal([Constraint::Length(LABEL_W as u16), Constraint::Min(1)])
+                .areas(*row);
+        let total: u64 = data.iter().sum();
+        frame.render_widget(
+            Paragraph::new(Span::styled(
+                format!(" {name:<8} {total:>3}"),
+                Style::default().fg(theme.text).bg(theme.background),
+            )),
+            label,
+        );
+        frame.render_widget(
+            Sparkline::default()
+                .data(&data)
+                .style(Style::default().fg(color).bg(theme.background)),
+            chart,
+        );
+    }
+}
+
+/// A gauge per active track: its done top-level tasks out of all of them.
+fn render_tracks(frame: &mut Frame, app: &App, activity: &Activity, area: Rect) {
+    let theme = &app.theme;
+    let tracks: Vec<_> = activity
+        .tracks
+        .iter()
+        .filter(|t| app.active_track_ids.contains(&t.track_id))
+        .collect();
+    let mut rows = vec![Constraint::Length(1)];
+    rows.extend(tracks.iter().map(|_| Constraint::Length(1)));
+    let rows = Layout::vertical(rows).split(area);
+    frame.render_widget(
+        Paragraph::new(Span::styled(
+            " Tracks",
+            Style::default()
+                .fg(theme.text_bright)
+                .bg(theme.background)
+                .add_modifier(Modifier::BOLD),
+        )),
+        rows[0],
+    );
+    for (track, &row) in tracks.iter().zip(&rows[1..]) {
+        let name = unicode::truncate_to_width(app.track_name(&track.track_id), LABEL_W - 2);
+        let [label, gauge] =
+            Layout::horizontal([Constraint::Length(LABEL_W as u16), Constraint::Min(1)]).areas(row);
+        frame.render_widget(
+            Paragraph::new(Span::styled(
+                format!(" {name}"),
+                Style::default().fg(theme.text).bg(theme.background),
+            )),
+            label,
+        );
+        frame.render_widget(
+            LineGauge::default()
+                .ratio(track.ratio())
+                .label(format!("{:>3}/{:<3}", track.done, track.total))
+                .style(Style::default().fg(theme.text).bg(theme.background))
+                .filled_style(Style::default().fg(theme.green).bg(theme.background))
+                .unfilled_style(Style::default().fg(theme.dim).bg(theme.background)),
+            gauge,
+        );
+    }
+}
+
+/// The tags open tasks carry most, as horizontal bars.
+fn render_tags(frame: &mut Frame, app: &App, activity: &Activity, area: Rect) {
+    let theme = &app.theme;
+    let [title, chart] = Layout::vertical([Constraint::Length(1), Constraint::Min(0)]).areas(area);
+    frame.render_widget(
+        Paragraph::new(Span::styled(
+            "Top tags",
+            Style::default()
+                .fg(theme.text_bright)
+                .bg(theme.background)
+                .add_modifier(Modifier::BOLD),
+        )),
+        title,
+    );
+    if activity.top_tags.is_empty() {
+        frame.render_widget(
+            Paragraph::new(Span::styled(
+                "No tags on open tasks",
+                Style::default().fg(theme.dim).bg(theme.background),
+            )),
+            chart,
+        );
+        return;
+    }
+    let bars: Vec<Bar> = activity
+        .top_tags
+        .iter()
+        .map(|(tag, n)| {
+            Bar::default()
+                .value(*n as u64)
+                .label(Line::from(format!("#{tag}")))
+                .style(Style::default().fg(theme.tag_color(tag)))
+                .value_style(
+                    Style::default()
+                        .fg(theme.background)
+                        .bg(theme.tag_color(tag)),
+                )
+        })
+        .collect();
+    frame.render_widget(
+        BarChart::default()
+            .direction(Direction::Horizontal)
+            .bar_width(1)
+            .bar_gap(0)
+            .data(BarGroup::default().bars(&bars))
+            .style(Style::default().bg(theme.background)),
+        chart,
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tui::render::test_helpers::*;
+
+    const ACTIVE_MD: &str = "\
+# Test Track
+
+## Backlog
+
+- [ ] `T-1` Open #infra
+  - added: 2025-05-12
+- [>] `T-2` Active #infra #api
+  - added: 2025-05-13
+
+## Done
+
+- [x] `T-3` Finished
+  - added: 2025-05-05
+  - resolved: 2025-05-12
+- [x] `T-4` Finished too
+  - resolved: 2025-05-12
+- [x] `T-5` Earlier
+  - resolved: 2025-05-06
+";
+
+    #[test]
+    fn stats_show_the_heatmap_trend_progress_and_tags() {
+        let app = app_with_track(ACTIVE_MD);
+        let today = NaiveDate::from_ymd_opt(2025, 5, 14).unwrap();
+        let output = render_to_string(TERM_W, TERM_H, |frame, area| {
+            render_stats(frame, &app, &gather_activity(&app), area, today);
+        });
+
+        assert!(
+            output.contains("3 in 37 weeks \u{00B7} 2 this week"),
+            "{output}"
+        );
+        let monday = output.lines().find(|l| l.starts_with(" Mon ")).unwrap();
+        // The 12th was the busiest day, the 5th had nothing
+        assert!(
+            monday.trim_end().ends_with("\u{00B7} \u{2588}"),
+            "{monday:?}"
+        );
+        let tuesday = output.lines().nth(3).unwrap();
+        assert!(
+            tuesday.trim_end().ends_with("\u{2592} \u{00B7}"),
+            "{tuesday:?}"
+        );
+        assert!(output.contains("added      3"));
+        assert!(output.contains("resolved   3"));
+        assert!(output.contains("3/5"), "track progress: {output}");
+        assert!(output.contains("#infra"));
+    }
+}
@@ -1,3 +1,5 @@
+use std::borrow::Cow;
+
 use ratatui::Frame;
 use ratatui::layout::Rect;
 use ratatui::style::{Color, Modifier, Style};
@@ -5,6 +7,7 @@ use ratatui::text::{Line, Span};
 use ratatui::widgets::Paragraph;
 
 use crate::tui::app::{App, EditTarget, Mode, MoveState, TriageSource, View};
+use crate::tui::jumps::MarkPending;
 use crate::util::unicode;
 
 /// Render the status row (bottom of screen)
@@ -35,6 +38,32 @@ pub fn render_status_row(frame: &mut Frame, app: &App, area: Rect) {
             build_mode_hint(&mut spans, hint, width, bg, app.theme.text_bright);
             Line::from(spans)
         }
+        Mode::Navigate if app.mark_pending.is_some() => {
+            let (prefix, hint) = match app.mark_pending {
+                Some(MarkPending::Set) => (" B", "a\u{2013}z=mark this task".to_string()),
+                _ => {
+                    let mut hint: Vec<String> = app
+                        .marks
+                        .iter()
+                        .map(|(name, task_id)| format!("{name}={task_id}"))
+                        .collect();
+                    hint.push("'=back".to_string());
+                    (" '", hint.join(" "))
+                }
+            };
+            let mut spans = vec![
+                Span::styled(
+                    prefix,
+                    Style::default()
+                        .fg(app.theme.highlight)
+                        .bg(bg)
+                        .add_modifier(Modifier::BOLD),
+                ),
+                Span::styled("\u{258C}", Style::default().fg(app.theme.highlight).bg(bg)),
+            ];
+            build_mode_hint(&mut spans, hint, width, bg, app.theme.text_bright);
+            Line::from(spans)
+        }
         Mode::Navigate => {
             if let Some(pattern) = app.last_search.as_ref()
                 && !matches!(app.view, View::Search)
@@ -496,16 +525,17 @@ fn match_count_message(app: &App, bg: Color) -> Option<(String, Style)> {
 /// Append right-aligned hint text to a span list.
 fn build_mode_hint<'a>(
     spans: &mut Vec<Span<'a>>,
-    hint: &'a str,
+    hint: impl Into<Cow<'a, str>>,
     width: usize,
     bg: Color,
     text_bright: Color,
 ) {
+    let hint = hint.into();
     let content_width: usize = spans
         .iter()
         .map(|s| unicode::display_width(&s.content))
         .sum();
-    let hint_width = unicode::display_width(hint);
+    let hint_width = unicode::display_width(&hint);
     if content_width + hint_width < width {
         let padding = width - content_width - hint_width;
         spans.push(Span::styled(" ".repeat(padding), Style::default().bg(bg)));
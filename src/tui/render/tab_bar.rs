@@ -4,9 +4,11 @@ use ratatui::style::{Modifier, Style};
 use ratatui::text::{Line, Span};
 use ratatui::widgets::Paragraph;
 
-use crate::tui::app::{App, StateFilter, View};
+use crate::tui::app::{App, Hit, StateFilter, View};
 use crate::util::unicode;
 
+use super::helpers::spans_width;
+
 /// Result of tab layout computation: labels and layout mode
 #[derive(Debug, Clone, PartialEq)]
 pub(crate) struct TabLayout {
@@ -39,7 +41,8 @@ fn fixed_width(inbox_count: usize) -> usize {
         4
     };
     let recent_tab = 4; // " ✓ " + "|"
-    leading + tracks_tab + board_tab + inbox_tab + recent_tab
+    let stats_tab = 4; // " ░ " + "|"
+    leading + tracks_tab + board_tab + inbox_tab + recent_tab + stats_tab
 }
 
 fn digit_count(n: usize) -> usize {
@@ -320,6 +323,8 @@ fn render_tabs(frame: &mut Frame, app: &mut App, area: Rect) -> Vec<usize> {
 
     let mut spans: Vec<Span> = Vec::new();
     let mut sep_cols: Vec<usize> = Vec::new();
+    // Where each tab starts and the view it stands for; it ends at its separator
+    let mut tab_views: Vec<(usize, View)> = Vec::new();
     let sep = Span::styled(
         "\u{2502}",
         Style::default().fg(app.theme.dim).bg(app.theme.background),
@@ -438,6 +443,7 @@ fn render_tabs(frame: &mut Frame, app: &mut App, area: Rect) -> Vec<usize> {
         let full_start = if let Some(ref label) = first_partial {
             let show_cc = first_partial_show_cc;
             tabs_used += track_tab_width(label, show_cc);
+            tab_views.push((spans_width(&spans), View::Track(app.tab_scroll)));
             render_track_tab(
                 &mut spans,
                 app,
@@ -456,6 +462,7 @@ fn render_tabs(frame: &mut Frame, app: &mut App, area: Rect) -> Vec<usize> {
         for i in full_start..full_end {
             let label = &layout.labels[i];
             let is_cc = Some(i) == cc_focus_idx;
+            tab_views.push((spans_width(&spans), View::Track(i)));
             render_track_tab(&mut spans, app, i, label, is_cc, &sep, &mut sep_cols);
         }
 
@@ -483,6 +490,7 @@ fn render_tabs(frame: &mut Frame, app: &mut App, area: Rect) -> Vec<usize> {
                 let trunc_label = truncate_display(&layout.labels[full_end], max_chars);
                 let partial_w = track_tab_width(&trunc_label, show_cc);
                 tabs_used += partial_w;
+                tab_views.push((spans_width(&spans), View::Track(full_end)));
                 render_track_tab(
                     &mut spans,
                     app,
@@ -517,11 +525,13 @@ fn render_tabs(frame: &mut Frame, app: &mut App, area: Rect) -> Vec<usize> {
         app.tab_scroll = 0;
         for (i, label) in layout.labels.iter().enumerate() {
             let is_cc = Some(i) == cc_focus_idx;
+            tab_views.push((spans_width(&spans), View::Track(i)));
             render_track_tab(&mut spans, app, i, label, is_cc, &sep, &mut sep_cols);
         }
     }
 
     // Tracks view tab (▶)
+    tab_views.push((spans_width(&spans), View::Tracks));
     let is_tracks = app.view == View::Tracks;
     spans.push(Span::styled(" \u{25B6} ", tab_style(app, is_tracks)));
     sep_cols.push(
@@ -533,6 +543,7 @@ fn render_tabs(frame: &mut Frame, app: &mut App, area: Rect) -> Vec<usize> {
     spans.push(sep.clone());
 
     // Board view tab (≡)
+    tab_views.push((spans_width(&spans), View::Board));
     let is_board = app.view == View::Board;
     spans.push(Span::styled(" \u{2261} ", tab_style(app, is_board)));
     sep_cols.push(
@@ -544,6 +555,7 @@ fn render_tabs(frame: &mut Frame, app: &mut App, area: Rect) -> Vec<usize> {
     spans.push(sep.clone());
 
     // Inbox tab with count (*N)
+    tab_views.push((spans_width(&spans), View::Inbox));
     let is_inbox = app.view == View::Inbox;
     let tab_bg = if is_inbox {
         app.theme.selection_bg
@@ -570,6 +582,7 @@ fn render_tabs(frame: &mut Frame, app: &mut App, area: Rect) -> Vec<usize> {
     spans.push(sep.clone());
 
     // Recent tab (✓)
+    tab_views.push((spans_width(&spans), View::Recent));
     let is_recent = app.view == View::Recent;
     spans.push(Span::styled(" \u{2713} ", tab_style(app, is_recent)));
     sep_cols.push(
@@ -580,6 +593,18 @@ fn render_tabs(frame: &mut Frame, app: &mut App, area: Rect) -> Vec<usize> {
     );
     spans.push(sep.clone());
 
+    // Stats tab (░)
+    tab_views.push((spans_width(&spans), View::Stats));
+    let is_stats = app.view == View::Stats;
+    spans.push(Span::styled(" \u{2591} ", tab_style(app, is_stats)));
+    sep_cols.push(
+        spans
+            .iter()
+            .map(|s| unicode::display_width(&s.content))
+            .sum(),
+    );
+    spans.push(sep.clone());
+
     // Right-justify project name in remaining space (only in non-scroll mode)
     if layout.show_project_name {
         let tabs_width: usize = spans
@@ -598,6 +623,11 @@ fn render_tabs(frame: &mut Frame, app: &mut App, area: Rect) -> Vec<usize> {
         }
     }
 
+    for ((start, view), &end) in tab_views.into_iter().zip(&sep_cols) {
+        let tab = Rect::new(area.x + start as u16, area.y, (end - start) as u16, 1);
+        app.hits.push((tab.intersection(area), Hit::Tab(view)));
+    }
+
     let line = Line::from(spans);
     let tabs = Paragraph::new(line).style(Style::default().bg(app.theme.background));
     frame.render_widget(tabs, area);
@@ -940,9 +970,9 @@ mod tests {
         let names = vec!["Alpha".to_string(), "Beta".to_string()];
         let prefixes = vec![None, None];
         // "Alpha" tab = 5+2+1 = 8, "Beta" tab = 4+2+1 = 7
-        // fixed(0 inbox) = 3+4+4+4 = 15
+        // fixed(0 inbox) = 3+4+4+4+4+4 = 23
         // project name "My Project" = 10+2 = 12
-        // total = 8+7+15+12 = 42
+        // total = 8+7+23+12 = 50
         let layout = compute_tab_layout(&names, &prefixes, None, 50, 10, 0);
         assert!(layout.show_project_name);
         assert!(!layout.scroll_mode);
@@ -953,9 +983,9 @@ mod tests {
     fn test_project_name_removed() {
         let names = vec!["Alpha".to_string(), "Beta".to_string()];
         let prefixes = vec![None, None];
-        // tabs + fixed = 8+7+15 = 30, + project 12 = 42
-        // Width 35 < 42 but >= 30
-        let layout = compute_tab_layout(&names, &prefixes, None, 35, 10, 0);
+        // tabs + fixed = 8+7+23 = 38, + project 12 = 50
+        // Width 43 < 50 but >= 38
+        let layout = compute_tab_layout(&names, &prefixes, None, 43, 10, 0);
         assert!(!layout.show_project_name);
         assert!(!layout.scroll_mode);
         assert_eq!(layout.labels, vec!["Alpha", "Beta"]);
@@ -969,9 +999,9 @@ mod tests {
             "Frontend".to_string(),       // 8
         ];
         let prefixes = vec![None, None, None];
-        // Full: 17+10+11 = 38, + fixed 19 = 57
-        // Width 49: need to shrink 8 chars of tab width
-        let layout = compute_tab_layout(&names, &prefixes, None, 49, 0, 0);
+        // Full: 17+10+11 = 38, + fixed 23 = 61
+        // Width 53: need to shrink 8 chars of tab width
+        let layout = compute_tab_layout(&names, &prefixes, None, 53, 0, 0);
         assert!(!layout.scroll_mode);
         // Longest (Infrastructure) should absorb most shrinking; shorter labels preserved
         let lens: Vec<usize> = layout
@@ -996,8 +1026,8 @@ mod tests {
             .iter()
             .map(|l| unicode::display_width(l) + 2 + 1)
             .sum::<usize>()
-            + 19;
-        assert!(total <= 49);
+            + 23;
+        assert!(total <= 53);
     }
 
     #[test]
@@ -1013,16 +1043,16 @@ mod tests {
             Some("BE".into()),  // 2 chars
             Some("FE".into()),  // 2 chars
         ];
-        // Width 36: shrinks all to 3, then FE swaps at 2 → fits at 36.
+        // Width 40: shrinks all to 3, then FE swaps at 2 → fits at 40.
         // INF swaps at 3 (same-size swap), but Bac(3) stays since BE is 2 chars.
-        let layout = compute_tab_layout(&names, &prefixes, None, 36, 0, 0);
+        let layout = compute_tab_layout(&names, &prefixes, None, 40, 0, 0);
         assert!(!layout.scroll_mode);
         // INF swapped at 3, FE swapped at 2, Backend truncated to "Bac"
         assert_eq!(layout.labels[0], "INF");
         assert_eq!(layout.labels[2], "FE");
 
-        // At width 35: Backend also reaches prefix (INF+BE+FE = 6+5+5+19=35)
-        let layout2 = compute_tab_layout(&names, &prefixes, None, 35, 0, 0);
+        // At width 39: Backend also reaches prefix (INF+BE+FE = 6+5+5+23=39)
+        let layout2 = compute_tab_layout(&names, &prefixes, None, 39, 0, 0);
         assert!(!layout2.scroll_mode);
         assert_eq!(layout2.labels, vec!["INF", "BE", "FE"]);
     }
@@ -1032,10 +1062,10 @@ mod tests {
         // Prefix swap doesn't cause extra shrinking — rightmost shrinks first
         let names = vec!["Alpha".to_string(), "Bravo".to_string()];
         let prefixes = vec![Some("ALP".into()), Some("BRV".into())];
-        // Full: 8+8+19=35. Width 32: need 3 chars removed.
-        // Bravo(rightmost) 5→4, Alpha 5→4, Bravo 4→3 (swap BRV) → 7+6+19=32 fits.
+        // Full: 8+8+23=39. Width 36: need 3 chars removed.
+        // Bravo(rightmost) 5→4, Alpha 5→4, Bravo 4→3 (swap BRV) → 7+6+23=36 fits.
         // Alpha stays at "Alph"(4), not over-shrunk.
-        let layout = compute_tab_layout(&names, &prefixes, None, 32, 0, 0);
+        let layout = compute_tab_layout(&names, &prefixes, None, 36, 0, 0);
         assert!(!layout.scroll_mode);
         assert_eq!(layout.labels[0], "Alph"); // not shrunk past 4
         assert_eq!(layout.labels[1], "BRV"); // prefix at 3
@@ -1056,8 +1086,8 @@ mod tests {
             Some("CCCC".into()),
             Some("DDDD".into()),
         ];
-        // At 4 chars: 7*4=28, +19=47. Width 43: need all at 3 (6*4=24, +19=43).
-        let layout = compute_tab_layout(&names, &prefixes, None, 43, 0, 0);
+        // At 4 chars: 7*4=28, +23=51. Width 47: need all at 3 (6*4=24, +23=47).
+        let layout = compute_tab_layout(&names, &prefixes, None, 47, 0, 0);
         assert!(!layout.scroll_mode);
         for label in &layout.labels {
             assert_eq!(unicode::display_width(label), 3);
@@ -1086,18 +1116,18 @@ mod tests {
             "Further".to_string(),  // 7
         ];
         let prefixes: Vec<Option<String>> = vec![None; 8];
-        // Full tab widths: 10+6+10+11+9+9+7+10 = 72, + fixed 19 = 91
-        let layout = compute_tab_layout(&names, &prefixes, None, 79, 0, 0);
+        // Full tab widths: 10+6+10+11+9+9+7+10 = 72, + fixed 23 = 95
+        let layout = compute_tab_layout(&names, &prefixes, None, 83, 0, 0);
         assert!(!layout.scroll_mode);
         let total: usize = layout
             .labels
             .iter()
             .map(|l| unicode::display_width(l) + 2 + 1)
             .sum::<usize>()
-            + 19;
-        assert!(total <= 79, "total {} should be <= 79", total);
+            + 23;
+        assert!(total <= 83, "total {} should be <= 83", total);
         // Should be tight: at most 1 char of slack
-        assert!(total >= 78, "total {} shouldn't leave much slack", total);
+        assert!(total >= 82, "total {} shouldn't leave much slack", total);
         // Short labels like "CLI"(3) shouldn't be shrunk when longer ones can absorb
         assert_eq!(layout.labels[1], "CLI");
     }
@@ -1159,18 +1189,18 @@ mod tests {
     #[test]
     fn test_inbox_count_affects_fixed_width() {
         assert!(fixed_width(99) > fixed_width(0));
-        assert_eq!(fixed_width(0), 19); // 3+4+4+4+4
-        assert_eq!(fixed_width(99), 21); // 3+4+4+6+4
+        assert_eq!(fixed_width(0), 23); // 3+4+4+4+4+4
+        assert_eq!(fixed_width(99), 25); // 3+4+4+6+4+4
 
         let names = vec!["A".to_string()];
         let prefixes = vec![None];
         // Track "A" = 1+2+1 = 4
-        // fixed(0)=19 + 4 = 23 fits in 24
-        let layout_0 = compute_tab_layout(&names, &prefixes, None, 24, 0, 0);
+        // fixed(0)=23 + 4 = 27 fits in 28
+        let layout_0 = compute_tab_layout(&names, &prefixes, None, 28, 0, 0);
         assert!(!layout_0.scroll_mode);
-        // fixed(99)=21 + 4 = 25, doesn't fit in 24 without shrinking
+        // fixed(99)=25 + 4 = 29, doesn't fit in 28 without shrinking
         // But "A" is already 1 char, can't shrink further → scroll
-        let layout_99 = compute_tab_layout(&names, &prefixes, None, 25, 0, 99);
+        let layout_99 = compute_tab_layout(&names, &prefixes, None, 29, 0, 99);
         assert!(!layout_99.scroll_mode);
     }
 
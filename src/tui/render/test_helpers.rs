@@ -60,6 +60,12 @@ pub fn minimal_project() -> Project {
             ui: Default::default(),
             recovery: Default::default(),
             limits: Default::default(),
+            deps: Default::default(),
+            templates: Default::default(),
+            fields: Default::default(),
+            workflow: Default::default(),
+            board: Default::default(),
+            keys: Default::default(),
         },
         tracks: vec![],
         inbox: None,
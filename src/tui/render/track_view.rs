@@ -6,7 +6,7 @@ use ratatui::widgets::Paragraph;
 use regex::Regex;
 
 use crate::model::{Metadata, SectionKind, Task, TaskState};
-use crate::tui::app::{App, EditTarget, FlatItem, Mode, MoveState};
+use crate::tui::app::{App, EditTarget, FlatItem, Hit, Mode, MoveState};
 use crate::tui::wrap;
 use crate::util::unicode;
 
@@ -102,6 +102,8 @@ pub fn render_track_view(frame: &mut Frame, app: &mut App, area: Rect) {
     let mut cursor_display_line_end: Option<usize> = None;
     let mut edit_anchor_info: Option<(u16, usize)> = None; // (prefix_w, display_line_index)
     let mut bulk_editor_anchor: Option<(u16, usize)> = None;
+    // The row each display line belongs to, for mouse clicks
+    let mut line_rows: Vec<Option<usize>> = Vec::new();
 
     // Compute range preview bounds for V-select
     let range_preview: Option<(usize, usize)> = app.range_anchor.map(|anchor| {
@@ -186,12 +188,17 @@ pub fn render_track_view(frame: &mut Frame, app: &mut App, area: Rect) {
                     }
                 }
             }
-            FlatItem::ParkedSeparator => {
+            FlatItem::SectionSeparator(kind) => {
                 if is_cursor {
                     cursor_display_line = Some(display_lines.len());
                     cursor_display_line_end = Some(display_lines.len());
                 }
-                display_lines.push(render_parked_separator(app, area.width as usize, is_cursor));
+                display_lines.push(render_section_separator(
+                    app,
+                    *kind,
+                    area.width as usize,
+                    is_cursor,
+                ));
             }
             FlatItem::BulkMoveStandin { count } => {
                 display_lines.push(render_bulk_standin(app, *count, area.width as usize));
@@ -212,6 +219,12 @@ pub fn render_track_view(frame: &mut Frame, app: &mut App, area: Rect) {
                 ));
             }
         }
+        let clickable = match item {
+            FlatItem::Task { is_context, .. } => !is_context,
+            FlatItem::SectionSeparator(_) => true,
+            _ => false,
+        };
+        line_rows.resize(display_lines.len(), clickable.then_some(row));
     }
 
     // Adjust scroll in display-line space, keeping the cursor item's full extent
@@ -233,6 +246,8 @@ pub fn render_track_view(frame: &mut Frame, app: &mut App, area: Rect) {
         state.scroll_offset = scroll;
     }
 
+    super::record_rows(app, area, scroll, &line_rows, Hit::Row);
+
     // Slice visible display lines
     let lines: Vec<Line> = display_lines
         .into_iter()
@@ -356,6 +371,17 @@ fn render_task_line(
             "\u{258C}",
             Style::default().fg(app.theme.highlight).bg(row_bg),
         ));
+    } else if !is_context
+        && task
+            .id
+            .as_deref()
+            .is_some_and(|id| app.activity.is_highlighted(id))
+    {
+        // Changed on disk by someone else, a moment ago
+        spans.push(Span::styled(
+            "\u{258E}",
+            Style::default().fg(app.theme.cyan).bg(bg),
+        ));
     } else {
         spans.push(Span::styled(" ", Style::default().bg(bg)));
     }
@@ -848,7 +874,12 @@ fn render_bulk_editor_line(app: &App, label: &str, width: usize) -> (Line<'stati
 }
 
 /// Render the parked section separator
-fn render_parked_separator(app: &App, width: usize, is_cursor: bool) -> Line<'static> {
+fn render_section_separator(
+    app: &App,
+    kind: SectionKind,
+    width: usize,
+    is_cursor: bool,
+) -> Line<'static> {
     let bg = if is_cursor {
         app.theme.selection_bg
     } else {
@@ -870,9 +901,9 @@ fn render_parked_separator(app: &App, width: usize, is_cursor: bool) -> Line<'st
         spans.push(Span::styled(" ", Style::default().bg(app.theme.background)));
     }
 
-    let label = " Parked ";
+    let label = format!(" {kind} ");
     let dashes_before = 2;
-    let dashes_after = width.saturating_sub(label.len() + dashes_before + 2);
+    let dashes_after = width.saturating_sub(unicode::display_width(&label) + dashes_before + 2);
 
     let line_text = format!(
         "{}{}{}",
@@ -5,6 +5,7 @@ use ratatui::text::{Line, Span};
 use ratatui::widgets::Paragraph;
 
 use crate::io::actors;
+use crate::ops::relations::{self, Epic};
 use crate::ops::track_ops::task_counts;
 use crate::tui::app::{App, EditTarget, Mode};
 use crate::util::unicode;
@@ -238,6 +239,30 @@ pub fn render_tracks_view(frame: &mut Frame, app: &mut App, area: Rect) {
         lines.push(Line::from(""));
     }
 
+    // Epics: read-only rollups, not part of the cursor's range. Membership is
+    // read off the members' `epic:` lines, so a track's rows above and an
+    // epic's bar here can count the same task.
+    let epics = relations::epics(&app.project);
+    if !epics.is_empty() {
+        lines.push(render_epics_header(app));
+        // As wide as the name columns, or wider for a title that needs it: the
+        // bars then sit right of the stat columns, but still line up together.
+        let longest = epics
+            .iter()
+            .map(|e| {
+                unicode::display_width(e.task.id.as_deref().unwrap_or(""))
+                    + 1
+                    + unicode::display_width(&e.task.title)
+            })
+            .max()
+            .unwrap_or(0);
+        let title_w = (max_name_len + 2 + max_id_len).max(longest.min(EPIC_TITLE_MAX_W));
+        for epic in &epics {
+            lines.push(render_epic_row(app, epic, num_width, title_w));
+        }
+        lines.push(Line::from(""));
+    }
+
     // Archived section
     if !archived_tracks.is_empty() {
         lines.push(render_section_row(app, "Archived", name_col, true));
@@ -353,6 +378,75 @@ fn render_section_row<'a>(
     Line::from(spans)
 }
 
+/// How far an epic's title may push its bar right of the stat columns
+const EPIC_TITLE_MAX_W: usize = 32;
+
+/// Width of an epic's progress bar: the stat columns less the count after it
+const BAR_W: usize = HEADERS.len() * COL_W - 10;
+
+fn render_epics_header(app: &App) -> Line<'_> {
+    let bg = app.theme.background;
+    Line::from(Span::styled(
+        " Epics",
+        Style::default()
+            .fg(app.theme.text)
+            .bg(bg)
+            .add_modifier(Modifier::BOLD),
+    ))
+}
+
+/// An epic's id and title in the name columns, then a bar of its done members
+/// and `done/total` under the stat columns, and the blocked count past them.
+fn render_epic_row<'a>(app: &'a App, epic: &Epic, num_width: usize, title_w: usize) -> Line<'a> {
+    let bg = app.theme.background;
+    let progress = epic.progress();
+    let mut spans: Vec<Span> = Vec::new();
+
+    spans.push(Span::styled(
+        " ".repeat(1 + num_width + 2),
+        Style::default().bg(bg),
+    ));
+    let id = epic.task.id.as_deref().unwrap_or("");
+    let label = unicode::truncate_to_width(&format!("{} {}", id, epic.task.title), title_w);
+    let label_w = unicode::display_width(&label);
+    spans.push(Span::styled(
+        label,
+        Style::default().fg(app.theme.text_bright).bg(bg),
+    ));
+    spans.push(Span::styled(
+        " ".repeat(title_w.saturating_sub(label_w) + 2),
+        Style::default().bg(bg),
+    ));
+
+    let filled = (progress.done * BAR_W)
+        .checked_div(progress.total)
+        .unwrap_or(0);
+    spans.push(Span::styled(
+        "\u{2588}".repeat(filled),
+        Style::default().fg(app.theme.green).bg(bg),
+    ));
+    spans.push(Span::styled(
+        "\u{2591}".repeat(BAR_W - filled),
+        Style::default().fg(app.theme.dim).bg(bg),
+    ));
+    spans.push(Span::styled(
+        format!(
+            "{:>width$}",
+            format!("{}/{}", progress.done, progress.total),
+            width = HEADERS.len() * COL_W - 2 - BAR_W
+        ),
+        Style::default().fg(app.theme.text).bg(bg),
+    ));
+    if progress.blocked > 0 {
+        spans.push(Span::styled(
+            format!("  {} blk", progress.blocked),
+            Style::default().fg(app.theme.red).bg(bg),
+        ));
+    }
+
+    Line::from(spans)
+}
+
 /// Render a single track data row with stat counts in columns
 #[allow(clippy::too_many_arguments)]
 fn render_track_row<'a>(
@@ -819,6 +913,24 @@ mod tests {
         assert_snapshot!(output);
     }
 
+    #[test]
+    fn overview_epics_section() {
+        let project = project_with_track(
+            "alpha",
+            "Alpha",
+            "# Alpha\n\n## Backlog\n\n- [ ] `A-1` Ship the effects system\n\
+             - [-] `A-2` Pipeline\n  - epic: A-1\n- [ ] `A-3` Docs\n  - epic: A-1\n\n\
+             ## Done\n\n- [x] `A-4` Schema\n  - epic: A-1\n",
+        );
+        let mut app = App::new(project);
+        app.view = crate::tui::app::View::Tracks;
+        let output = render_to_string(TERM_W, TERM_H, |frame, area| {
+            render_tracks_view(frame, &mut app, area);
+        });
+        assert!(output.contains(" Epics"), "{output}");
+        assert_snapshot!(output);
+    }
+
     #[test]
     fn overview_single_track() {
         let mut app = app_with_track(SIMPLE_TRACK_MD);
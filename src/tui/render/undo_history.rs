@@ -0,0 +1,170 @@
+use chrono::{DateTime, Local, Utc};
+use ratatui::Frame;
+use ratatui::layout::Rect;
+use ratatui::style::{Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, Clear, Paragraph};
+
+use crate::tui::app::App;
+use crate::tui::undo::Operation;
+use crate::tui::undo_history::{HistoryRow, history_rows};
+use crate::util::unicode;
+
+/// Width of the time column, `Oct 17 14:02` and a gap.
+const TIME_W: usize = 14;
+
+/// Render the undo history overlay: undone steps, then done ones newest
+/// first, then the start, with the present marked.
+pub fn render_undo_history(frame: &mut Frame, app: &mut App, area: Rect) {
+    let theme = &app.theme;
+    let bg = theme.background;
+    let Some(history) = app.undo_history.as_ref() else {
+        return;
+    };
+
+    let margin_x = 4u16.min(area.width / 8);
+    let margin_y = 2u16.min(area.height / 8);
+    let popup_area = Rect::new(
+        area.x + margin_x,
+        area.y + margin_y,
+        area.width.saturating_sub(margin_x * 2),
+        area.height.saturating_sub(margin_y * 2),
+    );
+    frame.render_widget(Clear, popup_area);
+
+    let block = Block::default()
+        .title(Span::styled(
+            " Undo History ",
+            Style::default()
+                .fg(theme.text_bright)
+                .bg(bg)
+                .add_modifier(Modifier::BOLD),
+        ))
+        .title_bottom(Span::styled(
+            " Enter go to this point \u{00B7} Esc close ",
+            Style::default().fg(theme.dim).bg(bg),
+        ))
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(theme.dim).bg(bg))
+        .style(Style::default().bg(bg));
+    let inner = block.inner(popup_area);
+    frame.render_widget(block, popup_area);
+
+    let rows = history_rows(&app.undo_stack);
+    let present = app.undo_stack.redo_depth();
+    let height = inner.height as usize;
+    let width = inner.width as usize;
+    let mut scroll = history.scroll;
+    if history.cursor < scroll {
+        scroll = history.cursor;
+    } else if history.cursor >= scroll + height {
+        scroll = history.cursor + 1 - height;
+    }
+    let today = Local::now().date_naive();
+
+    let mut lines = Vec::new();
+    for (i, row) in rows.iter().enumerate().skip(scroll).take(height) {
+        let row_bg = if i == history.cursor {
+            theme.selection_bg
+        } else {
+            bg
+        };
+        let style = |fg| Style::default().fg(fg).bg(row_bg);
+        let marker = if i == present {
+            Span::styled(" \u{25CF} ", style(theme.highlight))
+        } else {
+            Span::styled("   ", style(theme.text))
+        };
+        let (time, text, text_style) = match row {
+            HistoryRow::Redo(entry) => (
+                when(entry.at, today),
+                format!("{} (undone)", entry.op.describe()),
+                style(theme.dim),
+            ),
+            HistoryRow::Undo(entry) if matches!(entry.op, Operation::SyncMarker) => (
+                when(entry.at, today),
+                format!("\u{2500}\u{2500} {} \u{2500}\u{2500}", entry.op.describe()),
+                style(theme.dim).add_modifier(Modifier::ITALIC),
+            ),
+            HistoryRow::Undo(entry) => (
+                when(entry.at, today),
+                entry.op.describe(),
+                style(if i == present {
+                    theme.text_bright
+                } else {
+                    theme.text
+                }),
+            ),
+            HistoryRow::Start => (
+                String::new(),
+                "start of history".to_string(),
+                style(theme.dim),
+            ),
+        };
+        let text_w = width.saturating_sub(3 + TIME_W);
+        let text = unicode::truncate_to_width(&text, text_w);
+        let pad = " ".repeat(text_w.saturating_sub(unicode::display_width(&text)));
+        lines.push(Line::from(vec![
+            marker,
+            Span::styled(format!("{time:<TIME_W$}"), style(theme.dim)),
+            Span::styled(text, text_style),
+            Span::styled(pad, style(theme.text)),
+        ]));
+    }
+    frame.render_widget(Paragraph::new(lines).style(Style::default().bg(bg)), inner);
+    if let Some(history) = app.undo_history.as_mut() {
+        history.scroll = scroll;
+    }
+}
+
+/// `14:02` today, `Oct 17 14:02` before.
+fn when(at: DateTime<Utc>, today: chrono::NaiveDate) -> String {
+    let local = at.with_timezone(&Local);
+    if local.date_naive() == today {
+        local.format("%H:%M").to_string()
+    } else {
+        local.format("%b %d %H:%M").to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tui::render::test_helpers::*;
+    use crate::tui::undo_history::UndoHistoryState;
+
+    #[test]
+    fn the_history_marks_the_present_and_undone_steps() {
+        let mut app = app_with_track("# Test Track\n\n## Backlog\n\n- [ ] `T-1` One\n\n## Done\n");
+        app.undo_stack.push(Operation::InboxAdd {
+            index: 0,
+            title: "First idea".into(),
+        });
+        app.undo_stack.push(Operation::TitleEdit {
+            track_id: "test".into(),
+            task_id: "T-1".into(),
+            old_title: "One".into(),
+            new_title: "Uno".into(),
+        });
+        app.undo_stack.undo(&mut app.project.tracks, None);
+        app.undo_history = Some(UndoHistoryState {
+            cursor: 1,
+            scroll: 0,
+        });
+
+        let output = render_to_string(TERM_W, TERM_H, |frame, area| {
+            render_undo_history(frame, &mut app, area);
+        });
+
+        let undone = output.find("T-1 title edited (undone)").expect("redo row");
+        let present = output.find("\u{25CF}").expect("present marker");
+        let added = output
+            .find("inbox item added: First idea")
+            .expect("undo row");
+        let start = output.find("start of history").expect("start row");
+        assert!(
+            undone < present && present < added && added < start,
+            "{output}"
+        );
+    }
+}
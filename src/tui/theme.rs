@@ -1,8 +1,8 @@
 use std::collections::HashMap;
 
-use ratatui::style::Color;
+use ratatui::style::{Color, Modifier};
 
-use crate::model::UiConfig;
+use crate::model::{ThemeName, UiConfig};
 
 /// Parsed color theme for the TUI
 #[derive(Debug, Clone)]
@@ -28,39 +28,223 @@ pub struct Theme {
     pub bulk_selection_bg: Color,
     /// Per-tag colors
     pub tag_colors: HashMap<String, Color>,
+    /// Colour is off (`NO_COLOR`, or `ui.theme = "mono"`): see [`Theme::mono`].
+    pub mono: bool,
 }
 
 impl Default for Theme {
     fn default() -> Self {
-        let mut tag_colors = HashMap::new();
-        tag_colors.insert("research".into(), Color::Rgb(0x44, 0x88, 0xFF));
-        tag_colors.insert("design".into(), Color::Rgb(0x44, 0xDD, 0xFF));
-        tag_colors.insert("bug".into(), Color::Rgb(0xFF, 0x44, 0x44));
-        tag_colors.insert("cc".into(), Color::Rgb(0x44, 0xFF, 0x88));
-        tag_colors.insert("cc-added".into(), Color::Rgb(0xCC, 0x66, 0xFF));
-        tag_colors.insert("needs-input".into(), Color::Rgb(0xFF, 0xD7, 0x00));
+        Theme::dark()
+    }
+}
+
+/// What the terminal says about itself, read from the environment.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct TerminalInfo {
+    /// `NO_COLOR` is set: <https://no-color.org>.
+    pub no_color: bool,
+    /// Whether the background is light, where the terminal says.
+    pub light_background: Option<bool>,
+}
+
+impl TerminalInfo {
+    pub fn detect() -> Self {
+        TerminalInfo {
+            no_color: std::env::var_os("NO_COLOR").is_some_and(|v| !v.is_empty()),
+            light_background: std::env::var("COLORFGBG")
+                .ok()
+                .and_then(|v| light_from_colorfgbg(&v)),
+        }
+    }
+}
+
+/// `COLORFGBG` is `fg;bg` (rxvt, Konsole and iTerm2 set it), sometimes with a
+/// middle field; the background is the last. ANSI 7 and 9–15 are the light
+/// ones.
+fn light_from_colorfgbg(value: &str) -> Option<bool> {
+    let bg: u8 = value.rsplit(';').next()?.trim().parse().ok()?;
+    Some(bg == 7 || (9..=15).contains(&bg))
+}
+
+fn rgb(hex: u32) -> Color {
+    Color::Rgb((hex >> 16) as u8, (hex >> 8) as u8, hex as u8)
+}
+
+impl Theme {
+    /// The built-in tags, coloured from the palette so that each preset's
+    /// tags read on its own background.
+    fn with_default_tags(mut self) -> Self {
+        for (tag, color) in [
+            ("research", self.blue),
+            ("design", self.cyan),
+            ("bug", self.red),
+            ("cc", self.green),
+            ("cc-added", self.purple),
+            ("needs-input", self.yellow),
+        ] {
+            self.tag_colors.insert(tag.into(), color);
+        }
+        self
+    }
+
+    fn dark() -> Self {
+        Theme {
+            background: rgb(0x0C001B),
+            text: rgb(0xB0AAFF),
+            text_bright: rgb(0xFFFFFF),
+            highlight: rgb(0xFB4196),
+            dim: rgb(0x7D78BF),
+            red: rgb(0xFF4444),
+            yellow: rgb(0xFFD700),
+            green: rgb(0x44FF88),
+            cyan: rgb(0x44DDFF),
+            purple: rgb(0xCC66FF),
+            blue: rgb(0x4488FF),
+            selection_bg: rgb(0x43163E),
+            selection_border: rgb(0xFB4196),
+            selection_id: rgb(0xDAB8F0),
+            search_match_bg: rgb(0x40E0D0),
+            search_match_fg: rgb(0x0C001B),
+            flash_bg: rgb(0x3D2E10),
+            bulk_selection_bg: rgb(0x13204A),
+            tag_colors: HashMap::new(),
+            mono: false,
+        }
+        .with_default_tags()
+    }
+
+    /// For light terminals: every colour dark enough to read on near-white,
+    /// and selections tinted rather than dark.
+    fn light() -> Self {
+        Theme {
+            background: rgb(0xFAFAFA),
+            text: rgb(0x3A3654),
+            text_bright: rgb(0x000000),
+            highlight: rgb(0xC8266E),
+            dim: rgb(0x85819E),
+            red: rgb(0xC62828),
+            yellow: rgb(0x9A6700),
+            green: rgb(0x2E7D32),
+            cyan: rgb(0x00838F),
+            purple: rgb(0x8E44AD),
+            blue: rgb(0x1565C0),
+            selection_bg: rgb(0xF6D5E5),
+            selection_border: rgb(0xC8266E),
+            selection_id: rgb(0x6A1B9A),
+            search_match_bg: rgb(0x00A3A3),
+            search_match_fg: rgb(0xFFFFFF),
+            flash_bg: rgb(0xFFF0C2),
+            bulk_selection_bg: rgb(0xD8E4FF),
+            tag_colors: HashMap::new(),
+            mono: false,
+        }
+        .with_default_tags()
+    }
+
+    /// Solarized dark.
+    fn solarized() -> Self {
+        Theme {
+            background: rgb(0x002B36),
+            text: rgb(0x93A1A1),
+            text_bright: rgb(0xEEE8D5),
+            highlight: rgb(0xD33682),
+            dim: rgb(0x657B83),
+            red: rgb(0xDC322F),
+            yellow: rgb(0xB58900),
+            green: rgb(0x859900),
+            cyan: rgb(0x2AA198),
+            purple: rgb(0x6C71C4),
+            blue: rgb(0x268BD2),
+            selection_bg: rgb(0x073642),
+            selection_border: rgb(0xD33682),
+            selection_id: rgb(0xEEE8D5),
+            search_match_bg: rgb(0x2AA198),
+            search_match_fg: rgb(0x002B36),
+            flash_bg: rgb(0x3A3300),
+            bulk_selection_bg: rgb(0x0A3A5A),
+            tag_colors: HashMap::new(),
+            mono: false,
+        }
+        .with_default_tags()
+    }
 
+    /// Pure black and bright, saturated colours, for low vision and glare.
+    fn high_contrast() -> Self {
         Theme {
-            background: Color::Rgb(0x0C, 0x00, 0x1B),
-            text: Color::Rgb(0xB0, 0xAA, 0xFF),
-            text_bright: Color::Rgb(0xFF, 0xFF, 0xFF),
-            highlight: Color::Rgb(0xFB, 0x41, 0x96),
-            dim: Color::Rgb(0x7D, 0x78, 0xBF),
-            red: Color::Rgb(0xFF, 0x44, 0x44),
-            yellow: Color::Rgb(0xFF, 0xD7, 0x00),
-            green: Color::Rgb(0x44, 0xFF, 0x88),
-            cyan: Color::Rgb(0x44, 0xDD, 0xFF),
-            purple: Color::Rgb(0xCC, 0x66, 0xFF),
-            blue: Color::Rgb(0x44, 0x88, 0xFF),
-            selection_bg: Color::Rgb(0x43, 0x16, 0x3E),
-            selection_border: Color::Rgb(0xFB, 0x41, 0x96),
-            selection_id: Color::Rgb(0xDA, 0xB8, 0xF0),
-            search_match_bg: Color::Rgb(0x40, 0xE0, 0xD0),
-            search_match_fg: Color::Rgb(0x0C, 0x00, 0x1B),
-            flash_bg: Color::Rgb(0x3D, 0x2E, 0x10),
-            bulk_selection_bg: Color::Rgb(0x13, 0x20, 0x4A),
-            tag_colors,
+            background: rgb(0x000000),
+            text: rgb(0xFFFFFF),
+            text_bright: rgb(0xFFFFFF),
+            highlight: rgb(0xFF5FFF),
+            dim: rgb(0xC0C0C0),
+            red: rgb(0xFF5555),
+            yellow: rgb(0xFFFF55),
+            green: rgb(0x55FF55),
+            cyan: rgb(0x55FFFF),
+            purple: rgb(0xD7AFFF),
+            blue: rgb(0x7FBFFF),
+            selection_bg: rgb(0x444444),
+            selection_border: rgb(0xFFFF55),
+            selection_id: rgb(0xFFFFFF),
+            search_match_bg: rgb(0xFFFF55),
+            search_match_fg: rgb(0x000000),
+            flash_bg: rgb(0x5F5F00),
+            bulk_selection_bg: rgb(0x00005F),
+            tag_colors: HashMap::new(),
+            mono: false,
+        }
+        .with_default_tags()
+    }
+
+    /// Monochrome: each colour is a stand-in, distinct only so that
+    /// [`Theme::mono_modifier`] can tell what it meant. `render` replaces every
+    /// colour on screen with the terminal's own before anything is drawn.
+    fn mono() -> Self {
+        let slot = Color::Indexed;
+        Theme {
+            background: slot(232),
+            text: slot(233),
+            text_bright: slot(234),
+            highlight: slot(235),
+            dim: slot(236),
+            red: slot(237),
+            yellow: slot(238),
+            green: slot(239),
+            cyan: slot(240),
+            purple: slot(241),
+            blue: slot(242),
+            selection_bg: slot(243),
+            selection_border: slot(244),
+            selection_id: slot(245),
+            search_match_bg: slot(246),
+            search_match_fg: slot(247),
+            flash_bg: slot(248),
+            bulk_selection_bg: slot(249),
+            tag_colors: HashMap::new(),
+            mono: true,
+        }
+    }
+
+    /// The attributes a cell drawn in `fg` on `bg` carries in monochrome:
+    /// what the colours said, said with weight and lines instead.
+    pub fn mono_modifier(&self, fg: Color, bg: Color) -> Modifier {
+        let mut modifier = Modifier::empty();
+        if fg == self.highlight || fg == self.text_bright || fg == self.selection_border {
+            modifier |= Modifier::BOLD;
+        } else if fg == self.red {
+            modifier |= Modifier::BOLD | Modifier::UNDERLINED;
+        } else if fg == self.yellow {
+            modifier |= Modifier::ITALIC;
+        } else if fg == self.dim {
+            modifier |= Modifier::DIM;
+        }
+        if bg == self.selection_bg || bg == self.flash_bg {
+            modifier |= Modifier::REVERSED;
+        } else if bg == self.search_match_bg {
+            modifier |= Modifier::REVERSED | Modifier::UNDERLINED;
+        } else if bg == self.bulk_selection_bg {
+            modifier |= Modifier::UNDERLINED;
         }
+        modifier
     }
 }
 
@@ -82,9 +266,30 @@ fn parse_hex_color(hex: &str) -> Option<Color> {
 }
 
 impl Theme {
-    /// Create a theme from project UI config, falling back to defaults
+    /// Create a theme from project UI config for the terminal frame runs in
     pub fn from_config(ui: &UiConfig) -> Self {
-        let mut theme = Theme::default();
+        Theme::resolve(ui, TerminalInfo::detect())
+    }
+
+    /// The theme `ui` asks for, on a terminal that reports `terminal`.
+    ///
+    /// `NO_COLOR` wins over everything, `ui.theme` included: it is the
+    /// person's standing answer to "colour?", and a project file does not get
+    /// to overrule it. Monochrome takes no `[ui.colors]` for the same reason.
+    pub fn resolve(ui: &UiConfig, terminal: TerminalInfo) -> Self {
+        let name = if terminal.no_color {
+            ThemeName::Mono
+        } else {
+            ui.theme
+        };
+        let mut theme = match name {
+            ThemeName::Auto if terminal.light_background == Some(true) => Theme::light(),
+            ThemeName::Auto | ThemeName::Dark => Theme::dark(),
+            ThemeName::Light => Theme::light(),
+            ThemeName::Solarized => Theme::solarized(),
+            ThemeName::HighContrast => Theme::high_contrast(),
+            ThemeName::Mono => return Theme::mono(),
+        };
 
         // Apply color overrides from [ui.colors]
         for (key, value) in &ui.colors {
@@ -136,6 +341,7 @@ impl Theme {
             crate::model::TaskState::Blocked => self.red,
             crate::model::TaskState::Done => self.text,
             crate::model::TaskState::Parked => self.yellow,
+            crate::model::TaskState::Custom(_) => self.cyan,
         }
     }
 }
@@ -182,7 +388,7 @@ mod tests {
         ui.colors.insert("background".into(), "#000000".into());
         ui.tag_colors.insert("custom".into(), "#112233".into());
 
-        let theme = Theme::from_config(&ui);
+        let theme = Theme::resolve(&ui, TerminalInfo::default());
         assert_eq!(theme.background, Color::Rgb(0, 0, 0));
         assert_eq!(
             theme.tag_colors.get("custom"),
@@ -192,6 +398,54 @@ mod tests {
         assert_eq!(theme.text, Color::Rgb(0xB0, 0xAA, 0xFF));
     }
 
+    #[test]
+    fn presets_follow_the_terminal_and_no_color_wins() {
+        let mut ui = UiConfig::default();
+        let light_terminal = TerminalInfo {
+            light_background: Some(true),
+            ..Default::default()
+        };
+        assert_eq!(
+            Theme::resolve(&ui, light_terminal).background,
+            Theme::light().background
+        );
+        assert_eq!(
+            Theme::resolve(&ui, TerminalInfo::default()).background,
+            Theme::dark().background
+        );
+        // A named preset is not second-guessed
+        ui.theme = ThemeName::Solarized;
+        ui.colors.insert("highlight".into(), "#112233".into());
+        let theme = Theme::resolve(&ui, light_terminal);
+        assert_eq!(theme.background, Theme::solarized().background);
+        assert_eq!(theme.highlight, Color::Rgb(0x11, 0x22, 0x33));
+        assert_eq!(theme.tag_color("bug"), theme.red);
+
+        let no_color = TerminalInfo {
+            no_color: true,
+            ..Default::default()
+        };
+        let theme = Theme::resolve(&ui, no_color);
+        assert!(theme.mono);
+        assert_ne!(theme.highlight, Color::Rgb(0x11, 0x22, 0x33));
+        assert_eq!(
+            theme.mono_modifier(theme.red, theme.selection_bg),
+            Modifier::BOLD | Modifier::UNDERLINED | Modifier::REVERSED
+        );
+        assert_eq!(
+            theme.mono_modifier(theme.text, theme.background),
+            Modifier::empty()
+        );
+    }
+
+    #[test]
+    fn colorfgbg_names_the_background_last() {
+        assert_eq!(light_from_colorfgbg("15;0"), Some(false));
+        assert_eq!(light_from_colorfgbg("0;15"), Some(true));
+        assert_eq!(light_from_colorfgbg("0;default;7"), Some(true));
+        assert_eq!(light_from_colorfgbg("default"), None);
+    }
+
     #[test]
     fn test_tag_color_fallback() {
         let theme = Theme::default();
@@ -1,3 +1,6 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
 use crate::model::inbox::InboxItem;
 use crate::model::task::{Task, TaskState};
 use crate::model::track::{SectionKind, Track};
@@ -403,7 +406,7 @@ pub fn nav_target_for_op(op: &Operation, is_undo: bool) -> Option<UndoNavTarget>
 }
 
 /// A single undoable operation
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum Operation {
     /// State change on a task
     StateChange {
@@ -646,10 +649,155 @@ pub enum Operation {
     SyncMarker,
 }
 
+impl Operation {
+    /// One line for the undo history: what the step did, to what.
+    pub fn describe(&self) -> String {
+        fn state(s: &TaskState) -> String {
+            s.builtin_name()
+                .map(str::to_string)
+                .unwrap_or_else(|| format!("[{}]", s.checkbox_char()))
+        }
+        match self {
+            Operation::StateChange {
+                task_id,
+                old_state,
+                new_state,
+                ..
+            } => format!(
+                "{task_id} {} \u{2192} {}",
+                state(old_state),
+                state(new_state)
+            ),
+            Operation::TitleEdit { task_id, .. } => format!("{task_id} title edited"),
+            Operation::TaskAdd { task_id, title, .. }
+            | Operation::SubtaskAdd { task_id, title, .. } => {
+                format!("{task_id} added: {title}")
+            }
+            Operation::TaskMove { task_id, .. } => format!("{task_id} moved"),
+            Operation::FieldEdit { task_id, field, .. } => format!("{task_id} {field} edited"),
+            Operation::TrackMove { track_id, .. } => format!("track {track_id} moved"),
+            Operation::InboxAdd { title, .. } => format!("inbox item added: {title}"),
+            Operation::InboxDelete { item, .. } => format!("inbox item deleted: {}", item.title),
+            Operation::InboxTitleEdit { new_title, .. } => {
+                format!("inbox item edited: {new_title}")
+            }
+            Operation::InboxTagsEdit { .. } => "inbox item tags edited".to_string(),
+            Operation::InboxMove { .. } => "inbox item moved".to_string(),
+            Operation::InboxNoteEdit { .. } => "inbox item note edited".to_string(),
+            Operation::InboxTriage { task_id, .. } => format!("inbox item triaged to {task_id}"),
+            Operation::SectionMove {
+                task_id,
+                to_section,
+                ..
+            } => format!(
+                "{task_id} \u{2192} {}",
+                to_section.to_string().to_lowercase()
+            ),
+            Operation::Reopen { task_id, .. } => format!("{task_id} reopened"),
+            Operation::TrackAdd { track_name, .. } => format!("track {track_name} added"),
+            Operation::TrackNameEdit { new_name, .. } => format!("track renamed to {new_name}"),
+            Operation::TrackShelve {
+                track_id,
+                was_active,
+            } => format!(
+                "track {track_id} {}",
+                if *was_active { "shelved" } else { "activated" }
+            ),
+            Operation::TrackArchive { track_id, .. } => format!("track {track_id} archived"),
+            Operation::TrackDelete { track_name, .. } => format!("track {track_name} deleted"),
+            Operation::TrackCcFocus { new_focus, .. } => match new_focus {
+                Some(id) => format!("cc-focus set to {id}"),
+                None => "cc-focus cleared".to_string(),
+            },
+            Operation::CrossTrackMove {
+                task_id_old,
+                task_id_new,
+                ..
+            } => format!("{task_id_old} moved to {task_id_new}"),
+            Operation::Reparent { new_task_id, .. } => format!("{new_task_id} reparented"),
+            Operation::TaskDelete { task, .. } => match &task.id {
+                Some(id) => format!("{id} deleted"),
+                None => format!("deleted: {}", task.title),
+            },
+            Operation::BulkTaskDelete { deletions } => format!("{} tasks deleted", deletions.len()),
+            Operation::TrackUnarchive { track_id } => format!("track {track_id} unarchived"),
+            Operation::Import {
+                track_id, count, ..
+            } => format!("{count} tasks imported into {track_id}"),
+            Operation::Bulk(ops) => match ops.as_slice() {
+                [one] => one.describe(),
+                _ => format!("{} changes at once", ops.len()),
+            },
+            Operation::SyncMarker => "files changed on disk".to_string(),
+        }
+    }
+
+    /// The files undoing or redoing this step writes — what a persisted
+    /// history checks has not changed since, before offering the step again.
+    pub fn files(&self) -> Vec<UndoFile> {
+        let track = |id: &String| UndoFile::Track(id.clone());
+        match self {
+            Operation::StateChange { track_id, .. }
+            | Operation::TitleEdit { track_id, .. }
+            | Operation::TaskAdd { track_id, .. }
+            | Operation::SubtaskAdd { track_id, .. }
+            | Operation::TaskMove { track_id, .. }
+            | Operation::FieldEdit { track_id, .. }
+            | Operation::SectionMove { track_id, .. }
+            | Operation::Reopen { track_id, .. }
+            | Operation::Reparent { track_id, .. }
+            | Operation::TaskDelete { track_id, .. }
+            | Operation::Import { track_id, .. } => vec![track(track_id)],
+            Operation::InboxAdd { .. }
+            | Operation::InboxDelete { .. }
+            | Operation::InboxTitleEdit { .. }
+            | Operation::InboxTagsEdit { .. }
+            | Operation::InboxMove { .. }
+            | Operation::InboxNoteEdit { .. } => vec![UndoFile::Inbox],
+            Operation::InboxTriage { track_id, .. } => vec![UndoFile::Inbox, track(track_id)],
+            Operation::TrackMove { .. }
+            | Operation::TrackNameEdit { .. }
+            | Operation::TrackShelve { .. }
+            | Operation::TrackCcFocus { .. } => vec![UndoFile::Config],
+            Operation::TrackAdd { track_id, .. }
+            | Operation::TrackArchive { track_id, .. }
+            | Operation::TrackDelete { track_id, .. }
+            | Operation::TrackUnarchive { track_id } => vec![UndoFile::Config, track(track_id)],
+            Operation::CrossTrackMove {
+                source_track_id,
+                target_track_id,
+                ..
+            } => vec![track(source_track_id), track(target_track_id)],
+            Operation::BulkTaskDelete { deletions } => {
+                deletions.iter().map(|(id, ..)| track(id)).collect()
+            }
+            Operation::Bulk(ops) => ops.iter().flat_map(Operation::files).collect(),
+            Operation::SyncMarker => Vec::new(),
+        }
+    }
+}
+
+/// A file an [`Operation`] writes.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum UndoFile {
+    Track(String),
+    Inbox,
+    Config,
+}
+
+/// An operation on the stack, and when it was done.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UndoEntry {
+    pub op: Operation,
+    pub at: DateTime<Utc>,
+}
+
 /// The undo/redo stack
 pub struct UndoStack {
-    undo: Vec<Operation>,
-    redo: Vec<Operation>,
+    undo: Vec<UndoEntry>,
+    redo: Vec<UndoEntry>,
+    /// Entries pushed so far, evicted ones included (see [`Self::generation`])
+    pushes: u64,
 }
 
 impl Default for UndoStack {
@@ -663,23 +811,80 @@ impl UndoStack {
         UndoStack {
             undo: Vec::new(),
             redo: Vec::new(),
+            pushes: 0,
         }
     }
 
-    /// Push a new operation. Clears the redo stack.
-    pub fn push(&mut self, op: Operation) {
-        self.undo.push(op);
+    /// A stack holding `undo` and `redo`, each oldest first — a history read
+    /// back from disk.
+    pub fn from_entries(undo: Vec<UndoEntry>, redo: Vec<UndoEntry>) -> Self {
+        UndoStack {
+            undo,
+            redo,
+            pushes: 0,
+        }
+    }
+
+    /// The undo and redo entries, each oldest first.
+    pub fn entries(&self) -> (&[UndoEntry], &[UndoEntry]) {
+        (&self.undo, &self.redo)
+    }
+
+    fn push_entry(&mut self, op: Operation) {
+        self.undo.push(UndoEntry { op, at: Utc::now() });
+        self.pushes += 1;
         if self.undo.len() > UNDO_STACK_LIMIT {
             self.undo.drain(..self.undo.len() - UNDO_STACK_LIMIT);
         }
         self.redo.clear();
     }
 
+    /// Push a new operation. Clears the redo stack.
+    pub fn push(&mut self, op: Operation) {
+        self.push_entry(op);
+    }
+
     /// Push a sync marker. Clears the redo stack.
     pub fn push_sync_marker(&mut self) {
-        self.undo.push(Operation::SyncMarker);
-        if self.undo.len() > UNDO_STACK_LIMIT {
-            self.undo.drain(..self.undo.len() - UNDO_STACK_LIMIT);
+        self.push_entry(Operation::SyncMarker);
+    }
+
+    /// Fold `op` into the entry pushed since the stack was at generation
+    /// `since` (see [`Self::generation`]), so that one undo takes both back — for a step
+    /// built from actions that record themselves, such as a board card moved
+    /// onto a column that sets a state and a tag. If the action pushed nothing,
+    /// `op` is pushed on its own rather than folded into whatever came before.
+    /// Clears the redo stack.
+    pub fn amend(&mut self, since: u64, op: Operation) {
+        if self.pushes == since {
+            self.push(op);
+            return;
+        }
+        match self.undo.pop() {
+            Some(UndoEntry {
+                op: Operation::Bulk(mut ops),
+                at,
+            }) => {
+                ops.push(op);
+                self.undo.push(UndoEntry {
+                    op: Operation::Bulk(ops),
+                    at,
+                });
+            }
+            Some(
+                marker @ UndoEntry {
+                    op: Operation::SyncMarker,
+                    ..
+                },
+            ) => {
+                self.undo.push(marker);
+                self.push(op);
+            }
+            None => self.push(op),
+            Some(UndoEntry { op: last, at }) => self.undo.push(UndoEntry {
+                op: Operation::Bulk(vec![last, op]),
+                at,
+            }),
         }
         self.redo.clear();
     }
@@ -691,19 +896,19 @@ impl UndoStack {
         tracks: &mut [(String, Track)],
         inbox: Option<&mut crate::model::inbox::Inbox>,
     ) -> Option<UndoNavTarget> {
-        let op = self.undo.pop()?;
+        let entry = self.undo.pop()?;
 
         // Can't undo past a sync marker
-        if matches!(op, Operation::SyncMarker) {
+        if matches!(entry.op, Operation::SyncMarker) {
             // Put it back — we stop here
-            self.undo.push(op);
+            self.undo.push(entry);
             return None;
         }
 
-        let nav = nav_target_for_op(&op, true);
-        apply_inverse(&op, tracks, inbox);
+        let nav = nav_target_for_op(&entry.op, true);
+        apply_inverse(&entry.op, tracks, inbox);
         // Push the forward operation onto redo
-        self.redo.push(op);
+        self.redo.push(entry);
         nav
     }
 
@@ -713,16 +918,16 @@ impl UndoStack {
         tracks: &mut [(String, Track)],
         inbox: Option<&mut crate::model::inbox::Inbox>,
     ) -> Option<UndoNavTarget> {
-        let op = self.redo.pop()?;
+        let entry = self.redo.pop()?;
 
-        if matches!(op, Operation::SyncMarker) {
-            self.redo.push(op);
+        if matches!(entry.op, Operation::SyncMarker) {
+            self.redo.push(entry);
             return None;
         }
 
-        let nav = nav_target_for_op(&op, false);
-        apply_forward(&op, tracks, inbox);
-        self.undo.push(op);
+        let nav = nav_target_for_op(&entry.op, false);
+        apply_forward(&entry.op, tracks, inbox);
+        self.undo.push(entry);
         nav
     }
 
@@ -739,6 +944,13 @@ impl UndoStack {
         self.undo.len()
     }
 
+    /// How many entries have been pushed since the stack was created. Unlike
+    /// [`Self::undo_depth`] it moves on a push even when the stack is full and
+    /// the oldest entry is dropped to make room.
+    pub fn generation(&self) -> u64 {
+        self.pushes
+    }
+
     /// How many operations are waiting to be redone.
     pub fn redo_depth(&self) -> usize {
         self.redo.len()
@@ -746,12 +958,12 @@ impl UndoStack {
 
     /// Peek at the last operation on the redo stack (just pushed during undo)
     pub fn peek_last_redo(&self) -> Option<&Operation> {
-        self.redo.last()
+        self.redo.last().map(|e| &e.op)
     }
 
     /// Peek at the last operation on the undo stack (just pushed during redo)
     pub fn peek_last_undo(&self) -> Option<&Operation> {
-        self.undo.last()
+        self.undo.last().map(|e| &e.op)
     }
 }
 
@@ -1795,6 +2007,54 @@ mod tests {
         assert_eq!(stack.undo.len(), UNDO_STACK_LIMIT);
     }
 
+    #[test]
+    fn amend_folds_only_into_an_entry_pushed_since() {
+        let title = |task_id: &str| Operation::TitleEdit {
+            track_id: "t".into(),
+            task_id: task_id.into(),
+            old_title: "old".into(),
+            new_title: "new".into(),
+        };
+        let mut stack = UndoStack::new();
+        stack.push(title("T-001"));
+
+        let since = stack.generation();
+        stack.push(title("T-002"));
+        stack.amend(since, title("T-003"));
+        assert_eq!(stack.undo_depth(), 2);
+        assert!(matches!(stack.peek_last_undo(), Some(Operation::Bulk(ops)) if ops.len() == 2));
+
+        // Nothing pushed since: the earlier entry is left alone
+        let since = stack.generation();
+        stack.amend(since, title("T-004"));
+        assert_eq!(stack.undo_depth(), 3);
+        assert!(matches!(
+            stack.peek_last_undo(),
+            Some(Operation::TitleEdit { .. })
+        ));
+    }
+
+    #[test]
+    fn amend_folds_into_a_push_that_evicted_the_oldest_entry() {
+        let title = |task_id: &str| Operation::TitleEdit {
+            track_id: "t".into(),
+            task_id: task_id.into(),
+            old_title: "old".into(),
+            new_title: "new".into(),
+        };
+        let mut stack = UndoStack::new();
+        for _ in 0..UNDO_STACK_LIMIT {
+            stack.push(title("T-001"));
+        }
+
+        let since = stack.generation();
+        stack.push(title("T-002"));
+        assert_eq!(stack.undo_depth(), UNDO_STACK_LIMIT);
+        stack.amend(since, title("T-003"));
+        assert_eq!(stack.undo_depth(), UNDO_STACK_LIMIT);
+        assert!(matches!(stack.peek_last_undo(), Some(Operation::Bulk(ops)) if ops.len() == 2));
+    }
+
     #[test]
     fn peek_last_undo_after_push() {
         let mut stack = UndoStack::new();
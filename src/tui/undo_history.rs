@@ -0,0 +1,238 @@
+//! The undo history kept across sessions, in `frame/.undo.json`.
+//!
+//! Undo used to live only in memory, so quitting — on purpose or by a stray
+//! `QQ` after a bulk delete — took every step with it. The stack is now written
+//! out with a fingerprint of each file its steps write, and read back when the
+//! project opens.
+//!
+//! Steps are only as good as the files they were recorded against: undoing a
+//! title edit on a track someone has since rewritten would apply it to the
+//! wrong text. So reading the history back checks each step's files, newest
+//! first, and stops at the first step whose file has changed since. That step
+//! and every older one are dropped, exactly as a sync marker would have
+//! stopped undo at the point the file changed under a running session.
+
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::io::state::UNDO_FILE;
+use crate::model::ProjectConfig;
+
+use super::undo::{UndoEntry, UndoFile, UndoStack};
+
+/// Bumped when a change to [`super::undo::Operation`] would misread an older
+/// file; a history from another version is dropped rather than guessed at.
+const VERSION: u32 = 1;
+
+#[derive(Serialize, Deserialize)]
+struct SavedHistory {
+    version: u32,
+    /// Oldest first, as the stack holds them.
+    undo: Vec<UndoEntry>,
+    redo: Vec<UndoEntry>,
+    /// Each written file's fingerprint when the history was saved; `None`
+    /// for one that did not exist.
+    files: BTreeMap<String, Option<String>>,
+}
+
+/// Write the stack out, with a fingerprint of every file its steps write.
+pub fn save_history(
+    frame_dir: &Path,
+    config: &ProjectConfig,
+    stack: &UndoStack,
+) -> Result<(), std::io::Error> {
+    let (undo, redo) = stack.entries();
+    let mut files = BTreeMap::new();
+    for entry in undo.iter().chain(redo) {
+        for file in entry.op.files() {
+            files
+                .entry(key(&file))
+                .or_insert_with(|| fingerprint(frame_dir, config, &file));
+        }
+    }
+    let saved = SavedHistory {
+        version: VERSION,
+        undo: undo.to_vec(),
+        redo: redo.to_vec(),
+        files,
+    };
+    let content = serde_json::to_string(&saved)?;
+    crate::io::recovery::atomic_write(&frame_dir.join(UNDO_FILE), content.as_bytes())
+}
+
+/// Read the stack back, without the steps whose files have changed since it
+/// was saved. Returns the stack and how many steps were dropped; `None` when
+/// there is no history, or none this version can read.
+pub fn load_history(frame_dir: &Path, config: &ProjectConfig) -> Option<(UndoStack, usize)> {
+    let text = std::fs::read_to_string(frame_dir.join(UNDO_FILE)).ok()?;
+    let saved: SavedHistory = serde_json::from_str(&text).ok()?;
+    if saved.version != VERSION {
+        return None;
+    }
+    let unchanged =
+        |entry: &UndoEntry| {
+            entry.op.files().iter().all(|file| {
+                saved.files.get(&key(file)) == Some(&fingerprint(frame_dir, config, file))
+            })
+        };
+    let total = saved.undo.len() + saved.redo.len();
+    let undo = newest_unchanged(saved.undo, unchanged);
+    let redo = newest_unchanged(saved.redo, unchanged);
+    let dropped = total - undo.len() - redo.len();
+    Some((UndoStack::from_entries(undo, redo), dropped))
+}
+
+/// The run of entries at the top of a stack — its end — whose files are
+/// unchanged, oldest first.
+fn newest_unchanged(
+    mut entries: Vec<UndoEntry>,
+    unchanged: impl Fn(&UndoEntry) -> bool,
+) -> Vec<UndoEntry> {
+    let keep_from = entries
+        .iter()
+        .rposition(|e| !unchanged(e))
+        .map_or(0, |stale| stale + 1);
+    entries.drain(..keep_from);
+    entries
+}
+
+/// Undo history overlay state. The cursor is a row of [`history_rows`].
+#[derive(Debug, Clone, Default)]
+pub struct UndoHistoryState {
+    pub cursor: usize,
+    pub scroll: usize,
+}
+
+/// A row of the undo history overlay: a point the project can be taken back
+/// or forward to.
+#[derive(Debug, Clone, Copy)]
+pub enum HistoryRow<'a> {
+    /// An undone step, which redo would do again.
+    Redo(&'a UndoEntry),
+    /// A step as done; the first of these is where the project is now.
+    Undo(&'a UndoEntry),
+    /// Before the oldest step.
+    Start,
+}
+
+/// The overlay's rows, top to bottom: undone steps, furthest first, then done
+/// steps, newest first, then the start. Row `redo_depth` is the present, and
+/// the way to row `n` is `redo_depth - n` redos or `n - redo_depth` undos.
+pub fn history_rows(stack: &UndoStack) -> Vec<HistoryRow<'_>> {
+    let (undo, redo) = stack.entries();
+    redo.iter()
+        .map(HistoryRow::Redo)
+        .chain(undo.iter().rev().map(HistoryRow::Undo))
+        .chain(std::iter::once(HistoryRow::Start))
+        .collect()
+}
+
+fn key(file: &UndoFile) -> String {
+    match file {
+        UndoFile::Track(id) => format!("track:{id}"),
+        UndoFile::Inbox => "inbox".to_string(),
+        UndoFile::Config => "config".to_string(),
+    }
+}
+
+/// FNV-1a of the file's bytes, or `None` when it doesn't exist — or, for a
+/// track, when the config no longer lists it. Nothing here needs to resist a
+/// collision on purpose; it needs to be the same across builds, which the
+/// standard library's hasher does not promise.
+fn fingerprint(frame_dir: &Path, config: &ProjectConfig, file: &UndoFile) -> Option<String> {
+    let rel = match file {
+        UndoFile::Track(id) => config.tracks.iter().find(|tc| tc.id == *id)?.file.clone(),
+        UndoFile::Inbox => "inbox.md".to_string(),
+        UndoFile::Config => "project.toml".to_string(),
+    };
+    let bytes = std::fs::read(frame_dir.join(rel)).ok()?;
+    let hash = bytes.iter().fold(0xcbf2_9ce4_8422_2325u64, |h, b| {
+        (h ^ u64::from(*b)).wrapping_mul(0x0100_0000_01b3)
+    });
+    Some(format!("{hash:016x}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tui::app::app_on_disk;
+    use crate::tui::undo::Operation;
+
+    fn title_edit(task_id: &str, title: &str) -> Operation {
+        Operation::TitleEdit {
+            track_id: "a".into(),
+            task_id: task_id.into(),
+            old_title: "One".into(),
+            new_title: title.into(),
+        }
+    }
+
+    #[test]
+    fn the_history_survives_a_round_trip() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let app = app_on_disk(tmp.path());
+        let mut stack = UndoStack::new();
+        stack.push(title_edit("A-001", "Uno"));
+        stack.push(Operation::InboxAdd {
+            index: 0,
+            title: "Idea".into(),
+        });
+        let frame_dir = &app.project.frame_dir;
+        save_history(frame_dir, &app.project.config, &stack).unwrap();
+
+        let (loaded, dropped) = load_history(frame_dir, &app.project.config).unwrap();
+        assert_eq!(dropped, 0);
+        let (undo, redo) = loaded.entries();
+        assert_eq!(undo.len(), 2);
+        assert!(redo.is_empty());
+        assert_eq!(undo[0].op.describe(), "A-001 title edited");
+        assert_eq!(undo[0].at, stack.entries().0[0].at);
+    }
+
+    /// A step on a file that changed since is dropped, and so is everything
+    /// older: undo could not have crossed it in a running session either.
+    #[test]
+    fn steps_older_than_a_changed_file_are_dropped() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let app = app_on_disk(tmp.path());
+        let frame_dir = &app.project.frame_dir;
+        let mut stack = UndoStack::new();
+        stack.push(Operation::InboxAdd {
+            index: 0,
+            title: "Old idea".into(),
+        });
+        stack.push(title_edit("A-001", "Uno"));
+        stack.push(Operation::InboxAdd {
+            index: 0,
+            title: "New idea".into(),
+        });
+        save_history(frame_dir, &app.project.config, &stack).unwrap();
+
+        std::fs::write(
+            frame_dir.join("tracks/a.md"),
+            "# A\n\n## Backlog\n\n- [ ] `A-001` Rewritten elsewhere\n\n## Done\n",
+        )
+        .unwrap();
+
+        let (loaded, dropped) = load_history(frame_dir, &app.project.config).unwrap();
+        assert_eq!(dropped, 2);
+        let (undo, _) = loaded.entries();
+        assert_eq!(undo.len(), 1);
+        assert_eq!(undo[0].op.describe(), "inbox item added: New idea");
+    }
+
+    #[test]
+    fn a_history_from_another_version_is_ignored() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let app = app_on_disk(tmp.path());
+        let frame_dir = &app.project.frame_dir;
+        std::fs::write(
+            frame_dir.join(UNDO_FILE),
+            r#"{"version":0,"undo":[],"redo":[],"files":{}}"#,
+        )
+        .unwrap();
+        assert!(load_history(frame_dir, &app.project.config).is_none());
+    }
+}
@@ -122,6 +122,15 @@ side = "S"
     .unwrap();
 }
 
+/// Append `toml` to the fixture's `project.toml`.
+fn append_config(root: &Path, toml: &str) {
+    let path = root.join("frame/project.toml");
+    let mut text = fs::read_to_string(&path).unwrap();
+    text.push('\n');
+    text.push_str(toml);
+    fs::write(&path, text).unwrap();
+}
+
 /// Run `fr` with the given args in the given directory, returning (stdout, stderr, success).
 /// Overwrite a track file wholesale, for tests that need a shape the shared
 /// fixture doesn't have.
@@ -1141,6 +1150,443 @@ fn deps_reports_a_dangling_dependency_as_not_found() {
     assert!(out.contains("M-999 (not found)"), "{out}");
 }
 
+/// `fr plan` puts a task after every open dep, and its step says how many
+/// rounds of work stand in front of it.
+#[test]
+fn plan_orders_open_tasks_after_their_deps() {
+    let tmp = tempfile::TempDir::new().unwrap();
+    create_test_project(tmp.path());
+    write_track(
+        tmp.path(),
+        "main",
+        "# Main Track\n\n## Backlog\n\n\
+         - [ ] `M-001` Ship it\n  - dep: M-002\n\
+         - [ ] `M-002` Build it\n  - dep: M-003\n\
+         - [>] `M-003` Design it\n\n## Done\n",
+    );
+
+    let out = run_fr_ok(tmp.path(), &["plan", "--track", "main"]);
+    let order: Vec<&str> = out
+        .lines()
+        .filter_map(|l| l.split_whitespace().find(|w| w.starts_with("M-")))
+        .collect();
+    assert_eq!(order, vec!["M-003", "M-002", "M-001"], "{out}");
+    assert!(
+        out.contains("3. [main] [ ] M-001 Ship it (after: M-002)"),
+        "{out}"
+    );
+}
+
+#[test]
+fn deps_critical_path_follows_the_longest_open_chain() {
+    let tmp = tempfile::TempDir::new().unwrap();
+    create_test_project(tmp.path());
+    write_track(
+        tmp.path(),
+        "main",
+        "# Main Track\n\n## Backlog\n\n\
+         - [ ] `M-001` Goal\n  - dep: M-002, M-003\n\
+         - [ ] `M-002` Quick win\n\
+         - [ ] `M-003` Long haul\n  - dep: M-004\n\
+         - [ ] `M-004` Groundwork\n\n## Done\n",
+    );
+
+    let out = run_fr_ok(tmp.path(), &["--json", "deps", "M-001", "--critical-path"]);
+    let v: serde_json::Value = serde_json::from_str(&out).unwrap();
+    let ids: Vec<&str> = v["tasks"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|t| t["id"].as_str().unwrap())
+        .collect();
+    assert_eq!(ids, vec!["M-004", "M-003", "M-001"]);
+    assert_eq!(v["length"], 3);
+}
+
+#[test]
+fn deps_critical_path_can_be_weighted_by_an_estimate() {
+    let tmp = tempfile::TempDir::new().unwrap();
+    create_test_project(tmp.path());
+    append_config(tmp.path(), "[fields.estimate]\ntype = \"number\"\n");
+    write_track(
+        tmp.path(),
+        "main",
+        "# Main Track\n\n## Backlog\n\n\
+         - [ ] `M-001` Goal\n  - dep: M-002, M-003\n\
+         - [ ] `M-002` Big one\n  - estimate: 8\n\
+         - [ ] `M-003` Long haul\n  - dep: M-004\n\
+         - [ ] `M-004` Groundwork\n\n## Done\n",
+    );
+
+    let out = run_fr_ok(
+        tmp.path(),
+        &["deps", "M-001", "--critical-path", "--weight", "estimate"],
+    );
+    assert!(
+        out.starts_with("critical path, 2 tasks, estimate 9, first to do first"),
+        "{out}"
+    );
+    assert!(out.contains("M-002 Big one"), "{out}");
+
+    let (_, err, ok) = run_fr(
+        tmp.path(),
+        &["deps", "M-001", "--critical-path", "--weight", "size"],
+    );
+    assert!(!ok);
+    assert!(err.contains("no field named 'size'"), "{err}");
+}
+
+#[test]
+fn deps_reverse_lists_what_waits_on_a_task() {
+    let tmp = tempfile::TempDir::new().unwrap();
+    create_test_project(tmp.path());
+    write_track(
+        tmp.path(),
+        "main",
+        "# Main Track\n\n## Backlog\n\n\
+         - [ ] `M-001` Groundwork\n\
+         - [ ] `M-002` Walls\n  - dep: M-001\n\
+         - [ ] `M-003` Roof\n  - dep: M-002\n\
+         - [ ] `M-004` Unrelated\n\n## Done\n",
+    );
+
+    let out = run_fr_ok(tmp.path(), &["deps", "M-001", "--reverse"]);
+    let walls = out.find("M-002").expect(&out);
+    let roof = out.find("M-003").expect(&out);
+    assert!(walls < roof, "{out}");
+    assert!(!out.contains("M-004"), "{out}");
+
+    let out = run_fr_ok(tmp.path(), &["deps", "M-004", "--reverse"]);
+    assert!(out.contains("(no dependents)"), "{out}");
+}
+
+#[test]
+fn graph_exports_dot_mermaid_and_json() {
+    let tmp = tempfile::TempDir::new().unwrap();
+    create_test_project(tmp.path());
+    write_track(
+        tmp.path(),
+        "main",
+        "# Main Track\n\n## Backlog\n\n\
+         - [ ] `M-001` Groundwork\n\
+         - [ ] `M-002` Walls\n  - dep: M-001, M-999\n\
+         - [ ] `M-003` Unrelated\n\n## Done\n",
+    );
+
+    let dot = run_fr_ok(tmp.path(), &["graph"]);
+    assert!(dot.starts_with("digraph deps {"), "{dot}");
+    assert!(dot.contains("\"M-001\" -> \"M-002\";"), "{dot}");
+    assert!(
+        dot.contains("\"M-999\" [label=\"M-999\\n(not found)\", style=dashed];"),
+        "{dot}"
+    );
+    assert!(!dot.contains("M-003"), "{dot}");
+
+    let mermaid = run_fr_ok(tmp.path(), &["graph", "--format", "mermaid"]);
+    assert!(mermaid.starts_with("flowchart LR"), "{mermaid}");
+    assert!(mermaid.contains("M_001 --> M_002"), "{mermaid}");
+
+    let out = run_fr_ok(tmp.path(), &["--json", "graph"]);
+    let v: serde_json::Value = serde_json::from_str(&out).unwrap();
+    assert_eq!(v["nodes"].as_array().unwrap().len(), 3);
+    assert_eq!(v["edges"][0]["from"], "M-001");
+    assert_eq!(v["edges"][0]["to"], "M-002");
+}
+
+#[test]
+fn mermaid_keeps_lookalike_ids_apart_and_classes_custom_states() {
+    let tmp = tempfile::TempDir::new().unwrap();
+    create_test_project(tmp.path());
+    append_config(tmp.path(), "[workflow.states.in-review]\nchar = \"?\"\n");
+    write_track(
+        tmp.path(),
+        "main",
+        "# Main Track\n\n## Backlog\n\n\
+         - [?] `M-001` Groundwork\n\
+         - [ ] `M-002` Walls\n  - dep: M-001, M_001\n\n## Done\n",
+    );
+
+    let mermaid = run_fr_ok(tmp.path(), &["graph", "--format", "mermaid"]);
+    // `M_001` is a dangling dep, not the same node as `M-001`
+    assert!(mermaid.contains("M_001[\"M-001 Groundwork\"]"), "{mermaid}");
+    assert!(
+        mermaid.contains("M_001_2[\"M_001 (not found)\"]"),
+        "{mermaid}"
+    );
+    assert!(mermaid.contains("M_001 --> M_002"), "{mermaid}");
+    assert!(mermaid.contains("M_001_2 --> M_002"), "{mermaid}");
+    assert!(mermaid.contains("classDef in_review fill:"), "{mermaid}");
+    assert!(mermaid.contains("class M_001 in_review"), "{mermaid}");
+}
+
+/// Turn on `[deps] auto_block` on a project the helpers just built.
+fn enable_auto_block(root: &Path) {
+    let path = root.join("frame/project.toml");
+    let text = fs::read_to_string(&path).unwrap();
+    fs::write(&path, format!("{text}\n[deps]\nauto_block = true\n")).unwrap();
+}
+
+#[test]
+fn auto_block_follows_deps_through_fr_state_and_reports_each_change() {
+    let tmp = tempfile::TempDir::new().unwrap();
+    create_test_project(tmp.path());
+    write_track(
+        tmp.path(),
+        "main",
+        "# Main Track\n\n## Backlog\n\n\
+         - [ ] `M-001` Groundwork\n\
+         - [ ] `M-002` Walls\n\n## Done\n",
+    );
+    enable_auto_block(tmp.path());
+
+    let out = run_fr_ok(tmp.path(), &["dep", "M-002", "add", "M-001"]);
+    assert!(
+        out.contains("M-002 → blocked (auto: waiting on M-001)"),
+        "{out}"
+    );
+    let track = fs::read_to_string(tmp.path().join("frame/tracks/main.md")).unwrap();
+    assert!(track.contains("- [-] `M-002` Walls"), "{track}");
+
+    let out = run_fr_ok(tmp.path(), &["--json", "done", "M-001"]);
+    let v: serde_json::Value = serde_json::from_str(&out).unwrap();
+    assert_eq!(v["auto_state"][0]["id"], "M-002");
+    assert_eq!(v["auto_state"][0]["from"], "blocked");
+    assert_eq!(v["auto_state"][0]["to"], "todo");
+    let track = fs::read_to_string(tmp.path().join("frame/tracks/main.md")).unwrap();
+    assert!(track.contains("- [ ] `M-002` Walls"), "{track}");
+}
+
+#[test]
+fn without_auto_block_state_is_left_alone() {
+    let tmp = tempfile::TempDir::new().unwrap();
+    create_test_project(tmp.path());
+    write_track(
+        tmp.path(),
+        "main",
+        "# Main Track\n\n## Backlog\n\n\
+         - [ ] `M-001` Groundwork\n\
+         - [ ] `M-002` Walls\n  - dep: M-001\n\n## Done\n",
+    );
+
+    let out = run_fr_ok(tmp.path(), &["state", "M-001", "active"]);
+    assert!(!out.contains("auto:"), "{out}");
+    let track = fs::read_to_string(tmp.path().join("frame/tracks/main.md")).unwrap();
+    assert!(track.contains("- [ ] `M-002` Walls"), "{track}");
+}
+
+/// A second project beside `tmp/app`, registered in the registry `fr` will
+/// read when run from `tmp/app`.
+fn register_library(tmp: &Path) {
+    let lib = tmp.join("lib");
+    create_test_project(&lib);
+    write_track(
+        &lib,
+        "main",
+        "# Main Track\n\n## Backlog\n\n\
+         - [>] `M-001` Parser rewrite\n\n\
+         ## Done\n\n- [x] `M-002` Lexer\n",
+    );
+    write_registry(&tmp.join("app"), &[("lib", &lib)]);
+}
+
+#[test]
+fn a_cross_project_dep_is_resolved_through_the_registry() {
+    let tmp = tempfile::TempDir::new().unwrap();
+    let app = tmp.path().join("app");
+    create_test_project(&app);
+    register_library(tmp.path());
+    write_track(
+        &app,
+        "main",
+        "# Main Track\n\n## Backlog\n\n\
+         - [ ] `M-001` Use the new parser\n  - dep: lib:M-001\n\
+         - [ ] `M-002` Use the lexer\n  - dep: lib:M-002\n\n## Done\n",
+    );
+
+    let out = run_fr_ok(&app, &["deps", "M-001", "--json"]);
+    let v: serde_json::Value = serde_json::from_str(&out).unwrap();
+    let dep = &v["deps"][0];
+    assert_eq!(dep["id"], "lib:M-001");
+    assert_eq!(dep["project"], "lib");
+    assert_eq!(dep["title"], "Parser rewrite");
+
+    // The library's parser is still active, its lexer done.
+    let out = run_fr_ok(&app, &["ready"]);
+    assert!(!out.contains("Use the new parser"), "{out}");
+    assert!(out.contains("Use the lexer"), "{out}");
+
+    let (_, err, ok) = run_fr(&app, &["dep", "M-002", "add", "lib:M-999"]);
+    assert!(!ok);
+    assert!(err.contains("dep target lib:M-999"), "{err}");
+    run_fr_ok(&app, &["dep", "M-002", "add", "M-001"]);
+    run_fr_ok(&app, &["check"]);
+}
+
+#[test]
+fn a_cross_project_dep_names_its_project_in_any_case() {
+    let tmp = tempfile::TempDir::new().unwrap();
+    let app = tmp.path().join("app");
+    create_test_project(&app);
+    let lib = tmp.path().join("lib");
+    create_test_project(&lib);
+    write_track(
+        &lib,
+        "main",
+        "# Main Track\n\n## Backlog\n\n- [>] `M-001` Parser rewrite\n\n## Done\n",
+    );
+    write_registry(&app, &[("Lib", &lib)]);
+    write_track(
+        &app,
+        "main",
+        "# Main Track\n\n## Backlog\n\n\
+         - [ ] `M-001` Use the new parser\n  - dep: lib:M-001\n\n## Done\n",
+    );
+
+    let (out, err, ok) = run_fr(&app, &["deps", "M-001"]);
+    assert!(ok, "{err}");
+    assert!(out.contains("Parser rewrite"), "{out}");
+    assert!(!err.contains("unreachable"), "{err}");
+    let out = run_fr_ok(&app, &["ready"]);
+    assert!(!out.contains("Use the new parser"), "{out}");
+}
+
+#[test]
+fn an_unregistered_project_is_a_warning_not_a_dangling_dep() {
+    let tmp = tempfile::TempDir::new().unwrap();
+    create_test_project(tmp.path());
+    write_track(
+        tmp.path(),
+        "main",
+        "# Main Track\n\n## Backlog\n\n\
+         - [ ] `M-001` Use the new parser\n  - dep: lib:M-001\n\n## Done\n",
+    );
+
+    // Nothing known holds it back.
+    let (out, err, ok) = run_fr(tmp.path(), &["ready"]);
+    assert!(ok, "{err}");
+    assert!(out.contains("M-001"), "{out}");
+    assert!(err.contains("project 'lib' is unreachable"), "{err}");
+
+    let out = run_fr_ok(tmp.path(), &["deps", "M-001"]);
+    assert!(out.contains("(project unreachable)"), "{out}");
+
+    // A write is not the place to hear about it.
+    let (_, err, ok) = run_fr(tmp.path(), &["dep", "M-001", "add", "gone:G-1"]);
+    assert!(ok, "{err}");
+    assert!(!err.contains("unreachable"), "{err}");
+
+    let out = run_fr_ok(tmp.path(), &["check", "--json"]);
+    let v: serde_json::Value = serde_json::from_str(&out).unwrap();
+    assert_eq!(v["errors"].as_array().unwrap().len(), 0, "{out}");
+    assert!(
+        v["warnings"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .any(|w| w["type"] == "external_project_unreachable" && w["project"] == "lib"),
+        "{out}"
+    );
+}
+
+/// Write the registry `fr` reads when run from `cwd`.
+fn write_registry(cwd: &Path, projects: &[(&str, &Path)]) {
+    let config = cwd.join(".xdg-config").join("frame");
+    fs::create_dir_all(&config).unwrap();
+    let body: String = projects
+        .iter()
+        .map(|(name, path)| {
+            format!(
+                "[[projects]]\nname = \"{}\"\npath = \"{}\"\n\n",
+                name,
+                path.display()
+            )
+        })
+        .collect();
+    fs::write(config.join("projects.toml"), body).unwrap();
+}
+
+/// Two registered projects, `app` and `lib`, beside each other under `tmp`.
+fn two_registered_projects(tmp: &Path) {
+    let app = tmp.join("app");
+    let lib = tmp.join("lib");
+    create_test_project(&app);
+    create_test_project(&lib);
+    write_track(
+        &lib,
+        "main",
+        "# Main Track\n\n## Backlog\n\n\
+         - [ ] `M-001` Library parser #core\n\n\
+         ## Done\n\n- [x] `M-000` Library lexer\n  - resolved: 2025-05-10\n",
+    );
+    write_registry(tmp, &[("app", &app), ("lib", &lib)]);
+}
+
+#[test]
+fn ready_across_projects_prefixes_each_task_with_its_project() {
+    let tmp = tempfile::TempDir::new().unwrap();
+    two_registered_projects(tmp.path());
+
+    // Run from outside either project: the registry is all it needs.
+    let out = run_fr_ok(tmp.path(), &["ready", "--all-projects"]);
+    assert!(out.contains("[app:main] [ ] M-001 First task"), "{out}");
+    assert!(out.contains("[app:side] [ ] S-001"), "{out}");
+    assert!(out.contains("[lib:main] [ ] M-001 Library parser"), "{out}");
+
+    let out = run_fr_ok(tmp.path(), &["ready", "--projects", "lib", "--json"]);
+    let v: serde_json::Value = serde_json::from_str(&out).unwrap();
+    let tasks = v["tasks"].as_array().unwrap();
+    assert!(tasks.iter().all(|t| t["project"] == "lib"), "{out}");
+    assert_eq!(tasks[0]["track"], "main");
+    assert_eq!(tasks[0]["title"], "Library parser");
+
+    // Unprefixed, and without the key, in a single project as before.
+    let out = run_fr_ok(&tmp.path().join("app"), &["ready", "--json"]);
+    assert!(!out.contains("\"project\""), "{out}");
+
+    let (_, err, ok) = run_fr(tmp.path(), &["ready", "--projects", "nope"]);
+    assert!(!ok);
+    assert!(err.contains("no project named 'nope'"), "{err}");
+}
+
+#[test]
+fn list_search_recent_and_stats_run_across_projects() {
+    let tmp = tempfile::TempDir::new().unwrap();
+    two_registered_projects(tmp.path());
+
+    let out = run_fr_ok(tmp.path(), &["list", "--projects", "app,lib"]);
+    assert!(out.contains("== Main Track (app:main) =="), "{out}");
+    assert!(out.contains("== Main Track (lib:main) =="), "{out}");
+
+    let out = run_fr_ok(tmp.path(), &["search", "parser", "--all-projects"]);
+    assert_eq!(
+        out.lines().collect::<Vec<_>>(),
+        vec![
+            "[app:inbox:1] Bug in parser #bug",
+            "[lib:main] [ ] M-001 Library parser #core",
+            "[lib:inbox:1] Bug in parser #bug",
+        ]
+    );
+
+    let out = run_fr_ok(tmp.path(), &["recent", "--all-projects"]);
+    let lib = out.find("Library lexer (lib:main)").expect(&out);
+    let app = out.find("Setup project (app:main)").expect(&out);
+    assert!(lib < app, "newest first across projects: {out}");
+
+    let out = run_fr_ok(tmp.path(), &["stats", "--all-projects", "--json"]);
+    let v: serde_json::Value = serde_json::from_str(&out).unwrap();
+    let projects: Vec<&str> = v["tracks"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|t| t["project"].as_str().unwrap())
+        .collect();
+    assert_eq!(projects, vec!["app", "app", "lib", "lib"]);
+    // The app's M-001, M-003 and its two subtasks, then S-001 and S-002 in
+    // both projects, and the lib's M-001.
+    assert_eq!(v["totals"]["todo"], 9, "{out}");
+    let out = run_fr_ok(tmp.path(), &["stats", "--all-projects"]);
+    assert!(out.contains("lib:Main Track"), "{out}");
+}
 /// Archives are searched by default; `--no-archive` is the opt-out. The old
 /// `--archive` flag was declared and never read, so it did nothing either way.
 #[test]
@@ -1235,6 +1681,129 @@ fn test_add_task() {
     assert!(track.contains("M-011"));
 }
 
+#[test]
+fn add_from_a_template_fills_in_the_task() {
+    let tmp = tempfile::TempDir::new().unwrap();
+    create_test_project(tmp.path());
+    append_config(
+        tmp.path(),
+        "[templates.bug]\ntrack = \"side\"\ntags = [\"bug\"]\n\
+         note = \"Steps to reproduce:\"\nsubtasks = [\"Failing test\", \"Fix\"]\n",
+    );
+
+    // The template names a track, so the one positional is the title.
+    let out = run_fr_ok(tmp.path(), &["add", "--template", "bug", "Crash on save"]);
+    assert!(out.contains("S-003"), "{out}");
+    let track = fs::read_to_string(tmp.path().join("frame/tracks/side.md")).unwrap();
+    assert!(track.contains("`S-003` Crash on save #bug"), "{track}");
+    assert!(track.contains("note: Steps to reproduce:"), "{track}");
+    assert!(track.contains("`S-003.1` Failing test"), "{track}");
+    assert!(track.contains("`S-003.2` Fix"), "{track}");
+
+    // --after is read in the template's track, where the task goes
+    run_fr_ok(
+        tmp.path(),
+        &["add", "--template", "bug", "Crash on load", "--after", "1"],
+    );
+    let track = fs::read_to_string(tmp.path().join("frame/tracks/side.md")).unwrap();
+    let added = track.find("`S-004` Crash on load").expect(&track);
+    assert!(track.find("`S-001`").unwrap() < added, "{track}");
+    assert!(added < track.find("`S-002`").unwrap(), "{track}");
+
+    // A lone positional naming a track is a missing title, not the title
+    let (_, err, ok) = run_fr(tmp.path(), &["add", "main", "--template", "bug"]);
+    assert!(!ok);
+    assert!(err.contains("missing task title"), "{err}");
+
+    // A track given on the command line wins over the template's, and
+    // --found-from goes above the template's note rather than replacing it.
+    run_fr_ok(
+        tmp.path(),
+        &[
+            "add",
+            "main",
+            "Bad parse",
+            "--template",
+            "bug",
+            "--found-from",
+            "M-001",
+        ],
+    );
+    let out = run_fr_ok(tmp.path(), &["show", "M-011"]);
+    assert!(out.contains("Bad parse"), "{out}");
+    let found = out.find("Found while working on M-001").expect(&out);
+    assert!(out[found..].contains("Steps to reproduce:"), "{out}");
+
+    let (_, err, ok) = run_fr(tmp.path(), &["add", "--template", "nope", "X"]);
+    assert!(!ok);
+    assert!(err.contains("no template named 'nope'"), "{err}");
+}
+
+#[test]
+fn custom_fields_are_set_shown_filtered_and_checked() {
+    let tmp = tempfile::TempDir::new().unwrap();
+    create_test_project(tmp.path());
+    append_config(
+        tmp.path(),
+        "[fields.risk]\ntype = \"enum\"\nvalues = [\"low\", \"high\"]\n\n\
+         [fields.owner]\ntype = \"string\"\n\n\
+         [fields.blocks]\ntype = \"id-list\"\n",
+    );
+
+    let out = run_fr_ok(tmp.path(), &["set", "M-001", "risk", "high"]);
+    assert_eq!(out.trim(), "M-001 risk: high");
+    run_fr_ok(tmp.path(), &["set", "M-003", "risk", "low"]);
+    run_fr_ok(tmp.path(), &["set", "M-001", "blocks", "M-002, S-001"]);
+    let track = fs::read_to_string(tmp.path().join("frame/tracks/main.md")).unwrap();
+    assert!(
+        track.contains("  - added: 2025-05-01\n  - risk: high\n  - blocks: M-002, S-001\n"),
+        "{track}"
+    );
+
+    let out = run_fr_ok(tmp.path(), &["show", "M-001"]);
+    assert!(out.contains("risk: high"), "{out}");
+    let out = run_fr_ok(tmp.path(), &["--json", "show", "M-001"]);
+    let v: serde_json::Value = serde_json::from_str(&out).unwrap();
+    assert_eq!(v["fields"]["risk"], "high", "{out}");
+
+    let out = run_fr_ok(tmp.path(), &["list", "--field", "risk=high"]);
+    assert!(out.contains("M-001"), "{out}");
+    assert!(!out.contains("M-003 "), "{out}");
+    // A list field matches on any one of its items.
+    let out = run_fr_ok(tmp.path(), &["list", "--field", "blocks=S-001"]);
+    assert!(out.contains("M-001"), "{out}");
+
+    let (_, err, ok) = run_fr(tmp.path(), &["set", "M-001", "risk", "huge"]);
+    assert!(!ok);
+    assert!(
+        err.contains("invalid risk 'huge': not one of: low, high"),
+        "{err}"
+    );
+    let (_, err, ok) = run_fr(tmp.path(), &["set", "M-001", "blocks", "M-404"]);
+    assert!(!ok);
+    assert!(err.contains("M-404"), "{err}");
+    let (_, err, ok) = run_fr(tmp.path(), &["set", "M-001", "area", "x"]);
+    assert!(!ok);
+    assert!(err.contains("no field named 'area'"), "{err}");
+
+    // A value typed in by hand is only caught by `fr check`.
+    let path = tmp.path().join("frame/tracks/main.md");
+    let track = fs::read_to_string(&path).unwrap();
+    fs::write(&path, track.replace("risk: low", "risk: medium")).unwrap();
+    let out = run_fr_ok(tmp.path(), &["--json", "check"]);
+    let v: serde_json::Value = serde_json::from_str(&out).unwrap();
+    assert!(
+        v["warnings"].as_array().unwrap().iter().any(|w| {
+            w["type"] == "invalid_field" && w["task_id"] == "M-003" && w["key"] == "risk"
+        }),
+        "{out}"
+    );
+
+    run_fr_ok(tmp.path(), &["set", "M-001", "risk", "--clear"]);
+    let out = run_fr_ok(tmp.path(), &["show", "M-001"]);
+    assert!(!out.contains("risk:"), "{out}");
+}
+
 #[test]
 fn test_add_task_after() {
     let tmp = tempfile::TempDir::new().unwrap();
@@ -3172,6 +3741,416 @@ fn test_import_to_shelved_track_blocked() {
     );
 }
 
+#[test]
+fn workflow_states_and_sections_come_from_the_config() {
+    let tmp = tempfile::TempDir::new().unwrap();
+    create_test_project(tmp.path());
+    append_config(
+        tmp.path(),
+        "[workflow]\nsections = [\"Icebox\"]\n\n\
+         [workflow.states.review]\nchar = \"?\"\n\n\
+         [workflow.states.frozen]\nchar = \"*\"\nsection = \"icebox\"\n",
+    );
+    write_track(
+        tmp.path(),
+        "main",
+        "# Main\n\n## Backlog\n\n- [ ] `M-001` Write it\n- [ ] `M-002` Later maybe\n\n\
+         ## Parked\n\n## Done\n",
+    );
+    let path = tmp.path().join("frame/tracks/main.md");
+
+    run_fr_ok(tmp.path(), &["state", "M-001", "review"]);
+    run_fr_ok(tmp.path(), &["state", "M-002", "frozen"]);
+    let track = fs::read_to_string(&path).unwrap();
+    assert!(track.contains("- [?] `M-001` Write it"), "{track}");
+    // The section is written as `sections` spells it, between Parked and Done
+    let icebox = track.find("## Icebox").expect("section created");
+    assert!(track.find("## Parked").unwrap() < icebox, "{track}");
+    assert!(icebox < track.find("`M-002`").unwrap(), "{track}");
+    assert!(track.contains("- [*] `M-002` Later maybe"), "{track}");
+
+    let out = run_fr_ok(tmp.path(), &["list", "main", "--state", "review"]);
+    assert!(out.contains("M-001"), "{out}");
+    assert!(!out.contains("M-002"), "{out}");
+    let out = run_fr_ok(tmp.path(), &["list", "main"]);
+    assert!(out.contains("-- Icebox --"), "{out}");
+
+    // --json names a custom state the way `fr state` and `--state` take it
+    let out = run_fr_ok(tmp.path(), &["show", "M-001", "--json"]);
+    let json: serde_json::Value = serde_json::from_str(&out).unwrap();
+    assert_eq!(json["state"], "review", "{json}");
+    let out = run_fr_ok(tmp.path(), &["state", "M-002", "frozen", "--json"]);
+    let json: serde_json::Value = serde_json::from_str(&out).unwrap();
+    assert_eq!(json["tasks"][0]["state"], "frozen", "{json}");
+
+    let (_, err, ok) = run_fr(tmp.path(), &["state", "M-001", "reveiw"]);
+    assert!(!ok);
+    assert!(err.contains("review"), "{err}");
+
+    // A checkbox the config no longer declares is kept, and reported
+    write_track(
+        tmp.path(),
+        "main",
+        "# Main\n\n## Backlog\n\n- [!] `M-001` Write it\n\n## Parked\n\n## Done\n",
+    );
+    let (out, _, _) = run_fr(tmp.path(), &["check"]);
+    assert!(out.contains("M-001"), "{out}");
+    assert!(fs::read_to_string(&path).unwrap().contains("- [!] `M-001`"));
+}
+
+#[test]
+fn relations_are_written_on_one_task_and_shown_from_both() {
+    let tmp = tempfile::TempDir::new().unwrap();
+    create_test_project(tmp.path());
+
+    run_fr_ok(
+        tmp.path(),
+        &["relate", "M-002", "add", "duplicates", "M-001"],
+    );
+    run_fr_ok(tmp.path(), &["relate", "M-001", "add", "relates", "S-001"]);
+    let main = fs::read_to_string(tmp.path().join("frame/tracks/main.md")).unwrap();
+    assert!(main.contains("  - duplicates: M-001"), "{main}");
+
+    // The target shows the inverse without it being written there
+    let out = run_fr_ok(tmp.path(), &["show", "M-001"]);
+    assert!(out.contains("duplicated by: M-002"), "{out}");
+    assert!(out.contains("relates: S-001"), "{out}");
+    let out = run_fr_ok(tmp.path(), &["show", "M-001", "--json"]);
+    let json: serde_json::Value = serde_json::from_str(&out).unwrap();
+    assert_eq!(json["related_from"]["duplicates"][0], "M-002");
+    assert_eq!(json["relations"]["relates"][0], "S-001");
+
+    let (_, err, ok) = run_fr(tmp.path(), &["relate", "M-001", "add", "blocks", "M-002"]);
+    assert!(!ok);
+    assert!(err.contains("unknown relation"), "{err}");
+    let (_, err, ok) = run_fr(tmp.path(), &["relate", "M-001", "add", "relates", "M-999"]);
+    assert!(!ok, "{err}");
+
+    // A prefix rename rewrites relation targets like deps
+    run_fr_ok(
+        tmp.path(),
+        &["track", "rename", "side", "--prefix", "AUX", "--yes"],
+    );
+    let main = fs::read_to_string(tmp.path().join("frame/tracks/main.md")).unwrap();
+    assert!(main.contains("  - relates: AUX-001"), "{main}");
+
+    run_fr_ok(tmp.path(), &["relate", "M-001", "rm", "relates", "AUX-001"]);
+    let main = fs::read_to_string(tmp.path().join("frame/tracks/main.md")).unwrap();
+    assert!(!main.contains("relates:"), "{main}");
+
+    // A relation to an id that exists nowhere is a warning
+    let main = main.replace("  - duplicates: M-001", "  - duplicates: M-099");
+    fs::write(tmp.path().join("frame/tracks/main.md"), main).unwrap();
+    let out = run_fr_ok(tmp.path(), &["check", "--json"]);
+    assert!(out.contains("dangling_relation"), "{out}");
+}
+
+#[test]
+fn an_epic_rolls_up_members_from_other_tracks() {
+    let tmp = tempfile::TempDir::new().unwrap();
+    create_test_project(tmp.path());
+    run_fr_ok(tmp.path(), &["relate", "M-002", "add", "epic", "M-001"]);
+    run_fr_ok(tmp.path(), &["relate", "S-001", "add", "epic", "M-001"]);
+    run_fr_ok(tmp.path(), &["state", "S-001", "blocked"]);
+    run_fr_ok(tmp.path(), &["relate", "M-000", "add", "epic", "M-001"]);
+
+    let out = run_fr_ok(tmp.path(), &["epic", "M-001"]);
+    let lines: Vec<&str> = out.lines().collect();
+    assert!(
+        lines[0].contains("M-001") && lines[0].ends_with("1/3 done, 1 blocked"),
+        "{out}"
+    );
+    assert!(
+        lines[1].contains("[main]") && lines[1].contains("M-002"),
+        "{out}"
+    );
+    assert!(
+        lines[3].contains("[side]") && lines[3].contains("S-001"),
+        "{out}"
+    );
+
+    let out = run_fr_ok(tmp.path(), &["epic", "M-001", "--json"]);
+    let json: serde_json::Value = serde_json::from_str(&out).unwrap();
+    assert_eq!(json["total"], 3);
+    assert_eq!(json["done"], 1);
+    assert_eq!(json["blocked"], 1);
+    assert_eq!(json["tasks"][2]["id"], "S-001");
+
+    // Without an id, every epic
+    let out = run_fr_ok(tmp.path(), &["epic", "--json"]);
+    let json: serde_json::Value = serde_json::from_str(&out).unwrap();
+    assert_eq!(json.as_array().unwrap().len(), 1);
+    let (_, err, ok) = run_fr(tmp.path(), &["epic", "M-404"]);
+    assert!(!ok, "{err}");
+}
+
+#[test]
+fn task_ids_can_be_written_short_or_as_a_title() {
+    let tmp = tempfile::TempDir::new().unwrap();
+    create_test_project(tmp.path());
+
+    // Loose forms of M-001, and a bare number in the cc-focus track (main)
+    for input in ["m1", "M-1", "m-001", "1"] {
+        let out = run_fr_ok(tmp.path(), &["show", input, "--json"]);
+        let json: serde_json::Value = serde_json::from_str(&out).unwrap();
+        assert_eq!(json["id"], "M-001", "{input}");
+    }
+    run_fr_ok(tmp.path(), &["tag", "s1", "add", "urgent"]);
+    run_fr_ok(tmp.path(), &["dep", "side task two", "add", "s1"]);
+    let side = fs::read_to_string(tmp.path().join("frame/tracks/side.md")).unwrap();
+    assert!(side.contains("`S-001` Side task one #urgent"), "{side}");
+    assert!(side.contains("  - dep: S-001"), "{side}");
+
+    // A bare number is looked up in the track the command names before cc-focus
+    run_fr_ok(
+        tmp.path(),
+        &["add", "side", "Inserted after one", "--after", "1"],
+    );
+    let side = fs::read_to_string(tmp.path().join("frame/tracks/side.md")).unwrap();
+    let at = |title: &str| side.find(title).unwrap();
+    assert!(at("Side task one") < at("Inserted after one"), "{side}");
+    assert!(at("Inserted after one") < at("Side task two"), "{side}");
+
+    // Ambiguity names the candidates and changes nothing
+    let (_, err, ok) = run_fr(tmp.path(), &["done", "side task"]);
+    assert!(!ok);
+    assert!(err.contains("matches 2 tasks"), "{err}");
+    assert!(err.contains("S-001") && err.contains("S-002"), "{err}");
+
+    // An ID-shaped miss is still a plain not-found
+    let (_, err, ok) = run_fr(tmp.path(), &["show", "m99"]);
+    assert!(!ok);
+    assert!(err.contains("task not found: m99"), "{err}");
+}
+
+#[test]
+fn triage_after_takes_a_short_id_in_the_target_track() {
+    let tmp = tempfile::TempDir::new().unwrap();
+    create_test_project(tmp.path());
+
+    run_fr_ok(
+        tmp.path(),
+        &["triage", "1", "--track", "side", "--after", "1"],
+    );
+    let side = fs::read_to_string(tmp.path().join("frame/tracks/side.md")).unwrap();
+    let at = |title: &str| side.find(title).expect(&side);
+    assert!(at("Side task one") < at("Bug in parser"), "{side}");
+    assert!(at("Bug in parser") < at("Side task two"), "{side}");
+}
+
+#[test]
+fn import_after_takes_a_title_fragment() {
+    let tmp = tempfile::TempDir::new().unwrap();
+    create_test_project(tmp.path());
+    fs::write(tmp.path().join("in.md"), "- [ ] Imported task\n").unwrap();
+
+    run_fr_ok(
+        tmp.path(),
+        &[
+            "import",
+            "in.md",
+            "--track",
+            "side",
+            "--after",
+            "side task one",
+        ],
+    );
+    let side = fs::read_to_string(tmp.path().join("frame/tracks/side.md")).unwrap();
+    let at = |title: &str| side.find(title).expect(&side);
+    assert!(at("Side task one") < at("Imported task"), "{side}");
+    assert!(at("Imported task") < at("Side task two"), "{side}");
+}
+
+#[test]
+fn merge_resolve_takes_a_short_id() {
+    let tmp = tempfile::TempDir::new().unwrap();
+    create_test_project(tmp.path());
+    mark_conflicted(tmp.path());
+
+    run_fr_ok(tmp.path(), &["merge", "--resolve", "m1"]);
+    let main = fs::read_to_string(tmp.path().join("frame/tracks/main.md")).unwrap();
+    assert!(!main.contains("conflict:"), "{main}");
+}
+
+#[test]
+fn recovery_for_takes_a_short_id() {
+    let tmp = tempfile::TempDir::new().unwrap();
+    create_test_project(tmp.path());
+    write_matching_conflict_entry(tmp.path());
+
+    let out = run_fr_ok(tmp.path(), &["recovery", "--for", "m1"]);
+    assert!(out.contains("merge conflict on #M-001"), "{out}");
+    // A timestamp is not an ID, and is looked for as given
+    let out = run_fr_ok(tmp.path(), &["recovery", "--for", "2026-08-06T06:18:30Z"]);
+    assert!(out.contains("merge conflict on #M-001"), "{out}");
+}
+
+#[test]
+fn a_task_moves_to_another_project_and_leaves_a_pointer() {
+    let base = tempfile::TempDir::new().unwrap();
+    let xdg = base.path().join(".xdg-config");
+    let app = base.path().join("app");
+    let lib = base.path().join("lib");
+    create_test_project(&app);
+    create_test_project(&lib);
+    let lib_config = lib.join("frame/project.toml");
+    let renamed = fs::read_to_string(&lib_config)
+        .unwrap()
+        .replace("name = \"test-project\"", "name = \"lib\"");
+    fs::write(&lib_config, renamed).unwrap();
+    run_fr_registry(
+        base.path(),
+        &["projects", "add", app.to_str().unwrap()],
+        &xdg,
+    );
+    run_fr_registry(
+        base.path(),
+        &["projects", "add", lib.to_str().unwrap()],
+        &xdg,
+    );
+
+    run_fr_registry(&app, &["dep", "M-001", "add", "S-001"], &xdg);
+    let out = run_fr_registry(
+        &app,
+        &["mv", "M-001", "--project", "lib", "--track", "side"],
+        &xdg,
+    );
+    assert!(out.contains("M-001 → lib:S-003 (side)"), "{out}");
+
+    // Re-minted in the destination, its dep now naming where it points
+    let side = fs::read_to_string(lib.join("frame/tracks/side.md")).unwrap();
+    assert!(side.contains("`S-003` First task #core"), "{side}");
+    assert!(side.contains("  - dep: test-project:S-001"), "{side}");
+
+    // The source keeps a done stub, and its dependents follow the move
+    let main = fs::read_to_string(app.join("frame/tracks/main.md")).unwrap();
+    assert!(main.contains("- [x] `M-001` First task"), "{main}");
+    assert!(main.contains("  - moved-to: lib:S-003"), "{main}");
+    assert!(main.contains("  - dep: lib:S-003"), "{main}");
+
+    // The stub keeps the task's added date, so the source still checks clean
+    let (out, _, ok) = run_fr_env(
+        &app,
+        &["check"],
+        &[("XDG_CONFIG_HOME", &xdg.to_string_lossy())],
+    );
+    assert!(ok, "{out}");
+    assert!(!out.contains("missing added date"), "{out}");
+
+    // A subtask has to be promoted first; this project is not a destination
+    let (_, err, ok) = run_fr_env(
+        &app,
+        &["mv", "M-003.1", "--project", "lib"],
+        &[("XDG_CONFIG_HOME", &xdg.to_string_lossy())],
+    );
+    assert!(!ok && err.contains("--promote"), "{err}");
+    let (_, err, ok) = run_fr_env(
+        &app,
+        &["mv", "M-002", "--project", "test-project"],
+        &[("XDG_CONFIG_HOME", &xdg.to_string_lossy())],
+    );
+    assert!(!ok && err.contains("--track"), "{err}");
+}
+
+#[test]
+fn config_set_edits_in_place_and_refuses_what_would_not_load() {
+    let tmp = tempfile::TempDir::new().unwrap();
+    create_test_project(tmp.path());
+    let config = tmp.path().join("frame/project.toml");
+    append_config(
+        tmp.path(),
+        "\n[clean]\n# archive sooner\ndone_threshold = 50\n",
+    );
+
+    assert_eq!(
+        run_fr_ok(tmp.path(), &["config", "get", "clean.done_threshold"]),
+        "50\n"
+    );
+    run_fr_ok(tmp.path(), &["config", "set", "clean.done_threshold", "20"]);
+    run_fr_ok(
+        tmp.path(),
+        &["config", "set", "ui.tag_colors.bug", "#cc6666"],
+    );
+    let text = fs::read_to_string(&config).unwrap();
+    assert!(
+        text.contains("# archive sooner\ndone_threshold = 20"),
+        "{text}"
+    );
+    assert!(text.contains("bug = \"#cc6666\""), "{text}");
+
+    // Refused values and typo'd keys leave the file alone
+    let (_, err, ok) = run_fr(tmp.path(), &["config", "set", "clean.done_retain", "many"]);
+    assert!(!ok);
+    assert!(
+        err.contains("invalid value for clean.done_retain: invalid type: string \"many\""),
+        "{err}"
+    );
+    let (_, err, ok) = run_fr(
+        tmp.path(),
+        &["config", "set", "ui.tag_colors.bug", "notacolor"],
+    );
+    assert!(!ok && err.contains("'notacolor' is not a color"), "{err}");
+    let (_, err, ok) = run_fr(tmp.path(), &["config", "set", "clean.done_retian", "5"]);
+    assert!(
+        !ok && err.contains("unknown config key: clean.done_retian"),
+        "{err}"
+    );
+    assert_eq!(fs::read_to_string(&config).unwrap(), text);
+
+    // Unset falls back to the default, which list reports
+    let out = run_fr_ok(tmp.path(), &["config", "unset", "clean.done_threshold"]);
+    assert_eq!(out, "clean.done_threshold unset (default: 100)\n");
+    let list = run_fr_ok(tmp.path(), &["config", "list"]);
+    assert!(list.contains("clean.done_threshold = 100\n"), "{list}");
+    assert!(list.contains("ui.tag_colors.bug = \"#cc6666\"\n"), "{list}");
+}
+
+#[test]
+fn the_user_config_layers_under_shared_settings_and_over_visual_ones() {
+    let tmp = tempfile::TempDir::new().unwrap();
+    create_test_project(tmp.path());
+    append_config(
+        tmp.path(),
+        "[clean]\ndone_threshold = 50\n\n[ui]\nnote_wrap = true\n",
+    );
+    let user_dir = tmp.path().join(".xdg-config/frame");
+    fs::create_dir_all(&user_dir).unwrap();
+    fs::write(
+        user_dir.join("config.toml"),
+        "[clean]\ndone_threshold = 20\ndone_retain = 5\n\n[ui]\nnote_wrap = false\n",
+    )
+    .unwrap();
+    let get = |key: &str| run_fr_ok(tmp.path(), &["config", "get", key]);
+
+    assert_eq!(get("clean.done_threshold"), "50\n");
+    assert_eq!(get("clean.done_retain"), "5\n");
+    assert_eq!(get("ui.note_wrap"), "false\n");
+
+    let info = run_fr_ok(tmp.path(), &["info", "--json"]);
+    let info: serde_json::Value = serde_json::from_str(&info).unwrap();
+    assert_eq!(
+        info["user_config"]["applied"],
+        serde_json::json!(["clean.done_retain", "ui.note_wrap"])
+    );
+    assert_eq!(
+        info["user_config"]["overridden"],
+        serde_json::json!(["clean.done_threshold"])
+    );
+
+    // Project-level sections are refused, naming the file
+    fs::write(
+        user_dir.join("config.toml"),
+        "[agent]\ncc_focus = \"side\"\n",
+    )
+    .unwrap();
+    let (_, err, ok) = run_fr(tmp.path(), &["list"]);
+    assert!(!ok);
+    assert!(
+        err.contains("config.toml: [agent] belongs in project.toml"),
+        "{err}"
+    );
+}
+
 #[test]
 fn test_state_active_in_shelved_track_blocked() {
     let tmp = tempfile::TempDir::new().unwrap();
@@ -6758,6 +7737,64 @@ fn test_cross_track_move_survives_source_write_failure() {
     );
 }
 
+/// A move into another project writes the destination, then the source. Cut
+/// between the two, the task is in both projects; the marker left in the source
+/// has its next command finish the move — the stub under the old ID, and the
+/// source's deps on it pointing at the new qualified one.
+#[test]
+fn test_cross_project_move_recovers_from_interrupted_source_write() {
+    let base = tempfile::TempDir::new().unwrap();
+    let xdg = base.path().join(".xdg-config");
+    let env = [("XDG_CONFIG_HOME", xdg.to_str().unwrap())];
+    let app = base.path().join("app");
+    let lib = base.path().join("lib");
+    create_test_project(&app);
+    create_test_project(&lib);
+    let lib_config = lib.join("frame/project.toml");
+    let renamed = fs::read_to_string(&lib_config)
+        .unwrap()
+        .replace("name = \"test-project\"", "name = \"lib\"");
+    fs::write(&lib_config, renamed).unwrap();
+    for root in [&app, &lib] {
+        run_fr_registry(
+            base.path(),
+            &["projects", "add", root.to_str().unwrap()],
+            &xdg,
+        );
+    }
+    run_fr_registry(&app, &["dep", "M-002", "add", "M-001"], &xdg);
+
+    let cut = [env[0], ("FRAME_FAIL_WRITE", "app/frame/tracks/main.md")];
+    let (_, _, ok) = run_fr_env(
+        &app,
+        &["mv", "M-001", "--project", "lib", "--track", "side"],
+        &cut,
+    );
+    assert!(!ok, "the injected failure should fail the command");
+    let side = fs::read_to_string(lib.join("frame/tracks/side.md")).unwrap();
+    assert!(side.contains("`S-003` First task"), "{side}");
+    let main = fs::read_to_string(app.join("frame/tracks/main.md")).unwrap();
+    assert!(main.contains("- [ ] `M-001` First task"), "{main}");
+
+    // Any following write command finishes the move
+    let (_, _, ok) = run_fr_env(&app, &["tag", "M-003", "add", "later"], &env);
+    assert!(ok);
+    let main = fs::read_to_string(app.join("frame/tracks/main.md")).unwrap();
+    assert!(main.contains("- [x] `M-001` First task"), "{main}");
+    assert!(main.contains("  - moved-to: lib:S-003"), "{main}");
+    assert!(main.contains("  - dep: lib:S-003"), "{main}");
+    assert!(!app.join("frame/.inflight").exists());
+    let (out, _, ok) = run_fr_env(&app, &["check"], &env);
+    assert!(ok, "{out}");
+    assert!(!out.contains("missing added date"), "{out}");
+
+    // The stub reads back as one, undeclared key and all
+    let (out, _, ok) = run_fr_env(&app, &["show", "M-001", "--json"], &env);
+    assert!(ok);
+    let json: serde_json::Value = serde_json::from_str(&out).unwrap();
+    assert_eq!(json["fields"]["moved-to"], "lib:S-003", "{json}");
+}
+
 /// Archiving a track is two steps — mark it archived in config, then move the
 /// file into `archive/_tracks/`. Interrupted between them the config says
 /// archived while the file is still in `tracks/`; re-running must finish the job
@@ -724,6 +724,102 @@ const CASES: &[Case] = &[
         expect: &[warning("lost_task", &[("task_id", Match::Eq("M-004"))])],
         repair: Repair::None,
     },
+    Case {
+        name: "blocked-without-cause",
+        provenance: "`[-]` set by hand while a dep was open, and left when the dep was finished",
+        covers: &["blocked_without_cause"],
+        build: |root| {
+            append_backlog(
+                root,
+                "- [-] `M-004` Waited on M-001\n  - added: 2026-01-01\n",
+            );
+            Built::Ok
+        },
+        expect: &[warning(
+            "blocked_without_cause",
+            &[("task_id", Match::Eq("M-004"))],
+        )],
+        repair: Repair::None,
+    },
+    Case {
+        name: "external-project-unreachable",
+        provenance: "a cross-project dep on a clone that has never registered the other project",
+        covers: &["external_project_unreachable"],
+        build: |root| {
+            append_backlog(
+                root,
+                "- [ ] `M-004` Needs the library\n  - added: 2026-01-01\n  - dep: corpus-unregistered:LIB-1\n",
+            );
+            Built::Ok
+        },
+        expect: &[warning(
+            "external_project_unreachable",
+            &[("project", Match::Eq("corpus-unregistered"))],
+        )],
+        repair: Repair::None,
+    },
+    Case {
+        name: "invalid-field",
+        provenance: "a custom field typed by hand against a schema that lists its values",
+        covers: &["invalid_field"],
+        build: |root| {
+            let toml_path = root.join("frame/project.toml");
+            let text = fs::read_to_string(&toml_path).unwrap();
+            fs::write(
+                &toml_path,
+                format!("{text}\n[fields.risk]\ntype = \"enum\"\nvalues = [\"low\", \"high\"]\n"),
+            )
+            .unwrap();
+            append_backlog(
+                root,
+                "- [ ] `M-004` Risky\n  - added: 2026-01-01\n  - risk: huge\n",
+            );
+            Built::Ok
+        },
+        expect: &[warning(
+            "invalid_field",
+            &[("task_id", Match::Eq("M-004")), ("key", Match::Eq("risk"))],
+        )],
+        repair: Repair::None,
+    },
+    Case {
+        name: "undeclared-state",
+        provenance: "a `[?]` review checkbox from another tool's workflow, pasted into a project without one",
+        covers: &["undeclared_state"],
+        build: |root| {
+            append_backlog(root, "- [?] `M-004` In review\n  - added: 2026-01-01\n");
+            Built::Ok
+        },
+        expect: &[warning(
+            "undeclared_state",
+            &[
+                ("task_id", Match::Eq("M-004")),
+                ("checkbox", Match::Eq("?")),
+            ],
+        )],
+        repair: Repair::None,
+    },
+    Case {
+        name: "dangling-relation",
+        provenance: "a duplicate closed with `duplicates:` naming a task that was then deleted",
+        covers: &["dangling_relation"],
+        build: |root| {
+            append_backlog(
+                root,
+                "- [ ] `M-004` Same as the old one\n  - added: 2026-01-01\n  - duplicates: M-099\n",
+            );
+            Built::Ok
+        },
+        expect: &[warning(
+            "dangling_relation",
+            &[
+                ("task_id", Match::Eq("M-004")),
+                ("relation", Match::Eq("duplicates")),
+                ("target", Match::Eq("M-099")),
+            ],
+        )],
+        repair: Repair::None,
+    },
     Case {
         name: "child-id-not-under-parent",
         provenance: "`fr clean` before acdd4f1 resolved a duplicated subtask with a top-level number",
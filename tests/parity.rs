@@ -110,6 +110,9 @@ file = "tracks/shelf.md"
 main = "M"
 side = "S"
 shelf = "H"
+
+[fields.area]
+type = "string"
 "#,
     )
     .unwrap();
@@ -151,6 +154,7 @@ shelf = "H"
 - [x] `M-005` Second done thing
   - added: 2025-04-21
   - resolved: 2025-04-26
+  - epic: M-001
 ",
     )
     .unwrap();
@@ -164,6 +168,7 @@ shelf = "H"
 
 - [ ] `S-001` Side task one #cc
   - added: 2025-05-01
+  - epic: M-001
 - [~] `S-002` Side task two
   - added: 2025-05-02
 
@@ -377,6 +382,9 @@ enum Projection {
     /// `cli/handlers/mod.rs`) while the human surface omits them unless asked.
     /// Excluded here so the row asserts the rest of the pair still agrees.
     ShowTaskOnly,
+    /// `fr graph`: node ids, then the `from` end of each edge — the order the
+    /// DOT surface prints its node lines and then its edge lines.
+    Graph,
 }
 
 /// Collect `field` from every object, descending through the keys that hold
@@ -478,6 +486,17 @@ fn json_sequence(v: &Value, projection: Projection) -> Vec<String> {
             }
         }
         Projection::ShowTaskOnly => collect_subtree(v, &mut out),
+        Projection::Graph => {
+            for (key, field) in [("nodes", "id"), ("edges", "from")] {
+                if let Some(Value::Array(items)) = v.get(key) {
+                    for item in items {
+                        if let Some(id) = item.get(field).and_then(Value::as_str) {
+                            out.push(id.to_string());
+                        }
+                    }
+                }
+            }
+        }
     }
     out
 }
@@ -633,6 +652,40 @@ const ROWS: &[Row] = &[
     // A leaf. The human surface prints `(no dependencies)`; both still name the
     // root, so this is not an expect_empty row.
     row(&["deps", "H-004"], TASK_IDS, Projection::DepTree),
+    // The diamond from below: H-004's dependents, each side, then the root.
+    row(
+        &["deps", "H-004", "--reverse"],
+        TASK_IDS,
+        Projection::DepTree,
+    ),
+    // The longest chain under the diamond: one side of it, then the root.
+    row(
+        &["deps", "H-001", "--critical-path"],
+        TASK_IDS,
+        Projection::Field("id"),
+    ),
+    // -- fr plan -------------------------------------------------------------
+    // Active tracks: M-002 and M-004 wait on M-001, so they land a step later.
+    row(&["plan"], TASK_IDS, Projection::ListEntries),
+    // The dependency zoo. The cycle goes to the `unordered:` line, which both
+    // surfaces keep out of the listing.
+    row(
+        &["plan", "--track", "shelf"],
+        TASK_IDS,
+        Projection::ListEntries,
+    ),
+    // -- fr graph ------------------------------------------------------------
+    // DOT, the human default. Active tracks only hold M-001's fan-out.
+    row(&["graph"], TASK_IDS, Projection::Graph),
+    // The zoo: the diamond, the cycle, and H-999 as a dashed node of its own.
+    row(&["graph", "--track", "shelf"], TASK_IDS, Projection::Graph),
+    // -- fr epic -------------------------------------------------------------
+    // The epic, then its members from both tracks in track order.
+    row(&["epic", "M-001"], TASK_IDS, Projection::Field("id")),
+    row(&["epic"], TASK_IDS, Projection::Field("id")),
+    // A task nothing names is an epic with no members: the human surface says
+    // so and both still name the task.
+    row(&["epic", "M-004"], TASK_IDS, Projection::Field("id")),
     // -- fr inbox ------------------------------------------------------------
     row(&["inbox"], INBOX_TITLES, Projection::Field("title")),
 ];
@@ -675,6 +728,7 @@ fn human_and_json_agree_on_field_order() {
   - resolved: 2025-05-09
   - spec: doc/s.md#x
   - dep: S-002
+  - relates: S-002
   - added: 2025-05-01
   - conflict: both-edited 2026-08-03T04:08:38Z
 - [ ] `S-002` Dep target
@@ -761,7 +815,7 @@ fn human_and_json_agree_on_where_an_archive_hit_came_from() {
 /// once here so a rank change that nobody propagated fails with a diff a reader
 /// can act on rather than a bare inequality.
 const FIELDS: &[&str] = &[
-    "conflict", "added", "resolved", "dep", "spec", "ref", "note",
+    "conflict", "added", "resolved", "dep", "relates", "spec", "ref", "note",
 ];
 
 /// `--json` pluralises two keys and the human surface does not.
@@ -852,6 +906,9 @@ const CLASSIFICATION: &[(&str, Class)] = &[
     ("inbox", Class::Covered),
     ("search", Class::Covered),
     ("deps", Class::Covered),
+    ("plan", Class::Covered),
+    ("epic", Class::Covered),
+    ("graph", Class::Covered),
     ("info", Class::NotAListing),
     (
         "check",
@@ -872,6 +929,10 @@ const CLASSIFICATION: &[(&str, Class)] = &[
         "recovery",
         Class::Deferred("reads the recovery log, which is empty on a healthy fixture"),
     ),
+    (
+        "config",
+        Class::Deferred("reads and writes project.toml settings, not project content"),
+    ),
     ("init", Class::Write),
     // Writes the merged file the VCS handed it. Its real interface is an exit
     // status, not a listing, and `--json` has nothing to describe.
@@ -887,10 +948,12 @@ const CLASSIFICATION: &[(&str, Class)] = &[
     ("done", Class::Write),
     ("tag", Class::Write),
     ("dep", Class::Write),
+    ("relate", Class::Write),
     ("note", Class::Write),
     ("ref", Class::Write),
     ("spec", Class::Write),
     ("title", Class::Write),
+    ("set", Class::Write),
     ("mv", Class::Write),
     ("triage", Class::Write),
     ("track", Class::Write),
@@ -1522,6 +1585,9 @@ const JSON_SURFACE: &[JsonRow] = &[
     jrow(&["stats"], Json::Yes),
     jrow(&["recent"], Json::Yes),
     jrow(&["deps", "H-001"], Json::Yes),
+    jrow(&["plan"], Json::Yes),
+    jrow(&["epic", "M-001"], Json::Yes),
+    jrow(&["graph"], Json::Yes),
     jrow(&["check"], Json::Yes),
     jrow(&["info"], Json::Yes),
     jrow(&["recovery"], Json::Yes),
@@ -1549,6 +1615,10 @@ const JSON_SURFACE: &[JsonRow] = &[
         json: Json::Yes,
     },
     jrow(&["git", "setup"], Json::Yes),
+    jrow(&["config", "get", "clean.done_threshold"], Json::Yes),
+    jrow(&["config", "list"], Json::Yes),
+    jrow(&["config", "set", "clean.done_threshold", "40"], Json::Yes),
+    jrow(&["config", "unset", "clean.done_threshold"], Json::Yes),
     // Maintenance.
     jrow(&["clean"], Json::Yes),
     jrow(&["clean", "--normalize"], Json::Yes),
@@ -1563,10 +1633,12 @@ const JSON_SURFACE: &[JsonRow] = &[
     jrow(&["done", "M-001"], Json::Yes),
     jrow(&["tag", "M-001", "add", "x"], Json::Yes),
     jrow(&["dep", "M-001", "add", "M-005"], Json::Yes),
+    jrow(&["relate", "M-001", "add", "relates", "M-005"], Json::Yes),
     jrow(&["note", "M-001", "x"], Json::Yes),
     jrow(&["ref", "M-001", "add", "frame/inbox.md"], Json::Yes),
     jrow(&["spec", "M-001", "add", "frame/inbox.md"], Json::Yes),
     jrow(&["title", "M-001", "x"], Json::Yes),
+    jrow(&["set", "M-001", "area", "x"], Json::Yes),
     jrow(&["mv", "M-001", "--top"], Json::Yes),
     jrow(&["triage", "1", "--track", "main"], Json::Yes),
     jrow(&["delete", "M-001", "--yes"], Json::Yes),
@@ -1773,12 +1845,20 @@ const DRY_RUN_SURFACE: &[DryRunRow] = &[
     drow(&["stats"], DryRun::No("a read")),
     drow(&["recent"], DryRun::No("a read")),
     drow(&["deps", "H-001"], DryRun::No("a read")),
+    drow(&["plan"], DryRun::No("a read")),
+    drow(&["epic", "M-001"], DryRun::No("a read")),
+    drow(&["graph"], DryRun::No("a read")),
     drow(&["info"], DryRun::No("a read")),
     // `fr check` is a read, but the flag is real: it previews the repairs behind
     // `--fix`. Its row is the `--fix` one below, which is what both guards find.
     drow(&["recovery", "path"], DryRun::No("a read")),
     drow(&["projects", "list"], DryRun::No("a read")),
     drow(&["actor", "list"], DryRun::No("a read")),
+    drow(
+        &["config", "get", "clean.done_threshold"],
+        DryRun::No("a read"),
+    ),
+    drow(&["config", "list"], DryRun::No("a read")),
     // Writes.
     drow(&["inbox", "a new item"], DryRun::Yes),
     drow(&["add", "main", "x"], DryRun::Yes),
@@ -1789,10 +1869,12 @@ const DRY_RUN_SURFACE: &[DryRunRow] = &[
     drow(&["done", "M-001"], DryRun::Yes),
     drow(&["tag", "M-001", "add", "x"], DryRun::Yes),
     drow(&["dep", "M-001", "add", "M-005"], DryRun::Yes),
+    drow(&["relate", "M-001", "add", "relates", "M-005"], DryRun::Yes),
     drow(&["note", "M-001", "x"], DryRun::Yes),
     drow(&["ref", "M-001", "add", "frame/inbox.md"], DryRun::Yes),
     drow(&["spec", "M-001", "add", "frame/inbox.md"], DryRun::Yes),
     drow(&["title", "M-001", "x"], DryRun::Yes),
+    drow(&["set", "M-001", "area", "x"], DryRun::Yes),
     drow(&["mv", "M-003", "--top"], DryRun::Yes),
     drow(&["mv", "M-001", "--track", "side"], DryRun::Yes),
     drow(&["triage", "1", "--track", "main"], DryRun::Yes),
@@ -1833,6 +1915,11 @@ const DRY_RUN_SURFACE: &[DryRunRow] = &[
     },
     drow(&["actor", "set", "c"], DryRun::Yes),
     drow(&["actor", "claim"], DryRun::Yes),
+    drow(
+        &["config", "set", "clean.done_threshold", "40"],
+        DryRun::Yes,
+    ),
+    drow(&["config", "unset", "agent.cc_focus"], DryRun::Yes),
     DryRunRow {
         setup: &[&["actor", "set", "b"], &["actor", "set", "c"]],
         argv: &["actor", "retire", "b"],
@@ -793,14 +793,9 @@ fn arb_task_tree() -> impl Strategy<Value = Task> {
 }
 
 fn section(kind: SectionKind, tasks: Vec<Task>, last: bool) -> TrackNode {
-    let header = match kind {
-        SectionKind::Backlog => "## Backlog",
-        SectionKind::Parked => "## Parked",
-        SectionKind::Done => "## Done",
-    };
     TrackNode::Section {
         kind,
-        header_lines: vec![header.to_string(), String::new()],
+        header_lines: vec![format!("## {kind}"), String::new()],
         tasks,
         trailing_lines: if last {
             Vec::new()